@@ -1,3 +1,4 @@
+import { Signature } from 'ethers'
 import { Contract } from '../utils/contract'
 import { CredentialDefinitionRecord, mapCredentialDefinitionRecord } from './types'
 
@@ -13,6 +14,29 @@ export class CredentialDefinitionRegistry extends Contract {
     return tx.wait()
   }
 
+  public async endorseCredentialDefinition(
+    id: string,
+    issuerId: string,
+    schemaId: string,
+    credDef: string,
+    signature: Signature,
+  ) {
+    const tx = await this.instance.endorseCredentialDefinition(
+      id,
+      issuerId,
+      schemaId,
+      credDef,
+      signature.v,
+      signature.r,
+      signature.s,
+    )
+    return tx.wait()
+  }
+
+  public async nonce(identity: string): Promise<bigint> {
+    return this.instance.nonce(identity)
+  }
+
   public async resolveCredentialDefinition(id: string): Promise<CredentialDefinitionRecord> {
     const result = await this.instance.resolveCredentialDefinition(id)
     return mapCredentialDefinitionRecord(result)
@@ -1,3 +1,4 @@
+import { Signature } from 'ethers'
 import { Contract } from '../utils/contract'
 import { DidRecord, mapDidRecord } from './types'
 
@@ -13,6 +14,15 @@ export class IndyDidRegistry extends Contract {
     return tx.wait()
   }
 
+  public async endorseDid(identity: string, did: string, document: string, signature: Signature) {
+    const tx = await this.instance.endorseDid(identity, did, document, signature.v, signature.r, signature.s)
+    return tx.wait()
+  }
+
+  public async nonce(identity: string): Promise<bigint> {
+    return this.instance.nonce(identity)
+  }
+
   public async updateDid(did: string, document: string) {
     const tx = await this.instance.updateDid(did, document)
     return tx.wait()
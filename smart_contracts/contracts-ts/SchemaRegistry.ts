@@ -1,3 +1,4 @@
+import { Signature } from 'ethers'
 import { Contract } from '../utils/contract'
 import { mapSchemaRecord, SchemaRecord } from './types'
 
@@ -13,6 +14,15 @@ export class SchemaRegistry extends Contract {
     return tx.wait()
   }
 
+  public async endorseSchema(id: string, issuerId: string, schema: string, signature: Signature) {
+    const tx = await this.instance.endorseSchema(id, issuerId, schema, signature.v, signature.r, signature.s)
+    return tx.wait()
+  }
+
+  public async nonce(identity: string): Promise<bigint> {
+    return this.instance.nonce(identity)
+  }
+
   public async resolveSchema(id: string): Promise<SchemaRecord> {
     const result = await this.instance.resolveSchema(id)
     return mapSchemaRecord(result)
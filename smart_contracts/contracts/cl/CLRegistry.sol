@@ -4,7 +4,8 @@ pragma solidity ^0.8.20;
 import { DidNotFound, IncorrectDid } from "../did/DidErrors.sol";
 import { DidMetadata } from "../did/DidTypes.sol";
 import { UniversalDidResolverInterface } from "../did/UniversalDidResolverInterface.sol";
-import { Errors } from "../utils/Errors.sol";
+import { Errors, UnsupportedOperation } from "../utils/Errors.sol";
+import { SignatureUtils } from "../utils/SignatureUtils.sol";
 import { InvalidIssuerId, IssuerHasBeenDeactivated, IssuerNotFound, UnauthorizedIssuer } from "./ClErrors.sol";
 
 contract CLRegistry {
@@ -18,17 +19,60 @@ contract CLRegistry {
      * @param id The Issuer's DID.
      */
     modifier _validIssuer(string memory id) {
-        try _didResolver.resolveMetadata(id) returns (DidMetadata memory metadata) {
-            if (msg.sender != metadata.owner && msg.sender != metadata.sender) {
-                revert UnauthorizedIssuer(msg.sender);
-            }
-            if (metadata.deactivated) revert IssuerHasBeenDeactivated(id);
+        DidMetadata memory metadata = _resolveIssuer(id);
+        if (msg.sender != metadata.owner && msg.sender != metadata.sender) {
+            revert UnauthorizedIssuer(msg.sender);
+        }
+        _;
+    }
+
+    /**
+     * @dev Resolve metadata of the active Issuer DID.
+     * @param id The Issuer's DID.
+     * @return metadata The Issuer DID metadata.
+     */
+    function _resolveIssuer(string memory id) internal view returns (DidMetadata memory metadata) {
+        try _didResolver.resolveMetadata(id) returns (DidMetadata memory issuerMetadata) {
+            if (issuerMetadata.deactivated) revert IssuerHasBeenDeactivated(id);
+            return issuerMetadata;
         } catch (bytes memory reason) {
             if (Errors.equals(reason, DidNotFound.selector)) revert IssuerNotFound(id);
             if (Errors.equals(reason, IncorrectDid.selector)) revert InvalidIssuerId(id);
 
             Errors.rethrow(reason);
         }
-        _;
+    }
+
+    /**
+     * @dev Check that the Issuer DID owner authorized an Endorser to write the object and consume the owner nonce.
+     *
+     * The Issuer DID owner signs the hash built with `SignatureUtils.endorsementHash` over the ABI encoded method
+     * parameters with the identity account key.
+     *
+     * @param nonces Nonces of the identity accounts in the registry.
+     * @param issuerId The Issuer's DID.
+     * @param method Name of the endorsed method.
+     * @param payload ABI encoded parameters of the endorsed method (object id, Issuer's DID, object specific fields).
+     * @param sigV Part of EcDSA signature of the Issuer DID owner.
+     * @param sigR Part of EcDSA signature of the Issuer DID owner.
+     * @param sigS Part of EcDSA signature of the Issuer DID owner.
+     */
+    function _checkEndorsement(
+        mapping(address => uint256) storage nonces,
+        string memory issuerId,
+        string memory method,
+        bytes memory payload,
+        uint8 sigV,
+        bytes32 sigR,
+        bytes32 sigS
+    ) internal {
+        address identity = _resolveIssuer(issuerId).owner;
+        if (msg.sender == identity) {
+            revert UnsupportedOperation(method, "Issuer DID owner must create the object instead");
+        }
+
+        bytes32 hash = SignatureUtils.endorsementHash(nonces[identity], method, payload);
+        SignatureUtils.checkSignature(identity, hash, sigV, sigR, sigS);
+        nonces[identity]++;
     }
 }
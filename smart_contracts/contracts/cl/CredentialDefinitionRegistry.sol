@@ -24,6 +24,11 @@ contract CredentialDefinitionRegistry is CredentialDefinitionRegistryInterface,
      */
     mapping(string id => CredentialDefinitionRecord credentialDefinitionRecord) private _credDefs;
 
+    /**
+     * Mapping identity account to the nonce of its next endorsed Credential Definition.
+     */
+    mapping(address identity => uint256 identityNonce) public nonce;
+
     /**
      * Checks the uniqueness of the credential definition ID
      */
@@ -64,13 +69,30 @@ contract CredentialDefinitionRegistry is CredentialDefinitionRegistryInterface,
         string calldata issuerId,
         string calldata schemaId,
         string calldata credDef
-    ) public virtual _uniqueCredDefId(id) _schemaExist(schemaId) _validIssuer(issuerId) {
-        id.validateIdSyntax(issuerId, schemaId);
-
-        _credDefs[id].credDef = credDef;
-        _credDefs[id].metadata.created = block.timestamp;
+    ) public virtual _validIssuer(issuerId) {
+        _createCredentialDefinition(id, issuerId, schemaId, credDef);
+    }
 
-        emit CredentialDefinitionCreated(id);
+    /// @inheritdoc CredentialDefinitionRegistryInterface
+    function endorseCredentialDefinition(
+        string calldata id,
+        string calldata issuerId,
+        string calldata schemaId,
+        string calldata credDef,
+        uint8 sigV,
+        bytes32 sigR,
+        bytes32 sigS
+    ) public virtual {
+        _checkEndorsement(
+            nonce,
+            issuerId,
+            "endorseCredentialDefinition",
+            abi.encode(id, issuerId, schemaId, credDef),
+            sigV,
+            sigR,
+            sigS
+        );
+        _createCredentialDefinition(id, issuerId, schemaId, credDef);
     }
 
     /// @inheritdoc CredentialDefinitionRegistryInterface
@@ -79,4 +101,18 @@ contract CredentialDefinitionRegistry is CredentialDefinitionRegistryInterface,
     ) public view virtual _credDefExist(id) returns (CredentialDefinitionRecord memory credentialDefinitionRecord) {
         return _credDefs[id];
     }
+
+    function _createCredentialDefinition(
+        string calldata id,
+        string calldata issuerId,
+        string calldata schemaId,
+        string calldata credDef
+    ) internal _uniqueCredDefId(id) _schemaExist(schemaId) {
+        id.validateIdSyntax(issuerId, schemaId);
+
+        _credDefs[id].credDef = credDef;
+        _credDefs[id].metadata.created = block.timestamp;
+
+        emit CredentialDefinitionCreated(id);
+    }
 }
@@ -37,6 +37,45 @@ interface CredentialDefinitionRegistryInterface {
         string calldata credDef
     ) external;
 
+    /**
+     * @dev Creates a new Credential Definition on behalf of the issuer DID owner.
+     * The transaction is sent by an Endorser.
+     *
+     * The issuer DID owner authorizes the Endorser by signing the hash bound to the registry and the network with the
+     * identity account key: `keccak256(abi.encodePacked(bytes1(0x19), bytes1(0), address(this), block.chainid,
+     * nonce(owner), "endorseCredentialDefinition", abi.encode(id, issuerId, schemaId, credDef)))`. The nonce of the
+     * owner is incremented on success.
+     *
+     * Once the Credential Definition is created, this function emits a `CredentialDefinitionCreated` event
+     * with the new Credential Definition's ID.
+     *
+     * This function can revert with following errors:
+     * - `CredentialDefinitionAlreadyExist`: Raised if Credential Definition with provided ID already exist.
+     * - `SchemaNotFound`: Raised if the associated schema doesn't exist.
+     * - `IssuerNotFound`: Raised if the associated issuer doesn't exist.
+     * - `IssuerHasBeenDeactivated`: Raised if the associated issuer is not active.
+     * - `InvalidCredentialDefinitionId`: Raised if the Credential Definition ID syntax is invalid.
+     * - `UnsupportedOperation`: Raised if the sender is the issuer DID owner.
+     * - `InvalidSignature`: Raised if the signature is not made by the issuer DID owner.
+     *
+     * @param id        Id of credential definition to be created.
+     * @param issuerId  Id of credential definition issuer.
+     * @param schemaId  Id of credential definition schema.
+     * @param credDef   AnonCreds credential definition as JSON string.
+     * @param sigV      Part of EcDSA signature of the issuer DID owner.
+     * @param sigR      Part of EcDSA signature of the issuer DID owner.
+     * @param sigS      Part of EcDSA signature of the issuer DID owner.
+     */
+    function endorseCredentialDefinition(
+        string calldata id,
+        string calldata issuerId,
+        string calldata schemaId,
+        string calldata credDef,
+        uint8 sigV,
+        bytes32 sigR,
+        bytes32 sigS
+    ) external;
+
     /**
      * @dev Resolve the Credential Definition associated with the given ID.
      *
@@ -49,4 +88,13 @@ interface CredentialDefinitionRegistryInterface {
     function resolveCredentialDefinition(
         string calldata id
     ) external returns (CredentialDefinitionRecord memory credentialDefinitionRecord);
+
+    /**
+     * @dev Get the nonce of the identity which must be signed to endorse its next Credential Definition.
+     *
+     * @param identity Address of the issuer DID owner.
+     *
+     * @return The current nonce of the identity.
+     */
+    function nonce(address identity) external view returns (uint256);
 }
@@ -18,6 +18,11 @@ contract SchemaRegistry is SchemaRegistryInterface, ControlledUpgradeable, CLReg
      */
     mapping(string id => SchemaRecord SchemaRecord) private _schemas;
 
+    /**
+     * Mapping identity account to the nonce of its next endorsed Schema.
+     */
+    mapping(address identity => uint256 identityNonce) public nonce;
+
     /**
      * Checks the uniqueness of the Schema ID
      */
@@ -45,12 +50,20 @@ contract SchemaRegistry is SchemaRegistryInterface, ControlledUpgradeable, CLReg
         string calldata issuerId,
         string calldata schema
     ) public virtual _uniqueSchemaId(id) _validIssuer(issuerId) {
-        id.validateIdSyntax(issuerId);
-
-        _schemas[id].schema = schema;
-        _schemas[id].metadata.created = block.timestamp;
+        _createSchema(id, issuerId, schema);
+    }
 
-        emit SchemaCreated(id);
+    /// @inheritdoc SchemaRegistryInterface
+    function endorseSchema(
+        string calldata id,
+        string calldata issuerId,
+        string calldata schema,
+        uint8 sigV,
+        bytes32 sigR,
+        bytes32 sigS
+    ) public virtual _uniqueSchemaId(id) {
+        _checkEndorsement(nonce, issuerId, "endorseSchema", abi.encode(id, issuerId, schema), sigV, sigR, sigS);
+        _createSchema(id, issuerId, schema);
     }
 
     /// @inheritdoc SchemaRegistryInterface
@@ -59,4 +72,13 @@ contract SchemaRegistry is SchemaRegistryInterface, ControlledUpgradeable, CLReg
     ) public view virtual _schemaExist(id) returns (SchemaRecord memory schemaRecord) {
         return _schemas[id];
     }
+
+    function _createSchema(string calldata id, string calldata issuerId, string calldata schema) internal {
+        id.validateIdSyntax(issuerId);
+
+        _schemas[id].schema = schema;
+        _schemas[id].metadata.created = block.timestamp;
+
+        emit SchemaCreated(id);
+    }
 }
@@ -29,6 +29,40 @@ interface SchemaRegistryInterface {
      */
     function createSchema(string calldata id, string calldata issuerId, string calldata schema) external;
 
+    /**
+     * @dev Creates a new Schema on behalf of the issuer DID owner. The transaction is sent by an Endorser.
+     *
+     * The issuer DID owner authorizes the Endorser by signing the hash bound to the registry and the network with the
+     * identity account key: `keccak256(abi.encodePacked(bytes1(0x19), bytes1(0), address(this), block.chainid,
+     * nonce(owner), "endorseSchema", abi.encode(id, issuerId, schema)))`. The nonce of the owner is incremented on
+     * success.
+     *
+     * Once the Schema is created, this function emits a `SchemaCreated` event
+     * with the new Schema ID.
+     *
+     * This function can revert with following errors:
+     * - `SchemaAlreadyExist`: Raised if Schema with provided ID already exist.
+     * - `IssuerNotFound`: Raised if the associated issuer doesn't exist.
+     * - `IssuerHasBeenDeactivated`: Raised if the associated issuer is not active.
+     * - `UnsupportedOperation`: Raised if the sender is the issuer DID owner.
+     * - `InvalidSignature`: Raised if the signature is not made by the issuer DID owner.
+     *
+     * @param id        Id of schema to be created.
+     * @param issuerId  Id of schema issuer.
+     * @param schema    AnonCreds schema as JSON string.
+     * @param sigV      Part of EcDSA signature of the issuer DID owner.
+     * @param sigR      Part of EcDSA signature of the issuer DID owner.
+     * @param sigS      Part of EcDSA signature of the issuer DID owner.
+     */
+    function endorseSchema(
+        string calldata id,
+        string calldata issuerId,
+        string calldata schema,
+        uint8 sigV,
+        bytes32 sigR,
+        bytes32 sigS
+    ) external;
+
     /**
      * @dev Resolve the Schema associated with the given ID.
      *
@@ -39,4 +73,13 @@ interface SchemaRegistryInterface {
      * @return schemaRecord Returns the Schema with Metadata.
      */
     function resolveSchema(string calldata id) external returns (SchemaRecord memory schemaRecord);
+
+    /**
+     * @dev Get the nonce of the identity which must be signed to endorse its next Schema.
+     *
+     * @param identity Address of the issuer DID owner.
+     *
+     * @return The current nonce of the identity.
+     */
+    function nonce(address identity) external view returns (uint256);
 }
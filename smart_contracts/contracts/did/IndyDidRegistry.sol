@@ -2,6 +2,8 @@
 pragma solidity ^0.8.20;
 
 import { ControlledUpgradeable } from "../upgrade/ControlledUpgradeable.sol";
+import { UnsupportedOperation } from "../utils/Errors.sol";
+import { SignatureUtils } from "../utils/SignatureUtils.sol";
 
 import { DidAlreadyExist, DidHasBeenDeactivated, DidNotFound, UnauthorizedSender } from "./DidErrors.sol";
 import { IndyDidRegistryInterface } from "./IndyDidRegistryInterface.sol";
@@ -14,6 +16,11 @@ contract IndyDidRegistry is IndyDidRegistryInterface, ControlledUpgradeable {
      */
     mapping(string did => DidRecord didRecord) private _dids;
 
+    /**
+     * @dev Mapping identity account to the nonce of its next endorsed DID.
+     */
+    mapping(address identity => uint256 identityNonce) public nonce;
+
     /**
      * Checks that DID already exists
      */
@@ -54,15 +61,25 @@ contract IndyDidRegistry is IndyDidRegistryInterface, ControlledUpgradeable {
 
     /// @inheritdoc IndyDidRegistryInterface
     function createDid(address identity, string calldata did, string calldata document) public _didNotExist(did) {
-        IndyDidValidator.validateDidSyntax(did);
-
-        _dids[did].document = document;
-        _dids[did].metadata.owner = identity;
-        _dids[did].metadata.sender = msg.sender;
-        _dids[did].metadata.created = block.timestamp;
-        _dids[did].metadata.updated = block.timestamp;
+        _createDid(identity, did, document);
+    }
 
-        emit DIDCreated(did);
+    /// @inheritdoc IndyDidRegistryInterface
+    function endorseDid(
+        address identity,
+        string calldata did,
+        string calldata document,
+        uint8 sigV,
+        bytes32 sigR,
+        bytes32 sigS
+    ) public _didNotExist(did) {
+        if (msg.sender == identity) revert UnsupportedOperation("endorseDid", "DID owner must use createDid instead");
+
+        bytes32 hash = SignatureUtils.endorsementHash(nonce[identity], "endorseDid", abi.encode(did, document));
+        SignatureUtils.checkSignature(identity, hash, sigV, sigR, sigS);
+        nonce[identity]++;
+
+        _createDid(identity, did, document);
     }
 
     /// @inheritdoc IndyDidRegistryInterface
@@ -87,4 +104,16 @@ contract IndyDidRegistry is IndyDidRegistryInterface, ControlledUpgradeable {
     function resolveDid(string calldata did) public view virtual _didExist(did) returns (DidRecord memory didRecord) {
         return _dids[did];
     }
+
+    function _createDid(address identity, string calldata did, string calldata document) internal {
+        IndyDidValidator.validateDidSyntax(did);
+
+        _dids[did].document = document;
+        _dids[did].metadata.owner = identity;
+        _dids[did].metadata.sender = msg.sender;
+        _dids[did].metadata.created = block.timestamp;
+        _dids[did].metadata.updated = block.timestamp;
+
+        emit DIDCreated(did);
+    }
 }
@@ -37,6 +37,37 @@ interface IndyDidRegistryInterface {
      */
     function createDid(address identity, string calldata did, string calldata document) external;
 
+    /**
+     * @dev Creates a new DID on behalf of its owner. The transaction is sent by an Endorser.
+     *
+     * The DID owner authorizes the Endorser by signing the hash bound to the registry and the network with the
+     * identity account key: `keccak256(abi.encodePacked(bytes1(0x19), bytes1(0), address(this), block.chainid,
+     * nonce(identity), "endorseDid", abi.encode(did, document)))`. The nonce of the identity is incremented on success.
+     *
+     * Restrictions:
+     * - DID must not already exist; otherwise, will revert with a `DidAlreadyExist` error.
+     * - Sender must not be the identity; otherwise, will revert with an `UnsupportedOperation` error.
+     * - Signature must be made by the identity account; otherwise, will revert with an `InvalidSignature` error.
+     *
+     * Events:
+     * - On successful DID creation, will emit a `DIDCreated` event.
+     *
+     * @param identity  Address of DID owner.
+     * @param did       The new DID.
+     * @param document  The new DID Document as JSON string.
+     * @param sigV      Part of EcDSA signature of the DID owner.
+     * @param sigR      Part of EcDSA signature of the DID owner.
+     * @param sigS      Part of EcDSA signature of the DID owner.
+     */
+    function endorseDid(
+        address identity,
+        string calldata did,
+        string calldata document,
+        uint8 sigV,
+        bytes32 sigR,
+        bytes32 sigS
+    ) external;
+
     /**
      * @dev Updates an existing DID.
      *
@@ -80,4 +111,13 @@ interface IndyDidRegistryInterface {
      * @return didRecord The resolved DID document associated with provided DID.
      */
     function resolveDid(string calldata did) external view returns (DidRecord memory didRecord);
+
+    /**
+     * @dev Function to get the nonce of the identity which must be signed to endorse its next DID.
+     *
+     * @param identity Address of DID owner.
+     *
+     * @return The current nonce of the identity.
+     */
+    function nonce(address identity) external view returns (uint256);
 }
@@ -20,6 +20,12 @@ error ConflictingFields(string names);
  */
 error UnsupportedOperation(string operation, string description);
 
+/**
+ * @dev Error that occurs when the provided EcDSA signature is invalid or made by an unexpected account.
+ * @param description A brief error descriptiion
+ */
+error InvalidSignature(string description);
+
 /**
  * @title Errors
  * @dev A library that provides utility functions for error handling.
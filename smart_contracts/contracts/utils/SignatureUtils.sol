@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: Apache-2.0
+pragma solidity ^0.8.20;
+
+import { InvalidSignature } from "./Errors.sol";
+
+/**
+ * @title SignatureUtils
+ * @dev A library that provides utility functions for checking signatures of identity owners.
+ */
+library SignatureUtils {
+    /**
+     * @dev Checks that the hash is signed by the identity account.
+     * @param identity Address of the account expected to sign the hash.
+     * @param hash The signed hash.
+     * @param sigV Recovery id of the signature (27 or 28).
+     * @param sigR R part of the signature.
+     * @param sigS S part of the signature.
+     */
+    function checkSignature(address identity, bytes32 hash, uint8 sigV, bytes32 sigR, bytes32 sigS) internal pure {
+        address signer = ecrecover(hash, sigV, sigR, sigS);
+        if (signer == address(0)) revert InvalidSignature("Invalid signature provided");
+        if (signer != identity) revert InvalidSignature("Signature does not match to the target identity");
+    }
+
+    /**
+     * @dev Builds the hash signed by an identity owner to authorize an Endorser to call the method of the calling
+     * contract.
+     *
+     * The hash is bound to the calling contract and the network (EIP-191 data with intended validator), so the
+     * signature cannot be replayed with other contracts or networks:
+     * `keccak256(abi.encodePacked(bytes1(0x19), bytes1(0), address(this), block.chainid, nonce, method, payload))`
+     *
+     * @param nonce Current nonce of the identity in the calling contract.
+     * @param method Name of the endorsed method.
+     * @param payload ABI encoded parameters of the endorsed method.
+     * @return hash The hash to sign.
+     */
+    function endorsementHash(
+        uint256 nonce,
+        string memory method,
+        bytes memory payload
+    ) internal view returns (bytes32 hash) {
+        return
+            keccak256(abi.encodePacked(bytes1(0x19), bytes1(0), address(this), block.chainid, nonce, method, payload));
+    }
+}
@@ -1,14 +1,17 @@
 import { expect } from 'chai'
+import { BaseWallet } from 'ethers'
+import { ethers } from 'hardhat'
 import { IndyDidRegistry } from '../../contracts-ts'
 import { createCredentialDefinitionObject } from '../../utils'
 import {
   createDid,
   createSchema,
   deployCredentialDefinitionRegistry,
+  signEndorsement,
   TestableCredentialDefinitionRegistry,
   TestableSchemaRegistry,
 } from '../utils/contract-helpers'
-import { ClErrors } from '../utils/errors'
+import { ClErrors, Errors } from '../utils/errors'
 import { TestAccounts } from '../utils/test-entities'
 
 describe('CredentialDefinitionRegistry', function () {
@@ -125,6 +128,72 @@ describe('CredentialDefinitionRegistry', function () {
     })
   })
 
+  describe('Endorse Credential Definition', function () {
+    const authorIssuerId = 'did:indy2:mainnet:GEzcdDLhCpGCYRHW82kjHd'
+    const author = ethers.Wallet.createRandom()
+
+    beforeEach(async function () {
+      await createDid(didRegistry, author.address, authorIssuerId)
+    })
+
+    function signCredentialDefinition(
+      identity: BaseWallet,
+      nonce: bigint,
+      id: string,
+      issuerId: string,
+      credDef: string,
+    ) {
+      return signEndorsement(
+        identity,
+        credentialDefinitionRegistry,
+        nonce,
+        'endorseCredentialDefinition',
+        ['string', 'string', 'string', 'string'],
+        [id, issuerId, schemaId, credDef],
+      )
+    }
+
+    it('Should create Credential Definition signed by the Issuer DID owner', async function () {
+      const { id, credDef } = createCredentialDefinitionObject({ issuerId: authorIssuerId, schemaId })
+      const nonce = await credentialDefinitionRegistry.nonce(author.address)
+      const signature = await signCredentialDefinition(author, nonce, id, authorIssuerId, credDef)
+
+      await credentialDefinitionRegistry.endorseCredentialDefinition(id, authorIssuerId, schemaId, credDef, signature)
+      const result = await credentialDefinitionRegistry.resolveCredentialDefinition(id)
+
+      expect(result.credDef).to.be.deep.equal(credDef)
+      expect(await credentialDefinitionRegistry.nonce(author.address)).to.be.equal(1n)
+    })
+
+    it('Should fail if Credential Definition is signed by another account', async function () {
+      const { id, credDef } = createCredentialDefinitionObject({ issuerId: authorIssuerId, schemaId })
+      const signature = await signCredentialDefinition(ethers.Wallet.createRandom(), 0n, id, authorIssuerId, credDef)
+
+      await expect(
+        credentialDefinitionRegistry.endorseCredentialDefinition(id, authorIssuerId, schemaId, credDef, signature),
+      ).to.be.revertedWithCustomError(credentialDefinitionRegistry.baseInstance, Errors.InvalidSignature)
+    })
+
+    it('Should fail if the Issuer DID owner signature is made for another Credential Definition', async function () {
+      const { id, credDef } = createCredentialDefinitionObject({ issuerId: authorIssuerId, schemaId })
+      const { id: id2 } = createCredentialDefinitionObject({ issuerId: authorIssuerId, schemaId, tag: 'AnotherTag' })
+      const signature = await signCredentialDefinition(author, 0n, id2, authorIssuerId, credDef)
+
+      await expect(
+        credentialDefinitionRegistry.endorseCredentialDefinition(id, authorIssuerId, schemaId, credDef, signature),
+      ).to.be.revertedWithCustomError(credentialDefinitionRegistry.baseInstance, Errors.InvalidSignature)
+    })
+
+    it('Should fail if the Issuer DID owner is the transaction sender', async function () {
+      const { id, credDef } = createCredentialDefinitionObject({ issuerId, schemaId })
+      const signature = await signCredentialDefinition(author, 0n, id, issuerId, credDef)
+
+      await expect(
+        credentialDefinitionRegistry.endorseCredentialDefinition(id, issuerId, schemaId, credDef, signature),
+      ).to.be.revertedWithCustomError(credentialDefinitionRegistry.baseInstance, Errors.UnsupportedOperation)
+    })
+  })
+
   describe('Add/Resolve Credential Definition with did:ethr Issuer', function () {
     it('Should create and resolve Credential Definition', async function () {
       const ethrIssuerId = `did:ethr:${testAccounts.trustee.account.address}`
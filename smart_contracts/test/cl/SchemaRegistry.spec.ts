@@ -1,8 +1,10 @@
 import { expect } from 'chai'
+import { BaseWallet } from 'ethers'
+import { ethers } from 'hardhat'
 import { IndyDidRegistry, SchemaRegistry } from '../../contracts-ts'
 import { createSchemaObject } from '../../utils'
-import { createDid, deploySchemaRegistry, TestableSchemaRegistry } from '../utils/contract-helpers'
-import { ClErrors } from '../utils/errors'
+import { createDid, deploySchemaRegistry, signEndorsement, TestableSchemaRegistry } from '../utils/contract-helpers'
+import { ClErrors, Errors } from '../utils/errors'
 import { TestAccounts } from '../utils/test-entities'
 
 describe('SchemaRegistry', function () {
@@ -97,6 +99,68 @@ describe('SchemaRegistry', function () {
     })
   })
 
+  describe('Endorse Schema', function () {
+    const authorIssuerId = 'did:indy2:mainnet:GEzcdDLhCpGCYRHW82kjHd'
+    const author = ethers.Wallet.createRandom()
+
+    beforeEach(async function () {
+      await createDid(didRegistry, author.address, authorIssuerId)
+    })
+
+    function signSchema(identity: BaseWallet, nonce: bigint, id: string, issuerId: string, schema: string) {
+      return signEndorsement(
+        identity,
+        schemaRegistry,
+        nonce,
+        'endorseSchema',
+        ['string', 'string', 'string'],
+        [id, issuerId, schema],
+      )
+    }
+
+    it('Should create Schema signed by the Issuer DID owner', async function () {
+      const { id, schema } = createSchemaObject({ issuerId: authorIssuerId })
+      const signature = await signSchema(author, await schemaRegistry.nonce(author.address), id, authorIssuerId, schema)
+
+      await schemaRegistry.endorseSchema(id, authorIssuerId, schema, signature)
+      const result = await schemaRegistry.resolveSchema(id)
+
+      expect(result.schema).to.be.deep.equal(schema)
+      expect(await schemaRegistry.nonce(author.address)).to.be.equal(1n)
+    })
+
+    it('Should fail if Schema is signed by another account', async function () {
+      const { id, schema } = createSchemaObject({ issuerId: authorIssuerId })
+      const signature = await signSchema(ethers.Wallet.createRandom(), 0n, id, authorIssuerId, schema)
+
+      await expect(schemaRegistry.endorseSchema(id, authorIssuerId, schema, signature)).to.be.revertedWithCustomError(
+        schemaRegistry.baseInstance,
+        Errors.InvalidSignature,
+      )
+    })
+
+    it('Should fail if the Issuer DID owner signature is made for another Schema', async function () {
+      const { id, schema } = createSchemaObject({ issuerId: authorIssuerId })
+      const { id: id2 } = createSchemaObject({ issuerId: authorIssuerId, name: 'AnotherSchema' })
+      const signature = await signSchema(author, 0n, id2, authorIssuerId, schema)
+
+      await expect(schemaRegistry.endorseSchema(id, authorIssuerId, schema, signature)).to.be.revertedWithCustomError(
+        schemaRegistry.baseInstance,
+        Errors.InvalidSignature,
+      )
+    })
+
+    it('Should fail if the Issuer DID owner is the transaction sender', async function () {
+      const { id, schema } = createSchemaObject({ issuerId })
+      const signature = await signSchema(author, 0n, id, issuerId, schema)
+
+      await expect(schemaRegistry.endorseSchema(id, issuerId, schema, signature)).to.be.revertedWithCustomError(
+        schemaRegistry.baseInstance,
+        Errors.UnsupportedOperation,
+      )
+    })
+  })
+
   describe('Add/Resolve Schema with did:ethr Issuer', function () {
     it('Should create and resolve Schema', async function () {
       const ethrIssuerId = `did:ethr:${testAccounts.trustee.account.address}`
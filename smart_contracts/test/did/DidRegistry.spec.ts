@@ -1,8 +1,10 @@
 import { expect } from 'chai'
+import { BaseWallet } from 'ethers'
+import { ethers } from 'hardhat'
 import { createBaseDidDocument } from '../../utils/entity-factories'
-import { deployIndyDidRegistry, TestableIndyDidRegistry } from '../utils/contract-helpers'
+import { deployIndyDidRegistry, signEndorsement, TestableIndyDidRegistry } from '../utils/contract-helpers'
 import { IndyDidValidator } from '../utils/contract-helpers'
-import { DidError } from '../utils/errors'
+import { DidError, Errors } from '../utils/errors'
 import { TestAccounts } from '../utils/test-entities'
 
 describe('DIDContract', function () {
@@ -83,6 +85,81 @@ describe('DIDContract', function () {
     })
   })
 
+  describe('Endorse DID', function () {
+    function signDid(identity: BaseWallet, nonce: bigint, did: string, didDocument: string) {
+      return signEndorsement(identity, didRegistry, nonce, 'endorseDid', ['string', 'string'], [did, didDocument])
+    }
+
+    it('Should create DID signed by its owner', async function () {
+      const identity = ethers.Wallet.createRandom()
+      const did: string = 'did:indy2:testnet:SEp33q43PsdP7nDATyySSH'
+      const didDocument = createBaseDidDocument(did)
+      const signature = await signDid(identity, await didRegistry.nonce(identity.address), did, didDocument)
+
+      await didRegistry.endorseDid(identity.address, did, didDocument, signature)
+
+      const { document, metadata } = await didRegistry.resolveDid(did)
+
+      expect(document).to.be.deep.equal(didDocument)
+      expect(metadata.owner).to.be.equal(identity.address)
+      expect(metadata.sender).to.be.equal(testAccounts.trustee.account.address)
+      expect(await didRegistry.nonce(identity.address)).to.be.equal(1n)
+    })
+
+    it('Should fail if the DID is signed by another account', async function () {
+      const identity = ethers.Wallet.createRandom()
+      const did: string = 'did:indy2:testnet:SEp33q43PsdP7nDATyySSH'
+      const didDocument = createBaseDidDocument(did)
+      const signature = await signDid(ethers.Wallet.createRandom(), 0n, did, didDocument)
+
+      await expect(didRegistry.endorseDid(identity.address, did, didDocument, signature)).to.be.revertedWithCustomError(
+        didRegistry.baseInstance,
+        Errors.InvalidSignature,
+      )
+    })
+
+    it('Should fail if the owner signature is made for another DID', async function () {
+      const identity = ethers.Wallet.createRandom()
+      const did: string = 'did:indy2:testnet:SEp33q43PsdP7nDATyySSH'
+      const did2: string = 'did:indy2:testnet:GEzcdDLhCpGCYRHW82kjHd'
+      const didDocument = createBaseDidDocument(did)
+      const signature = await signDid(identity, 0n, did2, didDocument)
+
+      await expect(didRegistry.endorseDid(identity.address, did, didDocument, signature)).to.be.revertedWithCustomError(
+        didRegistry.baseInstance,
+        Errors.InvalidSignature,
+      )
+    })
+
+    it('Should fail if the owner signature is made for a consumed nonce', async function () {
+      const identity = ethers.Wallet.createRandom()
+      const did: string = 'did:indy2:testnet:SEp33q43PsdP7nDATyySSH'
+      const did2: string = 'did:indy2:testnet:GEzcdDLhCpGCYRHW82kjHd'
+      const didDocument = createBaseDidDocument(did)
+      const didDocument2 = createBaseDidDocument(did2)
+      const signature = await signDid(identity, 0n, did, didDocument)
+      const signature2 = await signDid(identity, 0n, did2, didDocument2)
+
+      await didRegistry.endorseDid(identity.address, did, didDocument, signature)
+
+      await expect(
+        didRegistry.endorseDid(identity.address, did2, didDocument2, signature2),
+      ).to.be.revertedWithCustomError(didRegistry.baseInstance, Errors.InvalidSignature)
+    })
+
+    it('Should fail if the DID owner is the transaction sender', async function () {
+      const identity = testAccounts.trustee.account
+      const did: string = 'did:indy2:testnet:SEp33q43PsdP7nDATyySSH'
+      const didDocument = createBaseDidDocument(did)
+      const signature = await signDid(ethers.Wallet.createRandom(), 0n, did, didDocument)
+
+      await expect(didRegistry.endorseDid(identity.address, did, didDocument, signature)).to.be.revertedWithCustomError(
+        didRegistry.baseInstance,
+        Errors.UnsupportedOperation,
+      )
+    })
+  })
+
   describe('Update DID', function () {
     it('Should update DID document', async function () {
       const did: string = 'did:indy2:testnet:SEp33q43PsdP7nDATyySSH'
@@ -1,3 +1,5 @@
+import { BaseWallet } from 'ethers'
+import { ethers } from 'hardhat'
 import {
   CredentialDefinitionRegistry,
   IndyDidRegistry,
@@ -102,6 +104,24 @@ export async function createSchema(schemaRegistry: SchemaRegistry, issuerId: str
   return { id, schema }
 }
 
+export async function signEndorsement(
+  identity: BaseWallet,
+  registry: Contract,
+  nonce: bigint,
+  method: string,
+  types: string[],
+  values: unknown[],
+) {
+  const { chainId } = await ethers.provider.getNetwork()
+  const payload = ethers.AbiCoder.defaultAbiCoder().encode(types, values)
+  return identity.signingKey.sign(
+    ethers.solidityPackedKeccak256(
+      ['bytes1', 'bytes1', 'address', 'uint256', 'uint256', 'string', 'bytes'],
+      ['0x19', '0x00', registry.address!, chainId, nonce, method, payload],
+    ),
+  )
+}
+
 function testableContractMixin<T extends new (...args: any[]) => Contract>(Base: T) {
   return class extends Base {
     public get baseInstance() {
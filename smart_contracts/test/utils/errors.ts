@@ -1,6 +1,8 @@
 export namespace Errors {
   export const ConflictingFields = 'ConflictingFields'
   export const FieldRequired = 'FieldRequired'
+  export const InvalidSignature = 'InvalidSignature'
+  export const UnsupportedOperation = 'UnsupportedOperation'
 }
 
 export namespace AuthErrors {
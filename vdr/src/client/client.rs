@@ -1,22 +1,34 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, ops::Range};
 
 use log::{info, trace, warn};
 
 use crate::{
     client::{
         implementation::web3::{client::Web3Client, contract::Web3Contract},
-        Client, Contract, QuorumHandler,
+        nonce::NonceManager,
+        Client, Contract, QuorumClientFactory, QuorumHandler,
     },
+    contracts::auth::account_control,
     error::{VdrError, VdrResult},
-    types::{ContractConfig, ContractSpec, PingStatus, Transaction, TransactionType},
+    signer::Signer,
+    types::{
+        Block, ContractConfig, ContractSpec, EventLog, EventQuery, EventStream, PingStatus,
+        Transaction, TransactionCall, TransactionConfig, TransactionEnvelopeType,
+        TransactionReceipt, TransactionType,
+    },
     Address, QuorumConfig,
 };
 
+#[cfg(feature = "embedded")]
+use crate::client::implementation::embedded::client::EmbeddedClient;
+
 pub struct LedgerClient {
     chain_id: u64,
     client: Box<dyn Client>,
     contracts: HashMap<String, Box<dyn Contract>>,
     quorum_handler: Option<QuorumHandler>,
+    nonce_manager: NonceManager,
+    transaction_config: TransactionConfig,
 }
 
 impl LedgerClient {
@@ -42,21 +54,13 @@ impl LedgerClient {
             rpc_node
         );
 
-        let client = Box::new(Web3Client::new(rpc_node)?);
-
-        let contracts = Self::init_contracts(&client, contract_configs)?;
-
-        let quorum_handler = match quorum_config {
-            Some(quorum_config) => Some(QuorumHandler::new(quorum_config.clone())?),
-            None => None,
-        };
-
-        let ledger_client = LedgerClient {
-            chain_id,
-            client,
-            contracts,
-            quorum_handler,
-        };
+        let mut builder = LedgerClientBuilder::new(chain_id)
+            .set_client(Box::new(Web3Client::new(rpc_node)?))
+            .set_contract_configs(contract_configs);
+        if let Some(quorum_config) = quorum_config {
+            builder = builder.set_quorum_config(quorum_config);
+        }
+        let ledger_client = builder.build()?;
 
         info!(
             "Created new LedgerClient. Chain id: {}, node address: {}",
@@ -66,6 +70,31 @@ impl LedgerClient {
         Ok(ledger_client)
     }
 
+    /// Create client executing transactions against an in-process EVM instead of a network node
+    ///
+    /// # Params
+    ///  - `client` - [EmbeddedClient] holding the ledger state
+    ///  - `contract_configs` - [ContractSpec] specifications for contracts deployed in the genesis
+    ///
+    /// # Returns
+    ///  client to use for building and sending transactions
+    #[cfg(feature = "embedded")]
+    pub fn new_embedded(
+        client: EmbeddedClient,
+        contract_configs: &[ContractConfig],
+    ) -> VdrResult<LedgerClient> {
+        let chain_id = client.chain_id();
+
+        let ledger_client = LedgerClientBuilder::new(chain_id)
+            .set_client(Box::new(client))
+            .set_contract_configs(contract_configs)
+            .build()?;
+
+        info!("Created new embedded LedgerClient. Chain id: {}", chain_id);
+
+        Ok(ledger_client)
+    }
+
     /// Ping Ledger.
     ///
     /// # Returns
@@ -76,6 +105,7 @@ impl LedgerClient {
 
     /// Submit prepared transaction to the ledger
     ///     Depending on the transaction type Write/Read ethereum methods will be used
+    ///     Write transactions are rejected before sending if they are not signed by their sender
     ///
     /// #Params
     ///  `transaction` - transaction to submit
@@ -90,8 +120,16 @@ impl LedgerClient {
                     .call_transaction(transaction.to.as_ref(), &transaction.data)
                     .await
             }
-            TransactionType::Write => self.client.submit_transaction(&transaction.encode()?).await,
-        }?;
+            TransactionType::Write => {
+                transaction.verify_signer()?;
+                self.client
+                    .submit_transaction(&transaction.encode()?)
+                    .await
+                    .inspect(|_| self.on_write_submitted(transaction))
+                    .inspect_err(|err| self.on_write_failed(transaction, err))
+            }
+        }
+        .map_err(|err| self.decode_revert(&transaction.to, err))?;
 
         if let Some(quorum_handler) = &self.quorum_handler {
             quorum_handler.check(transaction, &result).await?;
@@ -100,20 +138,314 @@ impl LedgerClient {
         Ok(result)
     }
 
-    /// Get receipt for the given block hash
+    /// Sign write transaction on behalf of its sender and submit it to the ledger
+    ///     Read transactions are submitted as is
     ///
     /// # Params
-    ///  `transaction` - transaction to submit
+    ///  `transaction` - transaction to sign and submit
+    ///  `signer` - signer holding the key of the transaction sender
+    ///
+    /// # Returns
+    ///  transaction execution result (same as for [LedgerClient::submit_transaction])
+    pub async fn sign_and_submit(
+        &self,
+        transaction: &Transaction,
+        signer: &dyn Signer,
+    ) -> VdrResult<Vec<u8>> {
+        if transaction.type_ == TransactionType::Write {
+            let signature = signer.sign_transaction(transaction).await?;
+            transaction.set_signature(signature);
+        }
+
+        self.submit_transaction(transaction).await
+    }
+
+    /// Simulate execution of a write transaction before signing and submitting it
+    ///     Transaction is executed on behalf of its sender at the latest block without changing
+    ///     the ledger state. If AccountControl contract is configured, sender permissions are
+    ///     checked as well
+    ///
+    /// # Params
+    ///  `transaction` - transaction to simulate
+    ///
+    /// # Returns
+    ///  data the transaction would return or decoded reason of the failure
+    pub async fn simulate_transaction(&self, transaction: &Transaction) -> VdrResult<Vec<u8>> {
+        trace!(
+            "Simulate transaction process has started. Transaction: {:?}",
+            transaction
+        );
+
+        let from = transaction.from.as_ref().ok_or_else(|| {
+            let vdr_error =
+                VdrError::ClientInvalidTransaction("Transaction `sender` is not set".to_string());
+
+            warn!("Error: {} during simulating transaction", vdr_error);
+
+            vdr_error
+        })?;
+
+        account_control::check_transaction_allowed(self, transaction).await?;
+
+        let result = self
+            .client
+            .simulate_transaction(from, transaction.to.as_ref(), &transaction.data)
+            .await
+            .map_err(|err| self.decode_revert(&transaction.to, err))?;
+
+        trace!("Simulated transaction. Result: {:?}", result);
+
+        Ok(result)
+    }
+
+    /// Get receipt for the given transaction hash
+    ///     Logs emitted by the loaded contracts are decoded into typed events
+    ///
+    /// # Params
+    ///  `hash` - hash of the submitted transaction
+    ///
+    /// # Returns
+    ///  receipt for the given transaction
+    pub async fn get_receipt(&self, hash: &[u8]) -> VdrResult<TransactionReceipt> {
+        let receipt = self.client.get_receipt(hash).await?.decode_events(self);
+
+        trace!("Got receipt: {:?}", receipt);
+
+        Ok(receipt)
+    }
+
+    /// Subscribe to events emitted by a contract
+    ///     Requires the client supporting subscriptions (WebSocket or embedded)
+    ///
+    /// # Params
+    ///  `contract` - name of the contract emitting events
+    ///  `event` - name of the event
+    ///
+    /// # Returns
+    ///  stream of event logs emitted after the subscription
+    pub async fn subscribe_events(&self, contract: &str, event: &str) -> VdrResult<EventStream> {
+        let contract = self.contract(contract)?;
+        let query = EventQuery {
+            address: contract.address().clone(),
+            event_signature: Some(contract.event_signature(event)?),
+            indexed_topic: None,
+        };
+        self.client.subscribe_events(&query).await
+    }
+
+    /// Get events emitted by a contract in the range of blocks
+    ///     Requires the client supporting log queries (HTTP, WebSocket or embedded)
+    ///
+    /// # Params
+    ///  `contract` - name of the contract emitting events
+    ///  `event` - name of the event (all events of the contract if not set)
+    ///  `indexed_topic` - value of the first indexed event parameter (any value if not set)
+    ///  `from_block` - number of the first block of the range
+    ///  `to_block` - number of the last block of the range (the latest block if not set)
+    ///
+    /// # Returns
+    ///  event logs ordered as they were emitted
+    pub async fn query_events(
+        &self,
+        contract: &str,
+        event: Option<&str>,
+        indexed_topic: Option<Vec<u8>>,
+        from_block: u64,
+        to_block: Option<u64>,
+    ) -> VdrResult<Vec<EventLog>> {
+        let contract = self.contract(contract)?;
+        let query = EventQuery {
+            address: contract.address().clone(),
+            event_signature: event
+                .map(|event| contract.event_signature(event))
+                .transpose()?,
+            indexed_topic,
+        };
+        self.client.get_logs(&query, from_block, to_block).await
+    }
+
+    /// Execute read transaction against the ledger state at the given block
+    ///     The result is not checked against other nodes even if quorum is configured
+    ///
+    /// # Params
+    ///  `transaction` - read transaction to execute
+    ///  `block` - number of the block
+    ///
+    /// # Returns
+    ///  transaction execution result
+    pub async fn call_transaction_at(
+        &self,
+        transaction: &Transaction,
+        block: u64,
+    ) -> VdrResult<Vec<u8>> {
+        if transaction.type_ != TransactionType::Read {
+            let vdr_error = VdrError::ClientInvalidTransaction(
+                "Only read transactions can be executed at historical blocks".to_string(),
+            );
+
+            warn!("Error: {} during calling transaction", vdr_error);
+
+            return Err(vdr_error);
+        }
+
+        let result = self
+            .client
+            .call_transaction_at(transaction.to.as_ref(), &transaction.data, block)
+            .await
+            .map_err(|err| self.decode_revert(&transaction.to, err))?;
+
+        trace!(
+            "Called transaction at block: {}. Result: {:?}",
+            block,
+            result
+        );
+
+        Ok(result)
+    }
+
+    /// Get block header
+    ///
+    /// # Params
+    ///  `number` - number of the block (the latest block if not set)
+    ///
+    /// # Returns
+    ///  block number and timestamp
+    pub async fn get_block(&self, number: Option<u64>) -> VdrResult<Block> {
+        self.client.get_block(number).await
+    }
+
+    /// Decode the contract method call executed by the transaction
+    ///     Can be used on the signing side to check that the call description attached to a
+    ///     deserialized transaction matches its data
+    ///
+    /// # Params
+    ///  `transaction` - transaction to decode
     ///
     /// # Returns
-    ///  receipt for the given block
-    pub async fn get_receipt(&self, hash: &[u8]) -> VdrResult<String> {
-        self.client.get_receipt(hash).await
+    ///  decoded call or `None` if the transaction target is not one of the loaded contracts
+    pub fn decode_transaction_call(
+        &self,
+        transaction: &Transaction,
+    ) -> VdrResult<Option<TransactionCall>> {
+        let (name, contract) = match self
+            .contracts
+            .iter()
+            .find(|(_, contract)| contract.address() == &transaction.to)
+        {
+            Some(contract) => contract,
+            None => return Ok(None),
+        };
+        let call = contract
+            .decode_input(&transaction.data)?
+            .map(|(method, params)| TransactionCall {
+                contract: name.clone(),
+                method,
+                params,
+            });
+
+        trace!("Decoded transaction call: {:?}", call);
+
+        Ok(call)
     }
 
-    pub(crate) async fn get_transaction_count(&self, address: &Address) -> VdrResult<Vec<u64>> {
-        let nonce = self.client.get_transaction_count(address).await?;
-        Ok(nonce.to_vec())
+    /// Reserve a range of consecutive nonces for a batch of write transactions sent by the account
+    ///     Nonces are tracked locally, so reserved nonces are not handed out to other transactions
+    ///     built by this client. The node transaction count is used as the lower bound
+    ///
+    /// # Params
+    ///  `address` - address of the transactions sender
+    ///  `count` - number of nonces to reserve
+    ///
+    /// # Returns
+    ///  reserved nonces to set into transactions created with [Transaction::new]
+    pub async fn reserve_nonces(&self, address: &Address, count: u64) -> VdrResult<Range<u64>> {
+        let node_nonce = self.client.get_transaction_count(address).await?;
+        self.nonce_manager.reserve(address, node_nonce, count)
+    }
+
+    /// Return reserved nonces which will not be used, so they are handed out again instead of
+    /// leaving gaps blocking the following transactions of the account
+    ///     Should be used when transactions with reserved nonces are dropped and will not be sent.
+    ///     The nonce is released automatically when a write transaction is rejected by the node.
+    ///     Nonces of submitted transactions dropped by the node afterwards are resynchronized with
+    ///     the node transaction count once no other reserved nonce of the account is in flight
+    ///
+    /// # Params
+    ///  `address` - address of the transactions sender
+    ///  `nonces` - reserved nonces which will not be used
+    pub fn release_nonces(&self, address: &Address, nonces: Range<u64>) {
+        self.nonce_manager.release(address, nonces)
+    }
+
+    /// Forget locally tracked nonces of the account, so the next nonce is requested from the node
+    ///     Nonces reserved by other callers for transactions which are not submitted yet are handed
+    ///     out again, so reset must be used only when the account has no such transactions.
+    ///     Use [LedgerClient::release_nonces] to return particular nonces
+    ///
+    /// # Params
+    ///  `address` - address of the transactions sender
+    pub fn reset_nonce(&self, address: &Address) {
+        self.nonce_manager.reset(address)
+    }
+
+    pub(crate) async fn next_nonce(&self, address: &Address) -> VdrResult<Vec<u64>> {
+        let nonce = self.reserve_nonces(address, 1).await?.start;
+        Ok(vec![nonce, 0, 0, 0])
+    }
+
+    // Gas and fee values not supported by the client are left unset, so defaults of fee and gas
+    // free networks are used
+    pub(crate) async fn set_transaction_gas(&self, transaction: &mut Transaction) -> VdrResult<()> {
+        let from = transaction.from.as_ref().ok_or_else(|| {
+            VdrError::ClientInvalidTransaction("Transaction `sender` is not set".to_string())
+        })?;
+
+        transaction.gas_limit = match self.transaction_config.gas_limit {
+            Some(gas_limit) => Some(gas_limit),
+            None => Self::supported(
+                self.client
+                    .estimate_gas(from, transaction.to.as_ref(), &transaction.data)
+                    .await,
+            )
+            .map_err(|err| self.decode_revert(&transaction.to, err))?
+            .map(|estimated_gas| self.transaction_config.gas_limit_with_margin(estimated_gas)),
+        };
+        transaction.envelope = self.transaction_config.envelope();
+
+        let gas_price = Self::supported(self.client.gas_price().await)?;
+        match transaction.envelope {
+            TransactionEnvelopeType::Legacy | TransactionEnvelopeType::AccessList => {
+                transaction.gas_price = gas_price;
+            }
+            TransactionEnvelopeType::DynamicFee => {
+                // twice the current price keeps the transaction valid while base fee grows
+                let priority_fee = Self::supported(self.client.max_priority_fee_per_gas().await)?;
+                transaction.max_priority_fee_per_gas = priority_fee;
+                transaction.max_fee_per_gas = gas_price.map(|gas_price| {
+                    gas_price
+                        .saturating_mul(2)
+                        .saturating_add(priority_fee.unwrap_or_default())
+                });
+            }
+        }
+
+        trace!(
+            "Set gas to transaction. Gas limit: {:?}, gas price: {:?}, max fee: {:?}, priority fee: {:?}",
+            transaction.gas_limit,
+            transaction.gas_price,
+            transaction.max_fee_per_gas,
+            transaction.max_priority_fee_per_gas
+        );
+
+        Ok(())
+    }
+
+    fn supported<T>(result: VdrResult<T>) -> VdrResult<Option<T>> {
+        match result {
+            Ok(value) => Ok(Some(value)),
+            Err(VdrError::ClientUnsupportedOperation(_)) => Ok(None),
+            Err(err) => Err(err),
+        }
     }
 
     pub(crate) fn contract(&self, name: &str) -> VdrResult<&dyn Contract> {
@@ -129,12 +461,89 @@ impl LedgerClient {
             })
     }
 
+    pub(crate) fn has_contract(&self, name: &str) -> bool {
+        self.contracts.contains_key(name)
+    }
+
     pub(crate) fn chain_id(&self) -> u64 {
         self.chain_id
     }
 
+    fn on_write_submitted(&self, transaction: &Transaction) {
+        if let Some((from, nonce)) = Self::write_nonce(transaction) {
+            self.nonce_manager.complete(from, nonce..nonce + 1);
+        }
+    }
+
+    // Only transactions rejected by the node on sending (like `nonce too low` or `replacement
+    // underpriced`) are known not to use the nonce, so it is released. In any other case (mined
+    // with failure status, receipt waiting timeout, transport error) the transaction may have been
+    // accepted, so the nonce is resynchronized with the node on the next reservation
+    fn on_write_failed(&self, transaction: &Transaction, error: &VdrError) {
+        let Some((from, nonce)) = Self::write_nonce(transaction) else {
+            return;
+        };
+        if let VdrError::ClientTransactionRejected(_) = error {
+            warn!(
+                "Write transaction rejected with error: {:?}. Releasing nonce: {} of account: {:?}",
+                error, nonce, from
+            );
+            self.nonce_manager.release(from, nonce..nonce + 1);
+        } else {
+            warn!(
+                "Write transaction failed with error: {:?}. Resynchronizing nonce: {} of account: {:?} with the node",
+                error, nonce, from
+            );
+            self.nonce_manager.complete(from, nonce..nonce + 1);
+        }
+    }
+
+    fn write_nonce(transaction: &Transaction) -> Option<(&Address, u64)> {
+        match (transaction.from.as_ref(), transaction.nonce.as_deref()) {
+            (Some(from), Some([nonce, ..])) => Some((from, *nonce)),
+            _ => None,
+        }
+    }
+
+    // Contracts rethrow errors of the contracts they call, so all loaded specs are checked
+    // starting from the contract the transaction was sent to
+    fn decode_revert(&self, to: &Address, error: VdrError) -> VdrError {
+        let data = match error.revert_data() {
+            Some(data) => data,
+            None => return error,
+        };
+
+        let mut contracts: Vec<&dyn Contract> = self
+            .contracts
+            .values()
+            .map(|contract| contract.as_ref())
+            .collect();
+        contracts.sort_by_key(|contract| contract.address() != to);
+
+        for contract in contracts {
+            match contract.decode_error(&data) {
+                Ok(Some((name, params))) => {
+                    let vdr_error = VdrError::from_contract_error(&name, &params)
+                        .ok()
+                        .flatten()
+                        .unwrap_or_else(|| error.with_revert_message(name));
+
+                    warn!("Error: {:?} during transaction execution", vdr_error);
+
+                    return vdr_error;
+                }
+                Ok(None) => continue,
+                Err(err) => warn!("Unable to decode revert data. Err: {:?}", err),
+            }
+        }
+
+        match VdrError::revert_reason(&data) {
+            Some(reason) => error.with_revert_message(reason),
+            None => error,
+        }
+    }
+
     fn init_contracts(
-        client: &Web3Client,
         contract_configs: &[ContractConfig],
     ) -> VdrResult<HashMap<String, Box<dyn Contract>>> {
         let mut contracts: HashMap<String, Box<dyn Contract>> = HashMap::new();
@@ -157,7 +566,7 @@ impl LedgerClient {
                 }
             };
 
-            let contract = Web3Contract::new(client, &contract_config.address, &spec)?;
+            let contract = Web3Contract::new(&contract_config.address, &spec)?;
             contracts.insert(spec.name.clone(), Box::new(contract));
         }
 
@@ -165,9 +574,134 @@ impl LedgerClient {
     }
 }
 
+/// Builder of [LedgerClient] allowing to inject custom [Client] implementations
+pub struct LedgerClientBuilder {
+    chain_id: u64,
+    client: Option<Box<dyn Client>>,
+    contract_configs: Vec<ContractConfig>,
+    quorum_config: Option<QuorumConfig>,
+    quorum_client_factory: Option<Box<QuorumClientFactory>>,
+    transaction_config: TransactionConfig,
+}
+
+impl LedgerClientBuilder {
+    /// Create builder of the client for the network with the given chain id
+    pub fn new(chain_id: u64) -> LedgerClientBuilder {
+        LedgerClientBuilder {
+            chain_id,
+            client: None,
+            contract_configs: Vec::new(),
+            quorum_config: None,
+            quorum_client_factory: None,
+            transaction_config: TransactionConfig::default(),
+        }
+    }
+
+    /// Set client used for sending transactions to the ledger
+    pub fn set_client(mut self, client: Box<dyn Client>) -> LedgerClientBuilder {
+        trace!("Set client to LedgerClientBuilder");
+
+        self.client = Some(client);
+
+        self
+    }
+
+    /// Set specifications of the contracts deployed on the network
+    pub fn set_contract_configs(
+        mut self,
+        contract_configs: &[ContractConfig],
+    ) -> LedgerClientBuilder {
+        trace!(
+            "Set contract configs: {:?} to LedgerClientBuilder",
+            contract_configs
+        );
+
+        self.contract_configs = contract_configs.to_vec();
+
+        self
+    }
+
+    /// Set quorum configuration. Quorum check is not performed if configuration is not set
+    pub fn set_quorum_config(mut self, quorum_config: &QuorumConfig) -> LedgerClientBuilder {
+        trace!("Set quorum config to LedgerClientBuilder");
+
+        self.quorum_config = Some(quorum_config.clone());
+
+        self
+    }
+
+    /// Set factory creating clients for the quorum nodes.
+    /// HTTP clients are created if factory is not set
+    pub fn set_quorum_client_factory(
+        mut self,
+        quorum_client_factory: Box<QuorumClientFactory>,
+    ) -> LedgerClientBuilder {
+        trace!("Set quorum client factory to LedgerClientBuilder");
+
+        self.quorum_client_factory = Some(quorum_client_factory);
+
+        self
+    }
+
+    /// Set configuration of built write transactions (envelope type and gas limit margin).
+    /// Legacy transactions are built if configuration is not set
+    pub fn set_transaction_config(
+        mut self,
+        transaction_config: &TransactionConfig,
+    ) -> LedgerClientBuilder {
+        trace!(
+            "Set transaction config: {:?} to LedgerClientBuilder",
+            transaction_config
+        );
+
+        self.transaction_config = transaction_config.clone();
+
+        self
+    }
+
+    /// Build [LedgerClient]
+    ///
+    /// # Returns
+    ///  client to use for building and sending transactions
+    pub fn build(self) -> VdrResult<LedgerClient> {
+        let client = self.client.ok_or_else(|| {
+            let vdr_error =
+                VdrError::CommonInvalidData("Client must be set to build LedgerClient".to_string());
+
+            warn!("Error: {} during building LedgerClient", vdr_error);
+
+            vdr_error
+        })?;
+
+        let contracts = LedgerClient::init_contracts(&self.contract_configs)?;
+
+        let quorum_handler = match (self.quorum_config, self.quorum_client_factory) {
+            (Some(quorum_config), Some(client_factory)) => Some(
+                QuorumHandler::with_client_factory(quorum_config, client_factory.as_ref())?,
+            ),
+            (Some(quorum_config), None) => Some(QuorumHandler::new(quorum_config)?),
+            (None, _) => None,
+        };
+
+        let ledger_client = LedgerClient {
+            chain_id: self.chain_id,
+            client,
+            contracts,
+            quorum_handler,
+            nonce_manager: NonceManager::default(),
+            transaction_config: self.transaction_config,
+        };
+
+        trace!("Built LedgerClient. Chain id: {}", self.chain_id);
+
+        Ok(ledger_client)
+    }
+}
+
 #[cfg(test)]
 pub mod test {
     use super::*;
+    use crate::types::SignatureData;
     use async_trait::async_trait;
     use once_cell::sync::Lazy;
     use std::{env, fs};
@@ -175,12 +709,21 @@ pub mod test {
     pub const CHAIN_ID: u64 = 1337;
     pub const CONTRACTS_SPEC_BASE_PATH: &str = "../smart_contracts/artifacts/contracts/";
     pub const DID_REGISTRY_SPEC_PATH: &str = "did/IndyDidRegistry.sol/IndyDidRegistry.json";
+    pub const ETHR_DID_REGISTRY_SPEC_PATH: &str =
+        "did/EthereumExtDidRegistry.sol/EthereumExtDidRegistry.json";
+    pub const UNIVERSAL_DID_RESOLVER_SPEC_PATH: &str =
+        "did/UniversalDidResolver.sol/UniversalDidResolver.json";
     pub const SCHEMA_REGISTRY_SPEC_PATH: &str = "cl/SchemaRegistry.sol/SchemaRegistry.json";
     pub const CRED_DEF_REGISTRY_SPEC_PATH: &str =
         "cl/CredentialDefinitionRegistry.sol/CredentialDefinitionRegistry.json";
     pub const VALIDATOR_CONTROL_PATH: &str = "network/ValidatorControl.sol/ValidatorControl.json";
     pub const ROLE_CONTROL_PATH: &str = "auth/RoleControl.sol/RoleControl.json";
+    #[cfg(not(feature = "embedded_test"))]
     pub const RPC_NODE_ADDRESS: &str = "http://127.0.0.1:8545";
+    #[cfg(all(feature = "ledger_test", not(feature = "embedded_test")))]
+    pub const WS_NODE_ADDRESS: &str = "ws://127.0.0.1:8546";
+    #[cfg(feature = "embedded")]
+    pub const GENESIS_PATH: &str = "../network/config/besu/genesis.json";
     pub const CLIENT_NODE_ADDRESSES: [&str; 4] = [
         "http://127.0.0.1:21001",
         "http://127.0.0.1:21002",
@@ -192,6 +735,12 @@ pub mod test {
     pub static DID_REGISTRY_ADDRESS: Lazy<Address> =
         Lazy::new(|| Address::from("0x0000000000000000000000000000000000003333"));
 
+    pub static ETHR_DID_REGISTRY_ADDRESS: Lazy<Address> =
+        Lazy::new(|| Address::from("0x0000000000000000000000000000000000018888"));
+
+    pub static UNIVERSAL_DID_RESOLVER_ADDRESS: Lazy<Address> =
+        Lazy::new(|| Address::from("0x0000000000000000000000000000000000019999"));
+
     pub static SCHEMA_REGISTRY_ADDRESS: Lazy<Address> =
         Lazy::new(|| Address::from("0x0000000000000000000000000000000000005555"));
 
@@ -204,12 +753,60 @@ pub mod test {
     pub static ROLE_CONTROL_ADDRESS: Lazy<Address> =
         Lazy::new(|| Address::from("0x0000000000000000000000000000000000006666"));
 
+    #[cfg(feature = "embedded_test")]
+    pub const DID_VALIDATOR_PATH: &str = "did/IndyDidValidator.sol/IndyDidValidator.json";
+    #[cfg(feature = "embedded_test")]
+    pub const DID_VALIDATOR_LIBRARY: &str = "IndyDidValidator";
+    #[cfg(feature = "embedded_test")]
+    pub static DID_VALIDATOR_ADDRESS: Lazy<Address> =
+        Lazy::new(|| Address::from("0x0000000000000000000000000000000000002222"));
+
+    pub static ACCOUNT_CONTROL_ADDRESS: Lazy<Address> =
+        Lazy::new(|| Address::from("0x0000000000000000000000000000000000008888"));
+
     pub static TRUSTEE_ACC: Lazy<Address> =
         Lazy::new(|| Address::from("0xf0e2db6c8dc6c681bb5d6ad121a107f300e9b2b5"));
 
     pub static IDENTITY_ACC: Lazy<Address> =
         Lazy::new(|| Address::from("0xb9059400dcd05158ffd8ca092937989dd27b3bdc"));
 
+    pub const TRUSTEE_PRIVATE_KEY: &str =
+        "8bbbb1b345af56b560a5b20bd4b0ed1cd8cc9958a16262bc75118453cb546df7";
+
+    pub const OTHER_PRIVATE_KEY: &str =
+        "0707070707070707070707070707070707070707070707070707070707070707";
+
+    pub fn sign_hash(hash: &[u8], private_key: &str) -> SignatureData {
+        let key = k256::ecdsa::SigningKey::from_slice(&hex::decode(private_key).unwrap()).unwrap();
+        let (signature, recovery_id) = key.sign_prehash_recoverable(hash).unwrap();
+        SignatureData {
+            recovery_id: recovery_id.to_byte() as u64,
+            signature: signature.to_bytes().to_vec(),
+        }
+    }
+
+    pub fn sign(transaction: &Transaction, private_key: &str) {
+        let signature = sign_hash(&transaction.get_signing_bytes().unwrap(), private_key);
+        transaction.set_signature(signature);
+    }
+
+    /// Signer holding the trustee key only
+    pub struct TestSigner;
+
+    #[async_trait]
+    impl Signer for TestSigner {
+        async fn accounts(&self) -> VdrResult<Vec<Address>> {
+            Ok(vec![TRUSTEE_ACC.clone()])
+        }
+
+        async fn sign_hash(&self, hash: &[u8], account: &Address) -> VdrResult<SignatureData> {
+            if account != &*TRUSTEE_ACC {
+                return Err(VdrError::SignerMissingKey(account.to_string()));
+            }
+            Ok(sign_hash(hash, TRUSTEE_PRIVATE_KEY))
+        }
+    }
+
     fn build_contract_path(contract_path: &str) -> String {
         let mut cur_dir = env::current_dir().unwrap();
         cur_dir.push(CONTRACTS_SPEC_BASE_PATH);
@@ -221,13 +818,23 @@ pub mod test {
             .to_string()
     }
 
-    fn contracts() -> Vec<ContractConfig> {
+    pub fn contracts() -> Vec<ContractConfig> {
         vec![
             ContractConfig {
                 address: DID_REGISTRY_ADDRESS.to_string(),
                 spec_path: Some(build_contract_path(DID_REGISTRY_SPEC_PATH)),
                 spec: None,
             },
+            ContractConfig {
+                address: ETHR_DID_REGISTRY_ADDRESS.to_string(),
+                spec_path: Some(build_contract_path(ETHR_DID_REGISTRY_SPEC_PATH)),
+                spec: None,
+            },
+            ContractConfig {
+                address: UNIVERSAL_DID_RESOLVER_ADDRESS.to_string(),
+                spec_path: Some(build_contract_path(UNIVERSAL_DID_RESOLVER_SPEC_PATH)),
+                spec: None,
+            },
             ContractConfig {
                 address: SCHEMA_REGISTRY_ADDRESS.to_string(),
                 spec_path: Some(build_contract_path(SCHEMA_REGISTRY_SPEC_PATH)),
@@ -251,6 +858,29 @@ pub mod test {
         ]
     }
 
+    #[cfg(feature = "embedded_test")]
+    pub fn client() -> LedgerClient {
+        let client = EmbeddedClient::new(GENESIS_PATH).unwrap();
+        client
+            .set_account_control(&ACCOUNT_CONTROL_ADDRESS)
+            .unwrap();
+        // run against the compiled contracts in case they changed after the genesis was generated
+        let libraries = HashMap::from([(
+            DID_VALIDATOR_LIBRARY.to_string(),
+            DID_VALIDATOR_ADDRESS.clone(),
+        )]);
+        client
+            .load_artifact(
+                &DID_VALIDATOR_ADDRESS,
+                &build_contract_path(DID_VALIDATOR_PATH),
+                &libraries,
+            )
+            .unwrap();
+        client.load_artifacts(&contracts(), &libraries).unwrap();
+        LedgerClient::new_embedded(client, &contracts()).unwrap()
+    }
+
+    #[cfg(not(feature = "embedded_test"))]
     pub fn client() -> LedgerClient {
         LedgerClient::new(
             CHAIN_ID,
@@ -277,7 +907,7 @@ pub mod test {
             todo!()
         }
 
-        async fn get_receipt(&self, _hash: &[u8]) -> VdrResult<String> {
+        async fn get_receipt(&self, _hash: &[u8]) -> VdrResult<TransactionReceipt> {
             todo!()
         }
 
@@ -291,15 +921,12 @@ pub mod test {
     }
 
     pub fn mock_client() -> LedgerClient {
-        let mut client = LedgerClient::new(
-            CHAIN_ID,
-            RPC_NODE_ADDRESS,
-            &contracts(),
-            Some(&QuorumConfig::default()),
-        )
-        .unwrap();
-        client.client = Box::new(MockClient {});
-        client
+        LedgerClientBuilder::new(CHAIN_ID)
+            .set_client(Box::new(MockClient {}))
+            .set_contract_configs(&contracts())
+            .set_quorum_config(&QuorumConfig::default())
+            .build()
+            .unwrap()
     }
 
     mod create {
@@ -309,6 +936,570 @@ pub mod test {
         fn create_client_test() {
             client();
         }
+
+        #[test]
+        fn build_client_without_client_test() {
+            let err = LedgerClientBuilder::new(CHAIN_ID).build().err().unwrap();
+            assert!(matches!(err, VdrError::CommonInvalidData(_)));
+        }
+    }
+
+    mod injected_client {
+        use super::*;
+        use crate::{
+            client::{
+                implementation::web3::contract::test::role_control_spec,
+                MockClient as AutoMockClient,
+            },
+            contracts::auth::{Role, RoleAssignedEvent},
+            types::{EventLog, LedgerEvent, ReceiptStatus},
+        };
+        use ethabi::Token;
+        use futures::{future, stream, StreamExt};
+        use serde_json::json;
+        use std::sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        };
+
+        const CALL_RESULT: [u8; 4] = [1, 2, 3, 4];
+
+        fn call_client() -> Box<dyn Client> {
+            let mut client = AutoMockClient::new();
+            client
+                .expect_call_transaction()
+                .returning(|_, _| Ok(CALL_RESULT.to_vec()));
+            Box::new(client)
+        }
+
+        fn role_control_client(client: Box<dyn Client>) -> LedgerClient {
+            LedgerClientBuilder::new(CHAIN_ID)
+                .set_client(client)
+                .set_contract_configs(&[ContractConfig {
+                    address: ROLE_CONTROL_ADDRESS.to_string(),
+                    spec_path: None,
+                    spec: Some(role_control_spec()),
+                }])
+                .build()
+                .unwrap()
+        }
+
+        fn read_transaction() -> Transaction {
+            Transaction {
+                type_: TransactionType::Read,
+                to: ROLE_CONTROL_ADDRESS.clone(),
+                data: vec![1],
+                ..Transaction::default()
+            }
+        }
+
+        fn write_transaction() -> Transaction {
+            Transaction {
+                type_: TransactionType::Write,
+                from: Some(TRUSTEE_ACC.clone()),
+                to: ROLE_CONTROL_ADDRESS.clone(),
+                data: vec![1],
+                ..Transaction::default()
+            }
+        }
+
+        fn account_control_spec() -> ContractSpec {
+            ContractSpec {
+                name: "AccountControl".to_string(),
+                abi: json!([{
+                    "type": "function",
+                    "name": "transactionAllowed",
+                    "stateMutability": "view",
+                    "inputs": [
+                        { "name": "sender", "type": "address" },
+                        { "name": "target", "type": "address" },
+                        { "name": "value", "type": "uint256" },
+                        { "name": "gasPrice", "type": "uint256" },
+                        { "name": "gasLimit", "type": "uint256" },
+                        { "name": "payload", "type": "bytes" }
+                    ],
+                    "outputs": [{ "name": "result", "type": "bool" }]
+                }]),
+            }
+        }
+
+        #[async_std::test]
+        async fn submit_transaction_with_injected_clients_test() {
+            let created_quorum_clients = Arc::new(AtomicUsize::new(0));
+            let counter = created_quorum_clients.clone();
+            let client = LedgerClientBuilder::new(CHAIN_ID)
+                .set_client(call_client())
+                .set_quorum_config(&QuorumConfig::default())
+                .set_quorum_client_factory(Box::new(move |_node_address| {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                    Ok(call_client())
+                }))
+                .build()
+                .unwrap();
+
+            let result = client
+                .submit_transaction(&read_transaction())
+                .await
+                .unwrap();
+
+            assert_eq!(CALL_RESULT.to_vec(), result);
+            assert_eq!(
+                CLIENT_NODE_ADDRESSES.len(),
+                created_quorum_clients.load(Ordering::SeqCst)
+            );
+        }
+
+        #[async_std::test]
+        async fn submit_reverted_transaction_decodes_contract_error_test() {
+            let sender = ethabi::Address::from_slice(
+                &hex::decode(TRUSTEE_ACC.as_ref().trim_start_matches("0x")).unwrap(),
+            );
+            let mut data =
+                ethabi::short_signature("Unauthorized", &[ethabi::ParamType::Address]).to_vec();
+            data.extend(ethabi::encode(&[Token::Address(sender)]));
+            let revert = json!({
+                "code": -32000,
+                "message": "Execution reverted",
+                "data": format!("0x{}", hex::encode(data)),
+            })
+            .to_string();
+
+            let mut mock_client = AutoMockClient::new();
+            mock_client
+                .expect_call_transaction()
+                .returning(move |_, _| Err(VdrError::ClientTransactionReverted(revert.clone())));
+            let client = role_control_client(Box::new(mock_client));
+
+            let err = client
+                .submit_transaction(&read_transaction())
+                .await
+                .err()
+                .unwrap();
+
+            assert_eq!(VdrError::ContractUnauthorized(TRUSTEE_ACC.clone()), err);
+        }
+
+        #[async_std::test]
+        async fn submit_failed_transaction_decodes_contract_error_test() {
+            let sender = ethabi::Address::from_slice(
+                &hex::decode(TRUSTEE_ACC.as_ref().trim_start_matches("0x")).unwrap(),
+            );
+            let mut data =
+                ethabi::short_signature("Unauthorized", &[ethabi::ParamType::Address]).to_vec();
+            data.extend(ethabi::encode(&[Token::Address(sender)]));
+
+            let mut mock_client = AutoMockClient::new();
+            mock_client
+                .expect_submit_transaction()
+                .returning(move |_| Err(VdrError::transaction_failed(&[1; 32], Some(&data))));
+            let client = role_control_client(Box::new(mock_client));
+
+            let transaction = Transaction {
+                chain_id: CHAIN_ID,
+                nonce: Some(DEFAULT_NONCE.clone()),
+                ..write_transaction()
+            };
+            sign(&transaction, TRUSTEE_PRIVATE_KEY);
+
+            let err = client.submit_transaction(&transaction).await.err().unwrap();
+
+            assert_eq!(VdrError::ContractUnauthorized(TRUSTEE_ACC.clone()), err);
+        }
+
+        #[async_std::test]
+        async fn simulate_transaction_test() {
+            let mut mock_client = AutoMockClient::new();
+            mock_client
+                .expect_simulate_transaction()
+                .withf(|from, to, _| from == &*TRUSTEE_ACC && to == ROLE_CONTROL_ADDRESS.as_ref())
+                .returning(|_, _, _| Ok(CALL_RESULT.to_vec()));
+            let client = role_control_client(Box::new(mock_client));
+
+            let result = client
+                .simulate_transaction(&write_transaction())
+                .await
+                .unwrap();
+
+            assert_eq!(CALL_RESULT.to_vec(), result);
+        }
+
+        #[async_std::test]
+        async fn simulate_transaction_without_sender_test() {
+            let client = role_control_client(Box::new(AutoMockClient::new()));
+            let transaction = Transaction {
+                from: None,
+                ..write_transaction()
+            };
+
+            let err = client
+                .simulate_transaction(&transaction)
+                .await
+                .err()
+                .unwrap();
+
+            assert!(matches!(err, VdrError::ClientInvalidTransaction(_)));
+        }
+
+        #[async_std::test]
+        async fn simulate_not_allowed_transaction_test() {
+            let mut mock_client = AutoMockClient::new();
+            mock_client
+                .expect_call_transaction()
+                .withf(|to, _| to == ACCOUNT_CONTROL_ADDRESS.as_ref())
+                .returning(|_, _| Ok(ethabi::encode(&[Token::Bool(false)])));
+            mock_client.expect_simulate_transaction().never();
+            let client = LedgerClientBuilder::new(CHAIN_ID)
+                .set_client(Box::new(mock_client))
+                .set_contract_configs(&[
+                    ContractConfig {
+                        address: ROLE_CONTROL_ADDRESS.to_string(),
+                        spec_path: None,
+                        spec: Some(role_control_spec()),
+                    },
+                    ContractConfig {
+                        address: ACCOUNT_CONTROL_ADDRESS.to_string(),
+                        spec_path: None,
+                        spec: Some(account_control_spec()),
+                    },
+                ])
+                .build()
+                .unwrap();
+
+            let err = client
+                .simulate_transaction(&write_transaction())
+                .await
+                .err()
+                .unwrap();
+
+            assert_eq!(VdrError::ContractUnauthorized(TRUSTEE_ACC.clone()), err);
+        }
+
+        #[async_std::test]
+        async fn concurrent_nonces_are_distinct_test() {
+            let mut mock_client = AutoMockClient::new();
+            mock_client
+                .expect_get_transaction_count()
+                .returning(|_| Ok([3, 0, 0, 0]));
+            let client = role_control_client(Box::new(mock_client));
+
+            let mut nonces: Vec<u64> =
+                future::join_all((0..5).map(|_| client.next_nonce(&TRUSTEE_ACC)))
+                    .await
+                    .into_iter()
+                    .map(|nonce| nonce.unwrap()[0])
+                    .collect();
+            nonces.sort();
+            assert_eq!(vec![3, 4, 5, 6, 7], nonces);
+
+            let reserved = client.reserve_nonces(&TRUSTEE_ACC, 3).await.unwrap();
+            assert_eq!(8..11, reserved);
+        }
+
+        async fn submit_rejected_write_transaction(error: VdrError) -> Vec<u64> {
+            let mut mock_client = AutoMockClient::new();
+            mock_client
+                .expect_get_transaction_count()
+                .returning(|_| Ok([3, 0, 0, 0]));
+            mock_client
+                .expect_submit_transaction()
+                .returning(move |_| Err(error.clone()));
+            let client = role_control_client(Box::new(mock_client));
+
+            let nonces = client.reserve_nonces(&TRUSTEE_ACC, 2).await.unwrap();
+            let transaction = Transaction {
+                chain_id: CHAIN_ID,
+                nonce: Some(vec![nonces.start, 0, 0, 0]),
+                ..write_transaction()
+            };
+            sign(&transaction, TRUSTEE_PRIVATE_KEY);
+
+            client.submit_transaction(&transaction).await.err().unwrap();
+
+            client.next_nonce(&TRUSTEE_ACC).await.unwrap()
+        }
+
+        #[async_std::test]
+        async fn rejected_write_transaction_releases_nonce_test() {
+            // nonce 4 is still reserved by another transaction
+            let node_rejection = json!({ "code": -32000, "message": "nonce too low" }).to_string();
+            assert_eq!(
+                vec![3, 0, 0, 0],
+                submit_rejected_write_transaction(VdrError::ClientTransactionRejected(
+                    node_rejection
+                ))
+                .await
+            );
+        }
+
+        #[async_std::test]
+        async fn unconfirmed_write_transaction_keeps_nonce_in_flight_test() {
+            // the transaction may have been accepted and nonce 4 is still reserved
+            assert_eq!(
+                vec![5, 0, 0, 0],
+                submit_rejected_write_transaction(VdrError::ClientNodeUnreachable).await
+            );
+        }
+
+        #[async_std::test]
+        async fn dropped_write_transaction_resyncs_nonce_test() {
+            let mut mock_client = AutoMockClient::new();
+            mock_client
+                .expect_get_transaction_count()
+                .returning(|_| Ok([3, 0, 0, 0]));
+            mock_client
+                .expect_submit_transaction()
+                .returning(|_| Err(VdrError::ClientNodeUnreachable));
+            let client = role_control_client(Box::new(mock_client));
+
+            let transaction = Transaction {
+                chain_id: CHAIN_ID,
+                nonce: Some(client.next_nonce(&TRUSTEE_ACC).await.unwrap()),
+                ..write_transaction()
+            };
+            sign(&transaction, TRUSTEE_PRIVATE_KEY);
+            client.submit_transaction(&transaction).await.err().unwrap();
+
+            // the node transaction count shows the transaction has been dropped
+            assert_eq!(
+                vec![3, 0, 0, 0],
+                client.next_nonce(&TRUSTEE_ACC).await.unwrap()
+            );
+        }
+
+        #[async_std::test]
+        async fn failed_write_transaction_keeps_nonce_test() {
+            assert_eq!(
+                vec![5, 0, 0, 0],
+                submit_rejected_write_transaction(VdrError::transaction_failed(&[1; 32], None))
+                    .await
+            );
+        }
+
+        #[async_std::test]
+        async fn sign_and_submit_transaction_test() {
+            let mut mock_client = AutoMockClient::new();
+            mock_client
+                .expect_submit_transaction()
+                .times(1)
+                .returning(|_| Ok(vec![1; 32]));
+            let client = role_control_client(Box::new(mock_client));
+
+            let transaction = Transaction {
+                chain_id: CHAIN_ID,
+                nonce: Some(DEFAULT_NONCE.clone()),
+                ..write_transaction()
+            };
+
+            let hash = client
+                .sign_and_submit(&transaction, &TestSigner)
+                .await
+                .unwrap();
+            assert_eq!(vec![1; 32], hash);
+            assert_eq!(*TRUSTEE_ACC, transaction.recover_signer().unwrap());
+        }
+
+        #[async_std::test]
+        async fn sign_and_submit_without_key_test() {
+            let client = role_control_client(Box::new(AutoMockClient::new()));
+
+            let transaction = Transaction {
+                from: Some(IDENTITY_ACC.clone()),
+                chain_id: CHAIN_ID,
+                nonce: Some(DEFAULT_NONCE.clone()),
+                ..write_transaction()
+            };
+
+            let err = client
+                .sign_and_submit(&transaction, &TestSigner)
+                .await
+                .unwrap_err();
+            assert_eq!(VdrError::SignerMissingKey(IDENTITY_ACC.to_string()), err);
+        }
+
+        #[async_std::test]
+        async fn submit_transaction_signed_by_other_account_test() {
+            // submission must not reach the node
+            let client = role_control_client(Box::new(AutoMockClient::new()));
+
+            let transaction = Transaction {
+                chain_id: CHAIN_ID,
+                nonce: Some(DEFAULT_NONCE.clone()),
+                ..write_transaction()
+            };
+            sign(&transaction, OTHER_PRIVATE_KEY);
+
+            let err = client.submit_transaction(&transaction).await.err().unwrap();
+            assert!(matches!(err, VdrError::SignerMismatch(_, from) if from == *TRUSTEE_ACC));
+        }
+
+        #[async_std::test]
+        async fn set_dynamic_fee_transaction_gas_test() {
+            let mut mock_client = AutoMockClient::new();
+            mock_client
+                .expect_estimate_gas()
+                .withf(|from, to, _| from == &*TRUSTEE_ACC && to == ROLE_CONTROL_ADDRESS.as_ref())
+                .returning(|_, _, _| Ok(1000));
+            mock_client.expect_gas_price().returning(|| Ok(10));
+            mock_client
+                .expect_max_priority_fee_per_gas()
+                .returning(|| Ok(2));
+            let client = LedgerClientBuilder::new(CHAIN_ID)
+                .set_client(Box::new(mock_client))
+                .set_transaction_config(&TransactionConfig {
+                    envelope: Some(TransactionEnvelopeType::DynamicFee),
+                    gas_limit_margin: Some(50),
+                    gas_limit: None,
+                })
+                .build()
+                .unwrap();
+
+            let mut transaction = write_transaction();
+            client.set_transaction_gas(&mut transaction).await.unwrap();
+
+            assert_eq!(TransactionEnvelopeType::DynamicFee, transaction.envelope);
+            assert_eq!(Some(1500), transaction.gas_limit);
+            assert_eq!(None, transaction.gas_price);
+            assert_eq!(Some(22), transaction.max_fee_per_gas);
+            assert_eq!(Some(2), transaction.max_priority_fee_per_gas);
+        }
+
+        #[async_std::test]
+        async fn set_fixed_transaction_gas_test() {
+            let mut mock_client = AutoMockClient::new();
+            mock_client.expect_estimate_gas().never();
+            mock_client.expect_gas_price().returning(|| Ok(10));
+            let client = LedgerClientBuilder::new(CHAIN_ID)
+                .set_client(Box::new(mock_client))
+                .set_transaction_config(&TransactionConfig {
+                    gas_limit: Some(3000),
+                    ..TransactionConfig::default()
+                })
+                .build()
+                .unwrap();
+
+            let mut transaction = write_transaction();
+            client.set_transaction_gas(&mut transaction).await.unwrap();
+
+            assert_eq!(Some(3000), transaction.gas_limit);
+            assert_eq!(Some(10), transaction.gas_price);
+        }
+
+        #[async_std::test]
+        async fn set_transaction_gas_not_supported_test() {
+            let mut mock_client = AutoMockClient::new();
+            mock_client.expect_estimate_gas().returning(|_, _, _| {
+                Err(VdrError::ClientUnsupportedOperation("estimate".to_string()))
+            });
+            mock_client
+                .expect_gas_price()
+                .returning(|| Err(VdrError::ClientUnsupportedOperation("price".to_string())));
+            let client = role_control_client(Box::new(mock_client));
+
+            let mut transaction = write_transaction();
+            client.set_transaction_gas(&mut transaction).await.unwrap();
+
+            assert_eq!(TransactionEnvelopeType::Legacy, transaction.envelope);
+            assert_eq!(None, transaction.gas_limit);
+            assert_eq!(None, transaction.gas_price);
+        }
+
+        #[async_std::test]
+        async fn subscribe_events_with_injected_client_test() {
+            let spec = role_control_spec();
+            let event_signature = Web3Contract::new(ROLE_CONTROL_ADDRESS.as_ref(), &spec)
+                .unwrap()
+                .event_signature("RoleAssigned")
+                .unwrap();
+            let expected_query = EventQuery {
+                address: ROLE_CONTROL_ADDRESS.clone(),
+                event_signature: Some(event_signature),
+                indexed_topic: None,
+            };
+
+            let mut mock_client = AutoMockClient::new();
+            mock_client
+                .expect_subscribe_events()
+                .withf(move |query| query == &expected_query)
+                .returning(|query| {
+                    let log = EventLog {
+                        address: query.address.clone(),
+                        ..EventLog::default()
+                    };
+                    Ok(Box::pin(stream::iter(vec![Ok(log)])))
+                });
+            let client = role_control_client(Box::new(mock_client));
+
+            let mut events = client
+                .subscribe_events("RoleControl", "RoleAssigned")
+                .await
+                .unwrap();
+
+            let log = events.next().await.unwrap().unwrap();
+            assert_eq!(ROLE_CONTROL_ADDRESS.clone(), log.address);
+        }
+
+        #[async_std::test]
+        async fn get_receipt_decodes_events_test() {
+            let spec = role_control_spec();
+            let event_signature = Web3Contract::new(ROLE_CONTROL_ADDRESS.as_ref(), &spec)
+                .unwrap()
+                .event_signature("RoleAssigned")
+                .unwrap();
+            let address_topic = |address: &Address| {
+                let mut topic = vec![0; 12];
+                topic.extend(hex::decode(address.as_ref().trim_start_matches("0x")).unwrap());
+                topic
+            };
+            let role_assigned_log = EventLog {
+                address: ROLE_CONTROL_ADDRESS.clone(),
+                topics: vec![
+                    event_signature,
+                    address_topic(&IDENTITY_ACC),
+                    address_topic(&TRUSTEE_ACC),
+                ],
+                data: ethabi::encode(&[Token::Uint((Role::Endorser as u8).into())]),
+                ..EventLog::default()
+            };
+            let unknown_log = EventLog {
+                address: DID_REGISTRY_ADDRESS.clone(),
+                topics: vec![vec![1; 32]],
+                ..EventLog::default()
+            };
+
+            let mut mock_client = AutoMockClient::new();
+            mock_client.expect_get_receipt().returning(move |hash| {
+                Ok(TransactionReceipt {
+                    transaction_hash: hash.to_vec(),
+                    status: ReceiptStatus::Success,
+                    logs: vec![role_assigned_log.clone(), unknown_log.clone()],
+                    ..TransactionReceipt::default()
+                })
+            });
+            let client = role_control_client(Box::new(mock_client));
+
+            let receipt = client.get_receipt(&[1; 32]).await.unwrap();
+
+            assert_eq!(2, receipt.logs.len());
+            assert_eq!(
+                vec![LedgerEvent::RoleAssigned(RoleAssignedEvent {
+                    role: Role::Endorser,
+                    account: IDENTITY_ACC.clone(),
+                    sender: TRUSTEE_ACC.clone(),
+                })],
+                receipt.events
+            );
+        }
+
+        #[async_std::test]
+        async fn subscribe_events_not_supported_test() {
+            let client = role_control_client(Box::new(MockClient {}));
+            let err = client
+                .subscribe_events("RoleControl", "RoleAssigned")
+                .await
+                .err()
+                .unwrap();
+            assert!(matches!(err, VdrError::ClientUnsupportedOperation(_)));
+        }
     }
 
     #[cfg(feature = "ledger_test")]
@@ -334,7 +1525,7 @@ pub mod test {
             .unwrap();
             match client.ping().await.unwrap().status {
                 Status::Err { .. } => {}
-                Status::Ok => assert!(false, "Ping status expected to be `Err`."),
+                Status::Ok => panic!("Ping status expected to be `Err`."),
             }
         }
     }
@@ -1,7 +1,8 @@
 use ethereum_types::U256;
 use once_cell::sync::Lazy;
 
-/// As the network is fee and gas free, use the max available gas value for each transaction
+/// Gas values used for transactions which gas is not estimated (fee and gas free networks):
+/// the max available gas value with zero price
 pub const GAS: u64 = 9_007_199_254_719_927;
 pub static GAS_PRICE: Lazy<U256> = Lazy::new(|| U256([0, 0, 0, 0]));
 pub static GAS_LIMIT: Lazy<U256> = Lazy::new(|| U256([GAS, 0, 0, 0]));
@@ -0,0 +1,1498 @@
+use crate::{
+    client::{
+        implementation::embedded::genesis::{
+            parse_address, parse_bytes, parse_storage_word, parse_u256, Genesis,
+        },
+        implementation::web3::client::convert_receipt,
+        Client,
+    },
+    error::{VdrError, VdrResult},
+    types::{
+        Block, ContractConfig, EventLog, EventQuery, EventStream, PingStatus, TransactionReceipt,
+    },
+    Address, Transaction,
+};
+
+use async_trait::async_trait;
+use ethabi::Token;
+use ethereum::{
+    EIP1559TransactionMessage, EIP2930TransactionMessage, EnvelopedDecodable,
+    LegacyTransactionMessage, TransactionAction, TransactionV2,
+};
+use ethereum_types::{H160, H256, U256, U64};
+use futures::channel::mpsc::{self, UnboundedSender};
+use log::{info, trace, warn};
+use revm::{
+    db::{AccountState, InMemoryDB},
+    primitives::{
+        AccountInfo, Address as EvmAddress, BlockEnv, Bytecode, Bytes as EvmBytes, ExecutionResult,
+        Log as EvmLog, Output, ResultAndState, SpecId, State, TransactTo, TxEnv, B256,
+        U256 as EvmU256,
+    },
+    DatabaseCommit, DatabaseRef, Evm,
+};
+use serde_json::json;
+use std::{collections::HashMap, sync::Mutex};
+use web3::{
+    signing::{keccak256, recover},
+    types::{Bytes, Index, Log, TransactionReceipt as EthTransactionReceipt, H2048},
+};
+
+/// Contracts deployed as ERC1967 proxies keep the address of the implementation in this slot
+const PROXY_IMPLEMENTATION_SLOT: &str =
+    "0x360894a13ba1a3210667c828492db98dca3e2076cc3735a920a3ca505d382bbc";
+const TRANSACTION_ALLOWED_SIGNATURE: &str =
+    "transactionAllowed(address,address,uint256,uint256,uint256,bytes)";
+const EXECUTION_REVERTED_CODE: i64 = -32000;
+
+/// In-process ledger client executing transactions against a local EVM instance.
+///
+/// The initial state (compiled contracts, their storage and account balances) is taken from the
+/// Besu genesis file so that contracts are deployed at the same addresses as on the real network.
+/// Every submitted write transaction is mined into its own block. The values changed by every block
+/// are kept, so read transactions can be executed at historical blocks.
+pub struct EmbeddedClient {
+    chain_id: u64,
+    state: Mutex<EmbeddedState>,
+}
+
+struct EmbeddedState {
+    db: InMemoryDB,
+    block: BlockEnv,
+    block_period: u64,
+    // previous blocks indexed by block number with the values changed by the next block
+    history: Vec<(BlockEnv, BlockReverts)>,
+    account_control: Option<EvmAddress>,
+    transactions: HashMap<H256, MinedTransaction>,
+    subscriptions: Vec<(EventQuery, UnboundedSender<VdrResult<EventLog>>)>,
+}
+
+/// Values of the accounts and storage slots before a block changed them
+#[derive(Default)]
+struct BlockReverts {
+    accounts: HashMap<EvmAddress, Option<AccountInfo>>,
+    storage: HashMap<(EvmAddress, EvmU256), EvmU256>,
+}
+
+impl BlockReverts {
+    fn new(db: &InMemoryDB, state: &State) -> BlockReverts {
+        let mut reverts = BlockReverts::default();
+        for (address, account) in state.iter().filter(|(_, account)| account.is_touched()) {
+            let previous = db.accounts.get(address);
+            reverts
+                .accounts
+                .insert(*address, previous.and_then(|account| account.info()));
+            if account.is_selfdestructed() {
+                // all the storage of a destroyed account is cleared
+                for (index, value) in previous.iter().flat_map(|account| account.storage.iter()) {
+                    reverts.storage.insert((*address, *index), *value);
+                }
+            }
+            for (index, slot) in account.storage.iter().filter(|(_, slot)| slot.is_changed()) {
+                reverts
+                    .storage
+                    .entry((*address, *index))
+                    .or_insert(slot.previous_or_original_value);
+            }
+        }
+        reverts
+    }
+}
+
+/// State of a previous block: the latest state with the values changed by the following blocks reverted
+struct HistoricalDb<'a> {
+    db: &'a InMemoryDB,
+    // reverts of the following blocks in the order of mining
+    reverts: Vec<&'a BlockReverts>,
+}
+
+impl DatabaseRef for HistoricalDb<'_> {
+    type Error = <InMemoryDB as DatabaseRef>::Error;
+
+    fn basic_ref(&self, address: EvmAddress) -> Result<Option<AccountInfo>, Self::Error> {
+        // the earliest following block which changed the account keeps its value at this block
+        match self
+            .reverts
+            .iter()
+            .find_map(|reverts| reverts.accounts.get(&address))
+        {
+            Some(info) => Ok(info.clone()),
+            None => self.db.basic_ref(address),
+        }
+    }
+
+    fn code_by_hash_ref(&self, code_hash: B256) -> Result<Bytecode, Self::Error> {
+        self.db.code_by_hash_ref(code_hash)
+    }
+
+    fn storage_ref(&self, address: EvmAddress, index: EvmU256) -> Result<EvmU256, Self::Error> {
+        for reverts in &self.reverts {
+            if let Some(value) = reverts.storage.get(&(address, index)) {
+                return Ok(*value);
+            }
+            if let Some(None) = reverts.accounts.get(&address) {
+                // the account did not exist yet
+                return Ok(EvmU256::ZERO);
+            }
+        }
+        self.db.storage_ref(address, index)
+    }
+
+    fn block_hash_ref(&self, number: EvmU256) -> Result<B256, Self::Error> {
+        self.db.block_hash_ref(number)
+    }
+}
+
+struct MinedTransaction {
+    from: H160,
+    to: Option<H160>,
+    nonce: U256,
+    data: Vec<u8>,
+    receipt: EthTransactionReceipt,
+}
+
+struct DecodedTransaction {
+    hash: H256,
+    from: H160,
+    to: Option<H160>,
+    nonce: U256,
+    data: Vec<u8>,
+    transaction_type: Option<U64>,
+    tx_env: TxEnv,
+}
+
+impl EmbeddedClient {
+    /// Create embedded client with the state defined by Besu genesis file
+    ///
+    /// # Params
+    ///  - `genesis_path` - path to the genesis file of the network
+    ///
+    /// # Returns
+    ///  client executing transactions locally
+    pub fn new(genesis_path: &str) -> VdrResult<EmbeddedClient> {
+        trace!(
+            "Started creating new EmbeddedClient. Genesis path: {}",
+            genesis_path
+        );
+
+        let genesis = Genesis::from_file(genesis_path)?;
+        let client = Self::from_genesis(&genesis)?;
+
+        info!(
+            "Created new EmbeddedClient. Genesis path: {}, chain id: {}",
+            genesis_path, client.chain_id
+        );
+
+        Ok(client)
+    }
+
+    /// Create embedded client with the state defined by parsed genesis
+    pub fn from_genesis(genesis: &Genesis) -> VdrResult<EmbeddedClient> {
+        let mut db = InMemoryDB::default();
+        for (address, account) in genesis.alloc.iter() {
+            let address = parse_address(address)?;
+            let code = match account.code.as_ref() {
+                Some(code) => Some(Bytecode::new_raw(parse_bytes(code)?)),
+                None => None,
+            };
+            let mut info = AccountInfo {
+                balance: parse_u256(account.balance.as_deref().unwrap_or("0"))?,
+                nonce: parse_u256(account.nonce.as_deref().unwrap_or("0"))?.to::<u64>(),
+                ..AccountInfo::default()
+            };
+            if let Some(code) = code {
+                info.code_hash = code.hash_slow();
+                info.code = Some(code);
+            }
+            db.insert_account_info(address, info);
+
+            for (slot, value) in account.storage.iter().flatten() {
+                db.insert_account_storage(
+                    address,
+                    parse_storage_word(slot)?,
+                    parse_storage_word(value)?,
+                )
+                .map_err(|err| VdrError::ClientUnexpectedError(format!("{:?}", err)))?;
+            }
+        }
+
+        let block = BlockEnv {
+            number: EvmU256::ZERO,
+            timestamp: parse_u256(&genesis.timestamp)?,
+            gas_limit: parse_u256(&genesis.gas_limit)?,
+            basefee: EvmU256::ZERO,
+            ..BlockEnv::default()
+        };
+
+        Ok(EmbeddedClient {
+            chain_id: genesis.config.chain_id,
+            state: Mutex::new(EmbeddedState {
+                db,
+                block,
+                block_period: genesis.block_period(),
+                history: Vec::new(),
+                account_control: None,
+                transactions: HashMap::new(),
+                subscriptions: Vec::new(),
+            }),
+        })
+    }
+
+    /// Set the code of a contract to the `deployedBytecode` of a Hardhat artifact
+    ///
+    /// If `address` points to an ERC1967 proxy, the code of its implementation is replaced,
+    /// so the proxy storage stays untouched.
+    ///
+    /// # Params
+    ///  - `address` - address of the deployed contract (as specified in the genesis) or a new address to deploy to
+    ///  - `artifact_path` - path to the Hardhat artifact of the contract
+    ///  - `libraries` - addresses of libraries to link, mapped by the library name
+    pub fn load_artifact(
+        &self,
+        address: &Address,
+        artifact_path: &str,
+        libraries: &HashMap<String, Address>,
+    ) -> VdrResult<()> {
+        let code = read_artifact_code(artifact_path, libraries)?;
+        let address = parse_address(address.as_ref())?;
+
+        let mut state = self.state()?;
+        let implementation_slot = parse_u256(PROXY_IMPLEMENTATION_SLOT)?;
+        let implementation = state
+            .db
+            .load_account(address)
+            .map_err(|err| VdrError::ClientUnexpectedError(format!("{:?}", err)))?
+            .storage
+            .get(&implementation_slot)
+            .filter(|implementation| !implementation.is_zero())
+            .map(|implementation| EvmAddress::from_word(implementation.to_be_bytes::<32>().into()));
+        let target = implementation.unwrap_or(address);
+
+        let account = state
+            .db
+            .load_account(target)
+            .map_err(|err| VdrError::ClientUnexpectedError(format!("{:?}", err)))?;
+        let code = Bytecode::new_raw(code);
+        account.info.code_hash = code.hash_slow();
+        account.info.code = Some(code.clone());
+        // code can be deployed to a new address as well
+        if account.account_state == AccountState::NotExisting {
+            account.account_state = AccountState::None;
+        }
+        state.db.contracts.insert(code.hash_slow(), code);
+
+        trace!(
+            "Loaded artifact: {} into contract: {:?}",
+            artifact_path,
+            target
+        );
+
+        Ok(())
+    }
+
+    /// Replace the code of the contracts deployed in the genesis with the artifacts referenced by contract configs
+    ///
+    /// Allows running the embedded ledger against contracts compiled after the genesis was generated.
+    /// Configs without `spec_path` and contracts not deployed in the genesis are skipped.
+    ///
+    /// # Params
+    ///  - `contract_configs` - [ContractConfig] with paths to the Hardhat artifacts of the contracts
+    ///  - `libraries` - addresses of libraries to link, mapped by the library name
+    pub fn load_artifacts(
+        &self,
+        contract_configs: &[ContractConfig],
+        libraries: &HashMap<String, Address>,
+    ) -> VdrResult<()> {
+        for contract_config in contract_configs {
+            let Some(spec_path) = contract_config.spec_path.as_deref() else {
+                continue;
+            };
+            let address = Address::from(contract_config.address.as_str());
+            if !self.is_deployed(&address)? {
+                trace!(
+                    "Skipped loading artifact: {} as contract: {:?} is not deployed",
+                    spec_path,
+                    address
+                );
+                continue;
+            }
+            self.load_artifact(&address, spec_path, libraries)?;
+        }
+
+        info!(
+            "Loaded artifacts of {} contracts into EmbeddedClient",
+            contract_configs.len()
+        );
+
+        Ok(())
+    }
+
+    fn is_deployed(&self, address: &Address) -> VdrResult<bool> {
+        let address = parse_address(address.as_ref())?;
+        let account = self
+            .state()?
+            .db
+            .load_account(address)
+            .map_err(|err| VdrError::ClientUnexpectedError(format!("{:?}", err)))?
+            .info
+            .clone();
+        Ok(!account.is_empty_code_hash())
+    }
+
+    /// Enable account permissioning via AccountControl contract (the same way as Besu does it)
+    ///
+    /// # Params
+    ///  - `address` - address of the deployed AccountControl contract
+    pub fn set_account_control(&self, address: &Address) -> VdrResult<()> {
+        let address = parse_address(address.as_ref())?;
+        self.state()?.account_control = Some(address);
+        Ok(())
+    }
+
+    /// Get chain id defined in the genesis
+    pub fn chain_id(&self) -> u64 {
+        self.chain_id
+    }
+
+    fn call(
+        &self,
+        from: EvmAddress,
+        to: &str,
+        transaction: &[u8],
+        block: Option<u64>,
+    ) -> VdrResult<(Vec<u8>, u64)> {
+        trace!(
+            "Call transaction process has started. Transaction: {:?}, block: {:?}",
+            transaction,
+            block
+        );
+
+        let address = parse_address(to).map_err(|_| {
+            let vdr_error = VdrError::ClientInvalidTransaction(format!(
+                "Invalid transaction target address {:?}",
+                to
+            ));
+
+            warn!(
+                "Error: {} during calling transaction: {:?}",
+                vdr_error, transaction
+            );
+
+            vdr_error
+        })?;
+
+        let response = self.state()?.execute_call(
+            self.chain_id,
+            from,
+            address,
+            transaction.to_vec(),
+            block,
+        )?;
+
+        trace!("Called transaction: {:?}", transaction);
+
+        Ok(response)
+    }
+
+    fn state(&self) -> VdrResult<std::sync::MutexGuard<'_, EmbeddedState>> {
+        self.state
+            .lock()
+            .map_err(|err| VdrError::ClientInvalidState(err.to_string()))
+    }
+
+    fn decode_transaction(&self, transaction: &[u8]) -> VdrResult<DecodedTransaction> {
+        let transaction = TransactionV2::decode(transaction).map_err(|err| {
+            VdrError::ClientInvalidTransaction(format!("Unable to decode transaction: {:?}", err))
+        })?;
+        let hash = transaction.hash();
+
+        let (message_hash, recovery_id, r, s, chain_id, transaction_type) = match &transaction {
+            TransactionV2::Legacy(tx) => (
+                LegacyTransactionMessage::from(tx.clone()).hash(),
+                tx.signature.standard_v(),
+                *tx.signature.r(),
+                *tx.signature.s(),
+                tx.signature.chain_id(),
+                None,
+            ),
+            TransactionV2::EIP2930(tx) => (
+                EIP2930TransactionMessage::from(tx.clone()).hash(),
+                tx.odd_y_parity as u8,
+                tx.r,
+                tx.s,
+                Some(tx.chain_id),
+                Some(U64::from(1)),
+            ),
+            TransactionV2::EIP1559(tx) => (
+                EIP1559TransactionMessage::from(tx.clone()).hash(),
+                tx.odd_y_parity as u8,
+                tx.r,
+                tx.s,
+                Some(tx.chain_id),
+                Some(U64::from(2)),
+            ),
+        };
+
+        let signature = [r.as_bytes(), s.as_bytes()].concat();
+        let from =
+            recover(message_hash.as_bytes(), &signature, recovery_id as i32).map_err(|err| {
+                VdrError::ClientInvalidTransaction(format!(
+                    "Unable to recover transaction sender: {:?}",
+                    err
+                ))
+            })?;
+
+        let (nonce, gas_limit, gas_price, priority_fee, action, value, input, access_list) =
+            match transaction {
+                TransactionV2::Legacy(tx) => (
+                    tx.nonce,
+                    tx.gas_limit,
+                    tx.gas_price,
+                    None,
+                    tx.action,
+                    tx.value,
+                    tx.input,
+                    vec![],
+                ),
+                TransactionV2::EIP2930(tx) => (
+                    tx.nonce,
+                    tx.gas_limit,
+                    tx.gas_price,
+                    None,
+                    tx.action,
+                    tx.value,
+                    tx.input,
+                    tx.access_list,
+                ),
+                TransactionV2::EIP1559(tx) => (
+                    tx.nonce,
+                    tx.gas_limit,
+                    tx.max_fee_per_gas,
+                    Some(tx.max_priority_fee_per_gas),
+                    tx.action,
+                    tx.value,
+                    tx.input,
+                    tx.access_list,
+                ),
+            };
+
+        let to = match action {
+            TransactionAction::Call(to) => Some(to),
+            TransactionAction::Create => None,
+        };
+
+        let tx_env = TxEnv {
+            caller: to_evm_address(&from),
+            gas_limit: gas_limit.low_u64(),
+            gas_price: to_evm_u256(&gas_price),
+            gas_priority_fee: priority_fee.as_ref().map(to_evm_u256),
+            transact_to: match to {
+                Some(to) => TransactTo::Call(to_evm_address(&to)),
+                None => TransactTo::create(),
+            },
+            value: to_evm_u256(&value),
+            data: EvmBytes::from(input.clone()),
+            nonce: Some(nonce.low_u64()),
+            chain_id,
+            access_list: access_list
+                .into_iter()
+                .map(|item| {
+                    (
+                        to_evm_address(&item.address),
+                        item.storage_keys
+                            .iter()
+                            .map(|key| EvmU256::from_be_bytes(key.to_fixed_bytes()))
+                            .collect(),
+                    )
+                })
+                .collect(),
+            ..TxEnv::default()
+        };
+
+        Ok(DecodedTransaction {
+            hash,
+            from,
+            to,
+            nonce,
+            data: input,
+            transaction_type,
+            tx_env,
+        })
+    }
+}
+
+impl EmbeddedState {
+    fn snapshot(&self, number: Option<u64>) -> VdrResult<(&BlockEnv, HistoricalDb<'_>)> {
+        let latest = HistoricalDb {
+            db: &self.db,
+            reverts: Vec::new(),
+        };
+        let number = match number {
+            Some(number) if number < self.history.len() as u64 => {
+                let history = &self.history[number as usize..];
+                let db = HistoricalDb {
+                    reverts: history.iter().map(|(_, reverts)| reverts).collect(),
+                    ..latest
+                };
+                return Ok((&history[0].0, db));
+            }
+            Some(number) => number,
+            None => return Ok((&self.block, latest)),
+        };
+        if number != self.block.number.to::<u64>() {
+            let vdr_error = VdrError::ClientInvalidResponse(format!("Block {} not found", number));
+
+            warn!("Error: {} getting block state", vdr_error);
+
+            return Err(vdr_error);
+        }
+        Ok((&self.block, latest))
+    }
+
+    fn execute(
+        &self,
+        chain_id: u64,
+        tx_env: TxEnv,
+        block: Option<u64>,
+    ) -> VdrResult<ResultAndState> {
+        let (block, db) = self.snapshot(block)?;
+        let mut evm = Evm::builder()
+            .with_ref_db(db)
+            .with_spec_id(SpecId::LONDON)
+            .modify_cfg_env(|cfg| cfg.chain_id = chain_id)
+            .with_block_env(block.clone())
+            .with_tx_env(tx_env)
+            .build();
+
+        evm.transact().map_err(|err| {
+            let vdr_error = VdrError::ClientInvalidTransaction(format!("{:?}", err));
+
+            warn!("Error: {} during transaction execution", vdr_error);
+
+            vdr_error
+        })
+    }
+
+    fn check_transaction_allowed(
+        &self,
+        chain_id: u64,
+        transaction: &DecodedTransaction,
+    ) -> VdrResult<()> {
+        let account_control = match self.account_control {
+            Some(account_control) => account_control,
+            None => return Ok(()),
+        };
+
+        let target = transaction.to.unwrap_or_default();
+        let mut data = keccak256(TRANSACTION_ALLOWED_SIGNATURE.as_bytes())[..4].to_vec();
+        data.extend(ethabi::encode(&[
+            Token::Address(transaction.from),
+            Token::Address(target),
+            Token::Uint(from_evm_u256(&transaction.tx_env.value)),
+            Token::Uint(from_evm_u256(&transaction.tx_env.gas_price)),
+            Token::Uint(U256::from(transaction.tx_env.gas_limit)),
+            Token::Bytes(transaction.data.clone()),
+        ]));
+
+        let output = self.call(chain_id, EvmAddress::ZERO, account_control, data, None)?;
+        let allowed = output.last().map(|byte| *byte == 1).unwrap_or(false);
+        if !allowed {
+            let vdr_error = VdrError::ClientInvalidTransaction(format!(
+                "Sender account {:?} not authorized to send transactions",
+                transaction.from
+            ));
+
+            warn!("Error: {} during transaction permission check", vdr_error);
+
+            return Err(vdr_error);
+        }
+
+        Ok(())
+    }
+
+    fn call(
+        &self,
+        chain_id: u64,
+        from: EvmAddress,
+        to: EvmAddress,
+        data: Vec<u8>,
+        block: Option<u64>,
+    ) -> VdrResult<Vec<u8>> {
+        self.execute_call(chain_id, from, to, data, block)
+            .map(|(output, _)| output)
+    }
+
+    fn execute_call(
+        &self,
+        chain_id: u64,
+        from: EvmAddress,
+        to: EvmAddress,
+        data: Vec<u8>,
+        block: Option<u64>,
+    ) -> VdrResult<(Vec<u8>, u64)> {
+        let tx_env = TxEnv {
+            caller: from,
+            gas_limit: self.block.gas_limit.to::<u64>(),
+            gas_price: EvmU256::ZERO,
+            transact_to: TransactTo::Call(to),
+            data: EvmBytes::from(data),
+            nonce: None,
+            chain_id: None,
+            ..TxEnv::default()
+        };
+
+        match self.execute(chain_id, tx_env, block)?.result {
+            // gas refund is applied after execution, so it is required in the gas limit
+            ExecutionResult::Success {
+                output,
+                gas_used,
+                gas_refunded,
+                ..
+            } => Ok((output.into_data().to_vec(), gas_used + gas_refunded)),
+            ExecutionResult::Revert { output, .. } => Err(execution_reverted(&output)),
+            ExecutionResult::Halt { reason, .. } => Err(VdrError::ClientTransactionReverted(
+                json!({
+                    "code": EXECUTION_REVERTED_CODE,
+                    "message": format!("Execution halted: {:?}", reason),
+                })
+                .to_string(),
+            )),
+        }
+    }
+
+    fn notify_subscribers(&mut self, logs: &[Log]) {
+        let events: Vec<EventLog> = logs.iter().map(convert_event_log).collect();
+        // subscriptions with dropped streams are removed on the first failed delivery
+        self.subscriptions.retain(|(query, sender)| {
+            events
+                .iter()
+                .filter(|event| query.matches(event))
+                .all(|event| sender.unbounded_send(Ok(event.clone())).is_ok())
+        });
+    }
+
+    fn mine(&mut self, chain_id: u64, transaction: DecodedTransaction) -> VdrResult<H256> {
+        let previous_block = self.block.clone();
+        self.block.number += EvmU256::from(1);
+        self.block.timestamp += EvmU256::from(self.block_period);
+
+        // execution does not change the state, so only the block has to be restored on failure
+        let ResultAndState { result, state } =
+            match self.execute(chain_id, transaction.tx_env.clone(), None) {
+                Ok(result) => result,
+                Err(err) => {
+                    self.block = previous_block;
+                    return Err(err);
+                }
+            };
+        self.history
+            .push((previous_block, BlockReverts::new(&self.db, &state)));
+        self.db.commit(state);
+
+        let block_number = self.block.number.to::<u64>();
+        let block_hash = H256::from(keccak256(
+            &[
+                block_number.to_be_bytes().as_slice(),
+                transaction.hash.as_bytes(),
+            ]
+            .concat(),
+        ));
+
+        let (status, gas_used, logs, contract_address, revert_data) = match result {
+            ExecutionResult::Success {
+                gas_used,
+                logs,
+                output,
+                ..
+            } => {
+                let contract_address = match output {
+                    Output::Create(_, address) => address.map(|address| from_evm_address(&address)),
+                    Output::Call(_) => None,
+                };
+                (1, gas_used, logs, contract_address, None)
+            }
+            ExecutionResult::Revert { gas_used, output } => {
+                (0, gas_used, vec![], None, Some(output.to_vec()))
+            }
+            ExecutionResult::Halt { gas_used, .. } => (0, gas_used, vec![], None, None),
+        };
+
+        let logs = logs
+            .iter()
+            .enumerate()
+            .map(|(index, log)| convert_log(log, index, block_number, &block_hash, &transaction))
+            .collect();
+
+        let receipt = EthTransactionReceipt {
+            transaction_hash: transaction.hash,
+            transaction_index: Index::from(0),
+            block_hash: Some(block_hash),
+            block_number: Some(U64::from(block_number)),
+            from: transaction.from,
+            to: transaction.to,
+            cumulative_gas_used: U256::from(gas_used),
+            gas_used: Some(U256::from(gas_used)),
+            contract_address,
+            logs,
+            status: Some(U64::from(status)),
+            root: None,
+            logs_bloom: H2048::default(),
+            transaction_type: transaction.transaction_type,
+            effective_gas_price: Some(from_evm_u256(&transaction.tx_env.gas_price)),
+        };
+
+        trace!("Mined transaction. Receipt: {:?}", receipt);
+
+        self.notify_subscribers(&receipt.logs);
+
+        self.transactions.insert(
+            transaction.hash,
+            MinedTransaction {
+                from: transaction.from,
+                to: transaction.to,
+                nonce: transaction.nonce,
+                data: transaction.data,
+                receipt,
+            },
+        );
+
+        if status == 0 {
+            let vdr_error =
+                VdrError::transaction_failed(transaction.hash.as_bytes(), revert_data.as_deref());
+
+            warn!("Error: {} during transaction execution", vdr_error);
+
+            return Err(vdr_error);
+        }
+
+        Ok(transaction.hash)
+    }
+}
+
+#[async_trait]
+impl Client for EmbeddedClient {
+    async fn get_transaction_count(&self, address: &Address) -> VdrResult<[u64; 4]> {
+        let address = parse_address(address.as_ref()).map_err(|_| {
+            VdrError::ClientInvalidTransaction(format!(
+                "Invalid transaction sender address {:?}",
+                address
+            ))
+        })?;
+
+        let nonce = self
+            .state()?
+            .db
+            .load_account(address)
+            .map_err(|err| VdrError::ClientUnexpectedError(format!("{:?}", err)))?
+            .info
+            .nonce;
+
+        Ok([nonce, 0, 0, 0])
+    }
+
+    async fn submit_transaction(&self, transaction: &[u8]) -> VdrResult<Vec<u8>> {
+        trace!(
+            "Submit transaction process has started. Transaction: {:?}",
+            transaction
+        );
+
+        let transaction = self.decode_transaction(transaction)?;
+
+        let mut state = self.state()?;
+        state.check_transaction_allowed(self.chain_id, &transaction)?;
+        let hash = state.mine(self.chain_id, transaction)?;
+
+        trace!("Submitted transaction: {:?}", hash);
+
+        Ok(hash.as_bytes().to_vec())
+    }
+
+    async fn call_transaction(&self, to: &str, transaction: &[u8]) -> VdrResult<Vec<u8>> {
+        self.call(EvmAddress::ZERO, to, transaction, None)
+            .map(|(output, _)| output)
+    }
+
+    async fn call_transaction_at(
+        &self,
+        to: &str,
+        transaction: &[u8],
+        block: u64,
+    ) -> VdrResult<Vec<u8>> {
+        self.call(EvmAddress::ZERO, to, transaction, Some(block))
+            .map(|(output, _)| output)
+    }
+
+    async fn simulate_transaction(
+        &self,
+        from: &Address,
+        to: &str,
+        transaction: &[u8],
+    ) -> VdrResult<Vec<u8>> {
+        self.call(parse_sender(from)?, to, transaction, None)
+            .map(|(output, _)| output)
+    }
+
+    async fn estimate_gas(&self, from: &Address, to: &str, transaction: &[u8]) -> VdrResult<u64> {
+        self.call(parse_sender(from)?, to, transaction, None)
+            .map(|(_, gas_used)| gas_used)
+    }
+
+    async fn gas_price(&self) -> VdrResult<u64> {
+        Ok(self.state()?.block.basefee.to::<u64>())
+    }
+
+    async fn max_priority_fee_per_gas(&self) -> VdrResult<u64> {
+        Ok(0)
+    }
+
+    async fn get_receipt(&self, hash: &[u8]) -> VdrResult<TransactionReceipt> {
+        let receipt = self
+            .state()?
+            .transactions
+            .get(&parse_hash(hash)?)
+            .map(|transaction| convert_receipt(transaction.receipt.clone()))
+            .ok_or_else(|| {
+                let vdr_error =
+                    VdrError::ClientInvalidResponse("Missing transaction receipt".to_string());
+
+                warn!("Error: {} getting receipt", vdr_error,);
+
+                vdr_error
+            });
+
+        trace!("Got receipt: {:?}", receipt);
+
+        receipt
+    }
+
+    async fn ping(&self) -> VdrResult<PingStatus> {
+        let ping_result = match self.state() {
+            Ok(_) => Ok(PingStatus::ok()),
+            Err(_) => Ok(PingStatus::err("Embedded ledger state is poisoned")),
+        };
+
+        trace!("Ping result: {:?}", ping_result);
+
+        ping_result
+    }
+
+    async fn get_transaction(&self, transaction_hash: &[u8]) -> VdrResult<Option<Transaction>> {
+        let state = self.state()?;
+        let transaction =
+            state
+                .transactions
+                .get(&parse_hash(transaction_hash)?)
+                .map(|transaction| Transaction {
+                    type_: Default::default(),
+                    from: Some(Address::from(format!("{:?}", transaction.from).as_str())),
+                    to: transaction
+                        .to
+                        .map(|to| Address::from(format!("{:?}", to).as_str()))
+                        .unwrap_or_default(),
+                    nonce: Some(transaction.nonce.0.to_vec()),
+                    chain_id: self.chain_id,
+                    data: transaction.data.clone(),
+                    signature: Default::default(),
+                    hash: Some(transaction.receipt.transaction_hash.as_bytes().to_vec()),
+                    ..Transaction::default()
+                });
+        Ok(transaction)
+    }
+
+    async fn subscribe_events(&self, query: &EventQuery) -> VdrResult<EventStream> {
+        let (sender, receiver) = mpsc::unbounded();
+        self.state()?.subscriptions.push((query.clone(), sender));
+
+        trace!("Subscribed to events. Query: {:?}", query);
+
+        Ok(Box::pin(receiver))
+    }
+
+    async fn get_logs(
+        &self,
+        query: &EventQuery,
+        from_block: u64,
+        to_block: Option<u64>,
+    ) -> VdrResult<Vec<EventLog>> {
+        let state = self.state()?;
+        let to_block = to_block.unwrap_or(state.block.number.to::<u64>());
+        let mut logs: Vec<&Log> = state
+            .transactions
+            .values()
+            .flat_map(|transaction| transaction.receipt.logs.iter())
+            .filter(|log| {
+                log.block_number
+                    .map(|number| (from_block..=to_block).contains(&number.as_u64()))
+                    .unwrap_or(false)
+            })
+            .collect();
+        logs.sort_by_key(|log| (log.block_number, log.log_index));
+        let logs: Vec<EventLog> = logs
+            .into_iter()
+            .map(convert_event_log)
+            .filter(|log| query.matches(log))
+            .collect();
+
+        trace!("Got logs: {:?}", logs);
+
+        Ok(logs)
+    }
+
+    async fn get_block(&self, number: Option<u64>) -> VdrResult<Block> {
+        let state = self.state()?;
+        let (block, _) = state.snapshot(number)?;
+        let block = Block {
+            number: block.number.to::<u64>(),
+            timestamp: block.timestamp.to::<u64>(),
+        };
+
+        trace!("Got block: {:?}", block);
+
+        Ok(block)
+    }
+}
+
+fn read_artifact_code(
+    artifact_path: &str,
+    libraries: &HashMap<String, Address>,
+) -> VdrResult<EvmBytes> {
+    let artifact = std::fs::read_to_string(artifact_path).map_err(|err| {
+        VdrError::ContractInvalidSpec(format!("Unable to read artifact file. Err: {:?}", err))
+    })?;
+    let artifact: serde_json::Value = serde_json::from_str(&artifact).map_err(|err| {
+        VdrError::ContractInvalidSpec(format!("Unable to parse artifact. Err: {:?}", err))
+    })?;
+
+    let bytecode = artifact["deployedBytecode"].as_str().ok_or_else(|| {
+        VdrError::ContractInvalidSpec("Artifact does not contain `deployedBytecode`".to_string())
+    })?;
+    let mut bytecode = bytecode.trim_start_matches("0x").to_string();
+
+    // library placeholders have to be replaced with addresses before the code can be decoded
+    if let Some(link_references) = artifact["deployedLinkReferences"].as_object() {
+        for references in link_references.values() {
+            for (library, positions) in references.as_object().into_iter().flatten() {
+                let address = libraries.get(library).ok_or_else(|| {
+                    VdrError::ContractInvalidSpec(format!("Missing library address: {}", library))
+                })?;
+                let address = address.as_ref().trim_start_matches("0x").to_lowercase();
+                for position in positions.as_array().into_iter().flatten() {
+                    let start = position["start"].as_u64().unwrap_or_default() as usize * 2;
+                    let length = position["length"].as_u64().unwrap_or_default() as usize * 2;
+                    if length != address.len() || start + length > bytecode.len() {
+                        return Err(VdrError::ContractInvalidSpec(format!(
+                            "Invalid link reference for library: {}",
+                            library
+                        )));
+                    }
+                    bytecode.replace_range(start..start + length, &address);
+                }
+            }
+        }
+    }
+
+    parse_bytes(&bytecode)
+}
+
+fn parse_hash(hash: &[u8]) -> VdrResult<H256> {
+    if hash.len() != H256::len_bytes() {
+        let vdr_error = VdrError::ClientInvalidTransaction(format!(
+            "Transaction hash must be {} bytes long, got {}",
+            H256::len_bytes(),
+            hash.len()
+        ));
+
+        warn!("Error: {} during transaction lookup", vdr_error);
+
+        return Err(vdr_error);
+    }
+    Ok(H256::from_slice(hash))
+}
+
+fn parse_sender(from: &Address) -> VdrResult<EvmAddress> {
+    parse_address(from.as_ref()).map_err(|_| {
+        VdrError::ClientInvalidTransaction(format!("Invalid transaction sender address {:?}", from))
+    })
+}
+
+fn execution_reverted(output: &EvmBytes) -> VdrError {
+    let vdr_error = VdrError::ClientTransactionReverted(
+        json!({
+            "code": EXECUTION_REVERTED_CODE,
+            "message": "Execution reverted",
+            "data": format!("0x{}", hex::encode(output)),
+        })
+        .to_string(),
+    );
+
+    warn!("Error: {} during transaction execution", vdr_error);
+
+    vdr_error
+}
+
+fn convert_log(
+    log: &EvmLog,
+    index: usize,
+    block_number: u64,
+    block_hash: &H256,
+    transaction: &DecodedTransaction,
+) -> Log {
+    Log {
+        address: from_evm_address(&log.address),
+        topics: log
+            .topics()
+            .iter()
+            .map(|topic| H256::from_slice(topic.as_slice()))
+            .collect(),
+        data: Bytes(log.data.data.to_vec()),
+        block_hash: Some(*block_hash),
+        block_number: Some(U64::from(block_number)),
+        transaction_hash: Some(transaction.hash),
+        transaction_index: Some(Index::from(0)),
+        log_index: Some(U256::from(index)),
+        transaction_log_index: Some(U256::from(index)),
+        log_type: None,
+        removed: Some(false),
+    }
+}
+
+fn convert_event_log(log: &Log) -> EventLog {
+    EventLog {
+        address: Address::from(format!("{:?}", log.address).as_str()),
+        topics: log
+            .topics
+            .iter()
+            .map(|topic| topic.as_bytes().to_vec())
+            .collect(),
+        data: log.data.0.clone(),
+        block_number: log.block_number.map(|number| number.as_u64()),
+        transaction_hash: log.transaction_hash.map(|hash| hash.as_bytes().to_vec()),
+    }
+}
+
+fn to_evm_address(address: &H160) -> EvmAddress {
+    EvmAddress::from_slice(address.as_bytes())
+}
+
+fn from_evm_address(address: &EvmAddress) -> H160 {
+    H160::from_slice(address.as_slice())
+}
+
+fn to_evm_u256(value: &U256) -> EvmU256 {
+    EvmU256::from_limbs(value.0)
+}
+
+fn from_evm_u256(value: &EvmU256) -> U256 {
+    U256(*value.as_limbs())
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::{
+        client::client::test::{
+            ACCOUNT_CONTROL_ADDRESS, CHAIN_ID, GENESIS_PATH, IDENTITY_ACC, ROLE_CONTROL_ADDRESS,
+            TRUSTEE_ACC,
+        },
+        contracts::auth::{role_control, Role},
+        types::{
+            ContractConfig, ContractSpec, LedgerEvent, ReceiptStatus, SignatureData,
+            TransactionConfig, TransactionEnvelopeType,
+        },
+        LedgerClientBuilder,
+    };
+    use ethereum::{EnvelopedEncodable, LegacyTransaction, TransactionSignature};
+    use futures::StreamExt;
+    use std::str::FromStr;
+    use web3::signing::{Key, SecretKey, SecretKeyRef};
+
+    const TRUSTEE_PRIVATE_KEY: &str =
+        "8bbbb1b345af56b560a5b20bd4b0ed1cd8cc9958a16262bc75118453cb546df7";
+    const ENDORSER_ROLE: u8 = 2;
+
+    fn embedded_client() -> EmbeddedClient {
+        let client = EmbeddedClient::new(GENESIS_PATH).unwrap();
+        client
+            .set_account_control(&ACCOUNT_CONTROL_ADDRESS)
+            .unwrap();
+        client
+    }
+
+    fn encode_call(signature: &str, tokens: &[Token]) -> Vec<u8> {
+        let mut data = keccak256(signature.as_bytes())[..4].to_vec();
+        data.extend(ethabi::encode(tokens));
+        data
+    }
+
+    fn role_tokens(account: &Address) -> Vec<Token> {
+        vec![
+            Token::Uint(U256::from(ENDORSER_ROLE)),
+            Token::Address(H160::from_str(account.as_ref()).unwrap()),
+        ]
+    }
+
+    fn sign_legacy_transaction(nonce: u64, to: &Address, data: Vec<u8>) -> Vec<u8> {
+        let message = LegacyTransactionMessage {
+            nonce: U256::from(nonce),
+            gas_price: U256::zero(),
+            gas_limit: U256::from(1_000_000),
+            action: TransactionAction::Call(H160::from_str(to.as_ref()).unwrap()),
+            value: U256::zero(),
+            input: data,
+            chain_id: Some(CHAIN_ID),
+        };
+        let key = SecretKey::from_str(TRUSTEE_PRIVATE_KEY).unwrap();
+        let signature = SecretKeyRef::new(&key)
+            .sign(message.hash().as_bytes(), Some(CHAIN_ID))
+            .unwrap();
+        let transaction = LegacyTransaction {
+            nonce: message.nonce,
+            gas_price: message.gas_price,
+            gas_limit: message.gas_limit,
+            action: message.action,
+            value: message.value,
+            input: message.input,
+            signature: TransactionSignature::new(signature.v, signature.r, signature.s).unwrap(),
+        };
+        TransactionV2::Legacy(transaction).encode().to_vec()
+    }
+
+    async fn has_endorser_role(client: &EmbeddedClient, account: &Address) -> bool {
+        let response = client
+            .call_transaction(
+                ROLE_CONTROL_ADDRESS.as_ref(),
+                &encode_call("hasRole(uint8,address)", &role_tokens(account)),
+            )
+            .await
+            .unwrap();
+        response.last() == Some(&1)
+    }
+
+    #[async_std::test]
+    async fn embedded_client_genesis_state_test() {
+        let client = embedded_client();
+        assert_eq!(CHAIN_ID, client.chain_id());
+        assert_eq!(
+            [0, 0, 0, 0],
+            client.get_transaction_count(&TRUSTEE_ACC).await.unwrap()
+        );
+        assert!(!has_endorser_role(&client, &IDENTITY_ACC).await);
+    }
+
+    #[async_std::test]
+    async fn embedded_client_submit_transaction_test() {
+        let client = embedded_client();
+        let transaction = sign_legacy_transaction(
+            0,
+            &ROLE_CONTROL_ADDRESS,
+            encode_call("assignRole(uint8,address)", &role_tokens(&IDENTITY_ACC)),
+        );
+
+        let hash = client.submit_transaction(&transaction).await.unwrap();
+
+        let receipt = client.get_receipt(&hash).await.unwrap();
+        assert_eq!(ReceiptStatus::Success, receipt.status);
+        assert_eq!(1, receipt.logs.len());
+        assert_eq!(
+            [1, 0, 0, 0],
+            client.get_transaction_count(&TRUSTEE_ACC).await.unwrap()
+        );
+        assert!(has_endorser_role(&client, &IDENTITY_ACC).await);
+
+        let submitted = client.get_transaction(&hash).await.unwrap().unwrap();
+        assert_eq!(TRUSTEE_ACC.clone(), submitted.from.unwrap());
+    }
+
+    #[async_std::test]
+    async fn embedded_client_subscribe_events_test() {
+        let client = embedded_client();
+        let role_assigned_signature =
+            keccak256("RoleAssigned(uint8,address,address)".as_bytes()).to_vec();
+        let query = EventQuery {
+            address: ROLE_CONTROL_ADDRESS.clone(),
+            event_signature: Some(role_assigned_signature.clone()),
+            indexed_topic: None,
+        };
+        let mut events = client.subscribe_events(&query).await.unwrap();
+
+        let transaction = sign_legacy_transaction(
+            0,
+            &ROLE_CONTROL_ADDRESS,
+            encode_call("assignRole(uint8,address)", &role_tokens(&IDENTITY_ACC)),
+        );
+        let hash = client.submit_transaction(&transaction).await.unwrap();
+
+        let event = events.next().await.unwrap().unwrap();
+        assert_eq!(Some(&role_assigned_signature), event.topics.first());
+        assert_eq!(Some(hash), event.transaction_hash);
+        assert_eq!(Some(1), event.block_number);
+    }
+
+    #[async_std::test]
+    async fn embedded_client_load_artifact_test() {
+        let client = embedded_client();
+        let contract = Address::from("0x0000000000000000000000000000000000000a0a");
+        let library = Address::from("0x0000000000000000000000000000000000002222");
+        // returns the address of the linked library: PUSH20 <library> PUSH1 0 MSTORE PUSH1 32 PUSH1 0 RETURN
+        let artifact = json!({
+            "deployedBytecode": "0x73__$0123456789abcdef0123456789abcdef01$__60005260206000f3",
+            "deployedLinkReferences": {
+                "contracts/TestLibrary.sol": {
+                    "TestLibrary": [{ "start": 1, "length": 20 }]
+                }
+            }
+        });
+        let artifact_path = std::env::temp_dir().join(format!(
+            "vdr-artifact-{}.json",
+            hex::encode(rand::random::<[u8; 8]>())
+        ));
+        std::fs::write(&artifact_path, artifact.to_string()).unwrap();
+
+        let loaded = client.load_artifact(
+            &contract,
+            artifact_path.to_str().unwrap(),
+            &HashMap::from([("TestLibrary".to_string(), library.clone())]),
+        );
+        std::fs::remove_file(&artifact_path).unwrap();
+        loaded.unwrap();
+
+        let response = client
+            .call_transaction(contract.as_ref(), &[])
+            .await
+            .unwrap();
+        assert_eq!(
+            H160::from_str(library.as_ref()).unwrap().as_bytes(),
+            &response[12..]
+        );
+    }
+
+    #[async_std::test]
+    async fn embedded_client_historical_state_test() {
+        let client = embedded_client();
+        let data = encode_call("assignRole(uint8,address)", &role_tokens(&IDENTITY_ACC));
+        client
+            .submit_transaction(&sign_legacy_transaction(0, &ROLE_CONTROL_ADDRESS, data))
+            .await
+            .unwrap();
+
+        let has_role = encode_call("hasRole(uint8,address)", &role_tokens(&IDENTITY_ACC));
+        let at_genesis = client
+            .call_transaction_at(ROLE_CONTROL_ADDRESS.as_ref(), &has_role, 0)
+            .await
+            .unwrap();
+        assert_eq!(Some(&0), at_genesis.last());
+        let at_latest = client
+            .call_transaction_at(ROLE_CONTROL_ADDRESS.as_ref(), &has_role, 1)
+            .await
+            .unwrap();
+        assert_eq!(Some(&1), at_latest.last());
+        client
+            .call_transaction_at(ROLE_CONTROL_ADDRESS.as_ref(), &has_role, 2)
+            .await
+            .unwrap_err();
+
+        let genesis = client.get_block(Some(0)).await.unwrap();
+        let latest = client.get_block(None).await.unwrap();
+        assert_eq!(1, latest.number);
+        assert!(latest.timestamp > genesis.timestamp);
+
+        let query = EventQuery {
+            address: ROLE_CONTROL_ADDRESS.clone(),
+            event_signature: Some(
+                keccak256("RoleAssigned(uint8,address,address)".as_bytes()).to_vec(),
+            ),
+            indexed_topic: None,
+        };
+        let logs = client.get_logs(&query, 0, None).await.unwrap();
+        assert_eq!(1, logs.len());
+        assert_eq!(Some(1), logs[0].block_number);
+        assert!(client
+            .get_logs(&query, 0, Some(0))
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[async_std::test]
+    async fn embedded_client_historical_state_changed_by_several_blocks_test() {
+        let client = embedded_client();
+        let assign = encode_call("assignRole(uint8,address)", &role_tokens(&IDENTITY_ACC));
+        let revoke = encode_call("revokeRole(uint8,address)", &role_tokens(&IDENTITY_ACC));
+        for (nonce, data) in [assign.clone(), revoke, assign].into_iter().enumerate() {
+            client
+                .submit_transaction(&sign_legacy_transaction(
+                    nonce as u64,
+                    &ROLE_CONTROL_ADDRESS,
+                    data,
+                ))
+                .await
+                .unwrap();
+        }
+
+        let has_role = encode_call("hasRole(uint8,address)", &role_tokens(&IDENTITY_ACC));
+        for (block, expected) in [(0, 0), (1, 1), (2, 0), (3, 1)] {
+            let output = client
+                .call_transaction_at(ROLE_CONTROL_ADDRESS.as_ref(), &has_role, block)
+                .await
+                .unwrap();
+            assert_eq!(Some(&expected), output.last(), "block {}", block);
+        }
+        assert!(has_endorser_role(&client, &IDENTITY_ACC).await);
+    }
+
+    #[async_std::test]
+    async fn embedded_client_invalid_transaction_hash_test() {
+        let client = embedded_client();
+
+        let err = client.get_receipt(&[1, 2, 3]).await.unwrap_err();
+        assert!(matches!(err, VdrError::ClientInvalidTransaction(_)));
+        let err = client.get_transaction(&[1; 33]).await.unwrap_err();
+        assert!(matches!(err, VdrError::ClientInvalidTransaction(_)));
+    }
+
+    #[async_std::test]
+    async fn embedded_client_reverted_call_test() {
+        let client = embedded_client();
+        let err = client
+            .call_transaction(
+                ROLE_CONTROL_ADDRESS.as_ref(),
+                &encode_call("unknownFunction()", &[]),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, VdrError::ClientTransactionReverted(_)));
+    }
+
+    #[async_std::test]
+    async fn embedded_client_failed_transaction_test() {
+        let client = embedded_client();
+        let transaction = sign_legacy_transaction(
+            0,
+            &ROLE_CONTROL_ADDRESS,
+            encode_call("unknownFunction()", &[]),
+        );
+
+        let err = client.submit_transaction(&transaction).await.unwrap_err();
+        let VdrError::ClientTransactionFailed(message) = &err else {
+            panic!("ClientTransactionFailed error expected, got: {:?}", err)
+        };
+        assert_eq!(Some(vec![]), err.revert_data());
+
+        // transaction is included into a block and consumes the nonce
+        let error: serde_json::Value = serde_json::from_str(message).unwrap();
+        let hash = hex::decode(
+            error["transactionHash"]
+                .as_str()
+                .unwrap()
+                .trim_start_matches("0x"),
+        )
+        .unwrap();
+        let receipt = client.get_receipt(&hash).await.unwrap();
+        assert_eq!(ReceiptStatus::Failure, receipt.status);
+        assert_eq!(
+            [1, 0, 0, 0],
+            client.get_transaction_count(&TRUSTEE_ACC).await.unwrap()
+        );
+    }
+
+    #[async_std::test]
+    async fn embedded_client_simulate_transaction_test() {
+        let client = embedded_client();
+        let data = encode_call("assignRole(uint8,address)", &role_tokens(&IDENTITY_ACC));
+
+        client
+            .simulate_transaction(&TRUSTEE_ACC, ROLE_CONTROL_ADDRESS.as_ref(), &data)
+            .await
+            .unwrap();
+        assert!(!has_endorser_role(&client, &IDENTITY_ACC).await);
+
+        // account without a role assigned
+        let sender = Address::from("0x0886328869e4e1f401e1052a5f4aae8b45f42610");
+        let err = client
+            .simulate_transaction(&sender, ROLE_CONTROL_ADDRESS.as_ref(), &data)
+            .await
+            .unwrap_err();
+        let unauthorized = keccak256("Unauthorized(address)".as_bytes())[..4].to_vec();
+        assert_eq!(
+            Some(unauthorized),
+            err.revert_data().map(|data| data[..4].to_vec())
+        );
+    }
+
+    fn role_control_spec() -> ContractSpec {
+        ContractSpec {
+            name: "RoleControl".to_string(),
+            abi: json!([{
+                "type": "function",
+                "name": "assignRole",
+                "stateMutability": "nonpayable",
+                "inputs": [
+                    { "name": "role", "type": "uint8" },
+                    { "name": "account", "type": "address" }
+                ],
+                "outputs": [{ "name": "assignedRole", "type": "uint8" }]
+            }, {
+                "type": "event",
+                "name": "RoleAssigned",
+                "anonymous": false,
+                "inputs": [
+                    { "name": "role", "type": "uint8", "indexed": false },
+                    { "name": "account", "type": "address", "indexed": true },
+                    { "name": "sender", "type": "address", "indexed": true }
+                ]
+            }]),
+        }
+    }
+
+    fn sign(message: &[u8]) -> SignatureData {
+        let key = SecretKey::from_str(TRUSTEE_PRIVATE_KEY).unwrap();
+        let signature = SecretKeyRef::new(&key).sign(message, None).unwrap();
+        SignatureData {
+            recovery_id: signature.v - 27,
+            signature: [signature.r.as_bytes(), signature.s.as_bytes()].concat(),
+        }
+    }
+
+    #[async_std::test]
+    async fn embedded_client_transaction_envelopes_test() {
+        for envelope in [
+            TransactionEnvelopeType::Legacy,
+            TransactionEnvelopeType::AccessList,
+            TransactionEnvelopeType::DynamicFee,
+        ] {
+            let client = LedgerClientBuilder::new(CHAIN_ID)
+                .set_client(Box::new(embedded_client()))
+                .set_contract_configs(&[ContractConfig {
+                    address: ROLE_CONTROL_ADDRESS.to_string(),
+                    spec_path: None,
+                    spec: Some(role_control_spec()),
+                }])
+                .set_transaction_config(&TransactionConfig {
+                    envelope: Some(envelope.clone()),
+                    gas_limit_margin: None,
+                    gas_limit: None,
+                })
+                .build()
+                .unwrap();
+
+            let transaction = role_control::build_assign_role_transaction(
+                &client,
+                &TRUSTEE_ACC,
+                &Role::Endorser,
+                &IDENTITY_ACC,
+            )
+            .await
+            .unwrap();
+            assert_eq!(envelope, transaction.envelope);
+            assert!(transaction.gas_limit.unwrap() < crate::client::GAS);
+
+            transaction.set_signature(sign(&transaction.get_signing_bytes().unwrap()));
+            let hash = client.submit_transaction(&transaction).await.unwrap();
+
+            let receipt = client.get_receipt(&hash).await.unwrap();
+            assert_eq!(ReceiptStatus::Success, receipt.status);
+            assert_eq!(TRUSTEE_ACC.clone(), receipt.from);
+            assert!(matches!(receipt.events[..], [LedgerEvent::RoleAssigned(_)]));
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use crate::error::{VdrError, VdrResult};
+
+use log::{trace, warn};
+use revm::primitives::{Address as EvmAddress, Bytes as EvmBytes, U256 as EvmU256};
+use serde_derive::Deserialize;
+use std::collections::HashMap;
+
+/// Subset of the Besu genesis file required to reproduce the initial state of the network
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Genesis {
+    pub config: GenesisConfig,
+    pub timestamp: String,
+    pub gas_limit: String,
+    pub alloc: HashMap<String, GenesisAccount>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenesisConfig {
+    pub chain_id: u64,
+    pub qbft: Option<QbftConfig>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub struct QbftConfig {
+    pub blockperiodseconds: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GenesisAccount {
+    pub balance: Option<String>,
+    pub nonce: Option<String>,
+    pub code: Option<String>,
+    pub storage: Option<HashMap<String, String>>,
+}
+
+impl Genesis {
+    /// Read and parse genesis from a JSON file
+    pub fn from_file(genesis_path: &str) -> VdrResult<Genesis> {
+        let genesis = std::fs::read_to_string(genesis_path).map_err(|err| {
+            let vdr_error =
+                VdrError::CommonInvalidData(format!("Unable to read genesis file. Err: {:?}", err));
+
+            warn!("Error: {:?} during reading genesis from file", vdr_error);
+
+            vdr_error
+        })?;
+        Self::from_json(&genesis)
+    }
+
+    /// Parse genesis from a JSON string
+    pub fn from_json(genesis: &str) -> VdrResult<Genesis> {
+        let genesis = serde_json::from_str(genesis).map_err(|err| {
+            let vdr_error = VdrError::CommonInvalidData(format!(
+                "Unable to parse genesis. Err: {:?}",
+                err.to_string()
+            ));
+
+            warn!("Error: {:?} during parsing genesis", vdr_error);
+
+            vdr_error
+        });
+
+        trace!("Parsed genesis. Result: {:?}", genesis);
+
+        genesis
+    }
+
+    pub fn block_period(&self) -> u64 {
+        self.config
+            .qbft
+            .as_ref()
+            .and_then(|qbft| qbft.blockperiodseconds)
+            .unwrap_or(1)
+    }
+}
+
+pub(crate) fn parse_address(address: &str) -> VdrResult<EvmAddress> {
+    let bytes = parse_padded_hex(address, 20)?;
+    Ok(EvmAddress::from_slice(&bytes))
+}
+
+pub(crate) fn parse_u256(value: &str) -> VdrResult<EvmU256> {
+    match value.strip_prefix("0x") {
+        Some("") => Ok(EvmU256::ZERO),
+        Some(hex) => EvmU256::from_str_radix(hex, 16),
+        None => EvmU256::from_str_radix(value, 10),
+    }
+    .map_err(|err| VdrError::CommonInvalidData(format!("Invalid number: {}. Err: {}", value, err)))
+}
+
+// Storage slots and values are always hex encoded, even when the `0x` prefix is omitted
+pub(crate) fn parse_storage_word(value: &str) -> VdrResult<EvmU256> {
+    let bytes = parse_padded_hex(value, 32)?;
+    Ok(EvmU256::from_be_slice(&bytes))
+}
+
+pub(crate) fn parse_bytes(value: &str) -> VdrResult<EvmBytes> {
+    hex::decode(value.trim_start_matches("0x"))
+        .map(EvmBytes::from)
+        .map_err(|err| {
+            VdrError::CommonInvalidData(format!("Invalid hex data: {}. Err: {}", value, err))
+        })
+}
+
+// Genesis files written by hand are allowed to drop leading zeros of addresses and storage slots
+fn parse_padded_hex(value: &str, size: usize) -> VdrResult<Vec<u8>> {
+    let value = value.trim_start_matches("0x");
+    if value.len() > size * 2 {
+        return Err(VdrError::CommonInvalidData(format!(
+            "Invalid hex value length: {}",
+            value
+        )));
+    }
+    hex::decode(format!("{:0>width$}", value, width = size * 2)).map_err(|err| {
+        VdrError::CommonInvalidData(format!("Invalid hex value: {}. Err: {}", value, err))
+    })
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+
+    #[test]
+    fn parse_short_address_test() {
+        let address = parse_address("0x000000000000000000000000000000000019999").unwrap();
+        assert_eq!(
+            "0x0000000000000000000000000000000000019999",
+            address.to_string().to_lowercase()
+        );
+    }
+
+    #[test]
+    fn parse_decimal_and_hex_balance_test() {
+        assert_eq!(
+            parse_u256("90000000000000000000000").unwrap(),
+            parse_u256("0x130EE8E7179044400000").unwrap()
+        );
+    }
+}
@@ -1 +1,3 @@
+#[cfg(feature = "embedded")]
+pub mod embedded;
 pub mod web3;
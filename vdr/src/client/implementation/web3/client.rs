@@ -1,37 +1,63 @@
 use crate::{
     client::Client,
     error::{VdrError, VdrResult},
-    types::PingStatus,
+    types::{
+        AccessListItem, Block, EventLog, EventQuery, PingStatus, ReceiptStatus,
+        TransactionEnvelopeType, TransactionReceipt,
+    },
     Address, Transaction,
 };
 
 use async_trait::async_trait;
 use log::{trace, warn};
-use serde_json::json;
 use std::{str::FromStr, time::Duration};
 
 #[cfg(not(feature = "wasm"))]
 use web3::{
-    api::Eth,
+    confirm::wait_for_confirmations,
     transports::Http,
-    types::{Address as EthAddress, Bytes, CallRequest, TransactionId, H256},
-    Web3,
+    types::{
+        Address as EthAddress, BlockId, BlockNumber, Bytes, CallRequest, FilterBuilder, Log,
+        TransactionId, TransactionReceipt as EthTransactionReceipt, H256, U256,
+    },
+    Error as Web3Error, Transport, Web3,
 };
 
 #[cfg(feature = "wasm")]
 use web3_wasm::{
-    api::Eth,
+    confirm::wait_for_confirmations,
     transports::Http,
-    types::{Address as EthAddress, Bytes, CallRequest, TransactionId, H256},
-    Web3,
+    types::{
+        Address as EthAddress, BlockId, BlockNumber, Bytes, CallRequest, FilterBuilder, Log,
+        TransactionId, TransactionReceipt as EthTransactionReceipt, H256, U256,
+    },
+    Error as Web3Error, Transport, Web3,
 };
 
-pub struct Web3Client {
-    client: Web3<Http>,
+#[cfg(not(feature = "wasm"))]
+use crate::types::EventStream;
+#[cfg(not(feature = "wasm"))]
+use futures::StreamExt;
+#[cfg(not(feature = "wasm"))]
+use web3::transports::WebSocket;
+
+/// Client interacting with a ledger node over the Ethereum JSON-RPC API.
+///
+/// HTTP transport is used by default. WebSocket transport ([Web3WsClient]) additionally supports
+/// event subscriptions and waits for transaction confirmation using `newHeads` subscription
+/// instead of polling.
+pub struct Web3Client<T: Transport = Http> {
+    client: Web3<T>,
 }
 
+/// Client interacting with a ledger node over WebSocket
+#[cfg(not(feature = "wasm"))]
+pub type Web3WsClient = Web3Client<WebSocket>;
+
 const POLL_INTERVAL: u64 = 200;
 const NUMBER_TX_CONFIRMATIONS: usize = 1; // FIXME: what number of confirmation events should we wait? 2n+1?
+#[cfg(not(feature = "wasm"))]
+const CONFIRMATION_TIMEOUT: u64 = 60000;
 
 impl Web3Client {
     pub fn new(node_address: &str) -> VdrResult<Web3Client> {
@@ -48,16 +74,147 @@ impl Web3Client {
 
         Ok(web3_client)
     }
+}
+
+#[cfg(not(feature = "wasm"))]
+impl Web3Client<WebSocket> {
+    /// Create client connected to the node over WebSocket
+    ///
+    /// # Params
+    ///  - `node_address` - WebSocket RPC endpoint of the node (`ws://` or `wss://`)
+    ///
+    /// # Returns
+    ///  client supporting event subscriptions
+    pub async fn new_ws(node_address: &str) -> VdrResult<Web3WsClient> {
+        trace!(
+            "Started creating new Web3WsClient. Node address: {}",
+            node_address
+        );
 
-    pub fn eth(&self) -> Eth<Http> {
-        self.client.eth()
+        let transport = WebSocket::new(node_address).await.map_err(|err| {
+            warn!(
+                "Error: {:?} during connecting to node: {}",
+                err, node_address
+            );
+
+            VdrError::ClientNodeUnreachable
+        })?;
+        let web3 = Web3::new(transport);
+        let web3_client = Web3Client { client: web3 };
+
+        trace!("Created new Web3WsClient. Node address: {}", node_address);
+
+        Ok(web3_client)
+    }
+
+    async fn submit_with_new_heads(&self, transaction: &[u8]) -> VdrResult<Vec<u8>> {
+        // subscribe before sending so the block including the transaction cannot be missed
+        let mut new_heads = self.client.eth_subscribe().subscribe_new_heads().await?;
+
+        let hash = self.send_raw_transaction(transaction).await?;
+
+        trace!("Sent transaction: {:?}. Waiting for confirmation", hash);
+
+        let confirmation = async {
+            loop {
+                let receipt = self.client.eth().transaction_receipt(hash).await?;
+                if let Some(receipt) = receipt.filter(|receipt| receipt.block_number.is_some()) {
+                    return Ok(receipt);
+                }
+
+                match new_heads.next().await {
+                    Some(Ok(header)) => trace!("Got new block: {:?}", header.number),
+                    Some(Err(err)) => return Err(VdrError::from(err)),
+                    None => {
+                        return Err(VdrError::ClientUnexpectedError(
+                            "Subscription to new blocks has been closed".to_string(),
+                        ))
+                    }
+                }
+            }
+        };
+        // the transaction can be dropped by the node after being accepted
+        let receipt =
+            async_std::future::timeout(Duration::from_millis(CONFIRMATION_TIMEOUT), confirmation)
+                .await
+                .map_err(|_| {
+                    let vdr_error = VdrError::ClientUnexpectedError(format!(
+                        "Transaction {:?} has not been included into a block within {} ms",
+                        hash, CONFIRMATION_TIMEOUT
+                    ));
+
+                    warn!(
+                        "Error: {} during waiting for transaction confirmation",
+                        vdr_error
+                    );
+
+                    vdr_error
+                })
+                .and_then(|receipt| receipt);
+
+        if let Err(err) = new_heads.unsubscribe().await {
+            warn!("Error: {:?} during unsubscribing from new blocks", err);
+        }
+
+        self.check_receipt_status(&receipt?).await?;
+
+        Ok(hash.0.to_vec())
     }
 }
 
-#[cfg_attr(not(feature = "wasm"), async_trait)]
-#[cfg_attr(feature = "wasm", async_trait(? Send))]
-impl Client for Web3Client {
-    async fn get_transaction_count(&self, address: &crate::Address) -> VdrResult<[u64; 4]> {
+impl<T: Transport> Web3Client<T> {
+    /// Errors returned by the node mean the transaction has been rejected and will not be included
+    /// into a block
+    async fn send_raw_transaction(&self, transaction: &[u8]) -> VdrResult<H256> {
+        self.client
+            .eth()
+            .send_raw_transaction(Bytes::from(transaction))
+            .await
+            .map_err(|err| {
+                let vdr_error = match err {
+                    Web3Error::Rpc(err) => {
+                        VdrError::ClientTransactionRejected(serde_json::json!(err).to_string())
+                    }
+                    err => VdrError::from(err),
+                };
+
+                warn!("Error: {} during sending transaction", vdr_error);
+
+                vdr_error
+            })
+    }
+
+    async fn wait_for_receipt(&self, hash: H256) -> VdrResult<EthTransactionReceipt> {
+        let eth = self.client.eth();
+        let check = || {
+            let eth = eth.clone();
+            async move {
+                let receipt = eth.transaction_receipt(hash).await?;
+                Ok(receipt.and_then(|receipt| receipt.block_number))
+            }
+        };
+        wait_for_confirmations(
+            self.client.eth(),
+            self.client.eth_filter(),
+            Duration::from_millis(POLL_INTERVAL),
+            NUMBER_TX_CONFIRMATIONS,
+            check,
+        )
+        .await?;
+
+        self.client
+            .eth()
+            .transaction_receipt(hash)
+            .await?
+            .ok_or_else(|| {
+                VdrError::ClientInvalidResponse(format!(
+                    "Receipt of confirmed transaction {:?} not found",
+                    hash
+                ))
+            })
+    }
+
+    async fn fetch_transaction_count(&self, address: &Address) -> VdrResult<[u64; 4]> {
         let account_address = EthAddress::from_str(address.as_ref()).map_err(|_| {
             VdrError::ClientInvalidTransaction(format!(
                 "Invalid transaction sender address {:?}",
@@ -70,37 +227,136 @@ impl Client for Web3Client {
             .eth()
             .transaction_count(account_address, None)
             .await
-            .unwrap();
+            .map_err(|err| {
+                warn!(
+                    "Error: {:?} during getting transaction count of account: {:?}",
+                    err, address
+                );
+
+                VdrError::ClientNodeUnreachable
+            })?;
 
         Ok(nonce.0)
     }
 
-    async fn submit_transaction(&self, transaction: &[u8]) -> VdrResult<Vec<u8>> {
+    async fn call(
+        &self,
+        from: Option<&Address>,
+        to: &str,
+        transaction: &[u8],
+        block: Option<u64>,
+    ) -> VdrResult<Vec<u8>> {
         trace!(
-            "Submit transaction process has started. Transaction: {:?}",
-            transaction
+            "Call transaction process has started. Transaction: {:?}, block: {:?}",
+            transaction,
+            block
         );
 
-        let receipt = self
-            .client
-            .send_raw_transaction_with_confirmation(
-                Bytes::from(transaction),
-                Duration::from_millis(POLL_INTERVAL),
-                NUMBER_TX_CONFIRMATIONS,
-            )
-            .await?;
+        let request = Self::call_request(from, to, transaction)?;
+        let block = block.map(|block| BlockId::Number(BlockNumber::Number(block.into())));
+        let response = self.client.eth().call(request, block).await?;
 
-        trace!("Submitted transaction: {:?}", transaction);
+        trace!("Called transaction: {:?}", transaction);
 
-        Ok(receipt.transaction_hash.0.to_vec())
+        Ok(response.0.to_vec())
     }
 
-    async fn call_transaction(&self, to: &str, transaction: &[u8]) -> VdrResult<Vec<u8>> {
+    /// Transactions mined with failure status are replayed at their block to get the revert data
+    async fn check_receipt_status(&self, receipt: &EthTransactionReceipt) -> VdrResult<()> {
+        if receipt.status != Some(0.into()) {
+            return Ok(());
+        }
+
+        let data = match self.replay_transaction(receipt).await {
+            Ok(_) => None,
+            Err(err) => err.revert_data(),
+        };
+        let vdr_error =
+            VdrError::transaction_failed(receipt.transaction_hash.as_bytes(), data.as_deref());
+
+        warn!("Error: {} during submitting transaction", vdr_error);
+
+        Err(vdr_error)
+    }
+
+    async fn replay_transaction(&self, receipt: &EthTransactionReceipt) -> VdrResult<Bytes> {
+        let transaction = self
+            .client
+            .eth()
+            .transaction(TransactionId::Hash(receipt.transaction_hash))
+            .await?
+            .ok_or_else(|| {
+                VdrError::GetTransactionError(format!(
+                    "Transaction {:?} not found",
+                    receipt.transaction_hash
+                ))
+            })?;
+        let request = CallRequest {
+            from: Some(receipt.from),
+            to: receipt.to,
+            gas: Some(transaction.gas),
+            value: Some(transaction.value),
+            data: Some(transaction.input),
+            ..CallRequest::default()
+        };
+        let block = receipt
+            .block_number
+            .map(|number| BlockId::Number(BlockNumber::Number(number)));
+
+        Ok(self.client.eth().call(request, block).await?)
+    }
+
+    async fn estimate_transaction_gas(
+        &self,
+        from: &Address,
+        to: &str,
+        transaction: &[u8],
+    ) -> VdrResult<u64> {
+        let request = Self::call_request(Some(from), to, transaction)?;
+        let gas = self.client.eth().estimate_gas(request, None).await?;
+
         trace!(
-            "Call transaction process has started. Transaction: {:?}",
+            "Estimated gas: {:?} for transaction: {:?}",
+            gas,
             transaction
         );
 
+        to_u64(gas, "gas")
+    }
+
+    async fn fetch_gas_price(&self) -> VdrResult<u64> {
+        let gas_price = self.client.eth().gas_price().await?;
+
+        trace!("Got gas price: {:?}", gas_price);
+
+        to_u64(gas_price, "gas price")
+    }
+
+    async fn fetch_max_priority_fee_per_gas(&self) -> VdrResult<u64> {
+        let response = self
+            .client
+            .transport()
+            .execute("eth_maxPriorityFeePerGas", vec![])
+            .await?;
+        let priority_fee: U256 = serde_json::from_value(response).map_err(|err| {
+            let vdr_error =
+                VdrError::ClientInvalidResponse(format!("Unable to parse priority fee: {:?}", err));
+
+            warn!("Error: {} getting priority fee", vdr_error);
+
+            vdr_error
+        })?;
+
+        trace!("Got priority fee: {:?}", priority_fee);
+
+        to_u64(priority_fee, "priority fee")
+    }
+
+    fn call_request(
+        from: Option<&Address>,
+        to: &str,
+        transaction: &[u8],
+    ) -> VdrResult<CallRequest> {
         let address = EthAddress::from_str(to).map_err(|_| {
             let vdr_error = VdrError::ClientInvalidTransaction(format!(
                 "Invalid transaction target address {:?}",
@@ -114,18 +370,29 @@ impl Client for Web3Client {
 
             vdr_error
         })?;
-        let request = CallRequest::builder()
+        let mut request = CallRequest::builder()
             .to(address)
-            .data(Bytes(transaction.to_vec()))
-            .build();
-        let response = self.client.eth().call(request, None).await?;
-
-        trace!("Called transaction: {:?}", transaction);
+            .data(Bytes(transaction.to_vec()));
+        if let Some(from) = from {
+            let from = EthAddress::from_str(from.as_ref()).map_err(|_| {
+                let vdr_error = VdrError::ClientInvalidTransaction(format!(
+                    "Invalid transaction sender address {:?}",
+                    from
+                ));
+
+                warn!(
+                    "Error: {} during calling transaction: {:?}",
+                    vdr_error, transaction
+                );
 
-        Ok(response.0.to_vec())
+                vdr_error
+            })?;
+            request = request.from(from);
+        }
+        Ok(request.build())
     }
 
-    async fn get_receipt(&self, hash: &[u8]) -> VdrResult<String> {
+    async fn fetch_receipt(&self, hash: &[u8]) -> VdrResult<TransactionReceipt> {
         let receipt = self
             .client
             .eth()
@@ -139,14 +406,87 @@ impl Client for Web3Client {
 
                 vdr_error
             })
-            .map(|receipt| json!(receipt).to_string());
+            .map(convert_receipt);
 
         trace!("Got receipt: {:?}", receipt);
 
         receipt
     }
 
-    async fn ping(&self) -> VdrResult<PingStatus> {
+    async fn fetch_logs(
+        &self,
+        query: &EventQuery,
+        from_block: u64,
+        to_block: Option<u64>,
+    ) -> VdrResult<Vec<EventLog>> {
+        trace!(
+            "Get logs process has started. Query: {:?}, blocks: {}..{:?}",
+            query,
+            from_block,
+            to_block
+        );
+
+        let to_block = match to_block {
+            Some(to_block) => BlockNumber::Number(to_block.into()),
+            None => BlockNumber::Latest,
+        };
+        let filter = FilterBuilder::default()
+            .address(vec![event_address(query)?])
+            .topics(
+                event_topic(query.event_signature.as_ref(), "event signature")?,
+                event_topic(query.indexed_topic.as_ref(), "indexed topic")?,
+                None,
+                None,
+            )
+            .from_block(BlockNumber::Number(from_block.into()))
+            .to_block(to_block)
+            .build();
+        let logs: Vec<EventLog> = self
+            .client
+            .eth()
+            .logs(filter)
+            .await?
+            .into_iter()
+            .map(convert_log)
+            .collect();
+
+        trace!("Got logs: {:?}", logs);
+
+        Ok(logs)
+    }
+
+    async fn fetch_block(&self, number: Option<u64>) -> VdrResult<Block> {
+        let block_number = match number {
+            Some(number) => BlockNumber::Number(number.into()),
+            None => BlockNumber::Latest,
+        };
+        let block = self
+            .client
+            .eth()
+            .block(BlockId::Number(block_number))
+            .await?
+            .ok_or_else(|| {
+                let vdr_error =
+                    VdrError::ClientInvalidResponse(format!("Block {:?} not found", number));
+
+                warn!("Error: {} getting block", vdr_error);
+
+                vdr_error
+            })?;
+        let block = Block {
+            number: block
+                .number
+                .map(|number| number.as_u64())
+                .unwrap_or_default(),
+            timestamp: to_u64(block.timestamp, "block timestamp")?,
+        };
+
+        trace!("Got block: {:?}", block);
+
+        Ok(block)
+    }
+
+    async fn check_block_number(&self) -> VdrResult<PingStatus> {
         let ping_result = match self.client.eth().block_number().await {
             Ok(_current_block) => Ok(PingStatus::ok()),
             Err(_) => Ok(PingStatus::err("Could not get current network block")),
@@ -157,7 +497,7 @@ impl Client for Web3Client {
         ping_result
     }
 
-    async fn get_transaction(&self, transaction_hash: &[u8]) -> VdrResult<Option<Transaction>> {
+    async fn fetch_transaction(&self, transaction_hash: &[u8]) -> VdrResult<Option<Transaction>> {
         let transaction_id = TransactionId::Hash(H256::from_slice(transaction_hash));
         let transaction = self
             .client
@@ -168,21 +508,350 @@ impl Client for Web3Client {
                 VdrError::GetTransactionError("Could not get transaction by hash".to_string())
             })?;
 
-        let transaction = transaction.map(|transaction| Transaction {
-            type_: Default::default(),
-            from: transaction
-                .from
-                .map(|from| Address::from(from.to_string().as_str())),
-            to: transaction
-                .to
-                .map(|from| Address::from(from.to_string().as_str()))
-                .unwrap_or_default(),
-            nonce: Some(transaction.nonce.0.to_vec()),
-            chain_id: 0,
-            data: transaction.input.0.to_vec(),
-            signature: Default::default(),
-            hash: Some(transaction.hash.as_bytes().to_vec()),
+        let transaction = transaction.map(|transaction| {
+            let envelope = match transaction.transaction_type.map(|type_| type_.as_u64()) {
+                Some(1) => TransactionEnvelopeType::AccessList,
+                Some(2) => TransactionEnvelopeType::DynamicFee,
+                _ => TransactionEnvelopeType::Legacy,
+            };
+            Transaction {
+                type_: Default::default(),
+                from: transaction
+                    .from
+                    .map(|from| Address::from(from.to_string().as_str())),
+                to: transaction
+                    .to
+                    .map(|from| Address::from(from.to_string().as_str()))
+                    .unwrap_or_default(),
+                nonce: Some(transaction.nonce.0.to_vec()),
+                chain_id: 0,
+                data: transaction.input.0.to_vec(),
+                signature: Default::default(),
+                hash: Some(transaction.hash.as_bytes().to_vec()),
+                gas_limit: to_u64(transaction.gas, "gas").ok(),
+                gas_price: match envelope {
+                    TransactionEnvelopeType::DynamicFee => None,
+                    _ => transaction
+                        .gas_price
+                        .and_then(|price| to_u64(price, "gas price").ok()),
+                },
+                max_fee_per_gas: transaction
+                    .max_fee_per_gas
+                    .and_then(|fee| to_u64(fee, "max fee").ok()),
+                max_priority_fee_per_gas: transaction
+                    .max_priority_fee_per_gas
+                    .and_then(|fee| to_u64(fee, "priority fee").ok()),
+                access_list: transaction
+                    .access_list
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|item| AccessListItem {
+                        address: Address::from(format!("{:?}", item.address).as_str()),
+                        storage_keys: item
+                            .storage_keys
+                            .iter()
+                            .map(|key| key.as_bytes().to_vec())
+                            .collect(),
+                    })
+                    .collect(),
+                envelope,
+                call: None,
+            }
         });
         Ok(transaction)
     }
 }
+
+#[cfg_attr(not(feature = "wasm"), async_trait)]
+#[cfg_attr(feature = "wasm", async_trait(? Send))]
+impl Client for Web3Client<Http> {
+    async fn get_transaction_count(&self, address: &crate::Address) -> VdrResult<[u64; 4]> {
+        self.fetch_transaction_count(address).await
+    }
+
+    async fn submit_transaction(&self, transaction: &[u8]) -> VdrResult<Vec<u8>> {
+        trace!(
+            "Submit transaction process has started. Transaction: {:?}",
+            transaction
+        );
+
+        let hash = self.send_raw_transaction(transaction).await?;
+        let receipt = self.wait_for_receipt(hash).await?;
+        self.check_receipt_status(&receipt).await?;
+
+        trace!("Submitted transaction: {:?}", transaction);
+
+        Ok(receipt.transaction_hash.0.to_vec())
+    }
+
+    async fn call_transaction(&self, to: &str, transaction: &[u8]) -> VdrResult<Vec<u8>> {
+        self.call(None, to, transaction, None).await
+    }
+
+    async fn simulate_transaction(
+        &self,
+        from: &Address,
+        to: &str,
+        transaction: &[u8],
+    ) -> VdrResult<Vec<u8>> {
+        self.call(Some(from), to, transaction, None).await
+    }
+
+    async fn estimate_gas(&self, from: &Address, to: &str, transaction: &[u8]) -> VdrResult<u64> {
+        self.estimate_transaction_gas(from, to, transaction).await
+    }
+
+    async fn gas_price(&self) -> VdrResult<u64> {
+        self.fetch_gas_price().await
+    }
+
+    async fn max_priority_fee_per_gas(&self) -> VdrResult<u64> {
+        self.fetch_max_priority_fee_per_gas().await
+    }
+
+    async fn get_receipt(&self, hash: &[u8]) -> VdrResult<TransactionReceipt> {
+        self.fetch_receipt(hash).await
+    }
+
+    async fn ping(&self) -> VdrResult<PingStatus> {
+        self.check_block_number().await
+    }
+
+    async fn get_transaction(&self, transaction_hash: &[u8]) -> VdrResult<Option<Transaction>> {
+        self.fetch_transaction(transaction_hash).await
+    }
+
+    async fn call_transaction_at(
+        &self,
+        to: &str,
+        transaction: &[u8],
+        block: u64,
+    ) -> VdrResult<Vec<u8>> {
+        self.call(None, to, transaction, Some(block)).await
+    }
+
+    async fn get_logs(
+        &self,
+        query: &EventQuery,
+        from_block: u64,
+        to_block: Option<u64>,
+    ) -> VdrResult<Vec<EventLog>> {
+        self.fetch_logs(query, from_block, to_block).await
+    }
+
+    async fn get_block(&self, number: Option<u64>) -> VdrResult<Block> {
+        self.fetch_block(number).await
+    }
+}
+
+#[cfg(not(feature = "wasm"))]
+#[async_trait]
+impl Client for Web3Client<WebSocket> {
+    async fn get_transaction_count(&self, address: &crate::Address) -> VdrResult<[u64; 4]> {
+        self.fetch_transaction_count(address).await
+    }
+
+    async fn submit_transaction(&self, transaction: &[u8]) -> VdrResult<Vec<u8>> {
+        trace!(
+            "Submit transaction process has started. Transaction: {:?}",
+            transaction
+        );
+
+        let hash = self.submit_with_new_heads(transaction).await?;
+
+        trace!("Submitted transaction: {:?}", transaction);
+
+        Ok(hash)
+    }
+
+    async fn call_transaction(&self, to: &str, transaction: &[u8]) -> VdrResult<Vec<u8>> {
+        self.call(None, to, transaction, None).await
+    }
+
+    async fn simulate_transaction(
+        &self,
+        from: &Address,
+        to: &str,
+        transaction: &[u8],
+    ) -> VdrResult<Vec<u8>> {
+        self.call(Some(from), to, transaction, None).await
+    }
+
+    async fn estimate_gas(&self, from: &Address, to: &str, transaction: &[u8]) -> VdrResult<u64> {
+        self.estimate_transaction_gas(from, to, transaction).await
+    }
+
+    async fn gas_price(&self) -> VdrResult<u64> {
+        self.fetch_gas_price().await
+    }
+
+    async fn max_priority_fee_per_gas(&self) -> VdrResult<u64> {
+        self.fetch_max_priority_fee_per_gas().await
+    }
+
+    async fn get_receipt(&self, hash: &[u8]) -> VdrResult<TransactionReceipt> {
+        self.fetch_receipt(hash).await
+    }
+
+    async fn ping(&self) -> VdrResult<PingStatus> {
+        self.check_block_number().await
+    }
+
+    async fn get_transaction(&self, transaction_hash: &[u8]) -> VdrResult<Option<Transaction>> {
+        self.fetch_transaction(transaction_hash).await
+    }
+
+    async fn call_transaction_at(
+        &self,
+        to: &str,
+        transaction: &[u8],
+        block: u64,
+    ) -> VdrResult<Vec<u8>> {
+        self.call(None, to, transaction, Some(block)).await
+    }
+
+    async fn get_logs(
+        &self,
+        query: &EventQuery,
+        from_block: u64,
+        to_block: Option<u64>,
+    ) -> VdrResult<Vec<EventLog>> {
+        self.fetch_logs(query, from_block, to_block).await
+    }
+
+    async fn get_block(&self, number: Option<u64>) -> VdrResult<Block> {
+        self.fetch_block(number).await
+    }
+
+    async fn subscribe_events(&self, query: &EventQuery) -> VdrResult<EventStream> {
+        trace!(
+            "Subscribe to events process has started. Query: {:?}",
+            query
+        );
+
+        let filter = FilterBuilder::default()
+            .address(vec![event_address(query)?])
+            .topics(
+                event_topic(query.event_signature.as_ref(), "event signature")?,
+                event_topic(query.indexed_topic.as_ref(), "indexed topic")?,
+                None,
+                None,
+            )
+            .build();
+
+        let subscription = self.client.eth_subscribe().subscribe_logs(filter).await?;
+
+        trace!("Subscribed to events. Query: {:?}", query);
+
+        let stream = subscription.map(|log| log.map(convert_log).map_err(VdrError::from));
+        Ok(Box::pin(stream))
+    }
+}
+
+fn event_address(query: &EventQuery) -> VdrResult<EthAddress> {
+    EthAddress::from_str(query.address.as_ref()).map_err(|_| {
+        VdrError::CommonInvalidData(format!("Invalid contract address {:?}", query.address))
+    })
+}
+
+fn event_topic(topic: Option<&Vec<u8>>, name: &str) -> VdrResult<Option<Vec<H256>>> {
+    match topic {
+        Some(topic) if topic.len() == H256::len_bytes() => Ok(Some(vec![H256::from_slice(topic)])),
+        Some(topic) => Err(VdrError::CommonInvalidData(format!(
+            "Invalid {} {:?}",
+            name, topic
+        ))),
+        None => Ok(None),
+    }
+}
+
+fn convert_log(log: Log) -> EventLog {
+    EventLog {
+        address: Address::from(format!("{:?}", log.address).as_str()),
+        topics: log
+            .topics
+            .iter()
+            .map(|topic| topic.as_bytes().to_vec())
+            .collect(),
+        data: log.data.0,
+        block_number: log.block_number.map(|number| number.as_u64()),
+        transaction_hash: log.transaction_hash.map(|hash| hash.as_bytes().to_vec()),
+    }
+}
+
+pub(crate) fn convert_receipt(receipt: EthTransactionReceipt) -> TransactionReceipt {
+    let status = match receipt.status.map(|status| status.as_u64()) {
+        Some(1) => ReceiptStatus::Success,
+        _ => ReceiptStatus::Failure,
+    };
+    TransactionReceipt {
+        transaction_hash: receipt.transaction_hash.as_bytes().to_vec(),
+        block_hash: receipt.block_hash.map(|hash| hash.as_bytes().to_vec()),
+        block_number: receipt.block_number.map(|number| number.as_u64()),
+        from: Address::from(format!("{:?}", receipt.from).as_str()),
+        to: receipt
+            .to
+            .map(|to| Address::from(format!("{:?}", to).as_str())),
+        gas_used: receipt.gas_used.map(|gas_used| gas_used.as_u64()),
+        status,
+        logs: receipt.logs.into_iter().map(convert_log).collect(),
+        events: Vec::new(),
+    }
+}
+
+fn to_u64(value: U256, name: &str) -> VdrResult<u64> {
+    if value > U256::from(u64::MAX) {
+        let vdr_error =
+            VdrError::ClientInvalidResponse(format!("Too big {} value: {:?}", name, value));
+
+        warn!("Error: {} during response conversion", vdr_error);
+
+        return Err(vdr_error);
+    }
+    Ok(value.as_u64())
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn convert_log_test() {
+        let log: Log = serde_json::from_value(json!({
+            "address": "0x0000000000000000000000000000000000003333",
+            "topics": ["0x0101010101010101010101010101010101010101010101010101010101010101"],
+            "data": "0x0102",
+            "blockNumber": "0x10",
+            "transactionHash": "0x0202020202020202020202020202020202020202020202020202020202020202",
+            "blockHash": null,
+            "transactionIndex": null,
+            "logIndex": null,
+            "transactionLogIndex": null,
+            "logType": null,
+            "removed": null
+        }))
+        .unwrap();
+
+        let expected = EventLog {
+            address: Address::from("0x0000000000000000000000000000000000003333"),
+            topics: vec![vec![1; 32]],
+            data: vec![1, 2],
+            block_number: Some(16),
+            transaction_hash: Some(vec![2; 32]),
+        };
+        assert_eq!(expected, convert_log(log));
+    }
+
+    #[async_std::test]
+    async fn get_transaction_count_of_unreachable_node_test() {
+        let client = Web3Client::new("http://127.0.0.1:1").unwrap();
+
+        let err = client
+            .get_transaction_count(&Address::from("0xf0e2db6c8dc6c681bb5d6ad121a107f300e9b2b5"))
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(VdrError::ClientNodeUnreachable, err);
+    }
+}
@@ -1,37 +1,24 @@
 use crate::{
-    client::{implementation::web3::client::Web3Client, Contract},
+    client::Contract,
     error::{VdrError, VdrResult},
-    types::{ContractOutput, ContractSpec},
+    types::{ContractOutput, ContractSpec, EventLog, TransactionCallParam},
     Address,
 };
 
+use ethabi::{
+    ethereum_types::H256, Address as EthAddress, Contract as EthContract, Event, Function, RawLog,
+    Token,
+};
 use log::{trace, warn};
 use std::str::FromStr;
 
-#[cfg(not(feature = "wasm"))]
-use web3::{
-    contract::Contract as Web3ContractImpl,
-    ethabi::{Address as EthAddress, Function, Token},
-    transports::Http,
-};
-#[cfg(feature = "wasm")]
-use web3_wasm::{
-    contract::Contract as Web3ContractImpl,
-    ethabi::{Address as EthAddress, Function, Token},
-    transports::Http,
-};
-
 pub struct Web3Contract {
     address: Address,
-    contract: Web3ContractImpl<Http>,
+    contract: EthContract,
 }
 
 impl Web3Contract {
-    pub fn new(
-        web3_client: &Web3Client,
-        address: &str,
-        contract_spec: &ContractSpec,
-    ) -> VdrResult<Web3Contract> {
+    pub fn new(address: &str, contract_spec: &ContractSpec) -> VdrResult<Web3Contract> {
         trace!("Started creating new Web3Contract. Address: {:?}", address);
 
         let abi = serde_json::to_vec(&contract_spec.abi).map_err(|err| {
@@ -54,10 +41,22 @@ impl Web3Contract {
 
             vdr_error
         })?;
-        let contract =
-            Web3ContractImpl::from_json(web3_client.eth(), parsed_address, abi.as_slice())?;
+        let contract = EthContract::load(abi.as_slice()).map_err(|err| {
+            let vdr_error = VdrError::ContractInvalidSpec(format!(
+                "Unable to parse contract ABI. Err: {:?}",
+                err.to_string()
+            ));
+
+            warn!("Error: {:?} during creating new Web3Contract", vdr_error);
+
+            vdr_error
+        })?;
 
-        trace!("Created new contract: {:?}", contract);
+        trace!(
+            "Created new contract: {:?} at address: {:?}",
+            contract,
+            parsed_address
+        );
 
         Ok(Web3Contract {
             contract,
@@ -66,7 +65,7 @@ impl Web3Contract {
     }
 
     fn function(&self, name: &str) -> VdrResult<&Function> {
-        self.contract.abi().function(name).map_err(|err| {
+        self.contract.function(name).map_err(|err| {
             let vdr_error = VdrError::from(err);
 
             warn!(
@@ -77,6 +76,19 @@ impl Web3Contract {
             vdr_error
         })
     }
+
+    fn event(&self, name: &str) -> VdrResult<&Event> {
+        self.contract.event(name).map_err(|err| {
+            let vdr_error = VdrError::from(err);
+
+            warn!(
+                "Error: {:?} during getting smart contract event: {}",
+                vdr_error, name
+            );
+
+            vdr_error
+        })
+    }
 }
 
 impl Contract for Web3Contract {
@@ -107,6 +119,49 @@ impl Contract for Web3Contract {
         encoded_input
     }
 
+    fn decode_input(&self, data: &[u8]) -> VdrResult<Option<(String, Vec<TransactionCallParam>)>> {
+        trace!("Input data: {:?} decoding has started", data);
+
+        if data.len() < 4 {
+            return Ok(None);
+        }
+        let function = match self
+            .contract
+            .functions()
+            .find(|function| function.short_signature() == data[..4])
+        {
+            Some(function) => function,
+            None => return Ok(None),
+        };
+        let tokens = function.decode_input(&data[4..]).map_err(|err| {
+            warn!(
+                "Error: {:?} during decoding input of contract method: {}",
+                err, function.name
+            );
+
+            VdrError::ContractInvalidInputData
+        })?;
+        let params = function
+            .inputs
+            .iter()
+            .zip(tokens.iter())
+            .map(|(input, token)| TransactionCallParam {
+                name: input.name.clone(),
+                type_: input.kind.to_string(),
+                value: format_token(token),
+            })
+            .collect();
+        let decoded_input = (function.name.clone(), params);
+
+        trace!(
+            "Input data: {:?} decoding has finished. Result: {:?}",
+            data,
+            decoded_input
+        );
+
+        Ok(Some(decoded_input))
+    }
+
     fn decode_output(&self, method: &str, output: &[u8]) -> VdrResult<ContractOutput> {
         trace!("Output: {:?} decoding has started", output);
 
@@ -124,4 +179,242 @@ impl Contract for Web3Contract {
 
         decoded_output
     }
+
+    fn event_signature(&self, event: &str) -> VdrResult<Vec<u8>> {
+        Ok(self.event(event)?.signature().as_bytes().to_vec())
+    }
+
+    fn decode_event(&self, event: &str, log: &EventLog) -> VdrResult<ContractOutput> {
+        trace!("Event log: {:?} decoding has started", log);
+
+        let topics = log
+            .topics
+            .iter()
+            .map(|topic| match topic.len() {
+                32 => Ok(H256::from_slice(topic)),
+                _ => Err(VdrError::ContractInvalidResponseData(format!(
+                    "Invalid event topic: {:?}",
+                    topic
+                ))),
+            })
+            .collect::<VdrResult<Vec<H256>>>()?;
+        let raw_log = RawLog {
+            topics,
+            data: log.data.clone(),
+        };
+        let decoded_event = self
+            .event(event)?
+            .parse_log(raw_log)
+            .map_err(|err| {
+                let vdr_error = VdrError::ContractInvalidResponseData(format!(
+                    "Unable to decode event log. Err: {:?}",
+                    err
+                ));
+
+                warn!("Error: {:?} during decoding event log", vdr_error);
+
+                vdr_error
+            })
+            .map(|log| {
+                ContractOutput::new(log.params.into_iter().map(|param| param.value).collect())
+            });
+
+        trace!(
+            "Event log: {:?} decoding has finished. Result: {:?}",
+            log,
+            decoded_event
+        );
+
+        decoded_event
+    }
+
+    fn decode_error(&self, data: &[u8]) -> VdrResult<Option<(String, ContractOutput)>> {
+        trace!("Error data: {:?} decoding has started", data);
+
+        if data.len() < 4 {
+            return Ok(None);
+        }
+        let error = match self
+            .contract
+            .errors()
+            .find(|error| error.signature()[..4] == data[..4])
+        {
+            Some(error) => error,
+            None => return Ok(None),
+        };
+        let params = error.decode(&data[4..]).map_err(|err| {
+            let vdr_error = VdrError::ContractInvalidResponseData(format!(
+                "Unable to decode contract error: {}. Err: {:?}",
+                error.name, err
+            ));
+
+            warn!("Error: {:?} during decoding contract error", vdr_error);
+
+            vdr_error
+        })?;
+        let decoded_error = (error.name.clone(), ContractOutput::from(params));
+
+        trace!(
+            "Error data: {:?} decoding has finished. Result: {:?}",
+            data,
+            decoded_error
+        );
+
+        Ok(Some(decoded_error))
+    }
+}
+
+pub(crate) fn format_token(token: &Token) -> String {
+    let format_tokens = |tokens: &[Token]| {
+        tokens
+            .iter()
+            .map(format_token)
+            .collect::<Vec<String>>()
+            .join(",")
+    };
+    match token {
+        Token::Address(address) => format!("{:?}", address),
+        Token::Bytes(bytes) | Token::FixedBytes(bytes) => format!("0x{}", hex::encode(bytes)),
+        Token::Uint(value) => value.to_string(),
+        Token::String(value) => value.clone(),
+        Token::Array(tokens) | Token::FixedArray(tokens) => format!("[{}]", format_tokens(tokens)),
+        Token::Tuple(tokens) => format!("({})", format_tokens(tokens)),
+        _ => token.to_string(),
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use serde_json::json;
+
+    pub const ROLE_CONTROL_ADDRESS: &str = "0x0000000000000000000000000000000000006666";
+    const ACCOUNT: &str = "0xb9059400dcd05158ffd8ca092937989dd27b3bdc";
+    const SENDER: &str = "0xf0e2db6c8dc6c681bb5d6ad121a107f300e9b2b5";
+
+    pub fn role_control_spec() -> ContractSpec {
+        ContractSpec {
+            name: "RoleControl".to_string(),
+            abi: json!([{
+                "type": "event",
+                "name": "RoleAssigned",
+                "anonymous": false,
+                "inputs": [
+                    { "name": "role", "type": "uint8", "indexed": false },
+                    { "name": "account", "type": "address", "indexed": true },
+                    { "name": "sender", "type": "address", "indexed": true }
+                ]
+            }, {
+                "type": "function",
+                "name": "assignRole",
+                "stateMutability": "nonpayable",
+                "inputs": [
+                    { "name": "role", "type": "uint8" },
+                    { "name": "account", "type": "address" }
+                ],
+                "outputs": []
+            }, {
+                "type": "error",
+                "name": "Unauthorized",
+                "inputs": [
+                    { "name": "sender", "type": "address" }
+                ]
+            }]),
+        }
+    }
+
+    fn address_topic(address: &str) -> Vec<u8> {
+        ethabi::encode(&[Token::Address(EthAddress::from_str(address).unwrap())])
+    }
+
+    #[test]
+    fn decode_event_test() {
+        let contract = Web3Contract::new(ROLE_CONTROL_ADDRESS, &role_control_spec()).unwrap();
+        let signature = contract.event_signature("RoleAssigned").unwrap();
+        let log = EventLog {
+            address: Address::from(ROLE_CONTROL_ADDRESS),
+            topics: vec![signature, address_topic(ACCOUNT), address_topic(SENDER)],
+            data: ethabi::encode(&[Token::Uint(2.into())]),
+            ..EventLog::default()
+        };
+
+        let output = contract.decode_event("RoleAssigned", &log).unwrap();
+
+        assert_eq!(2, output.get_u8(0).unwrap());
+        assert_eq!(Address::from(ACCOUNT), output.get_address(1).unwrap());
+        assert_eq!(Address::from(SENDER), output.get_address(2).unwrap());
+    }
+
+    #[test]
+    fn decode_unknown_event_test() {
+        let contract = Web3Contract::new(ROLE_CONTROL_ADDRESS, &role_control_spec()).unwrap();
+        let err = contract
+            .decode_event("RoleRevoked", &EventLog::default())
+            .unwrap_err();
+        assert_eq!(
+            VdrError::ContractInvalidName("RoleRevoked".to_string()),
+            err
+        );
+    }
+
+    #[test]
+    fn decode_error_test() {
+        let contract = Web3Contract::new(ROLE_CONTROL_ADDRESS, &role_control_spec()).unwrap();
+        let data = contract
+            .contract
+            .error("Unauthorized")
+            .unwrap()
+            .encode(&[Token::Address(EthAddress::from_str(SENDER).unwrap())])
+            .unwrap();
+
+        let (name, params) = contract.decode_error(&data).unwrap().unwrap();
+
+        assert_eq!("Unauthorized", name);
+        assert_eq!(Address::from(SENDER), params.get_address(0).unwrap());
+    }
+
+    #[test]
+    fn decode_unknown_error_test() {
+        let contract = Web3Contract::new(ROLE_CONTROL_ADDRESS, &role_control_spec()).unwrap();
+        assert!(contract.decode_error(&[1, 2, 3, 4]).unwrap().is_none());
+    }
+
+    #[test]
+    fn decode_input_test() {
+        let contract = Web3Contract::new(ROLE_CONTROL_ADDRESS, &role_control_spec()).unwrap();
+        let data = contract
+            .encode_input(
+                "assignRole",
+                &[
+                    Token::Uint(2.into()),
+                    Token::Address(EthAddress::from_str(ACCOUNT).unwrap()),
+                ],
+            )
+            .unwrap();
+
+        let (method, params) = contract.decode_input(&data).unwrap().unwrap();
+
+        assert_eq!("assignRole", method);
+        assert_eq!(
+            vec![
+                TransactionCallParam {
+                    name: "role".to_string(),
+                    type_: "uint8".to_string(),
+                    value: "2".to_string(),
+                },
+                TransactionCallParam {
+                    name: "account".to_string(),
+                    type_: "address".to_string(),
+                    value: ACCOUNT.to_string(),
+                },
+            ],
+            params
+        );
+    }
+
+    #[test]
+    fn decode_unknown_input_test() {
+        let contract = Web3Contract::new(ROLE_CONTROL_ADDRESS, &role_control_spec()).unwrap();
+        assert!(contract.decode_input(&[1, 2, 3, 4]).unwrap().is_none());
+    }
 }
@@ -1,77 +1,236 @@
 pub mod client;
 pub mod constants;
 pub mod implementation;
+pub(crate) mod nonce;
 pub mod quorum;
 
 use crate::{
-    error::VdrResult,
-    types::{Address, ContractOutput, ContractParam, PingStatus},
+    error::{VdrError, VdrResult},
+    types::{
+        Address, Block, ContractOutput, ContractParam, EventLog, EventQuery, EventStream,
+        PingStatus, TransactionCallParam, TransactionReceipt,
+    },
     Transaction,
 };
 use async_trait::async_trait;
 
-pub use client::LedgerClient;
+pub use client::{LedgerClient, LedgerClientBuilder};
 pub use constants::*;
-pub use quorum::{QuorumConfig, QuorumHandler};
+pub use quorum::{QuorumClientFactory, QuorumConfig, QuorumHandler};
 
 #[cfg(test)]
 use mockall::automock;
 
+/// Transport used by [LedgerClient] to interact with a ledger node.
+///
+/// `LedgerClient` performs all network interaction through this trait, so a custom implementation
+/// (another RPC transport, recording proxy, test double) can be injected using
+/// [LedgerClientBuilder::set_client] and [LedgerClientBuilder::set_quorum_client_factory].
+///
+/// Implementations are expected to follow the semantics of the corresponding Ethereum JSON-RPC
+/// methods (named for every method below) and report failures as [crate::VdrError]
+/// `Client*` variants.
 #[cfg_attr(test, automock)]
 #[cfg_attr(not(feature = "wasm"), async_trait)]
 #[cfg_attr(feature = "wasm", async_trait(?Send))]
 pub trait Client: Sync + Send {
-    /// Retrieve count of transaction for the given account
+    /// Retrieve count of transaction for the given account (`eth_getTransactionCount`)
     ///
     /// # Params
     /// - `address` address of an account to get number of written transactions
     ///
     /// # Returns
-    /// number of transactions
+    /// number of transactions as 256-bit unsigned integer split into little-endian 64-bit words
     async fn get_transaction_count(&self, address: &Address) -> VdrResult<[u64; 4]>;
 
-    /// Submit transaction to the ledger
+    /// Submit signed write transaction to the ledger (`eth_sendRawTransaction`)
+    ///
+    /// Implementation must wait until the transaction is included into a block.
     ///
     /// # Params
-    /// - `transaction` transaction to submit
-    /// - `transaction` prepared transaction to submit
+    /// - `transaction` signed transaction encoded according to EIP-2718 (RLP for legacy transactions)
     ///
     /// # Returns
-    /// hash of a block in which transaction included
+    /// hash of the submitted transaction.
+    /// Transaction included into a block with failure status must be reported as
+    /// [crate::VdrError::ClientTransactionFailed] in the same format as reverted calls, so its
+    /// revert data is decoded by [LedgerClient] as well.
+    /// Transaction rejected by the node on sending must be reported as
+    /// [crate::VdrError::ClientTransactionRejected], so [LedgerClient] hands out its nonce again
     async fn submit_transaction(&self, transaction: &[u8]) -> VdrResult<Vec<u8>>;
 
-    /// Submit read transaction to the ledger
+    /// Execute read transaction on the ledger without creating a transaction (`eth_call`)
     ///
     /// # Params
-    /// - `transaction` prepared transaction to submit
+    /// - `to` address of the contract to call
+    /// - `transaction` ABI encoded contract method call
     ///
     /// # Returns
-    /// result data of transaction execution
+    /// result data of transaction execution.
+    /// Reverted execution must be reported as [crate::VdrError::ClientTransactionReverted]
+    /// containing the JSON-RPC error object. Hex encoded revert data set into its `data` field is
+    /// decoded by [LedgerClient] into the matching contract error
     async fn call_transaction(&self, to: &str, transaction: &[u8]) -> VdrResult<Vec<u8>>;
 
-    /// Get the receipt for the given block hash
+    /// Execute read transaction against the ledger state at the given block (`eth_call` with block number)
+    ///
+    /// Transports which do not keep historical state keep the default implementation returning
+    /// [crate::VdrError::ClientUnsupportedOperation]
+    ///
+    /// # Params
+    /// - `to` address of the contract to call
+    /// - `transaction` ABI encoded contract method call
+    /// - `block` number of the block to execute the call at
+    ///
+    /// # Returns
+    /// result data of transaction execution.
+    /// Reverted execution must be reported the same way as for [Client::call_transaction]
+    async fn call_transaction_at(
+        &self,
+        _to: &str,
+        _transaction: &[u8],
+        _block: u64,
+    ) -> VdrResult<Vec<u8>> {
+        Err(VdrError::ClientUnsupportedOperation(
+            "Client does not support calls at historical blocks".to_string(),
+        ))
+    }
+
+    /// Execute write transaction on behalf of the sender without submitting it to the ledger
+    /// (`eth_call` with `from` at the latest block)
+    ///
+    /// # Params
+    /// - `from` transaction sender account address
+    /// - `to` address of the contract to call
+    /// - `transaction` ABI encoded contract method call
+    ///
+    /// # Returns
+    /// result data of transaction execution.
+    /// Reverted execution must be reported the same way as for [Client::call_transaction]
+    async fn simulate_transaction(
+        &self,
+        _from: &Address,
+        _to: &str,
+        _transaction: &[u8],
+    ) -> VdrResult<Vec<u8>> {
+        Err(VdrError::ClientUnsupportedOperation(
+            "Client does not support transaction simulation".to_string(),
+        ))
+    }
+
+    /// Estimate gas required to execute write transaction (`eth_estimateGas`)
+    ///
+    /// # Params
+    /// - `from` transaction sender account address
+    /// - `to` address of the contract to call
+    /// - `transaction` ABI encoded contract method call
+    ///
+    /// # Returns
+    /// estimated amount of gas.
+    /// Reverted execution must be reported the same way as for [Client::call_transaction]
+    async fn estimate_gas(
+        &self,
+        _from: &Address,
+        _to: &str,
+        _transaction: &[u8],
+    ) -> VdrResult<u64> {
+        Err(VdrError::ClientUnsupportedOperation(
+            "Client does not support gas estimation".to_string(),
+        ))
+    }
+
+    /// Get the current gas price (`eth_gasPrice`)
+    ///
+    /// # Returns
+    /// gas price in wei. For EIP-1559 networks it includes the base fee of the latest block
+    async fn gas_price(&self) -> VdrResult<u64> {
+        Err(VdrError::ClientUnsupportedOperation(
+            "Client does not support gas price requests".to_string(),
+        ))
+    }
+
+    /// Get the priority fee per gas suggested for EIP-1559 transactions (`eth_maxPriorityFeePerGas`)
+    ///
+    /// # Returns
+    /// priority fee in wei
+    async fn max_priority_fee_per_gas(&self) -> VdrResult<u64> {
+        Err(VdrError::ClientUnsupportedOperation(
+            "Client does not support priority fee requests".to_string(),
+        ))
+    }
+
+    /// Get the receipt for the given transaction hash (`eth_getTransactionReceipt`)
     ///
     /// # Params
-    /// - `hash` hash of a block to get the receipt
+    /// - `hash` hash of a transaction to get the receipt
     ///
     /// # Returns
-    /// receipt as JSON string for the requested block
-    async fn get_receipt(&self, hash: &[u8]) -> VdrResult<String>;
+    /// receipt with raw event logs (`events` are left empty and filled by [LedgerClient])
+    async fn get_receipt(&self, hash: &[u8]) -> VdrResult<TransactionReceipt>;
 
     /// Check client connection (passed node is alive and return valid ledger data)
     ///
     /// # Returns
-    /// ledger status
+    /// ledger status. Unreachable node should be reported as error status rather than error
     async fn ping(&self) -> VdrResult<PingStatus>;
 
-    /// Get the transaction for the given transaction hash
+    /// Get the transaction for the given transaction hash (`eth_getTransactionByHash`)
     ///
     /// # Params
     /// - `hash` hash of a transaction to get
     ///
     /// # Returns
-    /// transaction for the requested hash
+    /// transaction for the requested hash or `None` if the transaction is unknown to the node
     async fn get_transaction(&self, hash: &[u8]) -> VdrResult<Option<Transaction>>;
+
+    /// Get events emitted by a contract in the range of blocks (`eth_getLogs`)
+    ///
+    /// # Params
+    /// - `query` filter defining events to get
+    /// - `from_block` number of the first block of the range
+    /// - `to_block` number of the last block of the range (the latest block if not set)
+    ///
+    /// # Returns
+    /// event logs matching to the query ordered as they were emitted
+    async fn get_logs(
+        &self,
+        _query: &EventQuery,
+        _from_block: u64,
+        _to_block: Option<u64>,
+    ) -> VdrResult<Vec<EventLog>> {
+        Err(VdrError::ClientUnsupportedOperation(
+            "Client does not support event log queries".to_string(),
+        ))
+    }
+
+    /// Get block by number (`eth_getBlockByNumber`)
+    ///
+    /// # Params
+    /// - `number` number of the block (the latest block if not set)
+    ///
+    /// # Returns
+    /// block header. Unknown block must be reported as [crate::VdrError::ClientInvalidResponse]
+    async fn get_block(&self, _number: Option<u64>) -> VdrResult<Block> {
+        Err(VdrError::ClientUnsupportedOperation(
+            "Client does not support block requests".to_string(),
+        ))
+    }
+
+    /// Subscribe to events emitted by a contract (`eth_subscribe` for `logs`)
+    ///
+    /// Transports which do not support subscriptions (like HTTP) keep the default implementation
+    /// returning [crate::VdrError::ClientUnsupportedOperation]
+    ///
+    /// # Params
+    /// - `query` filter defining events to subscribe
+    ///
+    /// # Returns
+    /// stream of events matching to the query emitted after the subscription
+    async fn subscribe_events(&self, _query: &EventQuery) -> VdrResult<EventStream> {
+        Err(VdrError::ClientUnsupportedOperation(
+            "Client does not support event subscriptions".to_string(),
+        ))
+    }
 }
 
 pub trait Contract: Sync + Send {
@@ -91,6 +250,16 @@ pub trait Contract: Sync + Send {
     /// encoded data to set into transaction
     fn encode_input(&self, method: &str, params: &[ContractParam]) -> VdrResult<Vec<u8>>;
 
+    /// Decode the data of a contract method call into human readable form
+    ///
+    /// # Params
+    /// - `data` encoded contract method call (method selector followed by ABI encoded params)
+    ///
+    /// # Returns
+    /// name of the called method with its named and typed parameters
+    /// or `None` if the contract does not define a matching method
+    fn decode_input(&self, data: &[u8]) -> VdrResult<Option<(String, Vec<TransactionCallParam>)>>;
+
     /// Decode the value (bytes) returned as the result of the execution of a contract method
     ///
     /// # Params
@@ -100,4 +269,33 @@ pub trait Contract: Sync + Send {
     /// # Returns
     /// contract execution result in decoded form
     fn decode_output(&self, method: &str, output: &[u8]) -> VdrResult<ContractOutput>;
+
+    /// Get the hash of an event signature (the first topic of event logs)
+    ///
+    /// # Params
+    /// - `event` name of the event
+    ///
+    /// # Returns
+    /// event signature hash
+    fn event_signature(&self, event: &str) -> VdrResult<Vec<u8>>;
+
+    /// Decode the parameters of an event log emitted by the contract
+    ///
+    /// # Params
+    /// - `event` name of the event
+    /// - `log` event log to decode
+    ///
+    /// # Returns
+    /// event parameters in decoded form (in the order of the event definition)
+    fn decode_event(&self, event: &str, log: &EventLog) -> VdrResult<ContractOutput>;
+
+    /// Decode the data of a reverted execution as one of the custom errors defined by the contract
+    ///
+    /// # Params
+    /// - `data` revert data (error selector followed by ABI encoded error parameters)
+    ///
+    /// # Returns
+    /// name of the custom error with its parameters in decoded form
+    /// or `None` if the contract does not define a matching error
+    fn decode_error(&self, data: &[u8]) -> VdrResult<Option<(String, ContractOutput)>>;
 }
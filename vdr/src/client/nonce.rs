@@ -0,0 +1,360 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    ops::Range,
+    sync::{Mutex, MutexGuard},
+};
+
+use log::{trace, warn};
+
+use crate::{
+    error::{VdrError, VdrResult},
+    Address,
+};
+
+/// Tracks nonces of pending transactions per account, so transactions built concurrently for the
+/// same account get distinct nonces without waiting for the previous ones to be included into a
+/// block.
+///
+/// The node transaction count is used as the lower bound of the next nonce, so the local state is
+/// synchronized whenever the account sends transactions bypassing the manager. The local state
+/// falls back to the node transaction count when none of the nonces above it is in flight, so
+/// transactions dropped after being accepted by the node do not leave gaps.
+/// Nonces of transactions rejected by the node are released and handed out again, so they do not
+/// leave gaps blocking the following transactions of the account.
+#[derive(Debug, Default)]
+pub(crate) struct NonceManager {
+    // accounts are keyed by lowercase address, as the same account can be passed checksummed
+    accounts: Mutex<HashMap<String, AccountNonces>>,
+}
+
+#[derive(Debug)]
+struct AccountNonces {
+    next: u64,
+    released: BTreeSet<u64>,
+    // reserved nonces of transactions which have not been submitted yet
+    in_flight: BTreeSet<u64>,
+}
+
+impl NonceManager {
+    /// Reserve a range of consecutive nonces for the account
+    ///     A single nonce is taken from the released ones first
+    ///
+    /// # Params
+    /// - `address` account address
+    /// - `node_nonce` transaction count of the account reported by the node
+    /// - `count` number of nonces to reserve
+    ///
+    /// # Returns
+    /// reserved nonces
+    pub(crate) fn reserve(
+        &self,
+        address: &Address,
+        node_nonce: [u64; 4],
+        count: u64,
+    ) -> VdrResult<Range<u64>> {
+        let node_nonce = Self::to_u64(node_nonce)?;
+
+        let mut accounts = self.accounts()?;
+        let nonces = accounts
+            .entry(Self::key(address))
+            .or_insert_with(|| AccountNonces {
+                next: node_nonce,
+                released: BTreeSet::new(),
+                in_flight: BTreeSet::new(),
+            });
+
+        if nonces.next < node_nonce {
+            trace!(
+                "Local nonce: {} of account: {:?} is behind the node nonce: {}. Resynchronizing",
+                nonces.next,
+                address,
+                node_nonce
+            );
+            nonces.next = node_nonce;
+        }
+        // released nonces below the node nonce have been used by transactions sent bypassing the manager
+        nonces.released = nonces.released.split_off(&node_nonce);
+        nonces.in_flight = nonces.in_flight.split_off(&node_nonce);
+
+        if nonces.next > node_nonce && nonces.in_flight.is_empty() {
+            trace!(
+                "Local nonce: {} of account: {:?} is ahead of the node nonce: {} without transactions in flight. Resynchronizing",
+                nonces.next,
+                address,
+                node_nonce
+            );
+            nonces.next = node_nonce;
+            nonces.released.clear();
+        }
+
+        if count == 1 {
+            if let Some(nonce) = nonces.released.pop_first() {
+                nonces.in_flight.insert(nonce);
+
+                trace!(
+                    "Reserved released nonce: {} for account: {:?}",
+                    nonce,
+                    address
+                );
+
+                return Ok(nonce..nonce + 1);
+            }
+        }
+
+        let start = nonces.next;
+        let end = start.checked_add(count).ok_or_else(|| {
+            VdrError::CommonInvalidData(format!("Unable to reserve {} nonces", count))
+        })?;
+        nonces.next = end;
+        nonces.in_flight.extend(start..end);
+
+        trace!(
+            "Reserved nonces: {:?} for account: {:?}",
+            start..end,
+            address
+        );
+
+        Ok(start..end)
+    }
+
+    /// Return reserved nonces which will not be used by transactions included into a block,
+    /// so they are handed out again instead of leaving gaps
+    ///
+    /// # Params
+    /// - `address` account address
+    /// - `nonces` released nonces
+    pub(crate) fn release(&self, address: &Address, nonces: Range<u64>) {
+        let Ok(mut accounts) = self.accounts() else {
+            return;
+        };
+        let Some(account) = accounts.get_mut(&Self::key(address)) else {
+            return;
+        };
+
+        for nonce in nonces.clone() {
+            account.in_flight.remove(&nonce);
+        }
+        account
+            .released
+            .extend(nonces.clone().filter(|nonce| *nonce < account.next));
+        // the top of the reserved range can be reserved again as a range
+        while account
+            .next
+            .checked_sub(1)
+            .is_some_and(|last| account.released.remove(&last))
+        {
+            account.next -= 1;
+        }
+
+        trace!("Released nonces: {:?} of account: {:?}", nonces, address);
+    }
+
+    /// Mark reserved nonces as no longer in flight, as transactions using them have been submitted
+    ///     The node transaction count becomes the source of truth for them: if such a transaction
+    ///     is dropped by the node, the following reservation resynchronizes with the node
+    ///
+    /// # Params
+    /// - `address` account address
+    /// - `nonces` nonces of submitted transactions
+    pub(crate) fn complete(&self, address: &Address, nonces: Range<u64>) {
+        let Ok(mut accounts) = self.accounts() else {
+            return;
+        };
+        let Some(account) = accounts.get_mut(&Self::key(address)) else {
+            return;
+        };
+
+        for nonce in nonces.clone() {
+            account.in_flight.remove(&nonce);
+        }
+
+        trace!("Completed nonces: {:?} of account: {:?}", nonces, address);
+    }
+
+    /// Forget locally tracked nonces of the account, so the next one is taken from the node
+    ///     Nonces reserved for transactions which have not been submitted yet are handed out again,
+    ///     so reset must be used only when the account has no such transactions
+    ///
+    /// # Params
+    /// - `address` account address
+    pub(crate) fn reset(&self, address: &Address) {
+        if let Ok(mut accounts) = self.accounts() {
+            accounts.remove(&Self::key(address));
+        }
+
+        trace!("Reset nonce of account: {:?}", address);
+    }
+
+    fn accounts(&self) -> VdrResult<MutexGuard<'_, HashMap<String, AccountNonces>>> {
+        self.accounts.lock().map_err(|_| {
+            let vdr_error = VdrError::CommonInvalidData("Nonce manager state is poisoned".into());
+
+            warn!("Error: {} during accessing nonces", vdr_error);
+
+            vdr_error
+        })
+    }
+
+    fn key(address: &Address) -> String {
+        address.as_ref().to_lowercase()
+    }
+
+    // Ledger nonces are limited to 64 bits (EIP-2681)
+    fn to_u64(nonce: [u64; 4]) -> VdrResult<u64> {
+        match nonce {
+            [nonce, 0, 0, 0] => Ok(nonce),
+            _ => Err(VdrError::CommonInvalidData(format!(
+                "Nonce {:?} exceeds 64 bits",
+                nonce
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::client::client::test::{IDENTITY_ACC, TRUSTEE_ACC};
+
+    #[test]
+    fn reserve_nonces_test() {
+        let nonce_manager = NonceManager::default();
+
+        assert_eq!(
+            5..6,
+            nonce_manager
+                .reserve(&TRUSTEE_ACC, [5, 0, 0, 0], 1)
+                .unwrap()
+        );
+        assert_eq!(
+            6..9,
+            nonce_manager
+                .reserve(&TRUSTEE_ACC, [5, 0, 0, 0], 3)
+                .unwrap()
+        );
+        assert_eq!(
+            0..1,
+            nonce_manager
+                .reserve(&IDENTITY_ACC, [0, 0, 0, 0], 1)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn reserve_nonces_resync_with_node_test() {
+        let nonce_manager = NonceManager::default();
+        nonce_manager
+            .reserve(&TRUSTEE_ACC, [1, 0, 0, 0], 1)
+            .unwrap();
+
+        assert_eq!(
+            10..11,
+            nonce_manager
+                .reserve(&TRUSTEE_ACC, [10, 0, 0, 0], 1)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn reserve_nonces_resync_down_with_node_test() {
+        let nonce_manager = NonceManager::default();
+        nonce_manager
+            .reserve(&TRUSTEE_ACC, [5, 0, 0, 0], 3)
+            .unwrap();
+
+        // transactions using nonces 6 and 7 are still in flight
+        nonce_manager.complete(&TRUSTEE_ACC, 5..6);
+        assert_eq!(
+            8..9,
+            nonce_manager
+                .reserve(&TRUSTEE_ACC, [5, 0, 0, 0], 1)
+                .unwrap()
+        );
+
+        // all submitted transactions have been dropped by the node
+        nonce_manager.complete(&TRUSTEE_ACC, 6..9);
+        assert_eq!(
+            5..6,
+            nonce_manager
+                .reserve(&TRUSTEE_ACC, [5, 0, 0, 0], 1)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn reset_nonce_test() {
+        let nonce_manager = NonceManager::default();
+        nonce_manager
+            .reserve(&TRUSTEE_ACC, [1, 0, 0, 0], 5)
+            .unwrap();
+
+        nonce_manager.reset(&TRUSTEE_ACC);
+
+        assert_eq!(
+            1..2,
+            nonce_manager
+                .reserve(&TRUSTEE_ACC, [1, 0, 0, 0], 1)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn reserve_nonces_ignores_address_case_test() {
+        let nonce_manager = NonceManager::default();
+        let checksummed = Address::from("0xF0E2Db6C8dC6c681bB5D6aD121A107f300e9B2b5");
+        nonce_manager
+            .reserve(&TRUSTEE_ACC, [1, 0, 0, 0], 1)
+            .unwrap();
+
+        assert_eq!(
+            2..3,
+            nonce_manager
+                .reserve(&checksummed, [1, 0, 0, 0], 1)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn release_nonces_test() {
+        let nonce_manager = NonceManager::default();
+        nonce_manager
+            .reserve(&TRUSTEE_ACC, [1, 0, 0, 0], 5)
+            .unwrap();
+
+        // released nonce in the middle of the reserved range is reused
+        nonce_manager.release(&TRUSTEE_ACC, 2..3);
+        assert_eq!(
+            2..3,
+            nonce_manager
+                .reserve(&TRUSTEE_ACC, [1, 0, 0, 0], 1)
+                .unwrap()
+        );
+
+        // released nonces at the top of the reserved range are reserved again as a range
+        nonce_manager.release(&TRUSTEE_ACC, 4..6);
+        assert_eq!(
+            4..6,
+            nonce_manager
+                .reserve(&TRUSTEE_ACC, [1, 0, 0, 0], 2)
+                .unwrap()
+        );
+
+        // released nonce used by the account bypassing the manager is dropped
+        nonce_manager.release(&TRUSTEE_ACC, 3..4);
+        assert_eq!(
+            6..7,
+            nonce_manager
+                .reserve(&TRUSTEE_ACC, [4, 0, 0, 0], 1)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn reserve_too_big_nonce_test() {
+        let err = NonceManager::default()
+            .reserve(&TRUSTEE_ACC, [0, 1, 0, 0], 1)
+            .err()
+            .unwrap();
+        assert!(matches!(err, VdrError::CommonInvalidData(_)));
+    }
+}
@@ -29,6 +29,9 @@ const DEFAULT_REQUEST_RETRIES: u8 = 4;
 const DEFAULT_REQUEST_TIMEOUT: u64 = 2000;
 const DEFAULT_RETRY_INTERVAL: u64 = 500;
 
+/// Factory creating a [Client] connected to the quorum node with the given address
+pub type QuorumClientFactory = dyn Fn(&str) -> VdrResult<Box<dyn Client>> + Send + Sync;
+
 pub struct QuorumHandler {
     clients: Vec<Arc<Box<dyn Client>>>,
     request_retries: u8,
@@ -38,13 +41,28 @@ pub struct QuorumHandler {
 
 impl QuorumHandler {
     pub fn new(config: QuorumConfig) -> VdrResult<QuorumHandler> {
+        Self::with_client_factory(config, &|node_address| {
+            let client: Box<dyn Client> = Box::new(Web3Client::new(node_address)?);
+            Ok(client)
+        })
+    }
+
+    /// Create quorum handler using custom clients for the quorum nodes
+    ///
+    /// # Params
+    ///  - `config` - [QuorumConfig] quorum configuration
+    ///  - `client_factory` - [QuorumClientFactory] function creating a client for every node from `config`
+    ///
+    /// # Returns
+    ///  quorum handler
+    pub fn with_client_factory(
+        config: QuorumConfig,
+        client_factory: &QuorumClientFactory,
+    ) -> VdrResult<QuorumHandler> {
         let clients = config
             .nodes
             .iter()
-            .map(|node_address| {
-                let client: Box<dyn Client> = Box::new(Web3Client::new(node_address)?);
-                Ok(Arc::new(client))
-            })
+            .map(|node_address| client_factory(node_address).map(Arc::new))
             .collect::<Result<Vec<_>, VdrError>>()?;
 
         Ok(QuorumHandler {
@@ -279,7 +297,7 @@ pub mod test {
                 .expect_get_transaction()
                 .with(eq(txn_hash.to_vec()))
                 .returning(move |_| {
-                    thread::sleep(time::Duration::from_millis(sleep_time_sec.into()));
+                    thread::sleep(time::Duration::from_millis(sleep_time_sec));
                     Ok(expected_output.clone())
                 });
 
@@ -414,7 +432,7 @@ pub mod test {
                     eq(transaction.data.to_vec()),
                 )
                 .returning(move |_, _| {
-                    thread::sleep(time::Duration::from_millis(sleep_time_sec.into()));
+                    thread::sleep(time::Duration::from_millis(sleep_time_sec));
                     expected_output.clone()
                 });
 
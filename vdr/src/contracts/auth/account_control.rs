@@ -0,0 +1,109 @@
+use crate::{
+    client::LedgerClient,
+    error::{VdrError, VdrResult},
+    types::{
+        Address, ContractParam, Transaction, TransactionBuilder, TransactionParser, TransactionType,
+    },
+};
+use log::{debug, info, warn};
+
+const CONTRACT_NAME: &str = "AccountControl";
+const METHOD_TRANSACTION_ALLOWED: &str = "transactionAllowed";
+
+/// Build transaction to execute AccountControl.transactionAllowed contract method to check whether
+/// an account is permitted to send a transaction
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `sender` transaction sender account address
+/// - `target` address of the contract transaction is sent to
+/// - `payload` transaction payload
+///
+/// # Returns
+/// Read transaction to submit
+pub async fn build_transaction_allowed_transaction(
+    client: &LedgerClient,
+    sender: &Address,
+    target: &Address,
+    payload: &[u8],
+) -> VdrResult<Transaction> {
+    debug!(
+        "{} txn build has started. Sender: {:?}, target: {:?}",
+        METHOD_TRANSACTION_ALLOWED, sender, target
+    );
+
+    let transaction = TransactionBuilder::new()
+        .set_contract(CONTRACT_NAME)
+        .set_method(METHOD_TRANSACTION_ALLOWED)
+        .add_param(sender.try_into()?)
+        .add_param(target.try_into()?)
+        .add_param(ContractParam::Uint(0.into()))
+        .add_param(ContractParam::Uint(0.into()))
+        .add_param(ContractParam::Uint(0.into()))
+        .add_param(ContractParam::Bytes(payload.to_vec()))
+        .set_type(TransactionType::Read)
+        .build(client)
+        .await?;
+
+    info!(
+        "{} txn build has finished. Result: {:?}",
+        METHOD_TRANSACTION_ALLOWED, transaction
+    );
+
+    Ok(transaction)
+}
+
+/// Parse the result of execution AccountControl.transactionAllowed contract method
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `bytes` result bytes returned from the ledger
+///
+/// # Returns
+/// whether the transaction is allowed
+pub fn parse_transaction_allowed_result(client: &LedgerClient, bytes: &[u8]) -> VdrResult<bool> {
+    debug!(
+        "{} result parse has started. Bytes to parse: {:?}",
+        METHOD_TRANSACTION_ALLOWED, bytes
+    );
+
+    let allowed = TransactionParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_method(METHOD_TRANSACTION_ALLOWED)
+        .parse::<bool>(client, bytes)?;
+
+    info!(
+        "{} result parse has finished. Result: {:?}",
+        METHOD_TRANSACTION_ALLOWED, allowed
+    );
+
+    Ok(allowed)
+}
+
+// Account permissioning is only enforced when the transaction is submitted, so it is checked
+// explicitly for simulated transactions. Skipped if AccountControl contract is not configured.
+pub(crate) async fn check_transaction_allowed(
+    client: &LedgerClient,
+    transaction: &Transaction,
+) -> VdrResult<()> {
+    if !client.has_contract(CONTRACT_NAME) {
+        return Ok(());
+    }
+
+    let sender = transaction.from.as_ref().ok_or_else(|| {
+        VdrError::ClientInvalidTransaction("Transaction `sender` is not set".to_string())
+    })?;
+    let check_transaction =
+        build_transaction_allowed_transaction(client, sender, &transaction.to, &transaction.data)
+            .await?;
+    let result = client.submit_transaction(&check_transaction).await?;
+    if !parse_transaction_allowed_result(client, &result)? {
+        let vdr_error = VdrError::ContractUnauthorized(sender.clone());
+
+        warn!("Error: {} during transaction permission check", vdr_error);
+
+        return Err(vdr_error);
+    }
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
+pub mod account_control;
 pub mod role;
 pub mod role_control;
 
 pub use role::*;
-pub use role_control::*;
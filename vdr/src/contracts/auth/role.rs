@@ -1,12 +1,13 @@
 use log::trace;
+use serde_derive::{Deserialize, Serialize};
 
 use crate::{
     error::VdrError,
-    types::{ContractOutput, ContractParam},
+    types::{Address, ContractOutput, ContractParam},
 };
 
 #[repr(u8)]
-#[derive(Clone, Copy, PartialEq, Debug)]
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
 pub enum Role {
     Empty = 0,
     Trustee = 1,
@@ -115,3 +116,69 @@ impl TryFrom<ContractOutput> for HasRole {
         Ok(has_role)
     }
 }
+
+/// Data of RoleControl.RoleAssigned event
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct RoleAssignedEvent {
+    pub role: Role,
+    pub account: Address,
+    pub sender: Address,
+}
+
+impl TryFrom<ContractOutput> for RoleAssignedEvent {
+    type Error = VdrError;
+
+    fn try_from(value: ContractOutput) -> Result<Self, Self::Error> {
+        trace!(
+            "RoleAssignedEvent convert from ContractOutput: {:?} has started",
+            value
+        );
+
+        let event = RoleAssignedEvent {
+            role: Role::try_from(value.get_u8(0)?)?,
+            account: value.get_address(1)?,
+            sender: value.get_address(2)?,
+        };
+
+        trace!(
+            "RoleAssignedEvent convert from ContractOutput: {:?} has finished. Result: {:?}",
+            value,
+            event
+        );
+
+        Ok(event)
+    }
+}
+
+/// Data of RoleControl.RoleRevoked event
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct RoleRevokedEvent {
+    pub role: Role,
+    pub account: Address,
+    pub sender: Address,
+}
+
+impl TryFrom<ContractOutput> for RoleRevokedEvent {
+    type Error = VdrError;
+
+    fn try_from(value: ContractOutput) -> Result<Self, Self::Error> {
+        trace!(
+            "RoleRevokedEvent convert from ContractOutput: {:?} has started",
+            value
+        );
+
+        let event = RoleRevokedEvent {
+            role: Role::try_from(value.get_u8(0)?)?,
+            account: value.get_address(1)?,
+            sender: value.get_address(2)?,
+        };
+
+        trace!(
+            "RoleRevokedEvent convert from ContractOutput: {:?} has finished. Result: {:?}",
+            value,
+            event
+        );
+
+        Ok(event)
+    }
+}
@@ -1,8 +1,11 @@
 use crate::{
     client::LedgerClient,
-    contracts::auth::{HasRole, Role},
+    contracts::auth::{HasRole, Role, RoleAssignedEvent, RoleRevokedEvent},
     error::VdrResult,
-    types::{Address, Transaction, TransactionBuilder, TransactionParser, TransactionType},
+    types::{
+        Address, EventLog, EventParser, EventStream, LedgerEvent, Transaction, TransactionBuilder,
+        TransactionParser, TransactionType,
+    },
 };
 use log::{debug, info};
 
@@ -11,6 +14,8 @@ const METHOD_ASSIGN_ROLE: &str = "assignRole";
 const METHOD_REVOKE_ROLE: &str = "revokeRole";
 const METHOD_HAS_ROLE: &str = "hasRole";
 const METHOD_GET_ROLE: &str = "getRole";
+const EVENT_ROLE_ASSIGNED: &str = "RoleAssigned";
+const EVENT_ROLE_REVOKED: &str = "RoleRevoked";
 
 /// Build transaction to execute RoleControl.assignRole contract method to assign a role to an account
 ///
@@ -213,6 +218,131 @@ pub fn parse_get_role_result(client: &LedgerClient, bytes: &[u8]) -> VdrResult<R
     Ok(role)
 }
 
+/// Subscribe to RoleControl.RoleAssigned events emitted when a role is assigned to an account
+///
+/// # Params
+/// - `client` client connected to the network (must support event subscriptions)
+///
+/// # Returns
+/// stream of event logs to parse with [parse_role_assigned_event]
+pub async fn subscribe_role_assigned(client: &LedgerClient) -> VdrResult<EventStream> {
+    debug!("{} event subscription has started", EVENT_ROLE_ASSIGNED);
+
+    let stream = EventParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_event(EVENT_ROLE_ASSIGNED)
+        .subscribe(client)
+        .await?;
+
+    info!("{} event subscription has finished", EVENT_ROLE_ASSIGNED);
+
+    Ok(stream)
+}
+
+/// Parse RoleControl.RoleAssigned event log
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `log` event log received from the subscription
+///
+/// # Returns
+/// assigned role with assignee and sender accounts
+pub fn parse_role_assigned_event(
+    client: &LedgerClient,
+    log: &EventLog,
+) -> VdrResult<RoleAssignedEvent> {
+    debug!(
+        "{} event parse has started. Log to parse: {:?}",
+        EVENT_ROLE_ASSIGNED, log
+    );
+
+    let event = EventParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_event(EVENT_ROLE_ASSIGNED)
+        .parse::<RoleAssignedEvent>(client, log)?;
+
+    info!(
+        "{} event parse has finished. Result: {:?}",
+        EVENT_ROLE_ASSIGNED, event
+    );
+
+    Ok(event)
+}
+
+/// Subscribe to RoleControl.RoleRevoked events emitted when a role is revoked from an account
+///
+/// # Params
+/// - `client` client connected to the network (must support event subscriptions)
+///
+/// # Returns
+/// stream of event logs to parse with [parse_role_revoked_event]
+pub async fn subscribe_role_revoked(client: &LedgerClient) -> VdrResult<EventStream> {
+    debug!("{} event subscription has started", EVENT_ROLE_REVOKED);
+
+    let stream = EventParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_event(EVENT_ROLE_REVOKED)
+        .subscribe(client)
+        .await?;
+
+    info!("{} event subscription has finished", EVENT_ROLE_REVOKED);
+
+    Ok(stream)
+}
+
+/// Parse RoleControl.RoleRevoked event log
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `log` event log received from the subscription
+///
+/// # Returns
+/// revoked role with account and sender accounts
+pub fn parse_role_revoked_event(
+    client: &LedgerClient,
+    log: &EventLog,
+) -> VdrResult<RoleRevokedEvent> {
+    debug!(
+        "{} event parse has started. Log to parse: {:?}",
+        EVENT_ROLE_REVOKED, log
+    );
+
+    let event = EventParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_event(EVENT_ROLE_REVOKED)
+        .parse::<RoleRevokedEvent>(client, log)?;
+
+    info!(
+        "{} event parse has finished. Result: {:?}",
+        EVENT_ROLE_REVOKED, event
+    );
+
+    Ok(event)
+}
+
+pub(crate) fn decode_event(
+    client: &LedgerClient,
+    log: &EventLog,
+) -> VdrResult<Option<LedgerEvent>> {
+    if EventParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_event(EVENT_ROLE_ASSIGNED)
+        .matches(client, log)
+    {
+        return parse_role_assigned_event(client, log)
+            .map(|event| Some(LedgerEvent::RoleAssigned(event)));
+    }
+    if EventParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_event(EVENT_ROLE_REVOKED)
+        .matches(client, log)
+    {
+        return parse_role_revoked_event(client, log)
+            .map(|event| Some(LedgerEvent::RoleRevoked(event)));
+    }
+    Ok(None)
+}
+
 #[cfg(test)]
 pub mod test {
     use super::*;
@@ -257,6 +387,7 @@ pub mod test {
                 data: expected_data,
                 signature: RwLock::new(None),
                 hash: None,
+                ..Transaction::default()
             };
 
             assert_eq!(expected_transaction, transaction);
@@ -290,6 +421,7 @@ pub mod test {
                 data: expected_data,
                 signature: RwLock::new(None),
                 hash: None,
+                ..Transaction::default()
             };
 
             assert_eq!(expected_transaction, transaction);
@@ -321,6 +453,7 @@ pub mod test {
                 data: expected_data,
                 signature: RwLock::new(None),
                 hash: None,
+                ..Transaction::default()
             };
 
             assert_eq!(expected_transaction, transaction);
@@ -369,6 +502,7 @@ pub mod test {
                 data: expected_data,
                 signature: RwLock::new(None),
                 hash: None,
+                ..Transaction::default()
             };
 
             assert_eq!(expected_transaction, transaction);
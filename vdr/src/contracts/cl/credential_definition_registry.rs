@@ -6,13 +6,19 @@ use crate::{
         credential_definition::{CredentialDefinition, CredentialDefinitionRecord},
         credential_definition_id::CredentialDefinitionId,
     },
+    contracts::endorsement::{build_endorsing_bytes, signature_params},
     error::VdrResult,
-    types::{Address, Transaction, TransactionBuilder, TransactionParser, TransactionType},
+    types::{
+        Address, EventLog, EventParser, EventStream, LedgerEvent, SignatureData, Transaction,
+        TransactionBuilder, TransactionParser, TransactionType,
+    },
 };
 
 const CONTRACT_NAME: &str = "CredentialDefinitionRegistry";
 const METHOD_CREATE_CREDENTIAL_DEFINITION: &str = "createCredentialDefinition";
+const METHOD_ENDORSE_CREDENTIAL_DEFINITION: &str = "endorseCredentialDefinition";
 const METHOD_RESOLVE_CREDENTIAL_DEFINITION: &str = "resolveCredentialDefinition";
+const EVENT_CREDENTIAL_DEFINITION_CREATED: &str = "CredentialDefinitionCreated";
 
 /// Build transaction to execute CredentialDefinitionRegistry.createCredentialDefinition contract
 /// method to create a new Credential Definition
@@ -58,6 +64,89 @@ pub async fn build_create_credential_definition_transaction(
     Ok(transaction)
 }
 
+/// Build bytes the issuer DID owner signs to authorize an Endorser to create the Credential Definition with
+/// CredentialDefinitionRegistry.endorseCredentialDefinition
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `identity` account address of the issuer DID owner
+/// - `id` id of credential definition to be created
+/// - `credential_definition` Credential Definition to be created
+///
+/// # Returns
+/// hash to sign:
+///     `keccak256(0x19 0x00 registry chainId nonce "endorseCredentialDefinition" abi.encode(id, issuerId, schemaId, credDef))`
+/// (see [crate::Signer::sign_hash])
+pub async fn build_endorse_credential_definition_signing_bytes(
+    client: &LedgerClient,
+    identity: &Address,
+    id: &CredentialDefinitionId,
+    credential_definition: &CredentialDefinition,
+) -> VdrResult<Vec<u8>> {
+    build_endorsing_bytes(
+        client,
+        CONTRACT_NAME,
+        METHOD_ENDORSE_CREDENTIAL_DEFINITION,
+        identity,
+        &[
+            id.into(),
+            (&credential_definition.issuer_id).into(),
+            (&credential_definition.schema_id).into(),
+            credential_definition.into(),
+        ],
+    )
+    .await
+}
+
+/// Build transaction to execute CredentialDefinitionRegistry.endorseCredentialDefinition contract method to create
+/// a new Credential Definition on behalf of the issuer DID owner
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `from` transaction sender account address (Endorser)
+/// - `id` id of credential definition to be created
+/// - `credential_definition` Credential Definition object matching to the specification - https://hyperledger.github.io/anoncreds-spec/#term:credential-definition
+/// - `signature` issuer DID owner signature of the bytes built with [build_endorse_credential_definition_signing_bytes]
+///
+/// # Returns
+/// Write transaction to sign and submit
+pub async fn build_endorse_credential_definition_transaction(
+    client: &LedgerClient,
+    from: &Address,
+    id: &CredentialDefinitionId,
+    credential_definition: &CredentialDefinition,
+    signature: &SignatureData,
+) -> VdrResult<Transaction> {
+    debug!(
+        "{} txn build has started. Sender: {:?}, CredentialDefinition: {:?}",
+        METHOD_ENDORSE_CREDENTIAL_DEFINITION, from, credential_definition
+    );
+
+    let transaction = signature_params(signature)?
+        .into_iter()
+        .fold(
+            TransactionBuilder::new()
+                .set_contract(CONTRACT_NAME)
+                .set_method(METHOD_ENDORSE_CREDENTIAL_DEFINITION)
+                .add_param(id.into())
+                .add_param((&credential_definition.issuer_id).into())
+                .add_param((&credential_definition.schema_id).into())
+                .add_param(credential_definition.into()),
+            TransactionBuilder::add_param,
+        )
+        .set_type(TransactionType::Write)
+        .set_from(from)
+        .build(client)
+        .await?;
+
+    info!(
+        "{} txn build has finished. Result: {:?}",
+        METHOD_ENDORSE_CREDENTIAL_DEFINITION, transaction
+    );
+
+    Ok(transaction)
+}
+
 /// Build transaction to execute CredentialDefinitionRegistry.resolveCredentialDefinition contract
 /// method to retrieve an existing Credential Definition by the given id
 ///
@@ -128,6 +217,80 @@ pub fn parse_resolve_credential_definition_result(
     Ok(credential_definition)
 }
 
+/// Subscribe to CredentialDefinitionRegistry.CredentialDefinitionCreated events emitted when a new Credential Definition is created
+///
+/// # Params
+/// - `client` client connected to the network (must support event subscriptions)
+///
+/// # Returns
+/// stream of event logs to parse with [parse_credential_definition_created_event]
+pub async fn subscribe_credential_definition_created(
+    client: &LedgerClient,
+) -> VdrResult<EventStream> {
+    debug!(
+        "{} event subscription has started",
+        EVENT_CREDENTIAL_DEFINITION_CREATED
+    );
+
+    let stream = EventParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_event(EVENT_CREDENTIAL_DEFINITION_CREATED)
+        .subscribe(client)
+        .await?;
+
+    info!(
+        "{} event subscription has finished",
+        EVENT_CREDENTIAL_DEFINITION_CREATED
+    );
+
+    Ok(stream)
+}
+
+/// Parse CredentialDefinitionRegistry.CredentialDefinitionCreated event log
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `log` event log received from the subscription
+///
+/// # Returns
+/// id of the created Credential Definition
+pub fn parse_credential_definition_created_event(
+    client: &LedgerClient,
+    log: &EventLog,
+) -> VdrResult<CredentialDefinitionId> {
+    debug!(
+        "{} event parse has started. Log to parse: {:?}",
+        EVENT_CREDENTIAL_DEFINITION_CREATED, log
+    );
+
+    let event = EventParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_event(EVENT_CREDENTIAL_DEFINITION_CREATED)
+        .parse::<CredentialDefinitionId>(client, log)?;
+
+    info!(
+        "{} event parse has finished. Result: {:?}",
+        EVENT_CREDENTIAL_DEFINITION_CREATED, event
+    );
+
+    Ok(event)
+}
+
+pub(crate) fn decode_event(
+    client: &LedgerClient,
+    log: &EventLog,
+) -> VdrResult<Option<LedgerEvent>> {
+    if EventParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_event(EVENT_CREDENTIAL_DEFINITION_CREATED)
+        .matches(client, log)
+    {
+        return parse_credential_definition_created_event(client, log)
+            .map(|event| Some(LedgerEvent::CredentialDefinitionCreated(event)));
+    }
+    Ok(None)
+}
+
 #[cfg(test)]
 pub mod test {
     use super::*;
@@ -155,7 +318,7 @@ pub mod test {
             init_env_logger();
             let client = mock_client();
             let (id, cred_def) = credential_definition(
-                &DID::from(ISSUER_ID),
+                &DID::try_from(ISSUER_ID).unwrap(),
                 &SchemaId::from(SCHEMA_ID),
                 Some(CREDENTIAL_DEFINITION_TAG),
             );
@@ -221,6 +384,7 @@ pub mod test {
                 ],
                 signature: RwLock::new(None),
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
@@ -234,7 +398,7 @@ pub mod test {
             init_env_logger();
             let client = mock_client();
             let (id, _) = credential_definition(
-                &DID::from(ISSUER_ID),
+                &DID::try_from(ISSUER_ID).unwrap(),
                 &SchemaId::from(SCHEMA_ID),
                 Some(CREDENTIAL_DEFINITION_TAG),
             );
@@ -263,6 +427,7 @@ pub mod test {
                 ],
                 signature: RwLock::new(None),
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
@@ -300,7 +465,7 @@ pub mod test {
             let parsed_cred_def =
                 parse_resolve_credential_definition_result(&client, &data).unwrap();
             let (_, expected_cred_def) = credential_definition(
-                &DID::from(ISSUER_ID),
+                &DID::try_from(ISSUER_ID).unwrap(),
                 &SchemaId::from(SCHEMA_ID),
                 Some(CREDENTIAL_DEFINITION_TAG),
             );
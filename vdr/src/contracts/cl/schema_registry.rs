@@ -6,13 +6,19 @@ use crate::{
         schema::{Schema, SchemaRecord},
         schema_id::SchemaId,
     },
+    contracts::endorsement::{build_endorsing_bytes, signature_params},
     error::VdrResult,
-    types::{Address, Transaction, TransactionBuilder, TransactionParser, TransactionType},
+    types::{
+        Address, EventLog, EventParser, EventStream, LedgerEvent, SignatureData, Transaction,
+        TransactionBuilder, TransactionParser, TransactionType,
+    },
 };
 
 const CONTRACT_NAME: &str = "SchemaRegistry";
 const METHOD_CREATE_SCHEMA: &str = "createSchema";
+const METHOD_ENDORSE_SCHEMA: &str = "endorseSchema";
 const METHOD_RESOLVE_SCHEMA: &str = "resolveSchema";
+const EVENT_SCHEMA_CREATED: &str = "SchemaCreated";
 
 /// Build transaction to execute SchemaRegistry.createSchema contract method to create a new Schema
 ///
@@ -56,6 +62,81 @@ pub async fn build_create_schema_transaction(
     Ok(transaction)
 }
 
+/// Build bytes the issuer DID owner signs to authorize an Endorser to create the Schema with SchemaRegistry.endorseSchema
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `identity` account address of the issuer DID owner
+/// - `id` id of schema to be created
+/// - `schema` Schema to be created
+///
+/// # Returns
+/// hash to sign: `keccak256(0x19 0x00 registry chainId nonce "endorseSchema" abi.encode(id, issuerId, schema))`
+/// (see [crate::Signer::sign_hash])
+pub async fn build_endorse_schema_signing_bytes(
+    client: &LedgerClient,
+    identity: &Address,
+    id: &SchemaId,
+    schema: &Schema,
+) -> VdrResult<Vec<u8>> {
+    build_endorsing_bytes(
+        client,
+        CONTRACT_NAME,
+        METHOD_ENDORSE_SCHEMA,
+        identity,
+        &[id.into(), (&schema.issuer_id).into(), schema.into()],
+    )
+    .await
+}
+
+/// Build transaction to execute SchemaRegistry.endorseSchema contract method to create a new Schema on behalf of
+/// the issuer DID owner
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `from` transaction sender account address (Endorser)
+/// - `id` id of schema to be created
+/// - `schema` Schema object matching to the specification - https://hyperledger.github.io/anoncreds-spec/#term:schema
+/// - `signature` issuer DID owner signature of the bytes built with [build_endorse_schema_signing_bytes]
+///
+/// # Returns
+/// Write transaction to sign and submit
+pub async fn build_endorse_schema_transaction(
+    client: &LedgerClient,
+    from: &Address,
+    id: &SchemaId,
+    schema: &Schema,
+    signature: &SignatureData,
+) -> VdrResult<Transaction> {
+    debug!(
+        "{} txn build has started. Sender: {:?}, schema: {:?}",
+        METHOD_ENDORSE_SCHEMA, from, schema
+    );
+
+    let transaction = signature_params(signature)?
+        .into_iter()
+        .fold(
+            TransactionBuilder::new()
+                .set_contract(CONTRACT_NAME)
+                .set_method(METHOD_ENDORSE_SCHEMA)
+                .add_param(id.into())
+                .add_param((&schema.issuer_id).into())
+                .add_param(schema.into()),
+            TransactionBuilder::add_param,
+        )
+        .set_type(TransactionType::Write)
+        .set_from(from)
+        .build(client)
+        .await?;
+
+    info!(
+        "{} txn build has finished. Result: {:?}",
+        METHOD_ENDORSE_SCHEMA, transaction
+    );
+
+    Ok(transaction)
+}
+
 /// Build transaction to execute SchemaRegistry.resolveSchema contract method to retrieve an existing Schema by the given id
 ///
 /// # Params
@@ -119,6 +200,69 @@ pub fn parse_resolve_schema_result(client: &LedgerClient, bytes: &[u8]) -> VdrRe
     Ok(schema)
 }
 
+/// Subscribe to SchemaRegistry.SchemaCreated events emitted when a new Schema is created
+///
+/// # Params
+/// - `client` client connected to the network (must support event subscriptions)
+///
+/// # Returns
+/// stream of event logs to parse with [parse_schema_created_event]
+pub async fn subscribe_schema_created(client: &LedgerClient) -> VdrResult<EventStream> {
+    debug!("{} event subscription has started", EVENT_SCHEMA_CREATED);
+
+    let stream = EventParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_event(EVENT_SCHEMA_CREATED)
+        .subscribe(client)
+        .await?;
+
+    info!("{} event subscription has finished", EVENT_SCHEMA_CREATED);
+
+    Ok(stream)
+}
+
+/// Parse SchemaRegistry.SchemaCreated event log
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `log` event log received from the subscription
+///
+/// # Returns
+/// id of the created Schema
+pub fn parse_schema_created_event(client: &LedgerClient, log: &EventLog) -> VdrResult<SchemaId> {
+    debug!(
+        "{} event parse has started. Log to parse: {:?}",
+        EVENT_SCHEMA_CREATED, log
+    );
+
+    let event = EventParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_event(EVENT_SCHEMA_CREATED)
+        .parse::<SchemaId>(client, log)?;
+
+    info!(
+        "{} event parse has finished. Result: {:?}",
+        EVENT_SCHEMA_CREATED, event
+    );
+
+    Ok(event)
+}
+
+pub(crate) fn decode_event(
+    client: &LedgerClient,
+    log: &EventLog,
+) -> VdrResult<Option<LedgerEvent>> {
+    if EventParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_event(EVENT_SCHEMA_CREATED)
+        .matches(client, log)
+    {
+        return parse_schema_created_event(client, log)
+            .map(|event| Some(LedgerEvent::SchemaCreated(event)));
+    }
+    Ok(None)
+}
+
 #[cfg(test)]
 pub mod test {
     use super::*;
@@ -141,7 +285,7 @@ pub mod test {
         async fn build_create_schema_transaction_test() {
             init_env_logger();
             let client = mock_client();
-            let (id, schema) = schema(&DID::from(ISSUER_ID), Some(SCHEMA_NAME));
+            let (id, schema) = schema(&DID::try_from(ISSUER_ID).unwrap(), Some(SCHEMA_NAME));
             let transaction = build_create_schema_transaction(&client, &TRUSTEE_ACC, &id, &schema)
                 .await
                 .unwrap();
@@ -180,6 +324,7 @@ pub mod test {
                 ],
                 signature: RwLock::new(None),
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
@@ -192,7 +337,7 @@ pub mod test {
         async fn build_resolve_schema_transaction_test() {
             init_env_logger();
             let client = mock_client();
-            let (id, _) = schema(&DID::from(ISSUER_ID), Some(SCHEMA_NAME));
+            let (id, _) = schema(&DID::try_from(ISSUER_ID).unwrap(), Some(SCHEMA_NAME));
             let transaction = build_resolve_schema_transaction(&client, &id)
                 .await
                 .unwrap();
@@ -214,6 +359,7 @@ pub mod test {
                 ],
                 signature: RwLock::new(None),
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
@@ -244,7 +390,8 @@ pub mod test {
                 0, 0, 0,
             ];
             let parsed_schema = parse_resolve_schema_result(&client, &data).unwrap();
-            let (_, expected_schema) = schema(&DID::from(ISSUER_ID), Some(SCHEMA_NAME));
+            let (_, expected_schema) =
+                schema(&DID::try_from(ISSUER_ID).unwrap(), Some(SCHEMA_NAME));
             assert_eq!(expected_schema, parsed_schema);
         }
     }
@@ -187,7 +187,7 @@ pub mod test {
 
     fn cred_def_param() -> ContractParam {
         let (_, cred_def) = credential_definition(
-            &DID::from(ISSUER_ID),
+            &DID::try_from(ISSUER_ID).unwrap(),
             &SchemaId::from(SCHEMA_ID),
             Some(CREDENTIAL_DEFINITION_TAG),
         );
@@ -200,7 +200,7 @@ pub mod test {
         #[test]
         fn convert_cred_def_into_contract_param_test() {
             let (_, credential_definition) = credential_definition(
-                &DID::from(ISSUER_ID),
+                &DID::try_from(ISSUER_ID).unwrap(),
                 &SchemaId::from(SCHEMA_ID),
                 Some(CREDENTIAL_DEFINITION_TAG),
             );
@@ -217,7 +217,7 @@ pub mod test {
             let data = ContractOutput::new(vec![cred_def_param()]);
             let converted = CredentialDefinition::try_from(&data).unwrap();
             let (_, credential_definition) = credential_definition(
-                &DID::from(ISSUER_ID),
+                &DID::try_from(ISSUER_ID).unwrap(),
                 &SchemaId::from(SCHEMA_ID),
                 Some(CREDENTIAL_DEFINITION_TAG),
             );
@@ -1,6 +1,11 @@
-use crate::{contracts::did::types::did::DID, types::ContractParam};
+use crate::{
+    contracts::did::types::did::DID,
+    error::VdrError,
+    types::{ContractOutput, ContractParam},
+};
 use log::trace;
 use serde_derive::{Deserialize, Serialize};
+use std::fmt;
 
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct CredentialDefinitionId(String);
@@ -44,8 +49,29 @@ impl AsRef<str> for CredentialDefinitionId {
     }
 }
 
-impl ToString for CredentialDefinitionId {
-    fn to_string(&self) -> String {
-        self.0.to_string()
+impl fmt::Display for CredentialDefinitionId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl TryFrom<ContractOutput> for CredentialDefinitionId {
+    type Error = VdrError;
+
+    fn try_from(value: ContractOutput) -> Result<Self, Self::Error> {
+        trace!(
+            "CredentialDefinitionId convert from ContractOutput: {:?} has started",
+            value
+        );
+
+        let cred_def_id = CredentialDefinitionId(value.get_string(0)?);
+
+        trace!(
+            "CredentialDefinitionId convert from ContractOutput: {:?} has finished. Result: {:?}",
+            value,
+            cred_def_id
+        );
+
+        Ok(cred_def_id)
     }
 }
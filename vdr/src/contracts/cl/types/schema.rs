@@ -159,7 +159,7 @@ pub mod test {
     }
 
     fn schema_param() -> ContractParam {
-        let (_, schema) = schema(&DID::from(ISSUER_ID), Some(SCHEMA_NAME));
+        let (_, schema) = schema(&DID::try_from(ISSUER_ID).unwrap(), Some(SCHEMA_NAME));
         ContractParam::String(json!(schema).to_string())
     }
 
@@ -168,7 +168,7 @@ pub mod test {
 
         #[test]
         fn convert_schema_into_contract_param_test() {
-            let (_, schema) = schema(&DID::from(ISSUER_ID), Some(SCHEMA_NAME));
+            let (_, schema) = schema(&DID::try_from(ISSUER_ID).unwrap(), Some(SCHEMA_NAME));
             let param: ContractParam = (&schema).into();
             assert_eq!(schema_param(), param);
         }
@@ -181,7 +181,7 @@ pub mod test {
         fn convert_contract_output_into_schema() {
             let data = ContractOutput::new(vec![schema_param()]);
             let converted = Schema::try_from(&data).unwrap();
-            let (_, schema) = schema(&DID::from(ISSUER_ID), Some(SCHEMA_NAME));
+            let (_, schema) = schema(&DID::try_from(ISSUER_ID).unwrap(), Some(SCHEMA_NAME));
             assert_eq!(schema, converted);
         }
     }
@@ -1,6 +1,11 @@
-use crate::{contracts::did::types::did::DID, types::ContractParam};
+use crate::{
+    contracts::did::types::did::DID,
+    error::VdrError,
+    types::{ContractOutput, ContractParam},
+};
 use log::trace;
 use serde_derive::{Deserialize, Serialize};
+use std::fmt;
 
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct SchemaId(String);
@@ -44,8 +49,29 @@ impl AsRef<str> for SchemaId {
     }
 }
 
-impl ToString for SchemaId {
-    fn to_string(&self) -> String {
-        self.0.to_string()
+impl fmt::Display for SchemaId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl TryFrom<ContractOutput> for SchemaId {
+    type Error = VdrError;
+
+    fn try_from(value: ContractOutput) -> Result<Self, Self::Error> {
+        trace!(
+            "SchemaId convert from ContractOutput: {:?} has started",
+            value
+        );
+
+        let schema_id = SchemaId(value.get_string(0)?);
+
+        trace!(
+            "SchemaId convert from ContractOutput: {:?} has finished. Result: {:?}",
+            value,
+            schema_id
+        );
+
+        Ok(schema_id)
     }
 }
@@ -1,23 +1,38 @@
-use log::{debug, info};
+use log::{debug, info, warn};
 
 use crate::{
     client::LedgerClient,
+    contracts::did::did_resolver::INDY2_METHOD,
     contracts::did::types::{
         did::DID,
         did_doc::{DidDocument, DidRecord},
+        did_doc_builder::DidDocumentBuilder,
+        did_resolution::{DidChange, DidChangeKind},
+    },
+    contracts::endorsement::{build_endorsing_bytes, signature_params},
+    error::{VdrError, VdrResult},
+    types::{
+        Address, EventLog, EventParser, EventStream, LedgerEvent, SignatureData, Transaction,
+        TransactionBuilder, TransactionParser, TransactionType,
     },
-    error::VdrResult,
-    types::{Address, Transaction, TransactionBuilder, TransactionParser, TransactionType},
 };
 
 const CONTRACT_NAME: &str = "IndyDidRegistry";
 const METHOD_CREATE_DID: &str = "createDid";
+const METHOD_ENDORSE_DID: &str = "endorseDid";
 const METHOD_UPDATE_DID: &str = "updateDid";
 const METHOD_DEACTIVATE_DID: &str = "deactivateDid";
 const METHOD_RESOLVE_DID: &str = "resolveDid";
+const EVENT_DID_CREATED: &str = "DIDCreated";
+const EVENT_DID_UPDATED: &str = "DIDUpdated";
+const EVENT_DID_DEACTIVATED: &str = "DIDDeactivated";
 
 /// Build transaction to execute IndyDidRegistry.createDid contract method to create a new DID
 ///
+/// `did:indy2` DID must be derived from the verkey of the first Ed25519 verification method of the
+/// DID Document (see [DID::from_verkey]). Use [build_create_migrated_did_transaction] for DIDs
+/// migrated from other networks which keep their original identifier.
+///
 /// # Params
 /// - `client` client connected to the network where contract will be executed
 /// - `from` transaction sender account address
@@ -33,12 +48,47 @@ pub async fn build_create_did_transaction(
     identity: &Address,
     did: &DID,
     did_doc: &DidDocument,
+) -> VdrResult<Transaction> {
+    build_create_did(client, from, identity, did, did_doc, true).await
+}
+
+/// Build transaction to execute IndyDidRegistry.createDid contract method to create a DID migrated
+/// from another network (like `did:sov`) without checking that the DID is derived from its verkey
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `from` transaction sender account address
+/// - `identity` DID owner account address
+/// - `did` DID to be created
+/// - `did_doc` DID Document matching to the specification: https://www.w3.org/TR/did-core/
+///
+/// # Returns
+/// Write transaction to sign and submit
+pub async fn build_create_migrated_did_transaction(
+    client: &LedgerClient,
+    from: &Address,
+    identity: &Address,
+    did: &DID,
+    did_doc: &DidDocument,
+) -> VdrResult<Transaction> {
+    build_create_did(client, from, identity, did, did_doc, false).await
+}
+
+async fn build_create_did(
+    client: &LedgerClient,
+    from: &Address,
+    identity: &Address,
+    did: &DID,
+    did_doc: &DidDocument,
+    validate_id: bool,
 ) -> VdrResult<Transaction> {
     debug!(
         "{} txn build has started. Sender: {:?}, DidDocument: {:?}",
         METHOD_CREATE_DID, from, did_doc
     );
 
+    validate_new_did(did, did_doc, METHOD_CREATE_DID, validate_id)?;
+
     let transaction = TransactionBuilder::new()
         .set_contract(CONTRACT_NAME)
         .set_method(METHOD_CREATE_DID)
@@ -58,6 +108,84 @@ pub async fn build_create_did_transaction(
     Ok(transaction)
 }
 
+/// Build bytes the DID owner signs to authorize an Endorser to create the DID with IndyDidRegistry.endorseDid
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `identity` DID owner account address
+/// - `did` DID to be created
+/// - `did_doc` DID Document to be created
+///
+/// # Returns
+/// hash to sign: `keccak256(0x19 0x00 registry chainId nonce "endorseDid" abi.encode(did, didDocument))`
+/// (see [crate::Signer::sign_hash])
+pub async fn build_endorse_did_signing_bytes(
+    client: &LedgerClient,
+    identity: &Address,
+    did: &DID,
+    did_doc: &DidDocument,
+) -> VdrResult<Vec<u8>> {
+    build_endorsing_bytes(
+        client,
+        CONTRACT_NAME,
+        METHOD_ENDORSE_DID,
+        identity,
+        &[did.into(), did_doc.into()],
+    )
+    .await
+}
+
+/// Build transaction to execute IndyDidRegistry.endorseDid contract method to create a new DID on behalf of its owner
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `from` transaction sender account address (Endorser)
+/// - `identity` DID owner account address
+/// - `did` DID to be created
+/// - `did_doc` DID Document matching to the specification: https://www.w3.org/TR/did-core/
+/// - `signature` DID owner signature of the bytes built with [build_endorse_did_signing_bytes]
+///
+/// # Returns
+/// Write transaction to sign and submit
+pub async fn build_endorse_did_transaction(
+    client: &LedgerClient,
+    from: &Address,
+    identity: &Address,
+    did: &DID,
+    did_doc: &DidDocument,
+    signature: &SignatureData,
+) -> VdrResult<Transaction> {
+    debug!(
+        "{} txn build has started. Sender: {:?}, identity: {:?}, DidDocument: {:?}",
+        METHOD_ENDORSE_DID, from, identity, did_doc
+    );
+
+    validate_new_did(did, did_doc, METHOD_ENDORSE_DID, true)?;
+
+    let transaction = signature_params(signature)?
+        .into_iter()
+        .fold(
+            TransactionBuilder::new()
+                .set_contract(CONTRACT_NAME)
+                .set_method(METHOD_ENDORSE_DID)
+                .add_param(identity.try_into()?)
+                .add_param(did.into())
+                .add_param(did_doc.into()),
+            TransactionBuilder::add_param,
+        )
+        .set_type(TransactionType::Write)
+        .set_from(from)
+        .build(client)
+        .await?;
+
+    info!(
+        "{} txn build has finished. Result: {:?}",
+        METHOD_ENDORSE_DID, transaction
+    );
+
+    Ok(transaction)
+}
+
 /// Build transaction to execute IndyDidRegistry.updateDid contract method to update DID document for an existing DID
 ///
 /// # Params
@@ -79,6 +207,8 @@ pub async fn build_update_did_transaction(
         METHOD_UPDATE_DID, from, did_doc
     );
 
+    did_doc.validate()?;
+
     let transaction = TransactionBuilder::new()
         .set_contract(CONTRACT_NAME)
         .set_method(METHOD_UPDATE_DID)
@@ -97,6 +227,58 @@ pub async fn build_update_did_transaction(
     Ok(transaction)
 }
 
+/// Build transaction to execute IndyDidRegistry.updateDid contract method to write the changes made with the builder
+/// started from an existing DID Document (see [DidDocumentBuilder::from_document])
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `from` transaction sender account address
+/// - `builder` builder containing the edited DID Document
+///
+/// # Returns
+/// Write transaction to sign and submit
+pub async fn build_update_did_transaction_from_builder(
+    client: &LedgerClient,
+    from: &Address,
+    builder: &DidDocumentBuilder,
+) -> VdrResult<Transaction> {
+    let changes = builder.changed_properties();
+    debug!(
+        "{} txn build from DidDocumentBuilder has started. Sender: {:?}, changed properties: {:?}",
+        METHOD_UPDATE_DID, from, changes
+    );
+
+    let invalid_builder = |vdr_error: VdrError| {
+        warn!(
+            "Error: {} during {} txn build from DidDocumentBuilder",
+            vdr_error, METHOD_UPDATE_DID
+        );
+
+        vdr_error
+    };
+
+    let original = builder.original().ok_or_else(|| {
+        invalid_builder(VdrError::CommonInvalidData(
+            "DidDocumentBuilder must be started from an existing DID Document".to_string(),
+        ))
+    })?;
+    if changes.contains(&"id") {
+        return Err(invalid_builder(VdrError::DidInvalidIdentifier(format!(
+            "DID Document id can not be changed: {} -> {}",
+            original.id, builder.id
+        ))));
+    }
+    if changes.is_empty() {
+        return Err(invalid_builder(VdrError::CommonInvalidData(format!(
+            "DID Document has not been changed: {}",
+            original.id
+        ))));
+    }
+
+    let did_doc = builder.clone().build();
+    build_update_did_transaction(client, from, &original.id, &did_doc).await
+}
+
 /// Build transaction to execute IndyDidRegistry.deactivateDid contract method to deactivate an existing DID
 ///
 /// # Params
@@ -175,23 +357,353 @@ pub async fn build_resolve_did_transaction(
 /// # Returns
 /// parsed DID Document
 pub fn parse_resolve_did_result(client: &LedgerClient, bytes: &[u8]) -> VdrResult<DidDocument> {
+    parse_resolve_did_record(client, bytes).map(|record| record.document)
+}
+
+/// Parse the result of execution IndyDidRegistry.resolveDid contract method to receive a DID Document together with
+/// its metadata (owner, sender, created, updated, deactivated)
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `bytes` result bytes returned from the ledger
+///
+/// # Returns
+/// parsed DID record
+pub fn parse_resolve_did_record(client: &LedgerClient, bytes: &[u8]) -> VdrResult<DidRecord> {
     debug!(
         "{} result parse has started. Bytes to parse: {:?}",
         METHOD_RESOLVE_DID, bytes
     );
 
-    let document = TransactionParser::new()
+    let record = TransactionParser::new()
         .set_contract(CONTRACT_NAME)
         .set_method(METHOD_RESOLVE_DID)
-        .parse::<DidRecord>(client, bytes)?
-        .document;
+        .parse::<DidRecord>(client, bytes)?;
 
     info!(
         "{} result parse has finished. Result: {:?}",
-        METHOD_RESOLVE_DID, document
+        METHOD_RESOLVE_DID, record
     );
 
-    Ok(document)
+    Ok(record)
+}
+
+/// Subscribe to IndyDidRegistry.DIDCreated events emitted when a new DID is created
+///
+/// # Params
+/// - `client` client connected to the network (must support event subscriptions)
+///
+/// # Returns
+/// stream of event logs to parse with [parse_did_created_event]
+pub async fn subscribe_did_created(client: &LedgerClient) -> VdrResult<EventStream> {
+    debug!("{} event subscription has started", EVENT_DID_CREATED);
+
+    let stream = EventParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_event(EVENT_DID_CREATED)
+        .subscribe(client)
+        .await?;
+
+    info!("{} event subscription has finished", EVENT_DID_CREATED);
+
+    Ok(stream)
+}
+
+/// Parse IndyDidRegistry.DIDCreated event log
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `log` event log received from the subscription
+///
+/// # Returns
+/// created DID
+pub fn parse_did_created_event(client: &LedgerClient, log: &EventLog) -> VdrResult<DID> {
+    debug!(
+        "{} event parse has started. Log to parse: {:?}",
+        EVENT_DID_CREATED, log
+    );
+
+    let event = EventParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_event(EVENT_DID_CREATED)
+        .parse::<DID>(client, log)?;
+
+    info!(
+        "{} event parse has finished. Result: {:?}",
+        EVENT_DID_CREATED, event
+    );
+
+    Ok(event)
+}
+
+/// Subscribe to IndyDidRegistry.DIDUpdated events emitted when a DID Document is updated
+///
+/// # Params
+/// - `client` client connected to the network (must support event subscriptions)
+///
+/// # Returns
+/// stream of event logs to parse with [parse_did_updated_event]
+pub async fn subscribe_did_updated(client: &LedgerClient) -> VdrResult<EventStream> {
+    debug!("{} event subscription has started", EVENT_DID_UPDATED);
+
+    let stream = EventParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_event(EVENT_DID_UPDATED)
+        .subscribe(client)
+        .await?;
+
+    info!("{} event subscription has finished", EVENT_DID_UPDATED);
+
+    Ok(stream)
+}
+
+/// Parse IndyDidRegistry.DIDUpdated event log
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `log` event log received from the subscription
+///
+/// # Returns
+/// updated DID
+pub fn parse_did_updated_event(client: &LedgerClient, log: &EventLog) -> VdrResult<DID> {
+    debug!(
+        "{} event parse has started. Log to parse: {:?}",
+        EVENT_DID_UPDATED, log
+    );
+
+    let event = EventParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_event(EVENT_DID_UPDATED)
+        .parse::<DID>(client, log)?;
+
+    info!(
+        "{} event parse has finished. Result: {:?}",
+        EVENT_DID_UPDATED, event
+    );
+
+    Ok(event)
+}
+
+/// Subscribe to IndyDidRegistry.DIDDeactivated events emitted when a DID is deactivated
+///
+/// # Params
+/// - `client` client connected to the network (must support event subscriptions)
+///
+/// # Returns
+/// stream of event logs to parse with [parse_did_deactivated_event]
+pub async fn subscribe_did_deactivated(client: &LedgerClient) -> VdrResult<EventStream> {
+    debug!("{} event subscription has started", EVENT_DID_DEACTIVATED);
+
+    let stream = EventParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_event(EVENT_DID_DEACTIVATED)
+        .subscribe(client)
+        .await?;
+
+    info!("{} event subscription has finished", EVENT_DID_DEACTIVATED);
+
+    Ok(stream)
+}
+
+/// Parse IndyDidRegistry.DIDDeactivated event log
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `log` event log received from the subscription
+///
+/// # Returns
+/// deactivated DID
+pub fn parse_did_deactivated_event(client: &LedgerClient, log: &EventLog) -> VdrResult<DID> {
+    debug!(
+        "{} event parse has started. Log to parse: {:?}",
+        EVENT_DID_DEACTIVATED, log
+    );
+
+    let event = EventParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_event(EVENT_DID_DEACTIVATED)
+        .parse::<DID>(client, log)?;
+
+    info!(
+        "{} event parse has finished. Result: {:?}",
+        EVENT_DID_DEACTIVATED, event
+    );
+
+    Ok(event)
+}
+
+/// Get the history of DID changes from IndyDidRegistry.DIDCreated, DIDUpdated and DIDDeactivated events
+///
+/// The DID is not an indexed event parameter, so all events of the registry are fetched and filtered by the DID.
+/// The scan starts at the block the DID was created in: it is found by a binary search of the block with the
+/// creation timestamp of the DID (`metadata.created`), which costs a logarithmic number of block queries.
+/// Events which can not be decoded or belong to other DIDs are skipped.
+///
+/// # Params
+/// - `client` client connected to the network (must support event log queries)
+/// - `did` target DID
+///
+/// # Returns
+/// DID changes ordered by block number (empty if the DID does not exist)
+pub async fn get_did_history(client: &LedgerClient, did: &DID) -> VdrResult<Vec<DidChange>> {
+    debug!("DID history query has started. Did: {:?}", did);
+
+    let transaction = build_resolve_did_transaction(client, did).await?;
+    let created = match client.submit_transaction(&transaction).await {
+        Ok(response) => {
+            parse_resolve_did_record(client, &response)?
+                .metadata
+                .created
+        }
+        Err(VdrError::DidNotFound(_)) => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+    let from_block = find_first_block_since(client, created).await?;
+    let logs = client
+        .query_events(CONTRACT_NAME, None, None, from_block, None)
+        .await?;
+
+    let mut changes = did_changes(client, did, &logs)?;
+    changes.sort();
+
+    let mut history: Vec<DidChange> = Vec::with_capacity(changes.len());
+    for (block_number, kind) in changes {
+        // changes are sorted, so the block is fetched once for all changes it contains
+        let timestamp = match history.last() {
+            Some(last) if last.block_number == block_number => last.timestamp,
+            _ => client.get_block(Some(block_number)).await?.timestamp,
+        };
+        history.push(DidChange {
+            kind,
+            block_number,
+            timestamp,
+        });
+    }
+
+    info!("DID history query has finished. Result: {:?}", history);
+
+    Ok(history)
+}
+
+/// Select changes of the DID from the registry event logs
+///     The DID is compared as emitted by the contract, so events of other DIDs are skipped without parsing
+fn did_changes(
+    client: &LedgerClient,
+    did: &DID,
+    logs: &[EventLog],
+) -> VdrResult<Vec<(u64, DidChangeKind)>> {
+    let contract = client.contract(CONTRACT_NAME)?;
+    let events = [
+        (EVENT_DID_CREATED, DidChangeKind::Created),
+        (EVENT_DID_UPDATED, DidChangeKind::Updated),
+        (EVENT_DID_DEACTIVATED, DidChangeKind::Deactivated),
+    ];
+
+    let mut changes: Vec<(u64, DidChangeKind)> = Vec::new();
+    for log in logs {
+        let Some((event, kind)) = events.iter().find(|(event, _)| {
+            EventParser::new()
+                .set_contract(CONTRACT_NAME)
+                .set_event(event)
+                .matches(client, log)
+        }) else {
+            continue;
+        };
+        let changed_did = match contract
+            .decode_event(event, log)
+            .and_then(|output| output.get_string(0))
+        {
+            Ok(changed_did) => changed_did,
+            Err(err) => {
+                debug!("Skipped {} event log: {:?}. Err: {}", event, log, err);
+                continue;
+            }
+        };
+        if changed_did != did.as_ref() {
+            continue;
+        }
+        let block_number = log.block_number.ok_or_else(|| {
+            let vdr_error = VdrError::ClientInvalidResponse(format!(
+                "{} event log does not contain block number",
+                event
+            ));
+
+            warn!("Error: {} during DID history query", vdr_error);
+
+            vdr_error
+        })?;
+        changes.push((block_number, *kind));
+    }
+    Ok(changes)
+}
+
+/// Find the first block with the timestamp not less than the given one (binary search over the chain)
+async fn find_first_block_since(client: &LedgerClient, timestamp: u64) -> VdrResult<u64> {
+    let (mut low, mut high) = (0, client.get_block(None).await?.number);
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if client.get_block(Some(middle)).await?.timestamp < timestamp {
+            low = middle + 1;
+        } else {
+            high = middle;
+        }
+    }
+    Ok(low)
+}
+
+pub(crate) fn decode_event(
+    client: &LedgerClient,
+    log: &EventLog,
+) -> VdrResult<Option<LedgerEvent>> {
+    if EventParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_event(EVENT_DID_CREATED)
+        .matches(client, log)
+    {
+        return parse_did_created_event(client, log)
+            .map(|event| Some(LedgerEvent::DidCreated(event)));
+    }
+    if EventParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_event(EVENT_DID_UPDATED)
+        .matches(client, log)
+    {
+        return parse_did_updated_event(client, log)
+            .map(|event| Some(LedgerEvent::DidUpdated(event)));
+    }
+    if EventParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_event(EVENT_DID_DEACTIVATED)
+        .matches(client, log)
+    {
+        return parse_did_deactivated_event(client, log)
+            .map(|event| Some(LedgerEvent::DidDeactivated(event)));
+    }
+    Ok(None)
+}
+
+fn validate_new_did(
+    did: &DID,
+    did_doc: &DidDocument,
+    method: &str,
+    validate_id: bool,
+) -> VdrResult<()> {
+    if did != &did_doc.id {
+        let vdr_error = VdrError::DidInvalidIdentifier(format!(
+            "{} does not match DID Document id: {}",
+            did, did_doc.id
+        ));
+
+        warn!("Error: {} during {} txn build", vdr_error, method);
+
+        return Err(vdr_error);
+    }
+    did_doc.validate()?;
+    // only `did:indy2` identifiers are derived from the verkey
+    if validate_id && did.method() == INDY2_METHOD {
+        did_doc.validate_id()?;
+    }
+    Ok(())
 }
 
 #[cfg(test)]
@@ -202,9 +714,10 @@ pub mod test {
             mock_client, CHAIN_ID, DEFAULT_NONCE, DID_REGISTRY_ADDRESS, TRUSTEE_ACC,
         },
         contracts::did::types::{
-            did::DID,
+            did::{test::VERKEY_DID, DID},
             did_doc::test::{did_doc, ISSUER_ID},
         },
+        contracts::endorsement,
         utils::init_env_logger,
     };
     use std::sync::RwLock;
@@ -214,8 +727,11 @@ pub mod test {
         use crate::{
             client::client::test::{mock_client, IDENTITY_ACC},
             contracts::{
-                did::types::did_doc::test::service, StringOrVector, VerificationMethod,
-                VerificationMethodOrReference,
+                did::{
+                    types::did_doc::test::service, VerificationMethod,
+                    VerificationMethodOrReference,
+                },
+                StringOrVector,
             },
             VerificationKeyType,
         };
@@ -224,8 +740,8 @@ pub mod test {
         async fn build_create_did_transaction_test() {
             init_env_logger();
             let client = mock_client();
-            let did = DID::from(ISSUER_ID);
-            let did_doc = did_doc(Some(ISSUER_ID));
+            let did = DID::try_from(VERKEY_DID).unwrap();
+            let did_doc = did_doc(Some(VERKEY_DID));
             let transaction =
                 build_create_did_transaction(&client, &TRUSTEE_ACC, &IDENTITY_ACC, &did, &did_doc)
                     .await
@@ -243,10 +759,10 @@ pub mod test {
                     96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
                     0, 0, 0, 0, 0, 0, 192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
                     0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 100, 105, 100, 58, 105, 110, 100, 121,
-                    50, 58, 116, 101, 115, 116, 110, 101, 116, 58, 51, 76, 112, 106, 115, 122, 107,
-                    103, 84, 109, 69, 51, 113, 84, 104, 103, 101, 50, 53, 70, 90, 119, 0, 0, 0, 0,
+                    50, 58, 116, 101, 115, 116, 110, 101, 116, 58, 82, 118, 56, 122, 84, 85, 68,
+                    112, 52, 107, 74, 87, 75, 88, 81, 109, 83, 89, 76, 84, 77, 97, 0, 0, 0, 0, 0,
                     0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 25,
+                    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 25,
                     123, 34, 64, 99, 111, 110, 116, 101, 120, 116, 34, 58, 91, 34, 104, 116, 116,
                     112, 115, 58, 47, 47, 119, 119, 119, 46, 119, 51, 46, 111, 114, 103, 47, 110,
                     115, 47, 100, 105, 100, 47, 118, 49, 34, 93, 44, 34, 97, 108, 115, 111, 75,
@@ -254,74 +770,126 @@ pub mod test {
                     116, 105, 111, 110, 77, 101, 116, 104, 111, 100, 34, 58, 91, 93, 44, 34, 97,
                     117, 116, 104, 101, 110, 116, 105, 99, 97, 116, 105, 111, 110, 34, 58, 91, 34,
                     100, 105, 100, 58, 105, 110, 100, 121, 50, 58, 116, 101, 115, 116, 110, 101,
-                    116, 58, 51, 76, 112, 106, 115, 122, 107, 103, 84, 109, 69, 51, 113, 84, 104,
-                    103, 101, 50, 53, 70, 90, 119, 35, 75, 69, 89, 45, 49, 34, 93, 44, 34, 99, 97,
-                    112, 97, 98, 105, 108, 105, 116, 121, 68, 101, 108, 101, 103, 97, 116, 105,
-                    111, 110, 34, 58, 91, 93, 44, 34, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121,
-                    73, 110, 118, 111, 99, 97, 116, 105, 111, 110, 34, 58, 91, 93, 44, 34, 99, 111,
+                    116, 58, 82, 118, 56, 122, 84, 85, 68, 112, 52, 107, 74, 87, 75, 88, 81, 109,
+                    83, 89, 76, 84, 77, 97, 35, 75, 69, 89, 45, 49, 34, 93, 44, 34, 99, 97, 112,
+                    97, 98, 105, 108, 105, 116, 121, 68, 101, 108, 101, 103, 97, 116, 105, 111,
+                    110, 34, 58, 91, 93, 44, 34, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 73,
+                    110, 118, 111, 99, 97, 116, 105, 111, 110, 34, 58, 91, 93, 44, 34, 99, 111,
                     110, 116, 114, 111, 108, 108, 101, 114, 34, 58, 91, 93, 44, 34, 105, 100, 34,
                     58, 34, 100, 105, 100, 58, 105, 110, 100, 121, 50, 58, 116, 101, 115, 116, 110,
-                    101, 116, 58, 51, 76, 112, 106, 115, 122, 107, 103, 84, 109, 69, 51, 113, 84,
-                    104, 103, 101, 50, 53, 70, 90, 119, 34, 44, 34, 107, 101, 121, 65, 103, 114,
-                    101, 101, 109, 101, 110, 116, 34, 58, 91, 93, 44, 34, 115, 101, 114, 118, 105,
-                    99, 101, 34, 58, 91, 93, 44, 34, 118, 101, 114, 105, 102, 105, 99, 97, 116,
-                    105, 111, 110, 77, 101, 116, 104, 111, 100, 34, 58, 91, 123, 34, 99, 111, 110,
-                    116, 114, 111, 108, 108, 101, 114, 34, 58, 34, 100, 105, 100, 58, 105, 110,
-                    100, 121, 50, 58, 116, 101, 115, 116, 110, 101, 116, 58, 51, 76, 112, 106, 115,
-                    122, 107, 103, 84, 109, 69, 51, 113, 84, 104, 103, 101, 50, 53, 70, 90, 119,
-                    34, 44, 34, 105, 100, 34, 58, 34, 100, 105, 100, 58, 105, 110, 100, 121, 50,
-                    58, 116, 101, 115, 116, 110, 101, 116, 58, 51, 76, 112, 106, 115, 122, 107,
-                    103, 84, 109, 69, 51, 113, 84, 104, 103, 101, 50, 53, 70, 90, 119, 35, 75, 69,
-                    89, 45, 49, 34, 44, 34, 112, 117, 98, 108, 105, 99, 75, 101, 121, 77, 117, 108,
-                    116, 105, 98, 97, 115, 101, 34, 58, 34, 122, 65, 75, 74, 80, 51, 102, 55, 66,
-                    68, 54, 87, 52, 105, 87, 69, 81, 57, 106, 119, 110, 100, 86, 84, 67, 66, 113,
-                    56, 117, 97, 50, 85, 116, 116, 56, 69, 69, 106, 74, 54, 86, 120, 115, 102, 34,
-                    44, 34, 116, 121, 112, 101, 34, 58, 34, 69, 100, 50, 53, 53, 49, 57, 86, 101,
-                    114, 105, 102, 105, 99, 97, 116, 105, 111, 110, 75, 101, 121, 50, 48, 49, 56,
-                    34, 125, 93, 125, 0, 0, 0, 0, 0, 0, 0,
+                    101, 116, 58, 82, 118, 56, 122, 84, 85, 68, 112, 52, 107, 74, 87, 75, 88, 81,
+                    109, 83, 89, 76, 84, 77, 97, 34, 44, 34, 107, 101, 121, 65, 103, 114, 101, 101,
+                    109, 101, 110, 116, 34, 58, 91, 93, 44, 34, 115, 101, 114, 118, 105, 99, 101,
+                    34, 58, 91, 93, 44, 34, 118, 101, 114, 105, 102, 105, 99, 97, 116, 105, 111,
+                    110, 77, 101, 116, 104, 111, 100, 34, 58, 91, 123, 34, 99, 111, 110, 116, 114,
+                    111, 108, 108, 101, 114, 34, 58, 34, 100, 105, 100, 58, 105, 110, 100, 121, 50,
+                    58, 116, 101, 115, 116, 110, 101, 116, 58, 82, 118, 56, 122, 84, 85, 68, 112,
+                    52, 107, 74, 87, 75, 88, 81, 109, 83, 89, 76, 84, 77, 97, 34, 44, 34, 105, 100,
+                    34, 58, 34, 100, 105, 100, 58, 105, 110, 100, 121, 50, 58, 116, 101, 115, 116,
+                    110, 101, 116, 58, 82, 118, 56, 122, 84, 85, 68, 112, 52, 107, 74, 87, 75, 88,
+                    81, 109, 83, 89, 76, 84, 77, 97, 35, 75, 69, 89, 45, 49, 34, 44, 34, 112, 117,
+                    98, 108, 105, 99, 75, 101, 121, 77, 117, 108, 116, 105, 98, 97, 115, 101, 34,
+                    58, 34, 122, 65, 75, 74, 80, 51, 102, 55, 66, 68, 54, 87, 52, 105, 87, 69, 81,
+                    57, 106, 119, 110, 100, 86, 84, 67, 66, 113, 56, 117, 97, 50, 85, 116, 116, 56,
+                    69, 69, 106, 74, 54, 86, 120, 115, 102, 34, 44, 34, 116, 121, 112, 101, 34, 58,
+                    34, 69, 100, 50, 53, 53, 49, 57, 86, 101, 114, 105, 102, 105, 99, 97, 116, 105,
+                    111, 110, 75, 101, 121, 50, 48, 49, 56, 34, 125, 93, 125, 0, 0, 0, 0, 0, 0, 0,
                 ],
                 signature: RwLock::new(None),
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
 
+        #[async_std::test]
+        async fn build_create_did_transaction_with_mismatching_id_test() {
+            init_env_logger();
+            let client = mock_client();
+
+            let did = DID::try_from(ISSUER_ID).unwrap();
+            let err = build_create_did_transaction(
+                &client,
+                &TRUSTEE_ACC,
+                &IDENTITY_ACC,
+                &did,
+                &did_doc(Some(ISSUER_ID)),
+            )
+            .await
+            .unwrap_err();
+            assert!(matches!(err, VdrError::DidInvalidIdentifier(_)));
+
+            let err = build_create_did_transaction(
+                &client,
+                &TRUSTEE_ACC,
+                &IDENTITY_ACC,
+                &did,
+                &did_doc(Some(VERKEY_DID)),
+            )
+            .await
+            .unwrap_err();
+            assert!(matches!(err, VdrError::DidInvalidIdentifier(_)));
+        }
+
+        #[async_std::test]
+        async fn build_create_migrated_did_transaction_test() {
+            init_env_logger();
+            let client = mock_client();
+
+            let did = DID::try_from(ISSUER_ID).unwrap();
+            let transaction = build_create_migrated_did_transaction(
+                &client,
+                &TRUSTEE_ACC,
+                &IDENTITY_ACC,
+                &did,
+                &did_doc(Some(ISSUER_ID)),
+            )
+            .await
+            .unwrap();
+            assert_eq!(
+                Some(METHOD_CREATE_DID),
+                transaction.call.as_ref().map(|call| call.method.as_str())
+            );
+        }
+
         #[async_std::test]
         async fn build_create_did_transaction_with_two_keys_and_service_test() {
             init_env_logger();
             let client = mock_client();
 
-            let did = DID::from("did:indy2:testnet:3LpjszkgTmE3qThge25FZw");
+            let did = DID::try_from("did:indy2:testnet:2D3YF21WMjkMj2BemwbxJS").unwrap();
             let did_doc = DidDocument {
                 context: StringOrVector::Vector(vec!["https://www.w3.org/ns/did/v1".to_string()]),
-                id: DID::from("did:indy2:testnet:3LpjszkgTmE3qThge25FZw"),
+                id: DID::try_from("did:indy2:testnet:2D3YF21WMjkMj2BemwbxJS").unwrap(),
                 controller: StringOrVector::Vector(vec![]),
                 verification_method: vec![
                     VerificationMethod {
-                        id: "did:indy2:testnet:3LpjszkgTmE3qThge25FZw#KEY-1".to_string(),
+                        id: "did:indy2:testnet:2D3YF21WMjkMj2BemwbxJS#KEY-1".to_string(),
                         type_: VerificationKeyType::Ed25519VerificationKey2018,
-                        controller: "did:indy2:testnet:3LpjszkgTmE3qThge25FZw".to_string(),
+                        controller: "did:indy2:testnet:2D3YF21WMjkMj2BemwbxJS".to_string(),
                         public_key_multibase: Some("8rnQ4gvtEYi59DMAzN7FyCVatVATkFo7wPXVMy38WmvG".to_string()),
                         public_key_jwk: None,
+                        ..VerificationMethod::default()
                     },
                     VerificationMethod {
-                        id: "did:indy2:testnet:3LpjszkgTmE3qThge25FZw#KEY-2".to_string(),
+                        id: "did:indy2:testnet:2D3YF21WMjkMj2BemwbxJS#KEY-2".to_string(),
                         type_: VerificationKeyType::EcdsaSecp256k1VerificationKey2019,
-                        controller: "did:indy2:testnet:3LpjszkgTmE3qThge25FZw".to_string(),
+                        controller: "did:indy2:testnet:2D3YF21WMjkMj2BemwbxJS".to_string(),
                         public_key_multibase: Some("NaqS2qSLZTJcuKLvFAoBSeRFXeivDfyoUqvSs8DQ4ajydz4KbUvT6vdJyz8i9gJEqGjFkCN27niZhoAbQLgk3imn".to_string()),
                         public_key_jwk: None,
+                        ..VerificationMethod::default()
                     },
                 ],
                 authentication: vec![
-                    VerificationMethodOrReference::String("did:indy2:testnet:3LpjszkgTmE3qThge25FZw#KEY-1".to_string()),
-                    VerificationMethodOrReference::String("did:indy2:testnet:3LpjszkgTmE3qThge25FZw#KEY-2".to_string()),
+                    VerificationMethodOrReference::String("did:indy2:testnet:2D3YF21WMjkMj2BemwbxJS#KEY-1".to_string()),
+                    VerificationMethodOrReference::String("did:indy2:testnet:2D3YF21WMjkMj2BemwbxJS#KEY-2".to_string()),
                 ],
                 assertion_method: vec![],
                 capability_invocation: vec![],
                 capability_delegation: vec![],
                 key_agreement: vec![],
                 service: vec![
-                    service("did:indy2:testnet:3LpjszkgTmE3qThge25FZw#SERVICE-1")
+                    service("did:indy2:testnet:2D3YF21WMjkMj2BemwbxJS#SERVICE-1")
                 ],
                 also_known_as: Some(vec![]),
             };
@@ -342,10 +910,10 @@ pub mod test {
                     96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
                     0, 0, 0, 0, 0, 0, 192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
                     0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 100, 105, 100, 58, 105, 110, 100, 121,
-                    50, 58, 116, 101, 115, 116, 110, 101, 116, 58, 51, 76, 112, 106, 115, 122, 107,
-                    103, 84, 109, 69, 51, 113, 84, 104, 103, 101, 50, 53, 70, 90, 119, 0, 0, 0, 0,
+                    50, 58, 116, 101, 115, 116, 110, 101, 116, 58, 50, 68, 51, 89, 70, 50, 49, 87,
+                    77, 106, 107, 77, 106, 50, 66, 101, 109, 119, 98, 120, 74, 83, 0, 0, 0, 0, 0,
                     0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 203,
+                    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 203,
                     123, 34, 64, 99, 111, 110, 116, 101, 120, 116, 34, 58, 91, 34, 104, 116, 116,
                     112, 115, 58, 47, 47, 119, 119, 119, 46, 119, 51, 46, 111, 114, 103, 47, 110,
                     115, 47, 100, 105, 100, 47, 118, 49, 34, 93, 44, 34, 97, 108, 115, 111, 75,
@@ -353,65 +921,101 @@ pub mod test {
                     116, 105, 111, 110, 77, 101, 116, 104, 111, 100, 34, 58, 91, 93, 44, 34, 97,
                     117, 116, 104, 101, 110, 116, 105, 99, 97, 116, 105, 111, 110, 34, 58, 91, 34,
                     100, 105, 100, 58, 105, 110, 100, 121, 50, 58, 116, 101, 115, 116, 110, 101,
-                    116, 58, 51, 76, 112, 106, 115, 122, 107, 103, 84, 109, 69, 51, 113, 84, 104,
-                    103, 101, 50, 53, 70, 90, 119, 35, 75, 69, 89, 45, 49, 34, 44, 34, 100, 105,
-                    100, 58, 105, 110, 100, 121, 50, 58, 116, 101, 115, 116, 110, 101, 116, 58, 51,
-                    76, 112, 106, 115, 122, 107, 103, 84, 109, 69, 51, 113, 84, 104, 103, 101, 50,
-                    53, 70, 90, 119, 35, 75, 69, 89, 45, 50, 34, 93, 44, 34, 99, 97, 112, 97, 98,
-                    105, 108, 105, 116, 121, 68, 101, 108, 101, 103, 97, 116, 105, 111, 110, 34,
-                    58, 91, 93, 44, 34, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 73, 110, 118,
-                    111, 99, 97, 116, 105, 111, 110, 34, 58, 91, 93, 44, 34, 99, 111, 110, 116,
-                    114, 111, 108, 108, 101, 114, 34, 58, 91, 93, 44, 34, 105, 100, 34, 58, 34,
-                    100, 105, 100, 58, 105, 110, 100, 121, 50, 58, 116, 101, 115, 116, 110, 101,
-                    116, 58, 51, 76, 112, 106, 115, 122, 107, 103, 84, 109, 69, 51, 113, 84, 104,
-                    103, 101, 50, 53, 70, 90, 119, 34, 44, 34, 107, 101, 121, 65, 103, 114, 101,
-                    101, 109, 101, 110, 116, 34, 58, 91, 93, 44, 34, 115, 101, 114, 118, 105, 99,
-                    101, 34, 58, 91, 123, 34, 105, 100, 34, 58, 34, 100, 105, 100, 58, 105, 110,
-                    100, 121, 50, 58, 116, 101, 115, 116, 110, 101, 116, 58, 51, 76, 112, 106, 115,
-                    122, 107, 103, 84, 109, 69, 51, 113, 84, 104, 103, 101, 50, 53, 70, 90, 119,
-                    35, 83, 69, 82, 86, 73, 67, 69, 45, 49, 34, 44, 34, 115, 101, 114, 118, 105,
-                    99, 101, 69, 110, 100, 112, 111, 105, 110, 116, 34, 58, 34, 49, 50, 55, 46, 48,
-                    46, 48, 46, 49, 58, 53, 53, 53, 53, 34, 44, 34, 116, 121, 112, 101, 34, 58, 34,
-                    68, 73, 68, 67, 111, 109, 109, 83, 101, 114, 118, 105, 99, 101, 34, 125, 93,
-                    44, 34, 118, 101, 114, 105, 102, 105, 99, 97, 116, 105, 111, 110, 77, 101, 116,
-                    104, 111, 100, 34, 58, 91, 123, 34, 99, 111, 110, 116, 114, 111, 108, 108, 101,
+                    116, 58, 50, 68, 51, 89, 70, 50, 49, 87, 77, 106, 107, 77, 106, 50, 66, 101,
+                    109, 119, 98, 120, 74, 83, 35, 75, 69, 89, 45, 49, 34, 44, 34, 100, 105, 100,
+                    58, 105, 110, 100, 121, 50, 58, 116, 101, 115, 116, 110, 101, 116, 58, 50, 68,
+                    51, 89, 70, 50, 49, 87, 77, 106, 107, 77, 106, 50, 66, 101, 109, 119, 98, 120,
+                    74, 83, 35, 75, 69, 89, 45, 50, 34, 93, 44, 34, 99, 97, 112, 97, 98, 105, 108,
+                    105, 116, 121, 68, 101, 108, 101, 103, 97, 116, 105, 111, 110, 34, 58, 91, 93,
+                    44, 34, 99, 97, 112, 97, 98, 105, 108, 105, 116, 121, 73, 110, 118, 111, 99,
+                    97, 116, 105, 111, 110, 34, 58, 91, 93, 44, 34, 99, 111, 110, 116, 114, 111,
+                    108, 108, 101, 114, 34, 58, 91, 93, 44, 34, 105, 100, 34, 58, 34, 100, 105,
+                    100, 58, 105, 110, 100, 121, 50, 58, 116, 101, 115, 116, 110, 101, 116, 58, 50,
+                    68, 51, 89, 70, 50, 49, 87, 77, 106, 107, 77, 106, 50, 66, 101, 109, 119, 98,
+                    120, 74, 83, 34, 44, 34, 107, 101, 121, 65, 103, 114, 101, 101, 109, 101, 110,
+                    116, 34, 58, 91, 93, 44, 34, 115, 101, 114, 118, 105, 99, 101, 34, 58, 91, 123,
+                    34, 105, 100, 34, 58, 34, 100, 105, 100, 58, 105, 110, 100, 121, 50, 58, 116,
+                    101, 115, 116, 110, 101, 116, 58, 50, 68, 51, 89, 70, 50, 49, 87, 77, 106, 107,
+                    77, 106, 50, 66, 101, 109, 119, 98, 120, 74, 83, 35, 83, 69, 82, 86, 73, 67,
+                    69, 45, 49, 34, 44, 34, 115, 101, 114, 118, 105, 99, 101, 69, 110, 100, 112,
+                    111, 105, 110, 116, 34, 58, 34, 49, 50, 55, 46, 48, 46, 48, 46, 49, 58, 53, 53,
+                    53, 53, 34, 44, 34, 116, 121, 112, 101, 34, 58, 34, 68, 73, 68, 67, 111, 109,
+                    109, 83, 101, 114, 118, 105, 99, 101, 34, 125, 93, 44, 34, 118, 101, 114, 105,
+                    102, 105, 99, 97, 116, 105, 111, 110, 77, 101, 116, 104, 111, 100, 34, 58, 91,
+                    123, 34, 99, 111, 110, 116, 114, 111, 108, 108, 101, 114, 34, 58, 34, 100, 105,
+                    100, 58, 105, 110, 100, 121, 50, 58, 116, 101, 115, 116, 110, 101, 116, 58, 50,
+                    68, 51, 89, 70, 50, 49, 87, 77, 106, 107, 77, 106, 50, 66, 101, 109, 119, 98,
+                    120, 74, 83, 34, 44, 34, 105, 100, 34, 58, 34, 100, 105, 100, 58, 105, 110,
+                    100, 121, 50, 58, 116, 101, 115, 116, 110, 101, 116, 58, 50, 68, 51, 89, 70,
+                    50, 49, 87, 77, 106, 107, 77, 106, 50, 66, 101, 109, 119, 98, 120, 74, 83, 35,
+                    75, 69, 89, 45, 49, 34, 44, 34, 112, 117, 98, 108, 105, 99, 75, 101, 121, 77,
+                    117, 108, 116, 105, 98, 97, 115, 101, 34, 58, 34, 56, 114, 110, 81, 52, 103,
+                    118, 116, 69, 89, 105, 53, 57, 68, 77, 65, 122, 78, 55, 70, 121, 67, 86, 97,
+                    116, 86, 65, 84, 107, 70, 111, 55, 119, 80, 88, 86, 77, 121, 51, 56, 87, 109,
+                    118, 71, 34, 44, 34, 116, 121, 112, 101, 34, 58, 34, 69, 100, 50, 53, 53, 49,
+                    57, 86, 101, 114, 105, 102, 105, 99, 97, 116, 105, 111, 110, 75, 101, 121, 50,
+                    48, 49, 56, 34, 125, 44, 123, 34, 99, 111, 110, 116, 114, 111, 108, 108, 101,
                     114, 34, 58, 34, 100, 105, 100, 58, 105, 110, 100, 121, 50, 58, 116, 101, 115,
-                    116, 110, 101, 116, 58, 51, 76, 112, 106, 115, 122, 107, 103, 84, 109, 69, 51,
-                    113, 84, 104, 103, 101, 50, 53, 70, 90, 119, 34, 44, 34, 105, 100, 34, 58, 34,
-                    100, 105, 100, 58, 105, 110, 100, 121, 50, 58, 116, 101, 115, 116, 110, 101,
-                    116, 58, 51, 76, 112, 106, 115, 122, 107, 103, 84, 109, 69, 51, 113, 84, 104,
-                    103, 101, 50, 53, 70, 90, 119, 35, 75, 69, 89, 45, 49, 34, 44, 34, 112, 117,
-                    98, 108, 105, 99, 75, 101, 121, 77, 117, 108, 116, 105, 98, 97, 115, 101, 34,
-                    58, 34, 56, 114, 110, 81, 52, 103, 118, 116, 69, 89, 105, 53, 57, 68, 77, 65,
-                    122, 78, 55, 70, 121, 67, 86, 97, 116, 86, 65, 84, 107, 70, 111, 55, 119, 80,
-                    88, 86, 77, 121, 51, 56, 87, 109, 118, 71, 34, 44, 34, 116, 121, 112, 101, 34,
-                    58, 34, 69, 100, 50, 53, 53, 49, 57, 86, 101, 114, 105, 102, 105, 99, 97, 116,
-                    105, 111, 110, 75, 101, 121, 50, 48, 49, 56, 34, 125, 44, 123, 34, 99, 111,
-                    110, 116, 114, 111, 108, 108, 101, 114, 34, 58, 34, 100, 105, 100, 58, 105,
-                    110, 100, 121, 50, 58, 116, 101, 115, 116, 110, 101, 116, 58, 51, 76, 112, 106,
-                    115, 122, 107, 103, 84, 109, 69, 51, 113, 84, 104, 103, 101, 50, 53, 70, 90,
-                    119, 34, 44, 34, 105, 100, 34, 58, 34, 100, 105, 100, 58, 105, 110, 100, 121,
-                    50, 58, 116, 101, 115, 116, 110, 101, 116, 58, 51, 76, 112, 106, 115, 122, 107,
-                    103, 84, 109, 69, 51, 113, 84, 104, 103, 101, 50, 53, 70, 90, 119, 35, 75, 69,
-                    89, 45, 50, 34, 44, 34, 112, 117, 98, 108, 105, 99, 75, 101, 121, 77, 117, 108,
-                    116, 105, 98, 97, 115, 101, 34, 58, 34, 78, 97, 113, 83, 50, 113, 83, 76, 90,
-                    84, 74, 99, 117, 75, 76, 118, 70, 65, 111, 66, 83, 101, 82, 70, 88, 101, 105,
-                    118, 68, 102, 121, 111, 85, 113, 118, 83, 115, 56, 68, 81, 52, 97, 106, 121,
-                    100, 122, 52, 75, 98, 85, 118, 84, 54, 118, 100, 74, 121, 122, 56, 105, 57,
-                    103, 74, 69, 113, 71, 106, 70, 107, 67, 78, 50, 55, 110, 105, 90, 104, 111, 65,
-                    98, 81, 76, 103, 107, 51, 105, 109, 110, 34, 44, 34, 116, 121, 112, 101, 34,
-                    58, 34, 69, 99, 100, 115, 97, 83, 101, 99, 112, 50, 53, 54, 107, 49, 86, 101,
-                    114, 105, 102, 105, 99, 97, 116, 105, 111, 110, 75, 101, 121, 50, 48, 49, 57,
-                    34, 125, 93, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-                    0,
+                    116, 110, 101, 116, 58, 50, 68, 51, 89, 70, 50, 49, 87, 77, 106, 107, 77, 106,
+                    50, 66, 101, 109, 119, 98, 120, 74, 83, 34, 44, 34, 105, 100, 34, 58, 34, 100,
+                    105, 100, 58, 105, 110, 100, 121, 50, 58, 116, 101, 115, 116, 110, 101, 116,
+                    58, 50, 68, 51, 89, 70, 50, 49, 87, 77, 106, 107, 77, 106, 50, 66, 101, 109,
+                    119, 98, 120, 74, 83, 35, 75, 69, 89, 45, 50, 34, 44, 34, 112, 117, 98, 108,
+                    105, 99, 75, 101, 121, 77, 117, 108, 116, 105, 98, 97, 115, 101, 34, 58, 34,
+                    78, 97, 113, 83, 50, 113, 83, 76, 90, 84, 74, 99, 117, 75, 76, 118, 70, 65,
+                    111, 66, 83, 101, 82, 70, 88, 101, 105, 118, 68, 102, 121, 111, 85, 113, 118,
+                    83, 115, 56, 68, 81, 52, 97, 106, 121, 100, 122, 52, 75, 98, 85, 118, 84, 54,
+                    118, 100, 74, 121, 122, 56, 105, 57, 103, 74, 69, 113, 71, 106, 70, 107, 67,
+                    78, 50, 55, 110, 105, 90, 104, 111, 65, 98, 81, 76, 103, 107, 51, 105, 109,
+                    110, 34, 44, 34, 116, 121, 112, 101, 34, 58, 34, 69, 99, 100, 115, 97, 83, 101,
+                    99, 112, 50, 53, 54, 107, 49, 86, 101, 114, 105, 102, 105, 99, 97, 116, 105,
+                    111, 110, 75, 101, 121, 50, 48, 49, 57, 34, 125, 93, 125, 0, 0, 0, 0, 0, 0, 0,
+                    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
                 ],
                 signature: RwLock::new(None),
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
     }
 
+    mod build_endorse_did_transaction {
+        use super::*;
+        use crate::client::client::test::{sign_hash, IDENTITY_ACC, TRUSTEE_PRIVATE_KEY};
+
+        #[async_std::test]
+        async fn build_endorse_did_transaction_with_mismatching_id_test() {
+            init_env_logger();
+            let client = mock_client();
+            let did_doc = did_doc(Some(ISSUER_ID));
+            let did = DID::try_from(ISSUER_ID).unwrap();
+            let signature = sign_hash(
+                &endorsement::endorsing_bytes(
+                    &DID_REGISTRY_ADDRESS,
+                    CHAIN_ID,
+                    0,
+                    METHOD_ENDORSE_DID,
+                    &[(&did).into(), (&did_doc).into()],
+                )
+                .unwrap(),
+                TRUSTEE_PRIVATE_KEY,
+            );
+
+            let err = build_endorse_did_transaction(
+                &client,
+                &TRUSTEE_ACC,
+                &IDENTITY_ACC,
+                &did,
+                &did_doc,
+                &signature,
+            )
+            .await
+            .unwrap_err();
+            assert!(matches!(err, VdrError::DidInvalidIdentifier(_)));
+        }
+    }
+
     mod build_resolve_did_transaction {
         use super::*;
 
@@ -419,9 +1023,10 @@ pub mod test {
         async fn build_resolve_did_transaction_test() {
             init_env_logger();
             let client = mock_client();
-            let transaction = build_resolve_did_transaction(&client, &DID::from(ISSUER_ID))
-                .await
-                .unwrap();
+            let transaction =
+                build_resolve_did_transaction(&client, &DID::try_from(ISSUER_ID).unwrap())
+                    .await
+                    .unwrap();
             let expected_transaction = Transaction {
                 type_: TransactionType::Read,
                 from: None,
@@ -438,6 +1043,7 @@ pub mod test {
                 ],
                 signature: RwLock::new(None),
                 hash: None,
+                ..Transaction::default()
             };
             assert_eq!(expected_transaction, transaction);
         }
@@ -498,4 +1104,109 @@ pub mod test {
             assert_eq!(did_doc(Some(issuer_did)), parsed_did_doc);
         }
     }
+
+    mod get_did_history {
+        use super::*;
+        use crate::{
+            client::{LedgerClientBuilder, MockClient as AutoMockClient},
+            types::{Block, ContractConfig, ContractSpec},
+        };
+        use ethabi::Token;
+        use serde_json::json;
+
+        fn did_event(name: &str) -> serde_json::Value {
+            json!({
+                "type": "event",
+                "name": name,
+                "anonymous": false,
+                "inputs": [{ "name": "did", "type": "string", "indexed": false }]
+            })
+        }
+
+        fn did_registry_client(client: AutoMockClient) -> LedgerClient {
+            LedgerClientBuilder::new(CHAIN_ID)
+                .set_client(Box::new(client))
+                .set_contract_configs(&[ContractConfig {
+                    address: DID_REGISTRY_ADDRESS.to_string(),
+                    spec_path: None,
+                    spec: Some(ContractSpec {
+                        name: CONTRACT_NAME.to_string(),
+                        abi: json!([
+                            did_event(EVENT_DID_CREATED),
+                            did_event(EVENT_DID_UPDATED),
+                            did_event(EVENT_DID_DEACTIVATED),
+                        ]),
+                    }),
+                }])
+                .build()
+                .unwrap()
+        }
+
+        fn event_log(client: &LedgerClient, event: &str, data: Vec<u8>, block: u64) -> EventLog {
+            EventLog {
+                address: DID_REGISTRY_ADDRESS.clone(),
+                topics: vec![client
+                    .contract(CONTRACT_NAME)
+                    .unwrap()
+                    .event_signature(event)
+                    .unwrap()],
+                data,
+                block_number: Some(block),
+                ..EventLog::default()
+            }
+        }
+
+        fn did_data(did: &str) -> Vec<u8> {
+            ethabi::encode(&[Token::String(did.to_string())])
+        }
+
+        #[test]
+        fn did_changes_skips_other_and_malformed_events_test() {
+            init_env_logger();
+            let client = did_registry_client(AutoMockClient::new());
+            let did: DID = ISSUER_ID.parse().unwrap();
+            let logs = vec![
+                event_log(&client, EVENT_DID_CREATED, did_data(ISSUER_ID), 1),
+                event_log(&client, EVENT_DID_CREATED, did_data(VERKEY_DID), 2),
+                event_log(&client, EVENT_DID_CREATED, did_data("not a did"), 3),
+                event_log(&client, EVENT_DID_UPDATED, vec![1, 2, 3], 4),
+                EventLog {
+                    topics: vec![vec![1; 32]],
+                    ..event_log(&client, EVENT_DID_UPDATED, did_data(ISSUER_ID), 5)
+                },
+                event_log(&client, EVENT_DID_UPDATED, did_data(ISSUER_ID), 6),
+                event_log(&client, EVENT_DID_DEACTIVATED, did_data(ISSUER_ID), 7),
+            ];
+
+            let changes = did_changes(&client, &did, &logs).unwrap();
+
+            assert_eq!(
+                vec![
+                    (1, DidChangeKind::Created),
+                    (6, DidChangeKind::Updated),
+                    (7, DidChangeKind::Deactivated),
+                ],
+                changes
+            );
+        }
+
+        #[async_std::test]
+        async fn find_first_block_since_test() {
+            init_env_logger();
+            let mut mock_client = AutoMockClient::new();
+            mock_client.expect_get_block().returning(|number| {
+                let number = number.unwrap_or(100);
+                Ok(Block {
+                    number,
+                    timestamp: 1000 + number * 10,
+                })
+            });
+            let client = did_registry_client(mock_client);
+
+            assert_eq!(0, find_first_block_since(&client, 0).await.unwrap());
+            assert_eq!(42, find_first_block_since(&client, 1420).await.unwrap());
+            assert_eq!(43, find_first_block_since(&client, 1421).await.unwrap());
+            assert_eq!(100, find_first_block_since(&client, 5000).await.unwrap());
+        }
+    }
 }
@@ -0,0 +1,369 @@
+use log::{debug, info, warn};
+use std::str::FromStr;
+
+use crate::{
+    client::LedgerClient,
+    contracts::did::{
+        did_registry,
+        ethr::{did_ethr_resolver, types::ETHR_METHOD},
+        types::{
+            did::{DidUrl, DID},
+            did_doc::DidRecord,
+            did_resolution::{
+                parse_xml_datetime, DidChange, DidResolutionError, DidResolutionMetadata,
+                DidResolutionOptions, DidResolutionResult, DidVersion, DID_JSON, DID_LD_JSON,
+            },
+            did_url_dereferencing::{
+                DereferencedResource, DidUrlDereferencingResult, URI_LIST, VERSION_ID_PARAM,
+                VERSION_TIME_PARAM,
+            },
+        },
+    },
+    error::{VdrError, VdrResult},
+};
+
+pub(crate) const INDY2_METHOD: &str = "indy2";
+
+/// Resolve DID following the DID Resolution specification: https://w3c-ccg.github.io/did-resolution/
+///
+/// Errors are not returned but reported in `didResolutionMetadata.error` using standard error codes
+/// (`invalidDid`, `invalidOptions`, `notFound`, `methodNotSupported`, `representationNotSupported`, `deactivated`,
+/// `internalError`).
+///
+/// `did:indy2` DIDs are read from IndyDidRegistry. `did:ethr` DIDs are built from EthereumExtDidRegistry events
+/// (see [did_ethr_resolver::resolve_did]), their `versionId` is the number of the block containing the change.
+///
+/// Historical versions of `did:indy2` DIDs are located using IndyDidRegistry events and read with block-pinned
+/// calls, so the client must support event log queries and calls at historical blocks (archive node). If several
+/// version options are set, `versionId` takes precedence over `versionTime`, and `versionTime` over `blockNumber`.
+/// The metadata of historical versions contains `nextUpdate` and `nextVersionId` if the DID Document was updated
+/// later.
+///
+/// # Params
+/// - `client` client connected to the network where DID is stored
+/// - `did` DID to resolve
+/// - `options` resolution options:
+///     - `accept` media type: `application/did+ld+json` (default) or `application/did+json`
+///     - `versionId` version of the DID Document (`versionId` of the DID Document metadata)
+///     - `versionTime` resolve the DID Document as it was at the given time (XML Datetime)
+///     - `blockNumber` resolve the DID Document as it was at the given block
+///
+/// # Returns
+/// DID resolution result: resolution metadata, DID Document and DID Document metadata
+pub async fn resolve(
+    client: &LedgerClient,
+    did: &str,
+    options: Option<&DidResolutionOptions>,
+) -> DidResolutionResult {
+    debug!(
+        "DID resolution has started. Did: {}, options: {:?}",
+        did, options
+    );
+
+    let content_type = match options.and_then(|options| options.accept.as_deref()) {
+        None => DID_LD_JSON,
+        Some(accept) if accept == DID_LD_JSON || accept == DID_JSON => accept,
+        Some(accept) => {
+            return resolution_error(
+                DidResolutionError::RepresentationNotSupported,
+                &format!("Unsupported representation: {}", accept),
+            )
+        }
+    };
+
+    let version = match requested_version(options) {
+        Ok(version) => version,
+        Err(message) => return resolution_error(DidResolutionError::InvalidOptions, &message),
+    };
+
+    let result = resolve_document(client, did, version, content_type)
+        .await
+        .unwrap_or_else(|result| result);
+
+    info!("DID resolution has finished. Result: {:?}", result);
+
+    result
+}
+
+/// Dereference DID URL following the DID Resolution specification:
+/// https://w3c-ccg.github.io/did-resolution/#dereferencing
+///
+/// The DID is resolved with [resolve] and the URL is dereferenced within the resolved document
+/// (see [DidDocument::dereference](crate::DidDocument::dereference)): fragments select verification methods or
+/// services, `service` and `relativeRef` query parameters select a service endpoint URL (`text/uri-list`).
+/// `versionId` and `versionTime` query parameters select the version of the DID Document.
+///
+/// # Params
+/// - `client` client connected to the network where DID is stored
+/// - `did_url` DID URL to dereference (`did:indy2:testnet:3LpjszkgTmE3qThge25FZw#KEY-1`)
+/// - `options` resolution options applied to the DID
+///
+/// # Returns
+/// DID URL dereferencing result: dereferencing metadata, dereferenced resource and DID Document metadata
+pub async fn dereference(
+    client: &LedgerClient,
+    did_url: &str,
+    options: Option<&DidResolutionOptions>,
+) -> DidUrlDereferencingResult {
+    debug!(
+        "DID URL dereferencing has started. DID URL: {}, options: {:?}",
+        did_url, options
+    );
+
+    let did_url = match DidUrl::from_str(did_url) {
+        Ok(did_url) => did_url,
+        Err(err) => {
+            return dereferencing_error(DidResolutionError::InvalidDidUrl, &err.to_string())
+        }
+    };
+
+    let mut options = options.cloned().unwrap_or_default();
+    if let Some(version_id) = did_url.query_param(VERSION_ID_PARAM) {
+        options.version_id = Some(version_id.to_string());
+    }
+    if let Some(version_time) = did_url.query_param(VERSION_TIME_PARAM) {
+        options.version_time = Some(version_time.to_string());
+    }
+
+    let resolution = resolve(client, did_url.did.as_ref(), Some(&options)).await;
+    let metadata = resolution.did_resolution_metadata;
+    let Some(did_doc) = resolution.did_document else {
+        return DidUrlDereferencingResult {
+            dereferencing_metadata: metadata,
+            ..DidUrlDereferencingResult::default()
+        };
+    };
+
+    let result = match did_doc.dereference(&did_url) {
+        Ok(resource) => DidUrlDereferencingResult {
+            dereferencing_metadata: DidResolutionMetadata {
+                content_type: match resource {
+                    DereferencedResource::Url(_) => Some(URI_LIST.to_string()),
+                    _ => metadata.content_type,
+                },
+                ..metadata
+            },
+            content_stream: Some(resource),
+            content_metadata: resolution.did_document_metadata,
+        },
+        Err(err) => {
+            let error = match err {
+                VdrError::DidInvalidIdentifier(_) => DidResolutionError::InvalidDidUrl,
+                _ => (&err).into(),
+            };
+            dereferencing_error(error, &err.to_string())
+        }
+    };
+
+    info!("DID URL dereferencing has finished. Result: {:?}", result);
+
+    result
+}
+
+async fn resolve_document(
+    client: &LedgerClient,
+    did: &str,
+    version: Option<DidVersion>,
+    content_type: &str,
+) -> Result<DidResolutionResult, DidResolutionResult> {
+    let did = DID::from_str(did)
+        .map_err(|err| resolution_error(DidResolutionError::InvalidDid, &err.to_string()))?;
+
+    let result = match did.method() {
+        INDY2_METHOD => resolve_indy2(client, &did, version)
+            .await
+            .map(|(record, next)| {
+                DidResolutionResult::from_record(record, content_type)
+                    .set_next_version(next.as_ref())
+            }),
+        ETHR_METHOD => did_ethr_resolver::resolve_did(client, &did, version)
+            .await
+            .map(|resolution| {
+                DidResolutionResult::from_document(
+                    resolution.document,
+                    resolution.metadata,
+                    content_type,
+                )
+            }),
+        method => {
+            return Err(resolution_error(
+                DidResolutionError::MethodNotSupported,
+                &format!("Unsupported DID method: {}", method),
+            ))
+        }
+    };
+    result.map_err(|err| resolution_error((&err).into(), &err.to_string()))
+}
+
+async fn resolve_indy2(
+    client: &LedgerClient,
+    did: &DID,
+    version: Option<DidVersion>,
+) -> VdrResult<(DidRecord, Option<DidChange>)> {
+    match version {
+        None => fetch_record(client, did).await.map(|record| (record, None)),
+        Some(version) => fetch_version(client, did, version).await,
+    }
+}
+
+async fn fetch_record(client: &LedgerClient, did: &DID) -> VdrResult<DidRecord> {
+    let transaction = did_registry::build_resolve_did_transaction(client, did).await?;
+    let response = client.submit_transaction(&transaction).await?;
+    did_registry::parse_resolve_did_record(client, &response)
+}
+
+async fn fetch_version(
+    client: &LedgerClient,
+    did: &DID,
+    version: DidVersion,
+) -> VdrResult<(DidRecord, Option<DidChange>)> {
+    let history = did_registry::get_did_history(client, did).await?;
+    let block = version_block(&history, version)
+        .ok_or_else(|| VdrError::DidNotFound(format!("{} ({})", did, version)))?;
+
+    let transaction = did_registry::build_resolve_did_transaction(client, did).await?;
+    let response = client.call_transaction_at(&transaction, block).await?;
+    let record = did_registry::parse_resolve_did_record(client, &response)?;
+
+    Ok((record, next_version(&history, block).cloned()))
+}
+
+fn requested_version(options: Option<&DidResolutionOptions>) -> Result<Option<DidVersion>, String> {
+    let Some(options) = options else {
+        return Ok(None);
+    };
+    if let Some(version_id) = options.version_id.as_deref() {
+        return version_id
+            .parse::<u64>()
+            .map(|version_id| Some(DidVersion::Id(version_id)))
+            .map_err(|_| format!("Invalid versionId: {}", version_id));
+    }
+    if let Some(version_time) = options.version_time.as_deref() {
+        return parse_xml_datetime(version_time)
+            .map(|timestamp| Some(DidVersion::Time(timestamp)))
+            .ok_or_else(|| format!("Invalid versionTime: {}", version_time));
+    }
+    Ok(options.block_number.map(DidVersion::Block))
+}
+
+/// Find the block to read the requested version at
+fn version_block(history: &[DidChange], version: DidVersion) -> Option<u64> {
+    match version {
+        DidVersion::Id(version_id) => history
+            .iter()
+            .rev()
+            .find(|change| change.version_id() == Some(version_id))
+            .map(|change| change.block_number),
+        DidVersion::Time(timestamp) => history
+            .iter()
+            .rev()
+            .find(|change| change.timestamp <= timestamp)
+            .map(|change| change.block_number),
+        DidVersion::Block(block_number) => Some(block_number),
+    }
+}
+
+/// Find the first change after the block producing a new version of DID Document
+fn next_version(history: &[DidChange], block: u64) -> Option<&DidChange> {
+    history
+        .iter()
+        .find(|change| change.block_number > block && change.version_id().is_some())
+}
+
+fn resolution_error(error: DidResolutionError, message: &str) -> DidResolutionResult {
+    warn!("Error: {} during DID resolution", message);
+
+    DidResolutionResult::from_error(error, message)
+}
+
+fn dereferencing_error(error: DidResolutionError, message: &str) -> DidUrlDereferencingResult {
+    warn!("Error: {} during DID URL dereferencing", message);
+
+    DidUrlDereferencingResult::from_error(error, message)
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::contracts::did::types::did_resolution::{
+        test::{CREATED, UPDATED},
+        DidChangeKind,
+    };
+
+    const DEACTIVATED: u64 = UPDATED + 3600;
+
+    fn history() -> Vec<DidChange> {
+        vec![
+            DidChange {
+                kind: DidChangeKind::Created,
+                block_number: 10,
+                timestamp: CREATED,
+            },
+            DidChange {
+                kind: DidChangeKind::Updated,
+                block_number: 20,
+                timestamp: UPDATED,
+            },
+            DidChange {
+                kind: DidChangeKind::Deactivated,
+                block_number: 30,
+                timestamp: DEACTIVATED,
+            },
+        ]
+    }
+
+    #[test]
+    fn requested_version_test() {
+        let options = |version_id: Option<&str>, version_time: Option<&str>| DidResolutionOptions {
+            version_id: version_id.map(String::from),
+            version_time: version_time.map(String::from),
+            block_number: Some(5),
+            ..DidResolutionOptions::default()
+        };
+        assert_eq!(Ok(None), requested_version(None));
+        assert_eq!(
+            Ok(Some(DidVersion::Block(5))),
+            requested_version(Some(&options(None, None)))
+        );
+        assert_eq!(
+            Ok(Some(DidVersion::Time(CREATED))),
+            requested_version(Some(&options(None, Some("2024-01-16T09:12:31Z"))))
+        );
+        assert_eq!(
+            Ok(Some(DidVersion::Id(UPDATED))),
+            requested_version(Some(&options(
+                Some(&UPDATED.to_string()),
+                Some("2024-01-16T09:12:31Z")
+            )))
+        );
+        assert!(requested_version(Some(&options(Some("latest"), None))).is_err());
+        assert!(requested_version(Some(&options(None, Some("yesterday")))).is_err());
+    }
+
+    #[test]
+    fn version_block_test() {
+        let history = history();
+        assert_eq!(Some(10), version_block(&history, DidVersion::Id(CREATED)));
+        assert_eq!(Some(20), version_block(&history, DidVersion::Id(UPDATED)));
+        assert_eq!(None, version_block(&history, DidVersion::Id(DEACTIVATED)));
+        assert_eq!(None, version_block(&history, DidVersion::Time(CREATED - 1)));
+        assert_eq!(
+            Some(10),
+            version_block(&history, DidVersion::Time(UPDATED - 1))
+        );
+        assert_eq!(
+            Some(30),
+            version_block(&history, DidVersion::Time(DEACTIVATED))
+        );
+        assert_eq!(Some(15), version_block(&history, DidVersion::Block(15)));
+    }
+
+    #[test]
+    fn next_version_test() {
+        let history = history();
+        assert_eq!(Some(&history[1]), next_version(&history, 10));
+        assert_eq!(Some(&history[1]), next_version(&history, 15));
+        assert_eq!(None, next_version(&history, 20));
+        assert_eq!(None, next_version(&history, 30));
+    }
+}
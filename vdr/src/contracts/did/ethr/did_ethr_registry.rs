@@ -0,0 +1,1142 @@
+use log::{debug, info, trace, warn};
+use sha3::{Digest, Keccak256};
+
+use crate::{
+    client::LedgerClient,
+    contracts::did::{
+        ethr::types::{
+            delegate_type::DelegateType,
+            did_doc_attribute::DidDocAttribute,
+            did_events::{
+                DidAttributeChangedEvent, DidDelegateChangedEvent, DidEthrEvent,
+                DidOwnerChangedEvent,
+            },
+            DidEthrIdentity,
+        },
+        types::did::DID,
+    },
+    contracts::endorsement::{encode_packed, signature_params},
+    error::{VdrError, VdrResult},
+    types::{
+        Address, ContractOutput, ContractParam, EventLog, EventParser, EventStream, LedgerEvent,
+        SignatureData, Transaction, TransactionBuilder, TransactionParser, TransactionType,
+    },
+};
+
+const CONTRACT_NAME: &str = "EthereumExtDidRegistry";
+const METHOD_CHANGE_OWNER: &str = "changeOwner";
+const METHOD_CHANGE_OWNER_SIGNED: &str = "changeOwnerSigned";
+const METHOD_ADD_DELEGATE: &str = "addDelegate";
+const METHOD_ADD_DELEGATE_SIGNED: &str = "addDelegateSigned";
+const METHOD_REVOKE_DELEGATE: &str = "revokeDelegate";
+const METHOD_REVOKE_DELEGATE_SIGNED: &str = "revokeDelegateSigned";
+const METHOD_SET_ATTRIBUTE: &str = "setAttribute";
+const METHOD_SET_ATTRIBUTE_SIGNED: &str = "setAttributeSigned";
+const METHOD_REVOKE_ATTRIBUTE: &str = "revokeAttribute";
+const METHOD_REVOKE_ATTRIBUTE_SIGNED: &str = "revokeAttributeSigned";
+const METHOD_IDENTITY_OWNER: &str = "identityOwner";
+const METHOD_CHANGED: &str = "changed";
+const METHOD_NONCE: &str = "nonce";
+const EVENT_DID_OWNER_CHANGED: &str = "DIDOwnerChanged";
+const EVENT_DID_DELEGATE_CHANGED: &str = "DIDDelegateChanged";
+const EVENT_DID_ATTRIBUTE_CHANGED: &str = "DIDAttributeChanged";
+
+/// did:ethr identity change found in the ledger history
+#[derive(Clone, PartialEq, Debug)]
+pub struct DidEthrChange {
+    /// Number of the block containing the change
+    pub block_number: u64,
+    pub event: DidEthrEvent,
+}
+
+/// Build transaction to execute EthereumExtDidRegistry.changeOwner contract method to change the owner of did:ethr identity
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `from` transaction sender account address (current identity owner)
+/// - `did` DID to change the owner of
+/// - `new_owner` account address of the new owner
+///
+/// # Returns
+/// Write transaction to sign and submit
+pub async fn build_did_change_owner_transaction(
+    client: &LedgerClient,
+    from: &Address,
+    did: &DID,
+    new_owner: &Address,
+) -> VdrResult<Transaction> {
+    let identity = DidEthrIdentity::try_from(did)?;
+    build_write_transaction(
+        client,
+        METHOD_CHANGE_OWNER,
+        from,
+        vec![(&identity.address).try_into()?, new_owner.try_into()?],
+    )
+    .await
+}
+
+/// Build transaction to execute EthereumExtDidRegistry.changeOwnerSigned contract method: meta transaction changing
+/// the owner of did:ethr identity which can be submitted by any account
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `from` transaction sender account address
+/// - `did` DID to change the owner of
+/// - `new_owner` account address of the new owner
+/// - `signature` signature of the current identity owner over [build_did_change_owner_signing_bytes]
+///
+/// # Returns
+/// Write transaction to sign and submit
+pub async fn build_did_change_owner_signed_transaction(
+    client: &LedgerClient,
+    from: &Address,
+    did: &DID,
+    new_owner: &Address,
+    signature: &SignatureData,
+) -> VdrResult<Transaction> {
+    let identity = DidEthrIdentity::try_from(did)?;
+    let mut params = signed_params(&identity.address, signature)?;
+    params.push(new_owner.try_into()?);
+    build_write_transaction(client, METHOD_CHANGE_OWNER_SIGNED, from, params).await
+}
+
+/// Build bytes the identity owner signs to authorize EthereumExtDidRegistry.changeOwnerSigned meta transaction
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `did` DID to change the owner of
+/// - `new_owner` account address of the new owner
+///
+/// # Returns
+/// hash to sign (see [crate::Signer::sign_hash])
+pub async fn build_did_change_owner_signing_bytes(
+    client: &LedgerClient,
+    did: &DID,
+    new_owner: &Address,
+) -> VdrResult<Vec<u8>> {
+    build_signing_bytes(client, did, METHOD_CHANGE_OWNER, &[&new_owner.try_into()?]).await
+}
+
+/// Build transaction to execute EthereumExtDidRegistry.addDelegate contract method to add a delegate of did:ethr identity
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `from` transaction sender account address (identity owner)
+/// - `did` DID to add the delegate to
+/// - `delegate_type` type of the delegate
+/// - `delegate` account address of the delegate
+/// - `validity` number of seconds the delegate is valid for
+///
+/// # Returns
+/// Write transaction to sign and submit
+pub async fn build_did_add_delegate_transaction(
+    client: &LedgerClient,
+    from: &Address,
+    did: &DID,
+    delegate_type: &DelegateType,
+    delegate: &Address,
+    validity: u64,
+) -> VdrResult<Transaction> {
+    let identity = DidEthrIdentity::try_from(did)?;
+    build_write_transaction(
+        client,
+        METHOD_ADD_DELEGATE,
+        from,
+        vec![
+            (&identity.address).try_into()?,
+            delegate_type.try_into()?,
+            delegate.try_into()?,
+            ContractParam::Uint(validity.into()),
+        ],
+    )
+    .await
+}
+
+/// Build transaction to execute EthereumExtDidRegistry.addDelegateSigned contract method: meta transaction adding
+/// a delegate of did:ethr identity which can be submitted by any account
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `from` transaction sender account address
+/// - `did` DID to add the delegate to
+/// - `delegate_type` type of the delegate
+/// - `delegate` account address of the delegate
+/// - `validity` number of seconds the delegate is valid for
+/// - `signature` signature of the identity owner over [build_did_add_delegate_signing_bytes]
+///
+/// # Returns
+/// Write transaction to sign and submit
+pub async fn build_did_add_delegate_signed_transaction(
+    client: &LedgerClient,
+    from: &Address,
+    did: &DID,
+    delegate_type: &DelegateType,
+    delegate: &Address,
+    validity: u64,
+    signature: &SignatureData,
+) -> VdrResult<Transaction> {
+    let identity = DidEthrIdentity::try_from(did)?;
+    let mut params = signed_params(&identity.address, signature)?;
+    params.extend([
+        delegate_type.try_into()?,
+        delegate.try_into()?,
+        ContractParam::Uint(validity.into()),
+    ]);
+    build_write_transaction(client, METHOD_ADD_DELEGATE_SIGNED, from, params).await
+}
+
+/// Build bytes the identity owner signs to authorize EthereumExtDidRegistry.addDelegateSigned meta transaction
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `did` DID to add the delegate to
+/// - `delegate_type` type of the delegate
+/// - `delegate` account address of the delegate
+/// - `validity` number of seconds the delegate is valid for
+///
+/// # Returns
+/// hash to sign (see [crate::Signer::sign_hash])
+pub async fn build_did_add_delegate_signing_bytes(
+    client: &LedgerClient,
+    did: &DID,
+    delegate_type: &DelegateType,
+    delegate: &Address,
+    validity: u64,
+) -> VdrResult<Vec<u8>> {
+    build_signing_bytes(
+        client,
+        did,
+        METHOD_ADD_DELEGATE,
+        &[
+            &delegate_type.try_into()?,
+            &delegate.try_into()?,
+            &ContractParam::Uint(validity.into()),
+        ],
+    )
+    .await
+}
+
+/// Build transaction to execute EthereumExtDidRegistry.revokeDelegate contract method to revoke a delegate of did:ethr identity
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `from` transaction sender account address (identity owner)
+/// - `did` DID to revoke the delegate from
+/// - `delegate_type` type of the delegate
+/// - `delegate` account address of the delegate
+///
+/// # Returns
+/// Write transaction to sign and submit
+pub async fn build_did_revoke_delegate_transaction(
+    client: &LedgerClient,
+    from: &Address,
+    did: &DID,
+    delegate_type: &DelegateType,
+    delegate: &Address,
+) -> VdrResult<Transaction> {
+    let identity = DidEthrIdentity::try_from(did)?;
+    build_write_transaction(
+        client,
+        METHOD_REVOKE_DELEGATE,
+        from,
+        vec![
+            (&identity.address).try_into()?,
+            delegate_type.try_into()?,
+            delegate.try_into()?,
+        ],
+    )
+    .await
+}
+
+/// Build transaction to execute EthereumExtDidRegistry.revokeDelegateSigned contract method: meta transaction
+/// revoking a delegate of did:ethr identity which can be submitted by any account
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `from` transaction sender account address
+/// - `did` DID to revoke the delegate from
+/// - `delegate_type` type of the delegate
+/// - `delegate` account address of the delegate
+/// - `signature` signature of the identity owner over [build_did_revoke_delegate_signing_bytes]
+///
+/// # Returns
+/// Write transaction to sign and submit
+pub async fn build_did_revoke_delegate_signed_transaction(
+    client: &LedgerClient,
+    from: &Address,
+    did: &DID,
+    delegate_type: &DelegateType,
+    delegate: &Address,
+    signature: &SignatureData,
+) -> VdrResult<Transaction> {
+    let identity = DidEthrIdentity::try_from(did)?;
+    let mut params = signed_params(&identity.address, signature)?;
+    params.extend([delegate_type.try_into()?, delegate.try_into()?]);
+    build_write_transaction(client, METHOD_REVOKE_DELEGATE_SIGNED, from, params).await
+}
+
+/// Build bytes the identity owner signs to authorize EthereumExtDidRegistry.revokeDelegateSigned meta transaction
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `did` DID to revoke the delegate from
+/// - `delegate_type` type of the delegate
+/// - `delegate` account address of the delegate
+///
+/// # Returns
+/// hash to sign (see [crate::Signer::sign_hash])
+pub async fn build_did_revoke_delegate_signing_bytes(
+    client: &LedgerClient,
+    did: &DID,
+    delegate_type: &DelegateType,
+    delegate: &Address,
+) -> VdrResult<Vec<u8>> {
+    build_signing_bytes(
+        client,
+        did,
+        METHOD_REVOKE_DELEGATE,
+        &[&delegate_type.try_into()?, &delegate.try_into()?],
+    )
+    .await
+}
+
+/// Build transaction to execute EthereumExtDidRegistry.setAttribute contract method to add a public key or a service
+/// to did:ethr DID Document
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `from` transaction sender account address (identity owner)
+/// - `did` DID to set the attribute to
+/// - `attribute` public key or service
+/// - `validity` number of seconds the attribute is valid for
+///
+/// # Returns
+/// Write transaction to sign and submit
+pub async fn build_did_set_attribute_transaction(
+    client: &LedgerClient,
+    from: &Address,
+    did: &DID,
+    attribute: &DidDocAttribute,
+    validity: u64,
+) -> VdrResult<Transaction> {
+    let identity = DidEthrIdentity::try_from(did)?;
+    build_write_transaction(
+        client,
+        METHOD_SET_ATTRIBUTE,
+        from,
+        vec![
+            (&identity.address).try_into()?,
+            attribute.name_param()?,
+            attribute.value_param()?,
+            ContractParam::Uint(validity.into()),
+        ],
+    )
+    .await
+}
+
+/// Build transaction to execute EthereumExtDidRegistry.setAttributeSigned contract method: meta transaction setting
+/// an attribute of did:ethr DID Document which can be submitted by any account
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `from` transaction sender account address
+/// - `did` DID to set the attribute to
+/// - `attribute` public key or service
+/// - `validity` number of seconds the attribute is valid for
+/// - `signature` signature of the identity owner over [build_did_set_attribute_signing_bytes]
+///
+/// # Returns
+/// Write transaction to sign and submit
+pub async fn build_did_set_attribute_signed_transaction(
+    client: &LedgerClient,
+    from: &Address,
+    did: &DID,
+    attribute: &DidDocAttribute,
+    validity: u64,
+    signature: &SignatureData,
+) -> VdrResult<Transaction> {
+    let identity = DidEthrIdentity::try_from(did)?;
+    let mut params = signed_params(&identity.address, signature)?;
+    params.extend([
+        attribute.name_param()?,
+        attribute.value_param()?,
+        ContractParam::Uint(validity.into()),
+    ]);
+    build_write_transaction(client, METHOD_SET_ATTRIBUTE_SIGNED, from, params).await
+}
+
+/// Build bytes the identity owner signs to authorize EthereumExtDidRegistry.setAttributeSigned meta transaction
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `did` DID to set the attribute to
+/// - `attribute` public key or service
+/// - `validity` number of seconds the attribute is valid for
+///
+/// # Returns
+/// hash to sign (see [crate::Signer::sign_hash])
+pub async fn build_did_set_attribute_signing_bytes(
+    client: &LedgerClient,
+    did: &DID,
+    attribute: &DidDocAttribute,
+    validity: u64,
+) -> VdrResult<Vec<u8>> {
+    build_signing_bytes(
+        client,
+        did,
+        METHOD_SET_ATTRIBUTE,
+        &[
+            &attribute.name_param()?,
+            &attribute.value_param()?,
+            &ContractParam::Uint(validity.into()),
+        ],
+    )
+    .await
+}
+
+/// Build transaction to execute EthereumExtDidRegistry.revokeAttribute contract method to remove a public key or
+/// a service from did:ethr DID Document
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `from` transaction sender account address (identity owner)
+/// - `did` DID to revoke the attribute from
+/// - `attribute` public key or service
+///
+/// # Returns
+/// Write transaction to sign and submit
+pub async fn build_did_revoke_attribute_transaction(
+    client: &LedgerClient,
+    from: &Address,
+    did: &DID,
+    attribute: &DidDocAttribute,
+) -> VdrResult<Transaction> {
+    let identity = DidEthrIdentity::try_from(did)?;
+    build_write_transaction(
+        client,
+        METHOD_REVOKE_ATTRIBUTE,
+        from,
+        vec![
+            (&identity.address).try_into()?,
+            attribute.name_param()?,
+            attribute.value_param()?,
+        ],
+    )
+    .await
+}
+
+/// Build transaction to execute EthereumExtDidRegistry.revokeAttributeSigned contract method: meta transaction
+/// revoking an attribute of did:ethr DID Document which can be submitted by any account
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `from` transaction sender account address
+/// - `did` DID to revoke the attribute from
+/// - `attribute` public key or service
+/// - `signature` signature of the identity owner over [build_did_revoke_attribute_signing_bytes]
+///
+/// # Returns
+/// Write transaction to sign and submit
+pub async fn build_did_revoke_attribute_signed_transaction(
+    client: &LedgerClient,
+    from: &Address,
+    did: &DID,
+    attribute: &DidDocAttribute,
+    signature: &SignatureData,
+) -> VdrResult<Transaction> {
+    let identity = DidEthrIdentity::try_from(did)?;
+    let mut params = signed_params(&identity.address, signature)?;
+    params.extend([attribute.name_param()?, attribute.value_param()?]);
+    build_write_transaction(client, METHOD_REVOKE_ATTRIBUTE_SIGNED, from, params).await
+}
+
+/// Build bytes the identity owner signs to authorize EthereumExtDidRegistry.revokeAttributeSigned meta transaction
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `did` DID to revoke the attribute from
+/// - `attribute` public key or service
+///
+/// # Returns
+/// hash to sign (see [crate::Signer::sign_hash])
+pub async fn build_did_revoke_attribute_signing_bytes(
+    client: &LedgerClient,
+    did: &DID,
+    attribute: &DidDocAttribute,
+) -> VdrResult<Vec<u8>> {
+    build_signing_bytes(
+        client,
+        did,
+        METHOD_REVOKE_ATTRIBUTE,
+        &[&attribute.name_param()?, &attribute.value_param()?],
+    )
+    .await
+}
+
+/// Build transaction to execute EthereumExtDidRegistry.identityOwner contract method to get the owner of did:ethr identity
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `did` target DID
+///
+/// # Returns
+/// Read transaction to submit
+pub async fn build_get_did_owner_transaction(
+    client: &LedgerClient,
+    did: &DID,
+) -> VdrResult<Transaction> {
+    let identity = DidEthrIdentity::try_from(did)?;
+    build_read_transaction(
+        client,
+        METHOD_IDENTITY_OWNER,
+        vec![(&identity.address).try_into()?],
+    )
+    .await
+}
+
+/// Parse the result of execution EthereumExtDidRegistry.identityOwner contract method
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `bytes` result bytes returned from the ledger
+///
+/// # Returns
+/// identity owner account address (the identity itself if the owner was never changed)
+pub fn parse_did_owner_result(client: &LedgerClient, bytes: &[u8]) -> VdrResult<Address> {
+    parse_result::<AddressOutput>(client, METHOD_IDENTITY_OWNER, bytes).map(|owner| owner.0)
+}
+
+/// Build transaction to execute EthereumExtDidRegistry.changed contract method to get the block of the last
+/// change of did:ethr identity
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `did` target DID
+///
+/// # Returns
+/// Read transaction to submit
+pub async fn build_get_did_changed_transaction(
+    client: &LedgerClient,
+    did: &DID,
+) -> VdrResult<Transaction> {
+    let identity = DidEthrIdentity::try_from(did)?;
+    build_read_transaction(
+        client,
+        METHOD_CHANGED,
+        vec![(&identity.address).try_into()?],
+    )
+    .await
+}
+
+/// Parse the result of execution EthereumExtDidRegistry.changed contract method
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `bytes` result bytes returned from the ledger
+///
+/// # Returns
+/// number of the block containing the last change (0 if the identity was never changed)
+pub fn parse_did_changed_result(client: &LedgerClient, bytes: &[u8]) -> VdrResult<u64> {
+    parse_result::<UintOutput>(client, METHOD_CHANGED, bytes).map(|changed| changed.0)
+}
+
+/// Build transaction to execute EthereumExtDidRegistry.nonce contract method to get the nonce of the account
+/// used in signed meta transactions
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `account` identity owner account address
+///
+/// # Returns
+/// Read transaction to submit
+pub async fn build_get_identity_nonce_transaction(
+    client: &LedgerClient,
+    account: &Address,
+) -> VdrResult<Transaction> {
+    build_read_transaction(client, METHOD_NONCE, vec![account.try_into()?]).await
+}
+
+/// Parse the result of execution EthereumExtDidRegistry.nonce contract method
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `bytes` result bytes returned from the ledger
+///
+/// # Returns
+/// nonce of the account
+pub fn parse_identity_nonce_result(client: &LedgerClient, bytes: &[u8]) -> VdrResult<u64> {
+    parse_result::<UintOutput>(client, METHOD_NONCE, bytes).map(|nonce| nonce.0)
+}
+
+/// Subscribe to EthereumExtDidRegistry.DIDOwnerChanged events emitted when the owner of did:ethr identity is changed
+///
+/// # Params
+/// - `client` client connected to the network (must support event subscriptions)
+///
+/// # Returns
+/// stream of event logs to parse with [parse_did_owner_changed_event]
+pub async fn subscribe_did_owner_changed(client: &LedgerClient) -> VdrResult<EventStream> {
+    subscribe(client, EVENT_DID_OWNER_CHANGED).await
+}
+
+/// Parse EthereumExtDidRegistry.DIDOwnerChanged event log
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `log` event log received from the subscription
+///
+/// # Returns
+/// identity with its new owner
+pub fn parse_did_owner_changed_event(
+    client: &LedgerClient,
+    log: &EventLog,
+) -> VdrResult<DidOwnerChangedEvent> {
+    parse_event(client, EVENT_DID_OWNER_CHANGED, log)
+}
+
+/// Subscribe to EthereumExtDidRegistry.DIDDelegateChanged events emitted when a delegate of did:ethr identity is
+/// added or revoked
+///
+/// # Params
+/// - `client` client connected to the network (must support event subscriptions)
+///
+/// # Returns
+/// stream of event logs to parse with [parse_did_delegate_changed_event]
+pub async fn subscribe_did_delegate_changed(client: &LedgerClient) -> VdrResult<EventStream> {
+    subscribe(client, EVENT_DID_DELEGATE_CHANGED).await
+}
+
+/// Parse EthereumExtDidRegistry.DIDDelegateChanged event log
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `log` event log received from the subscription
+///
+/// # Returns
+/// identity with the changed delegate
+pub fn parse_did_delegate_changed_event(
+    client: &LedgerClient,
+    log: &EventLog,
+) -> VdrResult<DidDelegateChangedEvent> {
+    parse_event(client, EVENT_DID_DELEGATE_CHANGED, log)
+}
+
+/// Subscribe to EthereumExtDidRegistry.DIDAttributeChanged events emitted when an attribute of did:ethr identity is
+/// set or revoked
+///
+/// # Params
+/// - `client` client connected to the network (must support event subscriptions)
+///
+/// # Returns
+/// stream of event logs to parse with [parse_did_attribute_changed_event]
+pub async fn subscribe_did_attribute_changed(client: &LedgerClient) -> VdrResult<EventStream> {
+    subscribe(client, EVENT_DID_ATTRIBUTE_CHANGED).await
+}
+
+/// Parse EthereumExtDidRegistry.DIDAttributeChanged event log
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `log` event log received from the subscription
+///
+/// # Returns
+/// identity with the changed attribute
+pub fn parse_did_attribute_changed_event(
+    client: &LedgerClient,
+    log: &EventLog,
+) -> VdrResult<DidAttributeChangedEvent> {
+    parse_event(client, EVENT_DID_ATTRIBUTE_CHANGED, log)
+}
+
+/// Get the history of did:ethr identity changes
+///
+/// Events are collected following the `previousChange` references starting from the block returned by
+/// EthereumExtDidRegistry.changed, as described in the did:ethr method specification.
+///
+/// # Params
+/// - `client` client connected to the network (must support event log queries)
+/// - `did` target DID
+///
+/// # Returns
+/// identity changes in the order they were made
+pub async fn get_did_changes(client: &LedgerClient, did: &DID) -> VdrResult<Vec<DidEthrChange>> {
+    debug!("did:ethr history query has started. Did: {:?}", did);
+
+    let identity = DidEthrIdentity::try_from(did)?;
+    let transaction = build_get_did_changed_transaction(client, did).await?;
+    let response = client.submit_transaction(&transaction).await?;
+    let mut block = parse_did_changed_result(client, &response)?;
+    // identity is the first indexed parameter of all registry events
+    let mut identity_topic = vec![0; 12];
+    identity_topic.extend(encode_packed(&(&identity.address).try_into()?));
+
+    let mut blocks: Vec<Vec<DidEthrChange>> = Vec::new();
+    while block > 0 {
+        let logs = client
+            .query_events(
+                CONTRACT_NAME,
+                None,
+                Some(identity_topic.clone()),
+                block,
+                Some(block),
+            )
+            .await?;
+        let changes = did_changes(client, &identity.address, block, &logs);
+        // the first change in the block references the previous block, the rest reference the block itself
+        let previous = changes
+            .iter()
+            .map(|change| change.event.previous_change())
+            .filter(|previous| *previous < block)
+            .min();
+        if changes.is_empty() || previous.is_none() {
+            let vdr_error = VdrError::ClientInvalidResponse(format!(
+                "Broken history of {}: no valid changes found in block {}",
+                did, block
+            ));
+
+            warn!("Error: {} during did:ethr history query", vdr_error);
+
+            return Err(vdr_error);
+        }
+        blocks.push(changes);
+        block = previous.unwrap_or_default();
+    }
+    let history: Vec<DidEthrChange> = blocks.into_iter().rev().flatten().collect();
+
+    info!("did:ethr history query has finished. Result: {:?}", history);
+
+    Ok(history)
+}
+
+// Logs which are not identity changes or can not be decoded are skipped, so a single malformed
+// event does not break the history
+fn did_changes(
+    client: &LedgerClient,
+    identity: &Address,
+    block: u64,
+    logs: &[EventLog],
+) -> Vec<DidEthrChange> {
+    let mut changes = Vec::new();
+    for log in logs {
+        match decode_did_event(client, log) {
+            Ok(Some(event)) if event.identity() == identity => changes.push(DidEthrChange {
+                block_number: block,
+                event,
+            }),
+            Ok(_) => {}
+            Err(err) => warn!(
+                "Error: {} during decoding did:ethr event log: {:?}. The event is skipped",
+                err, log
+            ),
+        }
+    }
+    changes
+}
+
+pub(crate) fn decode_event(
+    client: &LedgerClient,
+    log: &EventLog,
+) -> VdrResult<Option<LedgerEvent>> {
+    decode_did_event(client, log).map(|event| {
+        event.map(|event| match event {
+            DidEthrEvent::OwnerChanged(event) => LedgerEvent::DidOwnerChanged(event),
+            DidEthrEvent::DelegateChanged(event) => LedgerEvent::DidDelegateChanged(event),
+            DidEthrEvent::AttributeChanged(event) => LedgerEvent::DidAttributeChanged(event),
+        })
+    })
+}
+
+fn decode_did_event(client: &LedgerClient, log: &EventLog) -> VdrResult<Option<DidEthrEvent>> {
+    let matches = |event: &str| {
+        EventParser::new()
+            .set_contract(CONTRACT_NAME)
+            .set_event(event)
+            .matches(client, log)
+    };
+    if matches(EVENT_DID_OWNER_CHANGED) {
+        return parse_did_owner_changed_event(client, log)
+            .map(|event| Some(DidEthrEvent::OwnerChanged(event)));
+    }
+    if matches(EVENT_DID_DELEGATE_CHANGED) {
+        return parse_did_delegate_changed_event(client, log)
+            .map(|event| Some(DidEthrEvent::DelegateChanged(event)));
+    }
+    if matches(EVENT_DID_ATTRIBUTE_CHANGED) {
+        return parse_did_attribute_changed_event(client, log)
+            .map(|event| Some(DidEthrEvent::AttributeChanged(event)));
+    }
+    Ok(None)
+}
+
+async fn build_write_transaction(
+    client: &LedgerClient,
+    method: &str,
+    from: &Address,
+    params: Vec<ContractParam>,
+) -> VdrResult<Transaction> {
+    debug!(
+        "{} txn build has started. Sender: {:?}, params: {:?}",
+        method, from, params
+    );
+
+    let transaction = params
+        .into_iter()
+        .fold(
+            TransactionBuilder::new()
+                .set_contract(CONTRACT_NAME)
+                .set_method(method),
+            TransactionBuilder::add_param,
+        )
+        .set_from(from)
+        .set_type(TransactionType::Write)
+        .build(client)
+        .await?;
+
+    info!(
+        "{} txn build has finished. Result: {:?}",
+        method, transaction
+    );
+
+    Ok(transaction)
+}
+
+async fn build_read_transaction(
+    client: &LedgerClient,
+    method: &str,
+    params: Vec<ContractParam>,
+) -> VdrResult<Transaction> {
+    debug!("{} txn build has started. Params: {:?}", method, params);
+
+    let transaction = params
+        .into_iter()
+        .fold(
+            TransactionBuilder::new()
+                .set_contract(CONTRACT_NAME)
+                .set_method(method),
+            TransactionBuilder::add_param,
+        )
+        .set_type(TransactionType::Read)
+        .build(client)
+        .await?;
+
+    info!(
+        "{} txn build has finished. Result: {:?}",
+        method, transaction
+    );
+
+    Ok(transaction)
+}
+
+fn parse_result<T: TryFrom<ContractOutput, Error = VdrError> + std::fmt::Debug>(
+    client: &LedgerClient,
+    method: &str,
+    bytes: &[u8],
+) -> VdrResult<T> {
+    debug!(
+        "{} result parse has started. Bytes to parse: {:?}",
+        method, bytes
+    );
+
+    let result = TransactionParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_method(method)
+        .parse::<T>(client, bytes)?;
+
+    info!("{} result parse has finished. Result: {:?}", method, result);
+
+    Ok(result)
+}
+
+async fn subscribe(client: &LedgerClient, event: &str) -> VdrResult<EventStream> {
+    debug!("{} event subscription has started", event);
+
+    let stream = EventParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_event(event)
+        .subscribe(client)
+        .await?;
+
+    info!("{} event subscription has finished", event);
+
+    Ok(stream)
+}
+
+fn parse_event<T: TryFrom<ContractOutput, Error = VdrError> + std::fmt::Debug>(
+    client: &LedgerClient,
+    event: &str,
+    log: &EventLog,
+) -> VdrResult<T> {
+    debug!("{} event parse has started. Log to parse: {:?}", event, log);
+
+    let parsed = EventParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_event(event)
+        .parse::<T>(client, log)?;
+
+    info!("{} event parse has finished. Result: {:?}", event, parsed);
+
+    Ok(parsed)
+}
+
+/// Parameters of signed meta transactions preceding the method parameters: identity, sigV, sigR, sigS
+fn signed_params(identity: &Address, signature: &SignatureData) -> VdrResult<Vec<ContractParam>> {
+    let mut params = vec![identity.try_into()?];
+    params.extend(signature_params(signature)?);
+    Ok(params)
+}
+
+/// Hash signed by the identity owner to authorize meta transaction:
+///     `keccak256(0x19 0x00 registry nonce(owner) identity method params)` with tightly packed params
+async fn build_signing_bytes(
+    client: &LedgerClient,
+    did: &DID,
+    method: &str,
+    params: &[&ContractParam],
+) -> VdrResult<Vec<u8>> {
+    debug!(
+        "{} signing bytes build has started. Did: {:?}, params: {:?}",
+        method, did, params
+    );
+
+    let identity = DidEthrIdentity::try_from(did)?;
+    let transaction = build_get_did_owner_transaction(client, did).await?;
+    let owner = parse_did_owner_result(client, &client.submit_transaction(&transaction).await?)?;
+    let transaction = build_get_identity_nonce_transaction(client, &owner).await?;
+    let nonce =
+        parse_identity_nonce_result(client, &client.submit_transaction(&transaction).await?)?;
+
+    let registry = client.contract(CONTRACT_NAME)?.address().clone();
+    let bytes = signing_bytes(&registry, nonce, &identity.address, method, params)?;
+
+    info!(
+        "{} signing bytes build has finished. Result: {:?}",
+        method, bytes
+    );
+
+    Ok(bytes)
+}
+
+fn signing_bytes(
+    registry: &Address,
+    nonce: u64,
+    identity: &Address,
+    method: &str,
+    params: &[&ContractParam],
+) -> VdrResult<Vec<u8>> {
+    let mut hasher = Keccak256::new();
+    hasher.update([0x19, 0x00]);
+    hasher.update(encode_packed(&registry.try_into()?));
+    hasher.update(encode_packed(&ContractParam::Uint(nonce.into())));
+    hasher.update(encode_packed(&identity.try_into()?));
+    hasher.update(method.as_bytes());
+    for param in params {
+        hasher.update(encode_packed(param));
+    }
+    Ok(hasher.finalize().to_vec())
+}
+
+#[derive(Debug)]
+struct AddressOutput(Address);
+
+impl TryFrom<ContractOutput> for AddressOutput {
+    type Error = VdrError;
+
+    fn try_from(value: ContractOutput) -> Result<Self, Self::Error> {
+        trace!("Address convert from ContractOutput: {:?}", value);
+
+        value.get_address(0).map(AddressOutput)
+    }
+}
+
+#[derive(Debug)]
+struct UintOutput(u64);
+
+impl TryFrom<ContractOutput> for UintOutput {
+    type Error = VdrError;
+
+    fn try_from(value: ContractOutput) -> Result<Self, Self::Error> {
+        trace!("Uint convert from ContractOutput: {:?}", value);
+
+        value.get_u64(0).map(UintOutput)
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::{
+        client::client::test::{
+            sign_hash, ETHR_DID_REGISTRY_ADDRESS, IDENTITY_ACC, TRUSTEE_ACC, TRUSTEE_PRIVATE_KEY,
+        },
+        contracts::did::ethr::types::did_doc_attribute::test::public_key_attribute,
+    };
+    use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+
+    #[test]
+    fn signing_bytes_are_signed_by_owner_test() {
+        let attribute = public_key_attribute();
+        let hash = signing_bytes(
+            &ETHR_DID_REGISTRY_ADDRESS,
+            0,
+            &IDENTITY_ACC,
+            METHOD_SET_ATTRIBUTE,
+            &[
+                &attribute.name_param().unwrap(),
+                &attribute.value_param().unwrap(),
+                &ContractParam::Uint(86400.into()),
+            ],
+        )
+        .unwrap();
+
+        // the same bytes hashed by the contract: abi.encodePacked(bytes1(0x19), bytes1(0), this, nonce, identity, ...)
+        let mut packed = vec![0x19, 0x00];
+        packed.extend(hex::decode(&ETHR_DID_REGISTRY_ADDRESS.as_ref()[2..]).unwrap());
+        packed.extend([0u8; 32]);
+        packed.extend(hex::decode(&IDENTITY_ACC.as_ref()[2..]).unwrap());
+        packed.extend(b"setAttribute");
+        let mut name = attribute.name().into_bytes();
+        name.resize(32, 0);
+        packed.extend(name);
+        packed.extend(attribute.value().unwrap());
+        let mut validity = [0u8; 32];
+        validity[29..].copy_from_slice(&[0x01, 0x51, 0x80]);
+        packed.extend(validity);
+        assert_eq!(Keccak256::digest(&packed).to_vec(), hash);
+
+        let signature = sign_hash(&hash, TRUSTEE_PRIVATE_KEY);
+        let params = signed_params(&IDENTITY_ACC, &signature).unwrap();
+        assert_eq!(
+            ContractParam::Uint((27 + signature.recovery_id).into()),
+            params[1]
+        );
+
+        // ecrecover in the contract restores the signer account
+        let recovered = VerifyingKey::recover_from_prehash(
+            &hash,
+            &Signature::from_slice(&signature.signature).unwrap(),
+            RecoveryId::from_byte(signature.recovery_id as u8).unwrap(),
+        )
+        .unwrap();
+        let public_key = recovered.to_encoded_point(false);
+        let account = Keccak256::digest(&public_key.as_bytes()[1..]);
+        assert_eq!(TRUSTEE_ACC.as_ref()[2..], hex::encode(&account[12..]));
+    }
+
+    mod get_did_changes {
+        use super::*;
+        use crate::{
+            client::{client::test::CHAIN_ID, LedgerClientBuilder, MockClient as AutoMockClient},
+            types::{ContractConfig, ContractSpec},
+        };
+        use ethabi::Token;
+        use serde_json::json;
+
+        const DID: &str = "did:ethr:0xb9059400dcd05158ffd8ca092937989dd27b3bdc";
+
+        fn did_ethr_registry_client(client: AutoMockClient) -> LedgerClient {
+            let input = |name: &str, type_: &str, indexed: bool| json!({ "name": name, "type": type_, "indexed": indexed });
+            LedgerClientBuilder::new(CHAIN_ID)
+                .set_client(Box::new(client))
+                .set_contract_configs(&[ContractConfig {
+                    address: ETHR_DID_REGISTRY_ADDRESS.to_string(),
+                    spec_path: None,
+                    spec: Some(ContractSpec {
+                        name: CONTRACT_NAME.to_string(),
+                        abi: json!([
+                            {
+                                "type": "function",
+                                "name": METHOD_CHANGED,
+                                "stateMutability": "view",
+                                "inputs": [{ "name": "", "type": "address" }],
+                                "outputs": [{ "name": "", "type": "uint256" }]
+                            },
+                            {
+                                "type": "event",
+                                "name": EVENT_DID_OWNER_CHANGED,
+                                "anonymous": false,
+                                "inputs": [
+                                    input("identity", "address", true),
+                                    input("owner", "address", false),
+                                    input("previousChange", "uint256", false),
+                                ]
+                            },
+                        ]),
+                    }),
+                }])
+                .build()
+                .unwrap()
+        }
+
+        fn address_topic(address: &Address) -> Vec<u8> {
+            let address = hex::decode(&address.as_ref()[2..]).unwrap();
+            ethabi::encode(&[Token::Address(ethabi::Address::from_slice(&address))])
+        }
+
+        fn owner_changed_log(client: &LedgerClient, identity: &Address, data: Vec<u8>) -> EventLog {
+            EventLog {
+                address: ETHR_DID_REGISTRY_ADDRESS.clone(),
+                topics: vec![
+                    client
+                        .contract(CONTRACT_NAME)
+                        .unwrap()
+                        .event_signature(EVENT_DID_OWNER_CHANGED)
+                        .unwrap(),
+                    address_topic(identity),
+                ],
+                data,
+                block_number: Some(5),
+                ..EventLog::default()
+            }
+        }
+
+        fn owner_changed_data(previous_change: u64) -> Vec<u8> {
+            let owner = hex::decode(&TRUSTEE_ACC.as_ref()[2..]).unwrap();
+            ethabi::encode(&[
+                Token::Address(ethabi::Address::from_slice(&owner)),
+                Token::Uint(previous_change.into()),
+            ])
+        }
+
+        #[async_std::test]
+        async fn get_did_changes_skips_malformed_events_test() {
+            let mut mock_client = AutoMockClient::new();
+            mock_client
+                .expect_call_transaction()
+                .returning(|_, _| Ok(ethabi::encode(&[Token::Uint(5.into())])));
+            let expected_topic = address_topic(&IDENTITY_ACC);
+            mock_client
+                .expect_get_logs()
+                .withf(move |query, from_block, to_block| {
+                    query.indexed_topic.as_ref() == Some(&expected_topic)
+                        && *from_block == 5
+                        && *to_block == Some(5)
+                })
+                .returning(|_, _, _| {
+                    let client = did_ethr_registry_client(AutoMockClient::new());
+                    Ok(vec![
+                        owner_changed_log(&client, &IDENTITY_ACC, vec![1, 2, 3]),
+                        owner_changed_log(&client, &TRUSTEE_ACC, owner_changed_data(0)),
+                        owner_changed_log(&client, &IDENTITY_ACC, owner_changed_data(0)),
+                    ])
+                });
+            let client = did_ethr_registry_client(mock_client);
+
+            let changes = get_did_changes(&client, &DID::try_from(DID).unwrap())
+                .await
+                .unwrap();
+
+            assert_eq!(1, changes.len());
+            assert_eq!(5, changes[0].block_number);
+            assert_eq!(&*IDENTITY_ACC, changes[0].event.identity());
+        }
+    }
+
+    #[test]
+    fn signed_params_reject_invalid_signature_test() {
+        let signature = SignatureData {
+            recovery_id: 0,
+            signature: vec![1; 63],
+        };
+        assert!(signed_params(&IDENTITY_ACC, &signature).is_err());
+    }
+}
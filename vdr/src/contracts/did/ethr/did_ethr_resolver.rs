@@ -0,0 +1,586 @@
+use log::{debug, info, warn};
+use std::str::FromStr;
+
+use crate::{
+    client::LedgerClient,
+    contracts::did::{
+        ethr::{
+            did_ethr_registry::{self, DidEthrChange},
+            types::{
+                checksum_address,
+                delegate_type::DelegateType,
+                did_doc_attribute::{DidDocAttribute, PublicKeyEncoding, PublicKeyPurpose},
+                did_events::DidEthrEvent,
+                DidEthrIdentity,
+            },
+        },
+        types::{
+            did::DID,
+            did_doc::{
+                DidDocument, Service, StringOrVector, VerificationKeyType, VerificationMethod,
+                VerificationMethodOrReference, CONTEXT,
+            },
+            did_resolution::{xml_datetime, DidDocumentMetadata, DidVersion},
+        },
+    },
+    error::{VdrError, VdrResult},
+};
+
+/// JSON-LD context of `EcdsaSecp256k1RecoveryMethod2020` verification methods
+pub const SECP256K1_RECOVERY_CONTEXT: &str =
+    "https://w3id.org/security/suites/secp256k1recovery-2020/v2";
+const NULL_ADDRESS: &str = "0x0000000000000000000000000000000000000000";
+const CONTROLLER_FRAGMENT: &str = "controller";
+const CONTROLLER_KEY_FRAGMENT: &str = "controllerKey";
+const DELEGATE_FRAGMENT: &str = "delegate";
+const SERVICE_FRAGMENT: &str = "service";
+const PUBLIC_KEY_ATTRIBUTE_PREFIX: &str = "did/pub/";
+const SERVICE_ATTRIBUTE_PREFIX: &str = "did/svc/";
+
+/// did:ethr DID Document reconstructed from EthereumExtDidRegistry events
+#[derive(Debug, Clone, PartialEq)]
+pub struct DidEthrResolution {
+    pub document: DidDocument,
+    pub metadata: DidDocumentMetadata,
+}
+
+/// Resolve did:ethr DID following the did:ethr method specification:
+/// https://github.com/decentralized-identity/ethr-did-resolver/blob/master/doc/did-method-spec.md#read-resolve
+///
+/// The DID Document is built from `DIDOwnerChanged`, `DIDDelegateChanged` and `DIDAttributeChanged` events of the
+/// identity, so the client must support event log queries. Delegates and attributes are included while their
+/// validity has not expired at the time of the resolved block. `versionId` of did:ethr DID Document is the number
+/// of the block containing the change.
+///
+/// # Params
+/// - `client` client connected to the network where EthereumExtDidRegistry is deployed
+/// - `did` DID to resolve
+/// - `version` version of the DID Document to resolve (latest if not set)
+///
+/// # Returns
+/// DID Document with its metadata (`deactivated` DIDs are returned with a minimal document)
+pub async fn resolve_did(
+    client: &LedgerClient,
+    did: &DID,
+    version: Option<DidVersion>,
+) -> VdrResult<DidEthrResolution> {
+    debug!(
+        "did:ethr resolution has started. Did: {:?}, version: {:?}",
+        did, version
+    );
+
+    let identity = DidEthrIdentity::try_from(did)?;
+    validate_network(client, did)?;
+
+    let history = did_ethr_registry::get_did_changes(client, did).await?;
+    let (block, now) = match version {
+        None => (None, client.get_block(None).await?.timestamp),
+        Some(DidVersion::Id(block))
+            if !history.iter().any(|change| change.block_number == block) =>
+        {
+            let vdr_error = VdrError::DidNotFound(format!("{} ({})", did, DidVersion::Id(block)));
+
+            warn!("Error: {} during did:ethr resolution", vdr_error);
+
+            return Err(vdr_error);
+        }
+        Some(DidVersion::Id(block)) | Some(DidVersion::Block(block)) => {
+            (Some(block), client.get_block(Some(block)).await?.timestamp)
+        }
+        Some(DidVersion::Time(timestamp)) => {
+            (time_block(client, &history, timestamp).await?, timestamp)
+        }
+    };
+
+    let changes: Vec<&DidEthrChange> = history
+        .iter()
+        .filter(|change| block.is_none_or(|block| change.block_number <= block))
+        .collect();
+    let (document, deactivated) = build_did_document(
+        did,
+        &identity,
+        client.chain_id(),
+        changes.iter().map(|change| &change.event),
+        now,
+    );
+
+    let mut metadata = DidDocumentMetadata {
+        deactivated: deactivated.then_some(true),
+        ..DidDocumentMetadata::default()
+    };
+    if let Some(last) = changes.last() {
+        let updated = client.get_block(Some(last.block_number)).await?;
+        metadata.version_id = Some(last.block_number.to_string());
+        metadata.updated = Some(xml_datetime(updated.timestamp));
+    }
+    if let Some(block) = block {
+        if let Some(next) = history.iter().find(|change| change.block_number > block) {
+            let next_update = client.get_block(Some(next.block_number)).await?;
+            metadata.next_version_id = Some(next.block_number.to_string());
+            metadata.next_update = Some(xml_datetime(next_update.timestamp));
+        }
+    }
+
+    let resolution = DidEthrResolution { document, metadata };
+
+    info!("did:ethr resolution has finished. Result: {:?}", resolution);
+
+    Ok(resolution)
+}
+
+/// Build DID Document from the identity changes made before `now` following the rules of ethr-did-resolver
+///
+/// # Params
+/// - `did` resolved DID
+/// - `identity` identity the DID refers to
+/// - `chain_id` chain id of the network used in `blockchainAccountId` of verification methods
+/// - `events` identity changes in chronological order
+/// - `now` timestamp delegates and attributes validity is checked against
+///
+/// # Returns
+/// DID Document and whether the DID is deactivated
+pub fn build_did_document<'a>(
+    did: &DID,
+    identity: &DidEthrIdentity,
+    chain_id: u64,
+    events: impl IntoIterator<Item = &'a DidEthrEvent>,
+    now: u64,
+) -> (DidDocument, bool) {
+    let method_id = |fragment: &str| format!("{}#{}", did, fragment);
+
+    let mut controller = identity.address.clone();
+    let mut delegate_count = 0;
+    let mut service_count = 0;
+    let mut methods = OrderedEntries::<VerificationMethod>::default();
+    let mut authentication = OrderedEntries::<String>::default();
+    let mut key_agreement = OrderedEntries::<String>::default();
+    let mut services = OrderedEntries::<Service>::default();
+
+    for event in events {
+        match event {
+            DidEthrEvent::OwnerChanged(event) => controller = event.owner.clone(),
+            DidEthrEvent::DelegateChanged(event) => {
+                delegate_count += 1;
+                let key = format!(
+                    "DIDDelegateChanged-{}-{}",
+                    event.delegate_type, event.delegate
+                );
+                let delegate_type = DelegateType::from_str(&event.delegate_type)
+                    .ok()
+                    .filter(|_| event.valid_to >= now);
+                let Some(delegate_type) = delegate_type else {
+                    methods.remove(&key);
+                    authentication.remove(&key);
+                    continue;
+                };
+                let id = method_id(&format!("{}-{}", DELEGATE_FRAGMENT, delegate_count));
+                if delegate_type == DelegateType::SigAuth {
+                    authentication.insert(&key, id.clone());
+                }
+                methods.insert(
+                    &key,
+                    recovery_method(&id, did, chain_id, event.delegate.as_ref()),
+                );
+            }
+            DidEthrEvent::AttributeChanged(event) => {
+                let key = format!(
+                    "DIDAttributeChanged-{}-0x{}",
+                    event.name,
+                    hex::encode(&event.value)
+                );
+                let attribute = if event.name.starts_with(PUBLIC_KEY_ATTRIBUTE_PREFIX) {
+                    delegate_count += 1;
+                    DidDocAttribute::from_raw(&event.name, &event.value)
+                } else if event.name.starts_with(SERVICE_ATTRIBUTE_PREFIX) {
+                    service_count += 1;
+                    DidDocAttribute::from_raw(&event.name, &event.value)
+                } else {
+                    None
+                };
+                let attribute = match attribute {
+                    Some(attribute) if event.valid_to >= now => attribute,
+                    _ => {
+                        methods.remove(&key);
+                        authentication.remove(&key);
+                        key_agreement.remove(&key);
+                        services.remove(&key);
+                        continue;
+                    }
+                };
+                match attribute {
+                    DidDocAttribute::PublicKey(public_key) => {
+                        let id = method_id(&format!("{}-{}", DELEGATE_FRAGMENT, delegate_count));
+                        match public_key.purpose {
+                            PublicKeyPurpose::SigAuth => authentication.insert(&key, id.clone()),
+                            PublicKeyPurpose::Enc => key_agreement.insert(&key, id.clone()),
+                            PublicKeyPurpose::VeriKey => {}
+                        }
+                        let mut method = VerificationMethod {
+                            id,
+                            type_: public_key.type_.verification_key_type(),
+                            controller: did.to_string(),
+                            ..VerificationMethod::default()
+                        };
+                        match public_key.encoding {
+                            PublicKeyEncoding::Hex => {
+                                method.public_key_hex = Some(public_key.value)
+                            }
+                            PublicKeyEncoding::Base58 => {
+                                method.public_key_base58 = Some(public_key.value)
+                            }
+                            PublicKeyEncoding::Base64 => {
+                                method.public_key_base64 = Some(public_key.value)
+                            }
+                        }
+                        methods.insert(&key, method);
+                    }
+                    DidDocAttribute::Service(service) => services.insert(
+                        &key,
+                        Service {
+                            id: method_id(&format!("{}-{}", SERVICE_FRAGMENT, service_count)),
+                            type_: service.type_,
+                            service_endpoint: service.service_endpoint,
+                        },
+                    ),
+                }
+            }
+        }
+    }
+
+    let context = StringOrVector::Vector(vec![
+        CONTEXT.to_string(),
+        SECP256K1_RECOVERY_CONTEXT.to_string(),
+    ]);
+    if controller.as_ref() == NULL_ADDRESS {
+        let document = DidDocument {
+            context,
+            id: did.clone(),
+            ..DidDocument::default()
+        };
+        return (document, true);
+    }
+
+    let mut verification_method = vec![recovery_method(
+        &method_id(CONTROLLER_FRAGMENT),
+        did,
+        chain_id,
+        controller.as_ref(),
+    )];
+    let mut authentication_refs = vec![method_id(CONTROLLER_FRAGMENT)];
+    if let Some(public_key) = identity
+        .public_key
+        .as_ref()
+        .filter(|_| controller == identity.address)
+    {
+        verification_method.push(VerificationMethod {
+            id: method_id(CONTROLLER_KEY_FRAGMENT),
+            type_: VerificationKeyType::EcdsaSecp256k1VerificationKey2019,
+            controller: did.to_string(),
+            public_key_hex: Some(hex::encode(public_key)),
+            ..VerificationMethod::default()
+        });
+        authentication_refs.push(method_id(CONTROLLER_KEY_FRAGMENT));
+    }
+    let key_agreement = key_agreement.into_values();
+    let assertion_method = authentication_refs
+        .iter()
+        .cloned()
+        .chain(
+            methods
+                .values()
+                .map(|method| method.id.clone())
+                .filter(|id| !key_agreement.contains(id)),
+        )
+        .collect::<Vec<String>>();
+    authentication_refs.extend(authentication.into_values());
+    verification_method.extend(methods.into_values());
+
+    let references = |ids: Vec<String>| {
+        ids.into_iter()
+            .map(VerificationMethodOrReference::String)
+            .collect()
+    };
+    let document = DidDocument {
+        context,
+        id: did.clone(),
+        verification_method,
+        authentication: references(authentication_refs),
+        assertion_method: references(assertion_method),
+        key_agreement: references(key_agreement),
+        service: services.into_values(),
+        ..DidDocument::default()
+    };
+    (document, false)
+}
+
+/// Check that the network of the DID (`did:ethr:<chain id>:<identifier>`) is the network of the client
+fn validate_network(client: &LedgerClient, did: &DID) -> VdrResult<()> {
+    let Some(chain_id) = did
+        .namespace()
+        .and_then(|namespace| namespace.strip_prefix("0x"))
+    else {
+        return Ok(());
+    };
+    if u64::from_str_radix(chain_id, 16).ok() != Some(client.chain_id()) {
+        let vdr_error = VdrError::DidIncorrect(format!(
+            "{} does not belong to the network with chain id {}",
+            did,
+            client.chain_id()
+        ));
+
+        warn!("Error: {} during did:ethr resolution", vdr_error);
+
+        return Err(vdr_error);
+    }
+    Ok(())
+}
+
+/// Find the block of the last change made not later than `timestamp`
+async fn time_block(
+    client: &LedgerClient,
+    history: &[DidEthrChange],
+    timestamp: u64,
+) -> VdrResult<Option<u64>> {
+    let mut block = history
+        .first()
+        .map(|change| change.block_number.saturating_sub(1));
+    for change in history {
+        if client.get_block(Some(change.block_number)).await?.timestamp > timestamp {
+            break;
+        }
+        block = Some(change.block_number);
+    }
+    Ok(block)
+}
+
+fn recovery_method(id: &str, did: &DID, chain_id: u64, account: &str) -> VerificationMethod {
+    VerificationMethod {
+        id: id.to_string(),
+        type_: VerificationKeyType::EcdsaSecp256k1RecoveryMethod2020,
+        controller: did.to_string(),
+        blockchain_account_id: Some(format!("eip155:{}:{}", chain_id, checksum_address(account))),
+        ..VerificationMethod::default()
+    }
+}
+
+/// Entries keyed by the event producing them: re-inserted keys keep their position, as with JS objects used by
+/// ethr-did-resolver, so verification method ordering matches the reference implementation
+struct OrderedEntries<T>(Vec<(String, T)>);
+
+impl<T> Default for OrderedEntries<T> {
+    fn default() -> Self {
+        OrderedEntries(Vec::new())
+    }
+}
+
+impl<T> OrderedEntries<T> {
+    fn insert(&mut self, key: &str, value: T) {
+        match self.0.iter_mut().find(|(existing, _)| existing == key) {
+            Some(entry) => entry.1 = value,
+            None => self.0.push((key.to_string(), value)),
+        }
+    }
+
+    fn remove(&mut self, key: &str) {
+        self.0.retain(|(existing, _)| existing != key)
+    }
+
+    fn values(&self) -> impl Iterator<Item = &T> {
+        self.0.iter().map(|(_, value)| value)
+    }
+
+    fn into_values(self) -> Vec<T> {
+        self.0.into_iter().map(|(_, value)| value).collect()
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::{
+        client::client::test::{CHAIN_ID, IDENTITY_ACC, TRUSTEE_ACC},
+        contracts::did::{
+            ethr::types::{
+                did_doc_attribute::test::{
+                    public_key_attribute, service_attribute, ED25519_KEY, SERVICE_ENDPOINT,
+                },
+                did_events::{
+                    DidAttributeChangedEvent, DidDelegateChangedEvent, DidOwnerChangedEvent,
+                },
+            },
+            types::did_doc::ServiceEndpoint,
+        },
+        Address,
+    };
+
+    const NOW: u64 = 1705396351;
+
+    fn did() -> DID {
+        DID::from_str(&format!("did:ethr:{}", IDENTITY_ACC.as_ref())).unwrap()
+    }
+
+    fn identity() -> DidEthrIdentity {
+        DidEthrIdentity::try_from(&did()).unwrap()
+    }
+
+    fn delegate_event(delegate_type: &str, valid_to: u64) -> DidEthrEvent {
+        DidEthrEvent::DelegateChanged(DidDelegateChangedEvent {
+            identity: IDENTITY_ACC.clone(),
+            delegate_type: delegate_type.to_string(),
+            delegate: TRUSTEE_ACC.clone(),
+            valid_to,
+            previous_change: 0,
+        })
+    }
+
+    fn attribute_event(attribute: &DidDocAttribute, valid_to: u64) -> DidEthrEvent {
+        DidEthrEvent::AttributeChanged(DidAttributeChangedEvent {
+            identity: IDENTITY_ACC.clone(),
+            name: attribute.name(),
+            value: attribute.value().unwrap(),
+            valid_to,
+            previous_change: 0,
+        })
+    }
+
+    fn owner_event(owner: &str) -> DidEthrEvent {
+        DidEthrEvent::OwnerChanged(DidOwnerChangedEvent {
+            identity: IDENTITY_ACC.clone(),
+            owner: Address::from(owner),
+            previous_change: 0,
+        })
+    }
+
+    fn ids(references: &[VerificationMethodOrReference]) -> Vec<String> {
+        references
+            .iter()
+            .map(|reference| match reference {
+                VerificationMethodOrReference::String(id) => id.clone(),
+                VerificationMethodOrReference::VerificationMethod(method) => method.id.clone(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn build_initial_did_document_test() {
+        let (document, deactivated) = build_did_document(&did(), &identity(), CHAIN_ID, [], NOW);
+        assert!(!deactivated);
+
+        let controller = format!("{}#controller", did());
+        assert_eq!(1, document.verification_method.len());
+        assert_eq!(
+            VerificationKeyType::EcdsaSecp256k1RecoveryMethod2020,
+            document.verification_method[0].type_
+        );
+        assert_eq!(
+            Some(format!(
+                "eip155:{}:{}",
+                CHAIN_ID,
+                checksum_address(IDENTITY_ACC.as_ref())
+            )),
+            document.verification_method[0].blockchain_account_id
+        );
+        assert_eq!(vec![controller.clone()], ids(&document.authentication));
+        assert_eq!(vec![controller], ids(&document.assertion_method));
+    }
+
+    #[test]
+    fn build_did_document_with_public_key_identifier_test() {
+        let did = DID::from_str(
+            "did:ethr:0x539:0x0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+        )
+        .unwrap();
+        let identity = DidEthrIdentity::try_from(&did).unwrap();
+
+        let (document, _) = build_did_document(&did, &identity, CHAIN_ID, [], NOW);
+        assert_eq!(
+            vec![
+                format!("{}#controller", did),
+                format!("{}#controllerKey", did)
+            ],
+            ids(&document.authentication)
+        );
+        assert_eq!(
+            Some("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798".to_string()),
+            document.verification_method[1].public_key_hex
+        );
+
+        // controller key is dropped once the owner is changed
+        let events = [owner_event(TRUSTEE_ACC.as_ref())];
+        let (document, _) = build_did_document(&did, &identity, CHAIN_ID, &events, NOW);
+        assert_eq!(1, document.verification_method.len());
+    }
+
+    #[test]
+    fn build_did_document_with_delegates_and_attributes_test() {
+        let events = [
+            delegate_event("sigAuth", NOW + 100),
+            attribute_event(&public_key_attribute(), NOW + 100),
+            attribute_event(&service_attribute(), NOW + 100),
+            delegate_event("veriKey", NOW - 1),
+        ];
+        let (document, _) = build_did_document(&did(), &identity(), CHAIN_ID, &events, NOW);
+
+        let id = |fragment: &str| format!("{}#{}", did(), fragment);
+        assert_eq!(
+            vec![id("controller"), id("delegate-1"), id("delegate-2")],
+            document
+                .verification_method
+                .iter()
+                .map(|method| method.id.clone())
+                .collect::<Vec<String>>()
+        );
+        assert_eq!(
+            Some(ED25519_KEY.to_string()),
+            document.verification_method[2].public_key_base58
+        );
+        assert_eq!(
+            vec![id("controller"), id("delegate-1")],
+            ids(&document.authentication)
+        );
+        assert_eq!(
+            vec![id("controller"), id("delegate-1"), id("delegate-2")],
+            ids(&document.assertion_method)
+        );
+        assert_eq!(1, document.service.len());
+        assert_eq!(id("service-1"), document.service[0].id);
+        assert_eq!(
+            ServiceEndpoint::String(SERVICE_ENDPOINT.to_string()),
+            document.service[0].service_endpoint
+        );
+    }
+
+    #[test]
+    fn build_did_document_with_revoked_entries_test() {
+        let events = [
+            delegate_event("sigAuth", NOW + 100),
+            attribute_event(&service_attribute(), NOW + 100),
+            // revocation sets validity to the time of the change
+            delegate_event("sigAuth", NOW - 1),
+            attribute_event(&service_attribute(), NOW - 1),
+            delegate_event("sigAuth", NOW + 100),
+        ];
+        let (document, _) = build_did_document(&did(), &identity(), CHAIN_ID, &events, NOW);
+
+        // counters grow with every event so the re-added delegate gets a new id
+        assert_eq!(2, document.verification_method.len());
+        assert_eq!(
+            format!("{}#delegate-3", did()),
+            document.verification_method[1].id
+        );
+        assert!(document.service.is_empty());
+    }
+
+    #[test]
+    fn build_deactivated_did_document_test() {
+        let events = [
+            delegate_event("sigAuth", NOW + 100),
+            owner_event(NULL_ADDRESS),
+        ];
+        let (document, deactivated) =
+            build_did_document(&did(), &identity(), CHAIN_ID, &events, NOW);
+        assert!(deactivated);
+        assert!(document.verification_method.is_empty());
+        assert!(document.authentication.is_empty());
+    }
+}
@@ -0,0 +1,3 @@
+pub mod did_ethr_registry;
+pub mod did_ethr_resolver;
+pub mod types;
@@ -0,0 +1,68 @@
+use log::trace;
+use serde_derive::{Deserialize, Serialize};
+use std::str::FromStr;
+
+use crate::{
+    contracts::did::ethr::types::bytes32_param,
+    error::{VdrError, VdrResult},
+    types::ContractParam,
+};
+
+const VERI_KEY: &str = "veriKey";
+const SIG_AUTH: &str = "sigAuth";
+
+/// Type of did:ethr delegate: https://github.com/decentralized-identity/ethr-did-resolver/blob/master/doc/did-method-spec.md#delegate-keys
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum DelegateType {
+    /// Delegate can sign on behalf of the identity (`assertionMethod`)
+    #[serde(rename = "veriKey")]
+    VeriKey,
+    /// Delegate can sign and authenticate on behalf of the identity (`assertionMethod` and `authentication`)
+    #[serde(rename = "sigAuth")]
+    SigAuth,
+}
+
+impl DelegateType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DelegateType::VeriKey => VERI_KEY,
+            DelegateType::SigAuth => SIG_AUTH,
+        }
+    }
+}
+
+impl FromStr for DelegateType {
+    type Err = VdrError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            VERI_KEY => Ok(DelegateType::VeriKey),
+            SIG_AUTH => Ok(DelegateType::SigAuth),
+            _ => Err(VdrError::CommonInvalidData(format!(
+                "Unsupported delegate type: {}",
+                value
+            ))),
+        }
+    }
+}
+
+impl TryInto<ContractParam> for &DelegateType {
+    type Error = VdrError;
+
+    fn try_into(self) -> VdrResult<ContractParam> {
+        trace!(
+            "DelegateType: {:?} convert into ContractParam has started",
+            self
+        );
+
+        let delegate_type_contract_param = bytes32_param(self.as_str())?;
+
+        trace!(
+            "DelegateType: {:?} convert into ContractParam has finished. Result: {:?}",
+            self,
+            delegate_type_contract_param
+        );
+
+        Ok(delegate_type_contract_param)
+    }
+}
@@ -0,0 +1,364 @@
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use log::{trace, warn};
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{
+    contracts::did::{
+        ethr::types::bytes32_param,
+        types::did_doc::{ServiceEndpoint, VerificationKeyType},
+    },
+    error::{VdrError, VdrResult},
+    types::ContractParam,
+};
+
+const ATTRIBUTE_PREFIX: &str = "did";
+const PUBLIC_KEY_ATTRIBUTE: &str = "pub";
+const SERVICE_ATTRIBUTE: &str = "svc";
+
+/// Attribute of did:ethr DID Document set with EthereumExtDidRegistry.setAttribute:
+/// https://github.com/decentralized-identity/ethr-did-resolver/blob/master/doc/did-method-spec.md#non-ethereum-attributes
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DidDocAttribute {
+    PublicKey(PublicKeyAttribute),
+    Service(ServiceAttribute),
+}
+
+/// Public key added as verification method: `did/pub/<type>/<purpose>/<encoding>`
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PublicKeyAttribute {
+    #[serde(rename = "type")]
+    pub type_: PublicKeyType,
+    pub purpose: PublicKeyPurpose,
+    pub encoding: PublicKeyEncoding,
+    /// Public key encoded using `encoding`
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum PublicKeyType {
+    Secp256k1,
+    Ed25519,
+    X25519,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum PublicKeyPurpose {
+    /// Key is used for signing (`assertionMethod`)
+    #[serde(rename = "veriKey")]
+    VeriKey,
+    /// Key is used for signing and authentication (`assertionMethod` and `authentication`)
+    #[serde(rename = "sigAuth")]
+    SigAuth,
+    /// Key is used for encryption (`keyAgreement`)
+    #[serde(rename = "enc")]
+    Enc,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PublicKeyEncoding {
+    Hex,
+    Base58,
+    Base64,
+}
+
+/// Service added to DID Document: `did/svc/<type>`
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServiceAttribute {
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub service_endpoint: ServiceEndpoint,
+}
+
+impl PublicKeyType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            PublicKeyType::Secp256k1 => "Secp256k1",
+            PublicKeyType::Ed25519 => "Ed25519",
+            PublicKeyType::X25519 => "X25519",
+        }
+    }
+
+    fn parse(value: &str) -> Option<PublicKeyType> {
+        [
+            PublicKeyType::Secp256k1,
+            PublicKeyType::Ed25519,
+            PublicKeyType::X25519,
+        ]
+        .into_iter()
+        .find(|type_| type_.as_str() == value)
+    }
+
+    /// Type of verification method representing the key
+    pub fn verification_key_type(&self) -> VerificationKeyType {
+        match self {
+            PublicKeyType::Secp256k1 => VerificationKeyType::EcdsaSecp256k1VerificationKey2019,
+            PublicKeyType::Ed25519 => VerificationKeyType::Ed25519VerificationKey2018,
+            PublicKeyType::X25519 => VerificationKeyType::X25519KeyAgreementKey2019,
+        }
+    }
+}
+
+impl PublicKeyPurpose {
+    fn as_str(&self) -> &'static str {
+        match self {
+            PublicKeyPurpose::VeriKey => "veriKey",
+            PublicKeyPurpose::SigAuth => "sigAuth",
+            PublicKeyPurpose::Enc => "enc",
+        }
+    }
+
+    fn parse(value: &str) -> Option<PublicKeyPurpose> {
+        [
+            PublicKeyPurpose::VeriKey,
+            PublicKeyPurpose::SigAuth,
+            PublicKeyPurpose::Enc,
+        ]
+        .into_iter()
+        .find(|purpose| purpose.as_str() == value)
+    }
+}
+
+impl PublicKeyEncoding {
+    fn as_str(&self) -> &'static str {
+        match self {
+            PublicKeyEncoding::Hex => "hex",
+            PublicKeyEncoding::Base58 => "base58",
+            PublicKeyEncoding::Base64 => "base64",
+        }
+    }
+
+    fn parse(value: &str) -> Option<PublicKeyEncoding> {
+        [
+            PublicKeyEncoding::Hex,
+            PublicKeyEncoding::Base58,
+            PublicKeyEncoding::Base64,
+        ]
+        .into_iter()
+        .find(|encoding| encoding.as_str() == value)
+    }
+
+    /// Encode raw key bytes
+    pub fn encode(&self, key: &[u8]) -> String {
+        match self {
+            PublicKeyEncoding::Hex => hex::encode(key),
+            PublicKeyEncoding::Base58 => bs58::encode(key).into_string(),
+            PublicKeyEncoding::Base64 => BASE64.encode(key),
+        }
+    }
+
+    /// Decode encoded key into raw bytes
+    pub fn decode(&self, key: &str) -> VdrResult<Vec<u8>> {
+        let decoded = match self {
+            PublicKeyEncoding::Hex => {
+                hex::decode(key.trim_start_matches("0x")).map_err(|err| err.to_string())
+            }
+            PublicKeyEncoding::Base58 => {
+                bs58::decode(key).into_vec().map_err(|err| err.to_string())
+            }
+            PublicKeyEncoding::Base64 => BASE64.decode(key).map_err(|err| err.to_string()),
+        };
+        decoded.map_err(|err| {
+            let vdr_error = VdrError::CommonInvalidData(format!(
+                "Unable to decode {} public key. Err: {}",
+                self.as_str(),
+                err
+            ));
+
+            warn!("Error: {} during public key decoding", vdr_error);
+
+            vdr_error
+        })
+    }
+}
+
+impl DidDocAttribute {
+    /// Attribute name stored as `bytes32` (`did/pub/Ed25519/veriKey/base58`, `did/svc/DIDCommMessaging`)
+    pub fn name(&self) -> String {
+        match self {
+            DidDocAttribute::PublicKey(key) => format!(
+                "{}/{}/{}/{}/{}",
+                ATTRIBUTE_PREFIX,
+                PUBLIC_KEY_ATTRIBUTE,
+                key.type_.as_str(),
+                key.purpose.as_str(),
+                key.encoding.as_str()
+            ),
+            DidDocAttribute::Service(service) => format!(
+                "{}/{}/{}",
+                ATTRIBUTE_PREFIX, SERVICE_ATTRIBUTE, service.type_
+            ),
+        }
+    }
+
+    /// Attribute value stored as `bytes`: raw public key or service endpoint
+    ///     (URI or JSON encoded object/set of endpoints)
+    pub fn value(&self) -> VdrResult<Vec<u8>> {
+        match self {
+            DidDocAttribute::PublicKey(key) => key.encoding.decode(&key.value),
+            DidDocAttribute::Service(service) => match &service.service_endpoint {
+                ServiceEndpoint::String(uri) => Ok(uri.as_bytes().to_vec()),
+                endpoint => serde_json::to_vec(endpoint).map_err(|err| {
+                    VdrError::CommonInvalidData(format!(
+                        "Unable to serialize service endpoint. Err: {}",
+                        err
+                    ))
+                }),
+            },
+        }
+    }
+
+    pub(crate) fn name_param(&self) -> VdrResult<ContractParam> {
+        bytes32_param(&self.name())
+    }
+
+    pub(crate) fn value_param(&self) -> VdrResult<ContractParam> {
+        Ok(ContractParam::Bytes(self.value()?))
+    }
+
+    /// Restore attribute from the name and value stored on the ledger
+    ///
+    /// # Params
+    /// - `name` attribute name
+    /// - `value` attribute value
+    ///
+    /// # Returns
+    /// attribute or `None` if the name does not follow the did:ethr attribute naming
+    pub fn from_raw(name: &str, value: &[u8]) -> Option<DidDocAttribute> {
+        let mut parts = name.split('/');
+        if parts.next() != Some(ATTRIBUTE_PREFIX) {
+            return None;
+        }
+        let attribute = match (parts.next()?, parts.next()?) {
+            (PUBLIC_KEY_ATTRIBUTE, type_) => {
+                let type_ = PublicKeyType::parse(type_)?;
+                let purpose = PublicKeyPurpose::parse(parts.next()?)?;
+                let encoding = match parts.next() {
+                    Some(encoding) => PublicKeyEncoding::parse(encoding)?,
+                    None => PublicKeyEncoding::Hex,
+                };
+                DidDocAttribute::PublicKey(PublicKeyAttribute {
+                    type_,
+                    purpose,
+                    encoding,
+                    value: encoding.encode(value),
+                })
+            }
+            (SERVICE_ATTRIBUTE, type_) if !type_.is_empty() => {
+                let endpoint = String::from_utf8_lossy(value).to_string();
+                let service_endpoint = serde_json::from_str::<ServiceEndpoint>(&endpoint)
+                    .ok()
+                    .filter(|endpoint| !matches!(endpoint, ServiceEndpoint::String(_)))
+                    .unwrap_or(ServiceEndpoint::String(endpoint));
+                DidDocAttribute::Service(ServiceAttribute {
+                    type_: type_.to_string(),
+                    service_endpoint,
+                })
+            }
+            _ => return None,
+        };
+        if parts.next().is_some() {
+            return None;
+        }
+
+        trace!(
+            "DidDocAttribute restored from name: {}. Result: {:?}",
+            name,
+            attribute
+        );
+
+        Some(attribute)
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::contracts::did::types::did_doc::ServiceEndpointObject;
+
+    pub const ED25519_KEY: &str = "FbQWLPRhTH95MCkQUeFYdiSoQt8zMwetqfWoxqPgaq7x";
+    pub const SERVICE_ENDPOINT: &str = "https://example.com/messaging";
+
+    pub fn public_key_attribute() -> DidDocAttribute {
+        DidDocAttribute::PublicKey(PublicKeyAttribute {
+            type_: PublicKeyType::Ed25519,
+            purpose: PublicKeyPurpose::VeriKey,
+            encoding: PublicKeyEncoding::Base58,
+            value: ED25519_KEY.to_string(),
+        })
+    }
+
+    pub fn service_attribute() -> DidDocAttribute {
+        DidDocAttribute::Service(ServiceAttribute {
+            type_: "DIDCommMessaging".to_string(),
+            service_endpoint: ServiceEndpoint::String(SERVICE_ENDPOINT.to_string()),
+        })
+    }
+
+    #[test]
+    fn public_key_attribute_test() {
+        let attribute = public_key_attribute();
+        assert_eq!("did/pub/Ed25519/veriKey/base58", attribute.name());
+
+        let value = attribute.value().unwrap();
+        assert_eq!(32, value.len());
+        assert_eq!(
+            Some(attribute.clone()),
+            DidDocAttribute::from_raw(&attribute.name(), &value)
+        );
+
+        let hex_attribute = DidDocAttribute::from_raw("did/pub/Ed25519/veriKey", &value).unwrap();
+        assert_eq!(
+            DidDocAttribute::PublicKey(PublicKeyAttribute {
+                type_: PublicKeyType::Ed25519,
+                purpose: PublicKeyPurpose::VeriKey,
+                encoding: PublicKeyEncoding::Hex,
+                value: hex::encode(&value),
+            }),
+            hex_attribute
+        );
+    }
+
+    #[test]
+    fn service_attribute_test() {
+        let attribute = service_attribute();
+        assert_eq!("did/svc/DIDCommMessaging", attribute.name());
+        assert_eq!(SERVICE_ENDPOINT.as_bytes(), attribute.value().unwrap());
+        assert_eq!(
+            Some(attribute),
+            DidDocAttribute::from_raw("did/svc/DIDCommMessaging", SERVICE_ENDPOINT.as_bytes())
+        );
+
+        let endpoint = ServiceEndpoint::Object(ServiceEndpointObject {
+            uri: SERVICE_ENDPOINT.to_string(),
+            accept: vec!["didcomm/v2".to_string()],
+            routing_keys: vec![],
+        });
+        let attribute = DidDocAttribute::Service(ServiceAttribute {
+            type_: "DIDCommMessaging".to_string(),
+            service_endpoint: endpoint,
+        });
+        let value = attribute.value().unwrap();
+        assert_eq!(
+            Some(attribute),
+            DidDocAttribute::from_raw("did/svc/DIDCommMessaging", &value)
+        );
+    }
+
+    #[test]
+    fn unknown_attribute_test() {
+        assert_eq!(
+            None,
+            DidDocAttribute::from_raw("did/pub/RSA/veriKey/hex", &[1])
+        );
+        assert_eq!(
+            None,
+            DidDocAttribute::from_raw("did/pub/Ed25519/sign/hex", &[1])
+        );
+        assert_eq!(None, DidDocAttribute::from_raw("did/svc", &[1]));
+        assert_eq!(None, DidDocAttribute::from_raw("other/svc/Type", &[1]));
+    }
+}
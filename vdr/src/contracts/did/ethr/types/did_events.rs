@@ -0,0 +1,144 @@
+use log::trace;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{
+    contracts::did::ethr::types::bytes32_to_string, error::VdrError, types::ContractOutput, Address,
+};
+
+/// Data of EthereumExtDidRegistry.DIDOwnerChanged event
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct DidOwnerChangedEvent {
+    pub identity: Address,
+    pub owner: Address,
+    /// Number of the block containing the previous change of the identity
+    pub previous_change: u64,
+}
+
+/// Data of EthereumExtDidRegistry.DIDDelegateChanged event
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct DidDelegateChangedEvent {
+    pub identity: Address,
+    /// Delegate type (`veriKey`, `sigAuth` or any custom type)
+    pub delegate_type: String,
+    pub delegate: Address,
+    /// Timestamp the delegate is valid until (the time of revocation for revoked delegates)
+    pub valid_to: u64,
+    pub previous_change: u64,
+}
+
+/// Data of EthereumExtDidRegistry.DIDAttributeChanged event
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct DidAttributeChangedEvent {
+    pub identity: Address,
+    /// Attribute name (`did/pub/Ed25519/veriKey/base58`, `did/svc/DIDCommMessaging`)
+    pub name: String,
+    pub value: Vec<u8>,
+    /// Timestamp the attribute is valid until (the time of revocation for revoked attributes)
+    pub valid_to: u64,
+    pub previous_change: u64,
+}
+
+/// Change of did:ethr identity emitted by EthereumExtDidRegistry
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub enum DidEthrEvent {
+    OwnerChanged(DidOwnerChangedEvent),
+    DelegateChanged(DidDelegateChangedEvent),
+    AttributeChanged(DidAttributeChangedEvent),
+}
+
+impl DidEthrEvent {
+    pub fn identity(&self) -> &Address {
+        match self {
+            DidEthrEvent::OwnerChanged(event) => &event.identity,
+            DidEthrEvent::DelegateChanged(event) => &event.identity,
+            DidEthrEvent::AttributeChanged(event) => &event.identity,
+        }
+    }
+
+    pub fn previous_change(&self) -> u64 {
+        match self {
+            DidEthrEvent::OwnerChanged(event) => event.previous_change,
+            DidEthrEvent::DelegateChanged(event) => event.previous_change,
+            DidEthrEvent::AttributeChanged(event) => event.previous_change,
+        }
+    }
+}
+
+impl TryFrom<ContractOutput> for DidOwnerChangedEvent {
+    type Error = VdrError;
+
+    fn try_from(value: ContractOutput) -> Result<Self, Self::Error> {
+        trace!(
+            "DidOwnerChangedEvent convert from ContractOutput: {:?} has started",
+            value
+        );
+
+        let event = DidOwnerChangedEvent {
+            identity: value.get_address(0)?,
+            owner: value.get_address(1)?,
+            previous_change: value.get_u64(2)?,
+        };
+
+        trace!(
+            "DidOwnerChangedEvent convert from ContractOutput: {:?} has finished. Result: {:?}",
+            value,
+            event
+        );
+
+        Ok(event)
+    }
+}
+
+impl TryFrom<ContractOutput> for DidDelegateChangedEvent {
+    type Error = VdrError;
+
+    fn try_from(value: ContractOutput) -> Result<Self, Self::Error> {
+        trace!(
+            "DidDelegateChangedEvent convert from ContractOutput: {:?} has started",
+            value
+        );
+
+        let event = DidDelegateChangedEvent {
+            identity: value.get_address(0)?,
+            delegate_type: bytes32_to_string(&value.get_bytes(1)?),
+            delegate: value.get_address(2)?,
+            valid_to: value.get_u64(3)?,
+            previous_change: value.get_u64(4)?,
+        };
+
+        trace!(
+            "DidDelegateChangedEvent convert from ContractOutput: {:?} has finished. Result: {:?}",
+            value,
+            event
+        );
+
+        Ok(event)
+    }
+}
+
+impl TryFrom<ContractOutput> for DidAttributeChangedEvent {
+    type Error = VdrError;
+
+    fn try_from(value: ContractOutput) -> Result<Self, Self::Error> {
+        trace!(
+            "DidAttributeChangedEvent convert from ContractOutput: {:?} has started",
+            value
+        );
+
+        let event = DidAttributeChangedEvent {
+            identity: value.get_address(0)?,
+            name: bytes32_to_string(&value.get_bytes(1)?),
+            value: value.get_bytes(2)?,
+            valid_to: value.get_u64(3)?,
+            previous_change: value.get_u64(4)?,
+        };
+
+        trace!(
+            "DidAttributeChangedEvent convert from ContractOutput: {:?} has finished. Result: {:?}",
+            value,
+            event
+        );
+
+        Ok(event)
+    }
+}
@@ -0,0 +1,148 @@
+pub mod delegate_type;
+pub mod did_doc_attribute;
+pub mod did_events;
+
+use k256::{elliptic_curve::sec1::ToEncodedPoint, PublicKey};
+use log::warn;
+use sha3::{Digest, Keccak256};
+
+use crate::{
+    contracts::did::types::did::DID,
+    error::{VdrError, VdrResult},
+    types::{Address, ContractParam},
+};
+
+pub const ETHR_METHOD: &str = "ethr";
+const BYTES32_LENGTH: usize = 32;
+const ADDRESS_LENGTH: usize = 20;
+
+/// Identifier of did:ethr DID: Ethereum address, optionally with the public key it is derived from
+#[derive(Debug, Clone, PartialEq)]
+pub struct DidEthrIdentity {
+    pub address: Address,
+    /// Compressed secp256k1 public key for DIDs using public key as identifier
+    pub public_key: Option<Vec<u8>>,
+}
+
+impl TryFrom<&DID> for DidEthrIdentity {
+    type Error = VdrError;
+
+    fn try_from(did: &DID) -> Result<Self, Self::Error> {
+        let invalid_did = |reason: &str| {
+            let vdr_error = VdrError::DidIncorrect(format!("{}: {}", did, reason));
+
+            warn!("Error: {} during did:ethr identity parsing", vdr_error);
+
+            vdr_error
+        };
+
+        if did.method() != ETHR_METHOD {
+            return Err(invalid_did("not a did:ethr DID"));
+        }
+        let id = did.id().trim_start_matches("0x");
+        let bytes = hex::decode(id).map_err(|_| invalid_did("identifier is not hex encoded"))?;
+        if bytes.len() == ADDRESS_LENGTH {
+            return Ok(DidEthrIdentity {
+                address: Address::from(id.to_lowercase().as_str()),
+                public_key: None,
+            });
+        }
+
+        // address is the last 20 bytes of the uncompressed public key hash
+        let public_key = PublicKey::from_sec1_bytes(&bytes)
+            .map_err(|_| invalid_did("identifier is not a secp256k1 public key"))?;
+        let hash = Keccak256::digest(&public_key.to_encoded_point(false).as_bytes()[1..]);
+        Ok(DidEthrIdentity {
+            address: Address::from(hex::encode(&hash[12..]).as_str()),
+            public_key: Some(bytes),
+        })
+    }
+}
+
+/// Encode short string into `bytes32` contract parameter padded with zeros on the right
+pub(crate) fn bytes32_param(value: &str) -> VdrResult<ContractParam> {
+    if value.len() > BYTES32_LENGTH {
+        return Err(VdrError::CommonInvalidData(format!(
+            "Value must fit into {} bytes: {}",
+            BYTES32_LENGTH, value
+        )));
+    }
+    let mut bytes = value.as_bytes().to_vec();
+    bytes.resize(BYTES32_LENGTH, 0);
+    Ok(ContractParam::FixedBytes(bytes))
+}
+
+/// Decode short string stored as `bytes32` value padded with zeros on the right
+pub(crate) fn bytes32_to_string(bytes: &[u8]) -> String {
+    let end = bytes
+        .iter()
+        .rposition(|byte| *byte != 0)
+        .map_or(0, |position| position + 1);
+    String::from_utf8_lossy(&bytes[..end]).to_string()
+}
+
+/// Mixed-case checksum encoding of the account address: https://eips.ethereum.org/EIPS/eip-55
+pub(crate) fn checksum_address(address: &str) -> String {
+    let address = address.trim_start_matches("0x").to_lowercase();
+    let hash = hex::encode(Keccak256::digest(address.as_bytes()));
+    let checksummed: String = address
+        .chars()
+        .zip(hash.chars())
+        .map(|(char, hash_char)| match hash_char.to_digit(16) {
+            Some(digit) if digit >= 8 => char.to_ascii_uppercase(),
+            _ => char,
+        })
+        .collect();
+    format!("0x{}", checksummed)
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn did_ethr_identity_test() {
+        let did = DID::from_str("did:ethr:0xF0e2Db6C8dC6c681bB5D6aD121A107f300e9B2b5").unwrap();
+        assert_eq!(
+            DidEthrIdentity {
+                address: Address::from("0xf0e2db6c8dc6c681bb5d6ad121a107f300e9b2b5"),
+                public_key: None,
+            },
+            DidEthrIdentity::try_from(&did).unwrap()
+        );
+
+        // public key of the secp256k1 private key `0x...01`
+        let did = DID::from_str(
+            "did:ethr:0x539:0x0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+        )
+        .unwrap();
+        let identity = DidEthrIdentity::try_from(&did).unwrap();
+        assert_eq!(
+            Address::from("0x7e5f4552091a69125d5dfcb7b8c2659029395bdf"),
+            identity.address
+        );
+        assert!(identity.public_key.is_some());
+
+        let did = DID::from_str("did:indy2:testnet:3LpjszkgTmE3qThge25FZw").unwrap();
+        assert!(DidEthrIdentity::try_from(&did).is_err());
+    }
+
+    #[test]
+    fn checksum_address_test() {
+        assert_eq!(
+            "0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed",
+            checksum_address("0x5aaeb6053f3e94c9b9a09f33669435e7ef1beaed")
+        );
+    }
+
+    #[test]
+    fn bytes32_test() {
+        let ContractParam::FixedBytes(bytes) = bytes32_param("veriKey").unwrap() else {
+            panic!("bytes32 param expected")
+        };
+        assert_eq!(32, bytes.len());
+        assert_eq!("veriKey", bytes32_to_string(&bytes));
+        assert!(bytes32_param(&"a".repeat(33)).is_err());
+    }
+}
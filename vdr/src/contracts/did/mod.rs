@@ -1,5 +1,7 @@
 pub mod did_registry;
+pub mod did_resolver;
+pub mod ethr;
 pub mod types;
+pub mod universal_did_resolver;
 
-pub use did_registry::*;
-pub use types::{did_doc::*, did_doc_builder::*};
+pub use types::did_doc::*;
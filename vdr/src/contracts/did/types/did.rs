@@ -1,26 +1,357 @@
-use crate::ContractParam;
+use crate::{
+    error::{VdrError, VdrResult},
+    types::{ContractOutput, ContractParam},
+};
+use log::{trace, warn};
 use serde_derive::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{fmt, str::FromStr};
 
-#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+/// Decentralized identifier validated against DID Core syntax and the rules of known methods
+///     (`indy2`, `indy`, `sov`, `ethr`): `did:<method>:[<namespace>:]<id>`
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
 pub struct DID(String);
 
 impl DID {
     pub const DID_PREFIX: &'static str = "did";
 
-    pub fn build(method: &str, network: &str, id: &str) -> DID {
-        DID::from(format!("{}:{}:{}:{}", Self::DID_PREFIX, method, network, id).as_str())
+    pub const VERKEY_LENGTH: usize = 32;
+    const ID_LENGTH: usize = 16;
+    const ETHR_ADDRESS_LENGTH: usize = 20;
+    const ETHR_PUBLIC_KEY_LENGTH: usize = 33;
+
+    pub fn build(method: &str, network: &str, id: &str) -> VdrResult<DID> {
+        DID::from_str(&format!(
+            "{}:{}:{}:{}",
+            Self::DID_PREFIX,
+            method,
+            network,
+            id
+        ))
+    }
+
+    /// Build DID with identifier derived from the initial Ed25519 verkey:
+    ///     `Base58(Truncate_msb(16(SHA256(verkey))))`
+    ///
+    /// # Params
+    /// - `method` DID method name (`indy2`)
+    /// - `network` network namespace (`testnet`)
+    /// - `verkey` base58 encoded Ed25519 verkey
+    ///
+    /// # Returns
+    /// DID
+    pub fn from_verkey(method: &str, network: &str, verkey: &str) -> VdrResult<DID> {
+        let verkey = bs58::decode(verkey).into_vec().map_err(|err| {
+            VdrError::CommonInvalidData(format!("Unable to decode verkey: {}", err))
+        })?;
+        DID::build(method, network, &DID::indy_id(&verkey)?)
+    }
+
+    /// Check that DID identifier is derived from the verkey (see [DID::from_verkey])
+    ///
+    /// # Params
+    /// - `verkey` raw Ed25519 verkey bytes
+    pub fn validate_verkey(&self, verkey: &[u8]) -> VdrResult<()> {
+        let expected = DID::indy_id(verkey)?;
+        if self.id() != expected {
+            let vdr_error = VdrError::DidInvalidIdentifier(format!(
+                "{} is not derived from its verkey, expected identifier: {}",
+                self, expected
+            ));
+
+            warn!("Error: {} during DID identifier validation", vdr_error);
+
+            return Err(vdr_error);
+        }
+        Ok(())
+    }
+
+    /// DID method name (`indy2` for `did:indy2:testnet:3LpjszkgTmE3qThge25FZw`)
+    pub fn method(&self) -> &str {
+        self.0.split(':').nth(1).unwrap_or_default()
+    }
+
+    /// Everything after the method name (`testnet:3LpjszkgTmE3qThge25FZw`)
+    pub fn method_specific_id(&self) -> &str {
+        self.0.splitn(3, ':').nth(2).unwrap_or_default()
+    }
+
+    /// Network namespace if present (`testnet`)
+    pub fn namespace(&self) -> Option<&str> {
+        self.method_specific_id()
+            .rsplit_once(':')
+            .map(|(namespace, _)| namespace)
+    }
+
+    /// Identifier within the namespace: last segment of the DID (`3LpjszkgTmE3qThge25FZw`)
+    pub fn id(&self) -> &str {
+        self.0.rsplit(':').next().unwrap_or_default()
+    }
+
+    fn indy_id(verkey: &[u8]) -> VdrResult<String> {
+        if verkey.len() != Self::VERKEY_LENGTH {
+            return Err(VdrError::CommonInvalidData(format!(
+                "Verkey must be {} bytes long, got {}",
+                Self::VERKEY_LENGTH,
+                verkey.len()
+            )));
+        }
+        let hash = Sha256::digest(verkey);
+        Ok(bs58::encode(&hash[..Self::ID_LENGTH]).into_string())
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        let mut parts = self.0.splitn(3, ':');
+        if parts.next() != Some(Self::DID_PREFIX) {
+            return Err(format!("must start with '{}:'", Self::DID_PREFIX));
+        }
+        let method = parts.next().unwrap_or_default();
+        if method.is_empty()
+            || !method
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+        {
+            return Err("method name must consist of lowercase letters and digits".to_string());
+        }
+        let method_specific_id = parts.next().unwrap_or_default();
+        if self.id().is_empty() || !is_id_chars(method_specific_id) {
+            return Err("invalid method specific identifier".to_string());
+        }
+
+        match method {
+            "indy2" | "indy" => self.validate_indy(true),
+            "sov" => self.validate_indy(false),
+            "ethr" => self.validate_ethr(),
+            _ => Ok(()),
+        }
+    }
+
+    fn validate_indy(&self, namespace_required: bool) -> Result<(), String> {
+        match self.namespace() {
+            None if namespace_required => return Err("namespace is missing".to_string()),
+            Some(namespace)
+                if !namespace.split(':').all(|segment| {
+                    !segment.is_empty()
+                        && segment.chars().all(|c| {
+                            c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_'
+                        })
+                }) =>
+            {
+                return Err(format!("invalid namespace: {}", namespace))
+            }
+            _ => {}
+        }
+        match bs58::decode(self.id()).into_vec() {
+            Ok(id) if id.len() == Self::ID_LENGTH => Ok(()),
+            _ => Err(format!(
+                "identifier must be base58 encoded {} bytes",
+                Self::ID_LENGTH
+            )),
+        }
+    }
+
+    fn validate_ethr(&self) -> Result<(), String> {
+        if let Some(namespace) = self.namespace() {
+            let valid = match namespace.strip_prefix("0x") {
+                Some(chain_id) => !chain_id.is_empty() && is_hex(chain_id),
+                None => namespace
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-'),
+            };
+            if !valid || namespace.contains(':') {
+                return Err(format!("invalid network: {}", namespace));
+            }
+        }
+        match self.id().strip_prefix("0x") {
+            Some(id)
+                if is_hex(id)
+                    && (id.len() == Self::ETHR_ADDRESS_LENGTH * 2
+                        || id.len() == Self::ETHR_PUBLIC_KEY_LENGTH * 2) =>
+            {
+                Ok(())
+            }
+            _ => Err("identifier must be hex encoded address or public key".to_string()),
+        }
     }
 }
 
-impl From<&DID> for ContractParam {
-    fn from(id: &DID) -> Self {
-        ContractParam::String(id.to_string())
+/// DID URL: DID followed by optional path, query and fragment
+///     (`did:indy2:testnet:3LpjszkgTmE3qThge25FZw/path?versionId=1#KEY-1`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DidUrl {
+    pub did: DID,
+    /// Path including leading `/`
+    pub path: Option<String>,
+    /// Query without leading `?`
+    pub query: Option<String>,
+    /// Fragment without leading `#`
+    pub fragment: Option<String>,
+}
+
+impl DidUrl {
+    /// Get value of query parameter
+    ///
+    /// # Params
+    /// - `name` parameter name
+    ///
+    /// # Returns
+    /// parameter value or `None` if the query does not contain it
+    pub fn query_param(&self, name: &str) -> Option<&str> {
+        self.query.as_deref()?.split('&').find_map(|param| {
+            let (key, value) = param.split_once('=').unwrap_or((param, ""));
+            (key == name).then_some(value)
+        })
+    }
+}
+
+impl FromStr for DID {
+    type Err = VdrError;
+
+    fn from_str(did: &str) -> Result<Self, Self::Err> {
+        let did_ = DID(did.to_string());
+        did_.validate().map_err(|reason| {
+            let vdr_error = VdrError::DidInvalidIdentifier(format!("{}: {}", did, reason));
+
+            warn!("Error: {} during DID parsing", vdr_error);
+
+            vdr_error
+        })?;
+
+        trace!("Parsed DID: {:?}", did_);
+
+        Ok(did_)
     }
 }
 
-impl From<&str> for DID {
-    fn from(did: &str) -> Self {
-        DID(did.to_string())
+impl TryFrom<&str> for DID {
+    type Error = VdrError;
+
+    fn try_from(did: &str) -> Result<Self, Self::Error> {
+        DID::from_str(did)
+    }
+}
+
+impl TryFrom<String> for DID {
+    type Error = VdrError;
+
+    fn try_from(did: String) -> Result<Self, Self::Error> {
+        DID::from_str(&did)
+    }
+}
+
+impl From<DID> for String {
+    fn from(did: DID) -> Self {
+        did.0
+    }
+}
+
+impl FromStr for DidUrl {
+    type Err = VdrError;
+
+    fn from_str(did_url: &str) -> Result<Self, Self::Err> {
+        let (rest, fragment) = split(did_url, '#');
+        let (rest, query) = split(rest, '?');
+        let (did, path) = match rest.find('/') {
+            Some(position) => (&rest[..position], Some(rest[position..].to_string())),
+            None => (rest, None),
+        };
+
+        let invalid = [&path, &query, &fragment].iter().any(|part| {
+            part.as_deref()
+                .map(|part| !is_url_chars(part))
+                .unwrap_or(false)
+        });
+        if invalid {
+            let vdr_error = VdrError::DidInvalidIdentifier(format!("{}: invalid DID URL", did_url));
+
+            warn!("Error: {} during DID URL parsing", vdr_error);
+
+            return Err(vdr_error);
+        }
+
+        let did_url = DidUrl {
+            did: DID::from_str(did)?,
+            path,
+            query,
+            fragment,
+        };
+
+        trace!("Parsed DID URL: {:?}", did_url);
+
+        Ok(did_url)
+    }
+}
+
+impl TryFrom<&str> for DidUrl {
+    type Error = VdrError;
+
+    fn try_from(did_url: &str) -> Result<Self, Self::Error> {
+        DidUrl::from_str(did_url)
+    }
+}
+
+impl fmt::Display for DidUrl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.did)?;
+        if let Some(path) = &self.path {
+            write!(f, "{}", path)?;
+        }
+        if let Some(query) = &self.query {
+            write!(f, "?{}", query)?;
+        }
+        if let Some(fragment) = &self.fragment {
+            write!(f, "#{}", fragment)?;
+        }
+        Ok(())
+    }
+}
+
+fn split(value: &str, separator: char) -> (&str, Option<String>) {
+    match value.split_once(separator) {
+        Some((value, rest)) => (value, Some(rest.to_string())),
+        None => (value, None),
+    }
+}
+
+fn is_hex(value: &str) -> bool {
+    value.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// `idchar = ALPHA / DIGIT / "." / "-" / "_" / pct-encoded` separated by `:`
+fn is_id_chars(value: &str) -> bool {
+    is_percent_encoded(value, |c| {
+        c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_' | ':')
+    })
+}
+
+/// RFC 3986 path / query / fragment characters
+fn is_url_chars(value: &str) -> bool {
+    is_percent_encoded(value, |c| {
+        c.is_ascii_alphanumeric() || "-._~!$&'()*+,;=:@/?".contains(c)
+    })
+}
+
+fn is_percent_encoded(value: &str, allowed: impl Fn(char) -> bool) -> bool {
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        let valid = match c {
+            '%' => {
+                chars.next().is_some_and(|c| c.is_ascii_hexdigit())
+                    && chars.next().is_some_and(|c| c.is_ascii_hexdigit())
+            }
+            c => allowed(c),
+        };
+        if !valid {
+            return false;
+        }
+    }
+    true
+}
+
+impl From<&DID> for ContractParam {
+    fn from(id: &DID) -> Self {
+        ContractParam::String(id.to_string())
     }
 }
 
@@ -30,8 +361,142 @@ impl AsRef<str> for DID {
     }
 }
 
-impl ToString for DID {
-    fn to_string(&self) -> String {
-        self.0.to_string()
+impl fmt::Display for DID {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl TryFrom<ContractOutput> for DID {
+    type Error = VdrError;
+
+    fn try_from(value: ContractOutput) -> Result<Self, Self::Error> {
+        trace!("DID convert from ContractOutput: {:?} has started", value);
+
+        let did = DID::from_str(&value.get_string(0)?)?;
+
+        trace!(
+            "DID convert from ContractOutput: {:?} has finished. Result: {:?}",
+            value,
+            did
+        );
+
+        Ok(did)
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+
+    pub const VERKEY: &str = "AKJP3f7BD6W4iWEQ9jwndVTCBq8ua2Utt8EEjJ6Vxsf";
+    pub const VERKEY_DID: &str = "did:indy2:testnet:Rv8zTUDp4kJWKXQmSYLTMa";
+
+    #[test]
+    fn did_from_verkey_test() {
+        let did = DID::from_verkey("indy2", "testnet", VERKEY).unwrap();
+        assert_eq!(DID::from_str(VERKEY_DID).unwrap(), did);
+
+        let verkey = bs58::decode(VERKEY).into_vec().unwrap();
+        did.validate_verkey(&verkey).unwrap();
+        let err = DID::from_str("did:indy2:testnet:3LpjszkgTmE3qThge25FZw")
+            .unwrap()
+            .validate_verkey(&verkey)
+            .unwrap_err();
+        assert!(matches!(err, VdrError::DidInvalidIdentifier(_)));
+    }
+
+    #[test]
+    fn did_from_invalid_verkey_test() {
+        DID::from_verkey("indy2", "testnet", "0OIl").unwrap_err();
+        DID::from_verkey("indy2", "testnet", "3LpjszkgTmE3qThge25FZw").unwrap_err();
+    }
+
+    #[test]
+    fn parse_did_test() {
+        let did = DID::from_str("did:indy2:testnet:3LpjszkgTmE3qThge25FZw").unwrap();
+        assert_eq!("indy2", did.method());
+        assert_eq!(Some("testnet"), did.namespace());
+        assert_eq!("testnet:3LpjszkgTmE3qThge25FZw", did.method_specific_id());
+        assert_eq!("3LpjszkgTmE3qThge25FZw", did.id());
+
+        let did = DID::from_str("did:indy:sovrin:staging:3LpjszkgTmE3qThge25FZw").unwrap();
+        assert_eq!(Some("sovrin:staging"), did.namespace());
+
+        let did = DID::from_str("did:sov:3LpjszkgTmE3qThge25FZw").unwrap();
+        assert_eq!(None, did.namespace());
+
+        let did = DID::from_str("did:ethr:0x5:0xb9059400dcd05158ffd8ca092937989dd27b3bdc").unwrap();
+        assert_eq!("ethr", did.method());
+        assert_eq!(Some("0x5"), did.namespace());
+        DID::from_str(
+            "did:ethr:0x03fdd57adec3d438ea237fe46b33ee1e016eda6b585c3e27ea66686c2ea5358479",
+        )
+        .unwrap();
+
+        let did = DID::from_str("did:web:example.com%3A8443:user:alice").unwrap();
+        assert_eq!("web", did.method());
+    }
+
+    #[test]
+    fn parse_invalid_did_test() {
+        let invalid = [
+            "",
+            "3LpjszkgTmE3qThge25FZw",
+            "did:indy2",
+            "did:INDY2:testnet:3LpjszkgTmE3qThge25FZw",
+            "did:indy2:3LpjszkgTmE3qThge25FZw",
+            "did:indy2:testnet:",
+            "did:indy2:testnet:3LpjszkgTmE3qThge25FZ",
+            "did:indy2:testnet:3Lpjszkg TmE3qThge25FZw",
+            "did:indy2:Test Net:3LpjszkgTmE3qThge25FZw",
+            "did:sov:3LpjszkgTmE3qThge25FZ0",
+            "did:ethr:0xb9059400dcd05158ffd8ca092937989dd27b3bd",
+            "did:ethr:main net:0xb9059400dcd05158ffd8ca092937989dd27b3bdc",
+            "did:web:example.com%3",
+        ];
+        for did in invalid {
+            let err = DID::from_str(did).unwrap_err();
+            assert!(matches!(err, VdrError::DidInvalidIdentifier(_)), "{}", did);
+        }
+    }
+
+    #[test]
+    fn deserialize_did_test() {
+        let did: DID = serde_json::from_str("\"did:sov:3LpjszkgTmE3qThge25FZw\"").unwrap();
+        assert_eq!(
+            "\"did:sov:3LpjszkgTmE3qThge25FZw\"",
+            serde_json::to_string(&did).unwrap()
+        );
+        serde_json::from_str::<DID>("\"did:sov:123\"").unwrap_err();
+    }
+
+    #[test]
+    fn parse_did_url_test() {
+        let did_url = DidUrl::from_str(
+            "did:indy2:testnet:3LpjszkgTmE3qThge25FZw/path/to?versionId=1&service=agent#KEY-1",
+        )
+        .unwrap();
+        assert_eq!(
+            DID::from_str("did:indy2:testnet:3LpjszkgTmE3qThge25FZw").unwrap(),
+            did_url.did
+        );
+        assert_eq!(Some("/path/to"), did_url.path.as_deref());
+        assert_eq!(Some("1"), did_url.query_param("versionId"));
+        assert_eq!(Some("agent"), did_url.query_param("service"));
+        assert_eq!(None, did_url.query_param("version"));
+        assert_eq!(Some("KEY-1"), did_url.fragment.as_deref());
+        assert_eq!(
+            "did:indy2:testnet:3LpjszkgTmE3qThge25FZw/path/to?versionId=1&service=agent#KEY-1",
+            did_url.to_string()
+        );
+
+        let did_url = DidUrl::from_str("did:sov:3LpjszkgTmE3qThge25FZw#KEY-1").unwrap();
+        assert_eq!(None, did_url.path);
+        assert_eq!(None, did_url.query);
+        assert_eq!(Some("KEY-1"), did_url.fragment.as_deref());
+
+        DidUrl::from_str("did:sov:3LpjszkgTmE3qThge25FZw#KEY 1").unwrap_err();
+        DidUrl::from_str("did:sov:123#KEY-1").unwrap_err();
     }
 }
@@ -1,11 +1,12 @@
 use crate::{
-    error::VdrError,
+    error::{VdrError, VdrResult},
+    signer::ed25519::MULTIBASE_BASE58_BTC,
     types::{ContractOutput, ContractParam},
-    Address,
+    Address, Ed25519PublicKey,
 };
 
 use crate::contracts::did::types::did::DID;
-use log::trace;
+use log::{trace, warn};
 use serde_derive::{Deserialize, Serialize};
 use serde_json::{json, Value};
 
@@ -35,6 +36,35 @@ pub struct DidDocument {
     pub also_known_as: Option<Vec<String>>,
 }
 
+impl DidDocument {
+    /// Check that DID Document id is derived from the verkey of its first Ed25519 verification method
+    ///     (see [DID::from_verkey])
+    pub fn validate_id(&self) -> VdrResult<()> {
+        let verkey = self
+            .verification_method
+            .iter()
+            .find(|method| {
+                matches!(
+                    method.type_,
+                    VerificationKeyType::Ed25519VerificationKey2018
+                        | VerificationKeyType::Ed25519VerificationKey2020
+                )
+            })
+            .and_then(VerificationMethod::ed25519_verkey)
+            .ok_or_else(|| {
+                let vdr_error = VdrError::DidInvalidIdentifier(format!(
+                    "{} does not contain Ed25519 verification method",
+                    self.id
+                ));
+
+                warn!("Error: {} during DID Document id validation", vdr_error);
+
+                vdr_error
+            })?;
+        self.id.validate_verkey(verkey.as_bytes())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct DidMetadata {
     pub owner: Address,
@@ -55,6 +85,31 @@ pub struct VerificationMethod {
     pub public_key_multibase: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub public_key_jwk: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_key_hex: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_key_base58: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_key_base64: Option<String>,
+    /// CAIP-10 account id (`eip155:1337:0xf0e2db6c8dc6c681bb5d6ad121a107f300e9b2b5`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blockchain_account_id: Option<String>,
+}
+
+impl VerificationMethod {
+    /// Ed25519 key published as `publicKeyBase58` or as base58btc multibase string with optional
+    /// multicodec prefix (`publicKeyMultibase`)
+    fn ed25519_verkey(&self) -> Option<Ed25519PublicKey> {
+        if let Some(key) = self.public_key_base58.as_deref() {
+            return Ed25519PublicKey::from_base58(key).ok();
+        }
+        let key = self.public_key_multibase.as_deref()?;
+        // Ed25519VerificationKey2018 keys are published without multicodec prefix
+        Ed25519PublicKey::from_multibase(key).ok().or_else(|| {
+            key.strip_prefix(MULTIBASE_BASE58_BTC)
+                .and_then(|key| Ed25519PublicKey::from_base58(key).ok())
+        })
+    }
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
@@ -66,6 +121,7 @@ pub enum VerificationKeyType {
     X25519KeyAgreementKey2020,
     JsonWebKey2020,
     EcdsaSecp256k1VerificationKey2019,
+    EcdsaSecp256k1RecoveryMethod2020,
 }
 
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
@@ -92,6 +148,17 @@ pub enum ServiceEndpoint {
     Set(Vec<ServiceEndpoint>),
 }
 
+impl ServiceEndpoint {
+    /// URI of the endpoint: the string itself, `uri` of the object or the first URI of the set
+    pub fn uri(&self) -> Option<&str> {
+        match self {
+            ServiceEndpoint::String(uri) => Some(uri),
+            ServiceEndpoint::Object(object) => Some(&object.uri),
+            ServiceEndpoint::Set(endpoints) => endpoints.iter().find_map(ServiceEndpoint::uri),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ServiceEndpointObject {
@@ -231,13 +298,14 @@ pub mod test {
     pub const SERVICE_TYPE: &str = "DIDCommService";
     pub const KEY_1: &str = "KEY-1";
 
-    pub fn verification_method(id: &str) -> VerificationMethod {
+    pub fn verification_method(id: &str, key: &str) -> VerificationMethod {
         VerificationMethod {
             id: format!("{}#{}", id, KEY_1),
             type_: VerificationKeyType::Ed25519VerificationKey2018,
             controller: id.to_string(),
-            public_key_multibase: Some(MULTIBASE_KEY.to_string()),
+            public_key_multibase: Some(key.to_string()),
             public_key_jwk: None,
+            ..VerificationMethod::default()
         }
     }
 
@@ -253,20 +321,22 @@ pub mod test {
         }
     }
 
-    pub fn new_id() -> String {
-        format!(
-            "did:indy2:testnet:{}",
-            &bs58::encode(rand_bytes()).into_string()
-        )
+    pub fn new_id_and_key() -> (String, String) {
+        let verkey = bs58::encode([rand_bytes(), rand_bytes()].concat()).into_string();
+        let id = DID::from_verkey("indy2", "testnet", &verkey).unwrap();
+        (id.to_string(), format!("z{}", verkey))
     }
 
     pub fn did_doc(id: Option<&str>) -> DidDocument {
-        let id = id.map(String::from).unwrap_or_else(new_id);
+        let (id, key) = match id {
+            Some(id) => (id.to_string(), MULTIBASE_KEY.to_string()),
+            None => new_id_and_key(),
+        };
         DidDocument {
             context: StringOrVector::Vector(vec![CONTEXT.to_string()]),
-            id: DID::from(id.as_str()),
+            id: DID::try_from(id.as_str()).unwrap(),
             controller: StringOrVector::Vector(vec![]),
-            verification_method: vec![verification_method(&id)],
+            verification_method: vec![verification_method(&id, &key)],
             authentication: vec![verification_relationship(&id)],
             assertion_method: vec![],
             capability_invocation: vec![],
@@ -281,6 +351,58 @@ pub mod test {
         ContractParam::String(json!(did_doc(Some(ISSUER_ID))).to_string())
     }
 
+    mod validate_id {
+        use super::*;
+        use crate::contracts::did::types::did::test::VERKEY_DID;
+
+        #[test]
+        fn validate_did_doc_id_test() {
+            did_doc(Some(VERKEY_DID)).validate_id().unwrap();
+            did_doc(None).validate_id().unwrap();
+
+            let mut did_doc = did_doc(Some(VERKEY_DID));
+            did_doc.verification_method[0].public_key_multibase =
+                Some(format!("z{}", bs58::encode([0xed, 0x01]).into_string()));
+            let err = did_doc.validate_id().unwrap_err();
+            assert!(matches!(err, VdrError::DidInvalidIdentifier(_)));
+        }
+
+        #[test]
+        fn validate_mismatching_did_doc_id_test() {
+            let err = did_doc(Some(ISSUER_ID)).validate_id().unwrap_err();
+            assert!(matches!(err, VdrError::DidInvalidIdentifier(_)));
+        }
+
+        #[test]
+        fn validate_did_doc_id_with_base58_key_test() {
+            let mut did_doc = did_doc(Some(VERKEY_DID));
+            did_doc.verification_method[0].public_key_base58 = Some(MULTIBASE_KEY[1..].to_string());
+            did_doc.verification_method[0].public_key_multibase = None;
+            did_doc.validate_id().unwrap();
+        }
+
+        #[test]
+        fn validate_did_doc_id_with_multicodec_key_test() {
+            let verkey = bs58::decode(&MULTIBASE_KEY[1..]).into_vec().unwrap();
+            let mut did_doc = did_doc(Some(VERKEY_DID));
+            did_doc.verification_method[0].type_ = VerificationKeyType::Ed25519VerificationKey2020;
+            did_doc.verification_method[0].public_key_multibase = Some(format!(
+                "z{}",
+                bs58::encode([&[0xed, 0x01], verkey.as_slice()].concat()).into_string()
+            ));
+            did_doc.validate_id().unwrap();
+        }
+
+        #[test]
+        fn validate_did_doc_id_with_not_multibase_key_test() {
+            let mut did_doc = did_doc(Some(VERKEY_DID));
+            did_doc.verification_method[0].public_key_multibase =
+                Some(MULTIBASE_KEY[1..].to_string());
+            let err = did_doc.validate_id().unwrap_err();
+            assert!(matches!(err, VdrError::DidInvalidIdentifier(_)));
+        }
+    }
+
     mod convert_into_contract_param {
         use super::*;
 
@@ -8,11 +8,14 @@ use crate::{
             Service, ServiceEndpoint, StringOrVector, VerificationMethod,
             VerificationMethodOrReference, CONTEXT,
         },
+        did_url_dereferencing::VerificationRelationship,
     },
     error::{VdrError, VdrResult},
     DidDocument, VerificationKeyType,
 };
 
+use crate::signer::VerificationKey;
+
 #[derive(Clone, Debug, Default, PartialEq)]
 pub struct DidDocumentBuilder {
     pub context: StringOrVector,
@@ -26,6 +29,8 @@ pub struct DidDocumentBuilder {
     pub key_agreement: Vec<VerificationMethodOrReference>,
     pub service: Vec<Service>,
     pub also_known_as: Option<Vec<String>>,
+    /// DID Document the builder was started from (see [DidDocumentBuilder::from_document])
+    original: Option<DidDocument>,
 }
 
 impl DidDocumentBuilder {
@@ -61,24 +66,79 @@ impl DidDocumentBuilder {
     }
 
     pub fn add_verification_method(
-        mut self,
+        self,
         type_: VerificationKeyType,
         controller: &DID,
         public_key_multibase: Option<String>,
         public_key_jwk: Option<Value>,
     ) -> DidDocumentBuilder {
-        let id = format!(
-            "{}:KEY-{}",
-            self.id.as_ref(),
-            self.verification_method.len() + 1
-        );
-        let verification_method = VerificationMethod {
-            id,
+        self.push_verification_method(VerificationMethod {
             type_,
             controller: controller.to_string(),
             public_key_multibase,
             public_key_jwk,
+            ..VerificationMethod::default()
+        })
+    }
+
+    /// Add verification method publishing the key
+    ///     `Ed25519VerificationKey2018` and `X25519KeyAgreementKey2019` methods get `publicKeyBase58`,
+    ///     `JsonWebKey2020` methods get `publicKeyJwk`, other types get `publicKeyMultibase`
+    ///
+    /// # Params
+    /// - `type_` verification method type supported by the key
+    /// - `controller` DID controlling the key
+    /// - `key` public key ([crate::Ed25519PublicKey] or [crate::X25519PublicKey])
+    ///
+    /// # Returns
+    /// builder with added verification method
+    pub fn add_verification_key(
+        self,
+        type_: VerificationKeyType,
+        controller: &DID,
+        key: &dyn VerificationKey,
+    ) -> VdrResult<DidDocumentBuilder> {
+        if !key.supports(&type_) {
+            let vdr_error = VdrError::CommonInvalidData(format!(
+                "Key can not be used for verification method of type: {:?}",
+                type_
+            ));
+
+            warn!(
+                "Error: {} during adding verification key to DidDocumentBuilder: {:?}",
+                vdr_error, self
+            );
+
+            return Err(vdr_error);
+        }
+
+        let mut verification_method = VerificationMethod {
+            type_: type_.clone(),
+            controller: controller.to_string(),
+            ..VerificationMethod::default()
+        };
+        match type_ {
+            VerificationKeyType::Ed25519VerificationKey2018
+            | VerificationKeyType::X25519KeyAgreementKey2019 => {
+                verification_method.public_key_base58 = Some(key.to_base58())
+            }
+            VerificationKeyType::JsonWebKey2020 => {
+                verification_method.public_key_jwk = Some(key.to_jwk())
+            }
+            _ => verification_method.public_key_multibase = Some(key.to_multibase()),
         };
+        Ok(self.push_verification_method(verification_method))
+    }
+
+    fn push_verification_method(
+        mut self,
+        mut verification_method: VerificationMethod,
+    ) -> DidDocumentBuilder {
+        verification_method.id = self.next_id(
+            &format!("{}#KEY-", self.id.as_ref()),
+            self.verification_method.len(),
+            |builder, id| builder.verification_method_position(id).is_some(),
+        );
         self.verification_method.push(verification_method.clone());
 
         trace!(
@@ -183,11 +243,30 @@ impl DidDocumentBuilder {
         Ok(self)
     }
 
-    pub fn add_service(mut self, type_: &str, endpoint: &str) -> DidDocumentBuilder {
+    pub fn add_service(self, type_: &str, endpoint: &str) -> DidDocumentBuilder {
+        self.add_service_endpoint(type_, ServiceEndpoint::String(endpoint.to_string()))
+    }
+
+    /// Add service with generated id (`#inline-<n>`)
+    ///
+    /// # Params
+    /// - `type_` service type
+    /// - `endpoint` service endpoint: URI, map or set of endpoints
+    ///
+    /// # Returns
+    /// builder with added service
+    pub fn add_service_endpoint(
+        mut self,
+        type_: &str,
+        endpoint: ServiceEndpoint,
+    ) -> DidDocumentBuilder {
+        let id = self.next_id("#inline-", self.service.len(), |builder, id| {
+            builder.service_position(id).is_some()
+        });
         let service = Service {
-            id: format!("#inline-{}", self.service.len() + 1),
+            id,
             type_: type_.to_string(),
-            service_endpoint: ServiceEndpoint::String(endpoint.to_string()),
+            service_endpoint: endpoint,
         };
         self.service.push(service.clone());
 
@@ -200,6 +279,334 @@ impl DidDocumentBuilder {
         self
     }
 
+    /// Start builder from an existing DID Document to edit it
+    ///
+    /// # Params
+    /// - `did_doc` DID Document to edit (usually resolved from the ledger)
+    ///
+    /// # Returns
+    /// builder containing the properties of the DID Document
+    pub fn from_document(did_doc: &DidDocument) -> DidDocumentBuilder {
+        let did_doc_builder = DidDocumentBuilder {
+            context: did_doc.context.clone(),
+            id: did_doc.id.clone(),
+            controller: did_doc.controller.clone(),
+            verification_method: did_doc.verification_method.clone(),
+            authentication: did_doc.authentication.clone(),
+            assertion_method: did_doc.assertion_method.clone(),
+            capability_invocation: did_doc.capability_invocation.clone(),
+            capability_delegation: did_doc.capability_delegation.clone(),
+            key_agreement: did_doc.key_agreement.clone(),
+            service: did_doc.service.clone(),
+            also_known_as: did_doc.also_known_as.clone(),
+            original: Some(did_doc.clone()),
+        };
+
+        trace!(
+            "Created DidDocumentBuilder: {:?} from DidDocument",
+            did_doc_builder
+        );
+
+        did_doc_builder
+    }
+
+    /// Add verification method having its own id
+    ///
+    /// # Params
+    /// - `verification_method` verification method (`publicKeyMultibase`, `publicKeyJwk` or other key format)
+    ///
+    /// # Returns
+    /// builder with added verification method or error if verification method with the same id exists
+    pub fn insert_verification_method(
+        mut self,
+        verification_method: VerificationMethod,
+    ) -> VdrResult<DidDocumentBuilder> {
+        if self
+            .verification_method_position(&verification_method.id)
+            .is_some()
+        {
+            return Err(self.invalid_data(
+                "Verification method already exists",
+                &verification_method.id,
+            ));
+        }
+        self.verification_method.push(verification_method.clone());
+
+        trace!(
+            "Inserted VerificationMethod: {:?} to DidDocumentBuilder: {:?}",
+            verification_method,
+            self
+        );
+
+        Ok(self)
+    }
+
+    /// Replace verification method having the same id
+    ///
+    /// # Params
+    /// - `verification_method` new version of the verification method
+    ///
+    /// # Returns
+    /// builder with replaced verification method or error if verification method does not exist
+    pub fn replace_verification_method(
+        mut self,
+        verification_method: VerificationMethod,
+    ) -> VdrResult<DidDocumentBuilder> {
+        let position = self
+            .verification_method_position(&verification_method.id)
+            .ok_or_else(|| {
+                self.invalid_data("Missing verification method", &verification_method.id)
+            })?;
+        self.verification_method[position] = verification_method.clone();
+
+        trace!(
+            "Replaced VerificationMethod: {:?} in DidDocumentBuilder: {:?}",
+            verification_method,
+            self
+        );
+
+        Ok(self)
+    }
+
+    /// Remove verification method and all verification relationships referencing it
+    ///
+    /// # Params
+    /// - `id` verification method id (absolute or relative DID URL)
+    ///
+    /// # Returns
+    /// builder without verification method or error if verification method does not exist
+    pub fn remove_verification_method(mut self, id: &str) -> VdrResult<DidDocumentBuilder> {
+        let id = self.absolute_id(id);
+        let position = self
+            .verification_method_position(&id)
+            .ok_or_else(|| self.invalid_data("Missing verification method", &id))?;
+        self.verification_method.remove(position);
+        for relationship in RELATIONSHIPS {
+            let did = self.id.clone();
+            self.relationship_mut(relationship).retain(|entry| {
+                let entry_id = match entry {
+                    VerificationMethodOrReference::String(reference) => reference,
+                    VerificationMethodOrReference::VerificationMethod(method) => &method.id,
+                };
+                absolute_id(&did, entry_id) != id
+            });
+        }
+
+        trace!(
+            "Removed VerificationMethod: {} from DidDocumentBuilder: {:?}",
+            id,
+            self
+        );
+
+        Ok(self)
+    }
+
+    /// Add reference to the verification method into the verification relationship
+    ///
+    /// # Params
+    /// - `relationship` verification relationship to add reference into
+    /// - `id` verification method id (absolute or relative DID URL)
+    ///
+    /// # Returns
+    /// builder with added reference or error if verification method does not exist
+    pub fn add_reference(
+        mut self,
+        relationship: VerificationRelationship,
+        id: &str,
+    ) -> VdrResult<DidDocumentBuilder> {
+        let position = self
+            .verification_method_position(id)
+            .ok_or_else(|| self.invalid_data("Missing verification method", id))?;
+        let reference =
+            VerificationMethodOrReference::String(self.verification_method[position].id.clone());
+        if !self.relationship_mut(relationship).contains(&reference) {
+            self.relationship_mut(relationship).push(reference.clone());
+        }
+
+        trace!(
+            "Added {:?} reference: {:?} to DidDocumentBuilder: {:?}",
+            relationship,
+            reference,
+            self
+        );
+
+        Ok(self)
+    }
+
+    /// Remove reference to the verification method from the verification relationship
+    ///
+    /// # Params
+    /// - `relationship` verification relationship to remove reference from
+    /// - `id` verification method id (absolute or relative DID URL)
+    ///
+    /// # Returns
+    /// builder without reference or error if verification relationship does not reference the method
+    pub fn remove_reference(
+        mut self,
+        relationship: VerificationRelationship,
+        id: &str,
+    ) -> VdrResult<DidDocumentBuilder> {
+        let id = self.absolute_id(id);
+        let did = self.id.clone();
+        let references = self.relationship_mut(relationship);
+        let count = references.len();
+        references.retain(|entry| {
+            !matches!(entry, VerificationMethodOrReference::String(reference) if absolute_id(&did, reference) == id)
+        });
+        if references.len() == count {
+            return Err(self.invalid_data("Missing verification relationship reference", &id));
+        }
+
+        trace!(
+            "Removed {:?} reference: {} from DidDocumentBuilder: {:?}",
+            relationship,
+            id,
+            self
+        );
+
+        Ok(self)
+    }
+
+    /// Add service having its own id
+    ///
+    /// # Params
+    /// - `service` service to add
+    ///
+    /// # Returns
+    /// builder with added service or error if service with the same id exists
+    pub fn insert_service(mut self, service: Service) -> VdrResult<DidDocumentBuilder> {
+        if self.service_position(&service.id).is_some() {
+            return Err(self.invalid_data("Service already exists", &service.id));
+        }
+        self.service.push(service.clone());
+
+        trace!(
+            "Inserted service: {:?} to DidDocumentBuilder: {:?}",
+            service,
+            self
+        );
+
+        Ok(self)
+    }
+
+    /// Replace service having the same id
+    ///
+    /// # Params
+    /// - `service` new version of the service
+    ///
+    /// # Returns
+    /// builder with replaced service or error if service does not exist
+    pub fn replace_service(mut self, service: Service) -> VdrResult<DidDocumentBuilder> {
+        let position = self
+            .service_position(&service.id)
+            .ok_or_else(|| self.invalid_data("Missing service", &service.id))?;
+        self.service[position] = service.clone();
+
+        trace!(
+            "Replaced service: {:?} in DidDocumentBuilder: {:?}",
+            service,
+            self
+        );
+
+        Ok(self)
+    }
+
+    /// Remove service
+    ///
+    /// # Params
+    /// - `id` service id (absolute or relative DID URL)
+    ///
+    /// # Returns
+    /// builder without service or error if service does not exist
+    pub fn remove_service(mut self, id: &str) -> VdrResult<DidDocumentBuilder> {
+        let position = self
+            .service_position(id)
+            .ok_or_else(|| self.invalid_data("Missing service", id))?;
+        let service = self.service.remove(position);
+
+        trace!(
+            "Removed service: {:?} from DidDocumentBuilder: {:?}",
+            service,
+            self
+        );
+
+        Ok(self)
+    }
+
+    pub fn add_also_known_as(mut self, uri: &str) -> DidDocumentBuilder {
+        let also_known_as = self.also_known_as.get_or_insert_with(Vec::new);
+        if !also_known_as.iter().any(|known_as| known_as == uri) {
+            also_known_as.push(uri.to_string());
+        }
+
+        trace!(
+            "Added alsoKnownAs: {} to DidDocumentBuilder: {:?}",
+            uri,
+            self
+        );
+
+        self
+    }
+
+    pub fn remove_also_known_as(mut self, uri: &str) -> DidDocumentBuilder {
+        if let Some(also_known_as) = self.also_known_as.as_mut() {
+            also_known_as.retain(|known_as| known_as != uri);
+        }
+
+        trace!(
+            "Removed alsoKnownAs: {} from DidDocumentBuilder: {:?}",
+            uri,
+            self
+        );
+
+        self
+    }
+
+    /// DID Document the builder was started from with [DidDocumentBuilder::from_document]
+    pub fn original(&self) -> Option<&DidDocument> {
+        self.original.as_ref()
+    }
+
+    /// Get DID Document properties changed since the builder was started from the existing DID Document
+    ///
+    /// # Returns
+    /// names of changed properties (`verificationMethod`, `service`, ...); all set properties for new DID Documents
+    pub fn changed_properties(&self) -> Vec<&'static str> {
+        let original = self.original.clone().unwrap_or_default();
+        [
+            ("@context", self.context != original.context),
+            ("id", self.id != original.id),
+            ("controller", self.controller != original.controller),
+            (
+                "verificationMethod",
+                self.verification_method != original.verification_method,
+            ),
+            (
+                "authentication",
+                self.authentication != original.authentication,
+            ),
+            (
+                "assertionMethod",
+                self.assertion_method != original.assertion_method,
+            ),
+            (
+                "capabilityInvocation",
+                self.capability_invocation != original.capability_invocation,
+            ),
+            (
+                "capabilityDelegation",
+                self.capability_delegation != original.capability_delegation,
+            ),
+            ("keyAgreement", self.key_agreement != original.key_agreement),
+            ("service", self.service != original.service),
+            ("alsoKnownAs", self.also_known_as != original.also_known_as),
+        ]
+        .into_iter()
+        .filter(|(_, changed)| *changed)
+        .map(|(name, _)| name)
+        .collect()
+    }
+
     pub fn build(self) -> DidDocument {
         let did_document = DidDocument {
             context: self.context,
@@ -242,4 +649,271 @@ impl DidDocumentBuilder {
 
         Ok(kid)
     }
+
+    fn relationship_mut(
+        &mut self,
+        relationship: VerificationRelationship,
+    ) -> &mut Vec<VerificationMethodOrReference> {
+        match relationship {
+            VerificationRelationship::Authentication => &mut self.authentication,
+            VerificationRelationship::AssertionMethod => &mut self.assertion_method,
+            VerificationRelationship::CapabilityInvocation => &mut self.capability_invocation,
+            VerificationRelationship::CapabilityDelegation => &mut self.capability_delegation,
+            VerificationRelationship::KeyAgreement => &mut self.key_agreement,
+        }
+    }
+
+    fn verification_method_position(&self, id: &str) -> Option<usize> {
+        let id = self.absolute_id(id);
+        self.verification_method
+            .iter()
+            .position(|method| self.absolute_id(&method.id) == id)
+    }
+
+    fn service_position(&self, id: &str) -> Option<usize> {
+        let id = self.absolute_id(id);
+        self.service
+            .iter()
+            .position(|service| self.absolute_id(&service.id) == id)
+    }
+
+    /// First `<prefix><n>` id starting from `count + 1` that is not used yet
+    fn next_id(
+        &self,
+        prefix: &str,
+        count: usize,
+        exists: impl Fn(&DidDocumentBuilder, &str) -> bool,
+    ) -> String {
+        (count + 1..)
+            .map(|index| format!("{}{}", prefix, index))
+            .find(|id| !exists(self, id))
+            .unwrap_or_default()
+    }
+
+    fn absolute_id(&self, id: &str) -> String {
+        absolute_id(&self.id, id)
+    }
+
+    fn invalid_data(&self, message: &str, id: &str) -> VdrError {
+        let vdr_error = VdrError::CommonInvalidData(format!("{}: {}", message, id));
+
+        warn!(
+            "Error: {} during editing DidDocumentBuilder: {:?}",
+            vdr_error, self
+        );
+
+        vdr_error
+    }
+}
+
+const RELATIONSHIPS: [VerificationRelationship; 5] = [
+    VerificationRelationship::Authentication,
+    VerificationRelationship::AssertionMethod,
+    VerificationRelationship::CapabilityInvocation,
+    VerificationRelationship::CapabilityDelegation,
+    VerificationRelationship::KeyAgreement,
+];
+
+/// Relative DID URLs (`#KEY-1`) are resolved against the DID
+fn absolute_id(did: &DID, id: &str) -> String {
+    if id.starts_with('#') {
+        format!("{}{}", did.as_ref(), id)
+    } else {
+        id.to_string()
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::contracts::did::types::did_doc::{
+        test::{did_doc, service, verification_method, ISSUER_ID, KEY_1, MULTIBASE_KEY},
+        ServiceEndpointObject,
+    };
+    use crate::signer::ed25519::test as ed25519_test;
+    use serde_json::json;
+
+    fn key_2() -> VerificationMethod {
+        VerificationMethod {
+            id: "#KEY-2".to_string(),
+            type_: VerificationKeyType::JsonWebKey2020,
+            controller: ISSUER_ID.to_string(),
+            public_key_jwk: Some(json!({"kty": "OKP", "crv": "Ed25519", "x": "x"})),
+            ..VerificationMethod::default()
+        }
+    }
+
+    #[test]
+    fn edit_did_doc_test() {
+        let original = did_doc(Some(ISSUER_ID));
+        let endpoint = ServiceEndpoint::Object(ServiceEndpointObject {
+            uri: "https://example.com/endpoint".to_string(),
+            accept: vec!["didcomm/v2".to_string()],
+            routing_keys: vec![],
+        });
+
+        let builder = DidDocumentBuilder::from_document(&original);
+        assert!(builder.changed_properties().is_empty());
+
+        let builder = builder
+            .insert_verification_method(key_2())
+            .unwrap()
+            .add_reference(VerificationRelationship::AssertionMethod, "#KEY-2")
+            .unwrap()
+            .add_service_endpoint("DIDCommMessaging", endpoint.clone())
+            .add_also_known_as("did:example:alias");
+        assert_eq!(
+            vec![
+                "verificationMethod",
+                "assertionMethod",
+                "service",
+                "alsoKnownAs"
+            ],
+            builder.changed_properties()
+        );
+
+        let did_doc = builder.build();
+        assert_eq!(2, did_doc.verification_method.len());
+        assert_eq!(
+            vec![VerificationMethodOrReference::String("#KEY-2".to_string())],
+            did_doc.assertion_method
+        );
+        assert_eq!("#inline-1", did_doc.service[0].id);
+        assert_eq!(endpoint, did_doc.service[0].service_endpoint);
+        assert_eq!(
+            Some(vec!["did:example:alias".to_string()]),
+            did_doc.also_known_as
+        );
+        did_doc.validate().unwrap();
+    }
+
+    #[test]
+    fn replace_and_remove_did_doc_entries_test() {
+        let mut original = did_doc(Some(ISSUER_ID));
+        original.service.push(service("#inline-1"));
+        let kid = format!("{}#{}", ISSUER_ID, KEY_1);
+
+        let mut key = verification_method(ISSUER_ID, MULTIBASE_KEY);
+        key.type_ = VerificationKeyType::Ed25519VerificationKey2020;
+        let mut replaced_service = service("#inline-1");
+        replaced_service.service_endpoint = ServiceEndpoint::Set(vec![
+            ServiceEndpoint::String("https://example.com/1".to_string()),
+            ServiceEndpoint::String("https://example.com/2".to_string()),
+        ]);
+        let did_doc = DidDocumentBuilder::from_document(&original)
+            .replace_verification_method(key)
+            .unwrap()
+            .replace_service(replaced_service.clone())
+            .unwrap()
+            .build();
+        assert_eq!(
+            VerificationKeyType::Ed25519VerificationKey2020,
+            did_doc.verification_method[0].type_
+        );
+        assert_eq!(vec![replaced_service], did_doc.service);
+
+        let builder = DidDocumentBuilder::from_document(&original)
+            .insert_verification_method(key_2())
+            .unwrap()
+            .remove_verification_method(&kid)
+            .unwrap()
+            .remove_service("#inline-1")
+            .unwrap()
+            .add_service("DIDCommService", "https://example.com");
+        assert_eq!(
+            vec!["verificationMethod", "authentication", "service"],
+            builder.changed_properties()
+        );
+        let did_doc = builder.add_verification_method(
+            VerificationKeyType::Ed25519VerificationKey2018,
+            &original.id,
+            Some(MULTIBASE_KEY.to_string()),
+            None,
+        );
+        // generated ids skip ids of existing methods
+        assert_eq!(
+            format!("{}#KEY-3", ISSUER_ID),
+            did_doc.verification_method[1].id
+        );
+        let did_doc = did_doc.build();
+        assert!(did_doc.authentication.is_empty());
+        assert_eq!("#inline-1", did_doc.service[0].id);
+    }
+
+    #[test]
+    fn edit_missing_did_doc_entries_test() {
+        let builder = DidDocumentBuilder::from_document(&did_doc(Some(ISSUER_ID)));
+        let kid = format!("{}#{}", ISSUER_ID, KEY_1);
+
+        let mut key = verification_method(ISSUER_ID, MULTIBASE_KEY);
+        assert!(builder
+            .clone()
+            .insert_verification_method(key.clone())
+            .is_err());
+        key.id = "#KEY-3".to_string();
+        assert!(builder.clone().replace_verification_method(key).is_err());
+        assert!(builder
+            .clone()
+            .remove_verification_method("#KEY-3")
+            .is_err());
+        assert!(builder
+            .clone()
+            .add_reference(VerificationRelationship::KeyAgreement, "#KEY-3")
+            .is_err());
+        assert!(builder
+            .clone()
+            .remove_reference(VerificationRelationship::AssertionMethod, &kid)
+            .is_err());
+        assert!(builder
+            .clone()
+            .replace_service(service("#inline-1"))
+            .is_err());
+        assert!(builder.remove_service("#inline-1").is_err());
+    }
+
+    #[test]
+    fn add_verification_key_test() {
+        let did = DID::try_from("did:indy2:testnet:3LpjszkgTmE3qThge25FZw").unwrap();
+        let public_key = ed25519_test::key_pair().public_key().clone();
+        let did_doc = DidDocumentBuilder::new()
+            .set_id(&did)
+            .add_verification_key(
+                VerificationKeyType::Ed25519VerificationKey2018,
+                &did,
+                &public_key,
+            )
+            .unwrap()
+            .add_verification_key(
+                VerificationKeyType::Ed25519VerificationKey2020,
+                &did,
+                &public_key,
+            )
+            .unwrap()
+            .add_verification_key(VerificationKeyType::JsonWebKey2020, &did, &public_key)
+            .unwrap()
+            .build();
+
+        assert_eq!(
+            Some(public_key.to_base58()),
+            did_doc.verification_method[0].public_key_base58
+        );
+        assert_eq!(None, did_doc.verification_method[0].public_key_multibase);
+        assert_eq!(
+            Some(public_key.to_multibase()),
+            did_doc.verification_method[1].public_key_multibase
+        );
+        assert_eq!(
+            Some(public_key.to_jwk()),
+            did_doc.verification_method[2].public_key_jwk
+        );
+
+        let err = DidDocumentBuilder::new()
+            .add_verification_key(
+                VerificationKeyType::X25519KeyAgreementKey2020,
+                &did,
+                &public_key,
+            )
+            .unwrap_err();
+        assert!(matches!(err, VdrError::CommonInvalidData(_)));
+    }
 }
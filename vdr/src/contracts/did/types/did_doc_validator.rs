@@ -0,0 +1,369 @@
+use std::{collections::HashSet, str::FromStr};
+
+use log::{trace, warn};
+
+use crate::{
+    contracts::did::types::{
+        did::{DidUrl, DID},
+        did_doc::{
+            DidDocument, Service, StringOrVector, VerificationMethod,
+            VerificationMethodOrReference, CONTEXT,
+        },
+    },
+    error::{VdrError, VdrResult},
+};
+
+const INDY_METHODS: [&str; 3] = ["indy", "indy2", "sov"];
+const INDY_ID_LENGTHS: [usize; 2] = [21, 22];
+
+impl DidDocument {
+    /// Validate DID Document before writing it to the ledger
+    ///
+    /// Checks the rules of `IndyDidValidator` contract, the structural rules of DID Core
+    /// (https://www.w3.org/TR/did-core/) and that verification relationships contain only ids of verification methods
+    /// or embedded verification methods.
+    ///
+    /// # Returns
+    /// `VdrError::DidInvalidDocument` listing all found problems if the DID Document is invalid
+    pub fn validate(&self) -> VdrResult<()> {
+        trace!("DidDocument: {:?} validation has started", self);
+
+        let problems = DidDocumentValidator::new(self).validate();
+        if !problems.is_empty() {
+            let vdr_error = VdrError::DidInvalidDocument(format!(
+                "{}: {}",
+                self.id.as_ref(),
+                problems.join("; ")
+            ));
+
+            warn!("Error: {} during DID Document validation", vdr_error);
+
+            return Err(vdr_error);
+        }
+
+        trace!("DidDocument validation has finished");
+
+        Ok(())
+    }
+}
+
+struct DidDocumentValidator<'a> {
+    did_doc: &'a DidDocument,
+    method_ids: HashSet<String>,
+    problems: Vec<String>,
+}
+
+impl<'a> DidDocumentValidator<'a> {
+    fn new(did_doc: &'a DidDocument) -> DidDocumentValidator<'a> {
+        DidDocumentValidator {
+            did_doc,
+            method_ids: HashSet::new(),
+            problems: Vec::new(),
+        }
+    }
+
+    fn validate(mut self) -> Vec<String> {
+        self.validate_id();
+        self.validate_context();
+        self.validate_controller();
+        for method in &self.did_doc.verification_method {
+            self.validate_method("verificationMethod", method);
+        }
+        // embedded methods first, so that references to them are resolved regardless of the relationship order
+        let relationships = self.relationships();
+        for (name, relationship) in &relationships {
+            for entry in relationship.iter() {
+                if let VerificationMethodOrReference::VerificationMethod(method) = entry {
+                    self.validate_method(name, method);
+                }
+            }
+        }
+        for (name, relationship) in &relationships {
+            for entry in relationship.iter() {
+                if let VerificationMethodOrReference::String(reference) = entry {
+                    self.validate_reference(name, reference);
+                }
+            }
+        }
+        self.validate_services();
+        self.problems
+    }
+
+    fn relationships(&self) -> [(&'static str, &'a Vec<VerificationMethodOrReference>); 5] {
+        [
+            ("authentication", &self.did_doc.authentication),
+            ("assertionMethod", &self.did_doc.assertion_method),
+            ("capabilityInvocation", &self.did_doc.capability_invocation),
+            ("capabilityDelegation", &self.did_doc.capability_delegation),
+            ("keyAgreement", &self.did_doc.key_agreement),
+        ]
+    }
+
+    fn validate_id(&mut self) {
+        let did = &self.did_doc.id;
+        if DID::from_str(did.as_ref()).is_err() {
+            self.problems
+                .push(format!("id '{}' is not a valid DID", did.as_ref()));
+            return;
+        }
+        // IndyDidValidator.validateDidSyntax
+        if !INDY_METHODS.contains(&did.method()) {
+            self.problems.push(format!(
+                "id '{}' must use one of the Indy DID methods: {}",
+                did.as_ref(),
+                INDY_METHODS.join(", ")
+            ));
+        } else if !INDY_ID_LENGTHS.contains(&did.id().len()) {
+            self.problems.push(format!(
+                "id '{}' must have identifier of {} or {} characters",
+                did.as_ref(),
+                INDY_ID_LENGTHS[0],
+                INDY_ID_LENGTHS[1]
+            ));
+        }
+    }
+
+    fn validate_context(&mut self) {
+        let first = match &self.did_doc.context {
+            StringOrVector::String(context) => Some(context),
+            StringOrVector::Vector(contexts) => contexts.first(),
+        };
+        if first.map(String::as_str) != Some(CONTEXT) {
+            self.problems
+                .push(format!("@context must start with '{}'", CONTEXT));
+        }
+    }
+
+    fn validate_controller(&mut self) {
+        for controller in self.controllers() {
+            if DID::from_str(controller).is_err() {
+                self.problems
+                    .push(format!("controller '{}' is not a valid DID", controller));
+            }
+        }
+    }
+
+    fn validate_method(&mut self, property: &str, method: &VerificationMethod) {
+        let id = self.absolute_id(&method.id);
+        if DidUrl::from_str(&id).is_err() {
+            self.problems.push(format!(
+                "{} id '{}' is not a valid DID URL",
+                property, method.id
+            ));
+        }
+        if !self.method_ids.insert(id) {
+            self.problems
+                .push(format!("{} id '{}' is not unique", property, method.id));
+        }
+
+        if DID::from_str(&method.controller).is_err() {
+            self.problems.push(format!(
+                "{} '{}' controller '{}' is not a valid DID",
+                property, method.id, method.controller
+            ));
+        } else if method.controller != self.did_doc.id.as_ref()
+            && !self.controllers().contains(&method.controller.as_str())
+        {
+            self.problems.push(format!(
+                "{} '{}' controller '{}' must be the DID or one of the DID Document controllers",
+                property, method.id, method.controller
+            ));
+        }
+
+        let key_properties = [
+            method.public_key_multibase.is_some(),
+            method.public_key_jwk.is_some(),
+            method.public_key_hex.is_some(),
+            method.public_key_base58.is_some(),
+            method.public_key_base64.is_some(),
+            method.blockchain_account_id.is_some(),
+        ]
+        .iter()
+        .filter(|set| **set)
+        .count();
+        if key_properties != 1 {
+            self.problems.push(format!(
+                "{} '{}' must contain exactly one verification material property, got {}",
+                property, method.id, key_properties
+            ));
+        }
+    }
+
+    fn validate_reference(&mut self, property: &str, reference: &str) {
+        let id = self.absolute_id(reference);
+        if DidUrl::from_str(&id).is_err() {
+            self.problems.push(format!(
+                "{} reference '{}' is not a valid DID URL",
+                property, reference
+            ));
+        } else if !self.method_ids.contains(&id) {
+            self.problems.push(format!(
+                "{} references missing verification method '{}'",
+                property, reference
+            ));
+        }
+    }
+
+    fn validate_services(&mut self) {
+        let mut ids = HashSet::new();
+        for Service {
+            id,
+            type_,
+            service_endpoint,
+        } in &self.did_doc.service
+        {
+            if DidUrl::from_str(&self.absolute_id(id)).is_err() {
+                self.problems
+                    .push(format!("service id '{}' is not a valid DID URL", id));
+            }
+            if !ids.insert(self.absolute_id(id)) {
+                self.problems
+                    .push(format!("service id '{}' is not unique", id));
+            }
+            if type_.is_empty() {
+                self.problems
+                    .push(format!("service '{}' type must not be empty", id));
+            }
+            if service_endpoint.uri().is_none_or(str::is_empty) {
+                self.problems
+                    .push(format!("service '{}' endpoint must not be empty", id));
+            }
+        }
+    }
+
+    fn controllers(&self) -> Vec<&'a str> {
+        match &self.did_doc.controller {
+            StringOrVector::String(controller) => vec![controller.as_str()],
+            StringOrVector::Vector(controllers) => controllers.iter().map(String::as_str).collect(),
+        }
+    }
+
+    /// Relative DID URLs (`#KEY-1`) are resolved against the DID Document id
+    fn absolute_id(&self, id: &str) -> String {
+        if id.starts_with('#') {
+            format!("{}{}", self.did_doc.id.as_ref(), id)
+        } else {
+            id.to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::contracts::did::types::{
+        did_doc::{
+            test::{did_doc, service, verification_method, ISSUER_ID, MULTIBASE_KEY},
+            ServiceEndpoint, VerificationKeyType,
+        },
+        did_doc_builder::DidDocumentBuilder,
+    };
+
+    #[test]
+    fn validate_did_doc_test() {
+        let mut did_doc = did_doc(Some(ISSUER_ID));
+        did_doc.service.push(service("#inline-1"));
+        did_doc
+            .assertion_method
+            .push(VerificationMethodOrReference::String(
+                did_doc.verification_method[0].id.clone(),
+            ));
+        did_doc.validate().unwrap();
+    }
+
+    #[test]
+    fn validate_built_did_doc_test() {
+        let did = DID::from_str(ISSUER_ID).unwrap();
+        let did_doc = DidDocumentBuilder::new()
+            .set_id(&did)
+            .add_verification_method(
+                VerificationKeyType::Ed25519VerificationKey2018,
+                &did,
+                Some(MULTIBASE_KEY.to_string()),
+                None,
+            )
+            .add_authentication_reference(0)
+            .unwrap()
+            .add_service("DIDCommService", "https://example.com/endpoint")
+            .build();
+        did_doc.validate().unwrap();
+    }
+
+    #[test]
+    fn validate_did_doc_with_embedded_method_test() {
+        let mut did_doc = did_doc(Some(ISSUER_ID));
+        let mut method = verification_method(ISSUER_ID, MULTIBASE_KEY);
+        method.id = "#KEY-2".to_string();
+        did_doc
+            .key_agreement
+            .push(VerificationMethodOrReference::String(method.id.clone()));
+        did_doc
+            .authentication
+            .push(VerificationMethodOrReference::VerificationMethod(method));
+        did_doc.validate().unwrap();
+    }
+
+    #[test]
+    fn validate_did_doc_reports_all_problems_test() {
+        let mut did_doc = did_doc(Some(ISSUER_ID));
+        did_doc.context = StringOrVector::Vector(vec![]);
+        did_doc.verification_method[0].controller =
+            "did:indy2:testnet:2D3YF21WMjkMj2BemwbxJS".to_string();
+        did_doc.verification_method[0].public_key_jwk = Some(serde_json::json!({}));
+        did_doc
+            .assertion_method
+            .push(VerificationMethodOrReference::String(format!(
+                "{}#KEY-2",
+                ISSUER_ID
+            )));
+        did_doc.service = vec![service("#inline-1"), service("#inline-1")];
+        did_doc.service[1].service_endpoint = ServiceEndpoint::String(String::new());
+
+        let VdrError::DidInvalidDocument(message) = did_doc.validate().unwrap_err() else {
+            panic!("DidInvalidDocument error expected")
+        };
+        assert_eq!(
+            format!(
+                "{id}: @context must start with '{context}'; \
+                verificationMethod '{id}#KEY-1' controller 'did:indy2:testnet:2D3YF21WMjkMj2BemwbxJS' \
+                must be the DID or one of the DID Document controllers; \
+                verificationMethod '{id}#KEY-1' must contain exactly one verification material property, got 2; \
+                assertionMethod references missing verification method '{id}#KEY-2'; \
+                service id '#inline-1' is not unique; \
+                service '#inline-1' endpoint must not be empty",
+                id = ISSUER_ID,
+                context = CONTEXT
+            ),
+            message
+        );
+    }
+
+    #[test]
+    fn validate_did_doc_with_non_indy_method_test() {
+        let id = "did:ethr:testnet:0xf0e2db6c8dc6c681bb5d6ad121a107f300e9b2b5";
+        let did_doc = did_doc(Some(id));
+
+        let VdrError::DidInvalidDocument(message) = did_doc.validate().unwrap_err() else {
+            panic!("DidInvalidDocument error expected")
+        };
+        assert_eq!(
+            format!(
+                "{id}: id '{id}' must use one of the Indy DID methods: indy, indy2, sov",
+                id = id
+            ),
+            message
+        );
+    }
+
+    #[test]
+    fn validate_did_doc_controller_test() {
+        let controller = "did:indy2:testnet:2D3YF21WMjkMj2BemwbxJS";
+        let mut did_doc = did_doc(Some(ISSUER_ID));
+        did_doc.controller = StringOrVector::String(controller.to_string());
+        did_doc.verification_method[0].controller = controller.to_string();
+        did_doc.validate().unwrap();
+
+        did_doc.controller = StringOrVector::Vector(vec!["controller".to_string()]);
+        assert!(did_doc.validate().is_err());
+    }
+}
@@ -0,0 +1,455 @@
+use crate::{
+    contracts::did::types::did_doc::{DidDocument, DidRecord},
+    error::VdrError,
+};
+use log::trace;
+use serde_derive::{Deserialize, Serialize};
+use std::fmt;
+
+/// Media type of DID Document represented as JSON-LD (default)
+pub const DID_LD_JSON: &str = "application/did+ld+json";
+/// Media type of DID Document represented as plain JSON
+pub const DID_JSON: &str = "application/did+json";
+
+/// Input options of DID resolution: https://w3c-ccg.github.io/did-resolution/#did-resolution-options
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidResolutionOptions {
+    /// Media type of the preferred DID Document representation
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accept: Option<String>,
+    /// Version of the DID Document to resolve: `versionId` returned in the DID Document metadata
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version_id: Option<String>,
+    /// Resolve the DID Document as it was at the given time (XML Datetime: `2024-01-16T09:12:31Z`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version_time: Option<String>,
+    /// Resolve the DID Document as it was at the given block
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_number: Option<u64>,
+}
+
+/// Version of DID Document requested in resolution options
+///
+/// The meaning of version id is method specific: ledger timestamp of the change for `did:indy2`, block number for
+/// `did:ethr`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DidVersion {
+    /// `versionId` resolution option
+    Id(u64),
+    /// `versionTime` resolution option as unix timestamp
+    Time(u64),
+    /// `blockNumber` resolution option
+    Block(u64),
+}
+
+impl fmt::Display for DidVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DidVersion::Id(version_id) => write!(f, "versionId={}", version_id),
+            DidVersion::Time(timestamp) => write!(f, "versionTime={}", timestamp),
+            DidVersion::Block(block_number) => write!(f, "blockNumber={}", block_number),
+        }
+    }
+}
+
+/// Result of DID resolution: https://w3c-ccg.github.io/did-resolution/#did-resolution-result
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidResolutionResult {
+    pub did_resolution_metadata: DidResolutionMetadata,
+    pub did_document: Option<DidDocument>,
+    pub did_document_metadata: DidDocumentMetadata,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidResolutionMetadata {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<DidResolutionError>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error_message: Option<String>,
+}
+
+/// Standard error codes of DID resolution: https://www.w3.org/TR/did-spec-registries/#error
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DidResolutionError {
+    InvalidDid,
+    InvalidDidUrl,
+    InvalidOptions,
+    NotFound,
+    RepresentationNotSupported,
+    MethodNotSupported,
+    Deactivated,
+    InternalError,
+}
+
+/// Metadata of the resolved DID Document: https://www.w3.org/TR/did-core/#did-document-metadata
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidDocumentMetadata {
+    /// Creation time as XML Datetime (`2024-01-16T09:12:31Z`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<String>,
+    /// Time of the last update as XML Datetime (omitted if the document was never updated)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deactivated: Option<bool>,
+    /// Version of the document: ledger timestamp of the last change
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version_id: Option<String>,
+    /// Time of the next update as XML Datetime (set only for historical versions)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_update: Option<String>,
+    /// Version of the next update (set only for historical versions)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_version_id: Option<String>,
+}
+
+/// Kind of DID change recorded by IndyDidRegistry events
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DidChangeKind {
+    Created,
+    Updated,
+    Deactivated,
+}
+
+/// DID change found in the ledger history
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidChange {
+    pub kind: DidChangeKind,
+    /// Number of the block containing the change
+    pub block_number: u64,
+    /// Timestamp of the block containing the change
+    pub timestamp: u64,
+}
+
+impl DidChange {
+    /// Version id of the DID Document produced by the change (deactivation does not produce a new version)
+    pub fn version_id(&self) -> Option<u64> {
+        (self.kind != DidChangeKind::Deactivated).then_some(self.timestamp)
+    }
+}
+
+impl DidResolutionResult {
+    /// Build successful resolution result from the DID record stored on the ledger
+    ///
+    /// Deactivated DIDs are returned with `deactivated` resolution error along with the document and its metadata.
+    ///
+    /// # Params
+    /// - `record` DID Document with metadata returned by IndyDidRegistry.resolveDid
+    /// - `content_type` media type of the document representation
+    ///
+    /// # Returns
+    /// DID resolution result
+    pub fn from_record(record: DidRecord, content_type: &str) -> DidResolutionResult {
+        let metadata = record.metadata;
+        let result = DidResolutionResult {
+            did_resolution_metadata: DidResolutionMetadata {
+                content_type: Some(content_type.to_string()),
+                error: metadata
+                    .deactivated
+                    .then_some(DidResolutionError::Deactivated),
+                error_message: None,
+            },
+            did_document: Some(record.document),
+            did_document_metadata: DidDocumentMetadata {
+                created: Some(xml_datetime(metadata.created)),
+                updated: (metadata.updated != metadata.created)
+                    .then(|| xml_datetime(metadata.updated)),
+                deactivated: metadata.deactivated.then_some(true),
+                version_id: Some(metadata.updated.to_string()),
+                ..DidDocumentMetadata::default()
+            },
+        };
+
+        trace!("Created DidResolutionResult: {:?}", result);
+
+        result
+    }
+
+    /// Build successful resolution result from the DID Document and its metadata
+    ///
+    /// Deactivated DIDs are returned with `deactivated` resolution error along with the document and its metadata.
+    ///
+    /// # Params
+    /// - `document` resolved DID Document
+    /// - `metadata` DID Document metadata
+    /// - `content_type` media type of the document representation
+    ///
+    /// # Returns
+    /// DID resolution result
+    pub fn from_document(
+        document: DidDocument,
+        metadata: DidDocumentMetadata,
+        content_type: &str,
+    ) -> DidResolutionResult {
+        let result = DidResolutionResult {
+            did_resolution_metadata: DidResolutionMetadata {
+                content_type: Some(content_type.to_string()),
+                error: (metadata.deactivated == Some(true))
+                    .then_some(DidResolutionError::Deactivated),
+                error_message: None,
+            },
+            did_document: Some(document),
+            did_document_metadata: metadata,
+        };
+
+        trace!("Created DidResolutionResult: {:?}", result);
+
+        result
+    }
+
+    /// Set the version following the resolved one
+    ///
+    /// # Params
+    /// - `next` change producing the next version of the DID Document
+    ///
+    /// # Returns
+    /// DID resolution result with `nextUpdate` and `nextVersionId` metadata
+    pub fn set_next_version(mut self, next: Option<&DidChange>) -> DidResolutionResult {
+        if let Some(version_id) = next.and_then(DidChange::version_id) {
+            self.did_document_metadata.next_update = Some(xml_datetime(version_id));
+            self.did_document_metadata.next_version_id = Some(version_id.to_string());
+        }
+
+        trace!("Set next version to DidResolutionResult: {:?}", self);
+
+        self
+    }
+
+    /// Build failed resolution result
+    ///
+    /// # Params
+    /// - `error` resolution error code
+    /// - `message` human readable description of the error
+    ///
+    /// # Returns
+    /// DID resolution result without DID Document
+    pub fn from_error(error: DidResolutionError, message: &str) -> DidResolutionResult {
+        let result = DidResolutionResult {
+            did_resolution_metadata: DidResolutionMetadata {
+                content_type: None,
+                error: Some(error),
+                error_message: Some(message.to_string()),
+            },
+            ..DidResolutionResult::default()
+        };
+
+        trace!("Created DidResolutionResult: {:?}", result);
+
+        result
+    }
+}
+
+impl From<&VdrError> for DidResolutionError {
+    fn from(value: &VdrError) -> Self {
+        match value {
+            VdrError::DidNotFound(_) | VdrError::DidUrlNotFound(_) => DidResolutionError::NotFound,
+            VdrError::DidInvalidIdentifier(_) | VdrError::DidIncorrect(_) => {
+                DidResolutionError::InvalidDid
+            }
+            VdrError::DidHasBeenDeactivated(_) => DidResolutionError::Deactivated,
+            _ => DidResolutionError::InternalError,
+        }
+    }
+}
+
+/// Format unix timestamp as XML Datetime normalized to UTC without sub-second decimal fraction
+pub(crate) fn xml_datetime(timestamp: u64) -> String {
+    const SECONDS_PER_DAY: u64 = 86400;
+
+    let (days, seconds) = (timestamp / SECONDS_PER_DAY, timestamp % SECONDS_PER_DAY);
+
+    // civil date from days since 1970-01-01 (http://howardhinnant.github.io/date_algorithms.html)
+    let z = days + 719468;
+    let era = z / 146097;
+    let day_of_era = z % 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
+
+/// Parse XML Datetime in UTC (`2024-01-16T09:12:31Z`) into unix timestamp
+///
+/// Sub-second decimal fraction is truncated. Returns `None` if the value is not a valid UTC datetime.
+pub(crate) fn parse_xml_datetime(value: &str) -> Option<u64> {
+    let value = value.strip_suffix('Z')?;
+    let (date, time) = value.split_once('T')?;
+    let time = time.split_once('.').map_or(time, |(time, _)| time);
+
+    let mut date = date.split('-').map(|part| part.parse::<u64>().ok());
+    let (year, month, day) = (date.next()??, date.next()??, date.next()??);
+    let mut time = time.split(':').map(|part| part.parse::<u64>().ok());
+    let (hour, minute, second) = (time.next()??, time.next()??, time.next()??);
+    if date.next().is_some()
+        || time.next().is_some()
+        || year < 1970
+        || !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || hour > 23
+        || minute > 59
+        || second > 59
+    {
+        return None;
+    }
+
+    // days since 1970-01-01 from civil date (http://howardhinnant.github.io/date_algorithms.html)
+    let year = year - u64::from(month <= 2);
+    let era = year / 400;
+    let year_of_era = year % 400;
+    let shifted_month = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * shifted_month + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = (era * 146097 + day_of_era).checked_sub(719468)?;
+
+    let timestamp = days * 86400 + hour * 3600 + minute * 60 + second;
+
+    // reject dates overflowing the month (`2023-02-30`)
+    (xml_datetime(timestamp).get(..10) == value.get(..10)).then_some(timestamp)
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::{
+        client::client::test::{IDENTITY_ACC, TRUSTEE_ACC},
+        contracts::did::types::did_doc::{
+            test::{did_doc, ISSUER_ID},
+            DidMetadata,
+        },
+    };
+    use serde_json::json;
+
+    pub const CREATED: u64 = 1705396351;
+    pub const UPDATED: u64 = 1705482751;
+
+    pub fn did_record(updated: u64, deactivated: bool) -> DidRecord {
+        DidRecord {
+            document: did_doc(Some(ISSUER_ID)),
+            metadata: DidMetadata {
+                owner: IDENTITY_ACC.clone(),
+                sender: TRUSTEE_ACC.clone(),
+                created: CREATED,
+                updated,
+                deactivated,
+            },
+        }
+    }
+
+    #[test]
+    fn xml_datetime_test() {
+        assert_eq!("1970-01-01T00:00:00Z", xml_datetime(0));
+        assert_eq!("2000-02-29T23:59:59Z", xml_datetime(951868799));
+        assert_eq!("2024-01-16T09:12:31Z", xml_datetime(CREATED));
+    }
+
+    #[test]
+    fn parse_xml_datetime_test() {
+        assert_eq!(Some(0), parse_xml_datetime("1970-01-01T00:00:00Z"));
+        assert_eq!(Some(951868799), parse_xml_datetime("2000-02-29T23:59:59Z"));
+        assert_eq!(
+            Some(CREATED),
+            parse_xml_datetime("2024-01-16T09:12:31.250Z")
+        );
+        assert_eq!(None, parse_xml_datetime("2024-01-16T09:12:31"));
+        assert_eq!(None, parse_xml_datetime("2024-01-16T09:12:31+02:00"));
+        assert_eq!(None, parse_xml_datetime("2023-02-29T00:00:00Z"));
+        assert_eq!(None, parse_xml_datetime("1969-12-31T23:59:59Z"));
+    }
+
+    #[test]
+    fn resolution_result_from_record_test() {
+        let result = DidResolutionResult::from_record(did_record(CREATED, false), DID_LD_JSON);
+        assert_eq!(
+            json!({
+                "didResolutionMetadata": { "contentType": DID_LD_JSON },
+                "didDocument": did_doc(Some(ISSUER_ID)),
+                "didDocumentMetadata": {
+                    "created": "2024-01-16T09:12:31Z",
+                    "versionId": CREATED.to_string(),
+                }
+            }),
+            json!(result)
+        );
+    }
+
+    #[test]
+    fn resolution_result_from_updated_deactivated_record_test() {
+        let result = DidResolutionResult::from_record(did_record(UPDATED, true), DID_JSON);
+        assert_eq!(
+            json!({
+                "didResolutionMetadata": { "contentType": DID_JSON, "error": "deactivated" },
+                "didDocument": did_doc(Some(ISSUER_ID)),
+                "didDocumentMetadata": {
+                    "created": "2024-01-16T09:12:31Z",
+                    "updated": "2024-01-17T09:12:31Z",
+                    "deactivated": true,
+                    "versionId": UPDATED.to_string(),
+                }
+            }),
+            json!(result)
+        );
+    }
+
+    #[test]
+    fn resolution_result_with_next_version_test() {
+        let next = DidChange {
+            kind: DidChangeKind::Updated,
+            block_number: 2,
+            timestamp: UPDATED,
+        };
+        let result = DidResolutionResult::from_record(did_record(CREATED, false), DID_LD_JSON)
+            .set_next_version(Some(&next));
+        assert_eq!(
+            json!({
+                "created": "2024-01-16T09:12:31Z",
+                "versionId": CREATED.to_string(),
+                "nextUpdate": "2024-01-17T09:12:31Z",
+                "nextVersionId": UPDATED.to_string(),
+            }),
+            json!(result.did_document_metadata)
+        );
+    }
+
+    #[test]
+    fn resolution_result_from_error_test() {
+        let error = VdrError::DidNotFound(ISSUER_ID.to_string());
+        let result = DidResolutionResult::from_error((&error).into(), &error.to_string());
+        assert_eq!(
+            json!({
+                "didResolutionMetadata": {
+                    "error": "notFound",
+                    "errorMessage": format!("DID: Not found: {}", ISSUER_ID),
+                },
+                "didDocument": null,
+                "didDocumentMetadata": {}
+            }),
+            json!(result)
+        );
+    }
+}
@@ -0,0 +1,484 @@
+use crate::{
+    contracts::did::types::{
+        did::DidUrl,
+        did_doc::{DidDocument, Service, VerificationMethod, VerificationMethodOrReference},
+        did_resolution::{DidDocumentMetadata, DidResolutionError, DidResolutionMetadata},
+    },
+    error::{VdrError, VdrResult},
+};
+use log::{trace, warn};
+use serde_derive::{Deserialize, Serialize};
+
+/// DID URL query parameter selecting a service by the fragment of its id
+pub const SERVICE_PARAM: &str = "service";
+/// DID URL query parameter holding a relative reference resolved against the selected service endpoint
+pub const RELATIVE_REF_PARAM: &str = "relativeRef";
+/// DID URL query parameter selecting the version of the DID Document by its `versionId`
+pub const VERSION_ID_PARAM: &str = "versionId";
+/// DID URL query parameter selecting the version of the DID Document valid at the given time
+pub const VERSION_TIME_PARAM: &str = "versionTime";
+/// Media type of dereferenced service endpoint URL
+pub const URI_LIST: &str = "text/uri-list";
+
+/// Verification relationships defined by DID Core: https://www.w3.org/TR/did-core/#verification-relationships
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum VerificationRelationship {
+    Authentication,
+    AssertionMethod,
+    CapabilityInvocation,
+    CapabilityDelegation,
+    KeyAgreement,
+}
+
+/// Resource selected by DID URL
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum DereferencedResource {
+    DidDocument(DidDocument),
+    VerificationMethod(VerificationMethod),
+    Service(Service),
+    /// Service endpoint URL selected with `service` (and `relativeRef`) query parameters
+    Url(String),
+}
+
+/// Result of DID URL dereferencing: https://w3c-ccg.github.io/did-resolution/#did-url-dereferencing-result
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DidUrlDereferencingResult {
+    pub dereferencing_metadata: DidResolutionMetadata,
+    pub content_stream: Option<DereferencedResource>,
+    pub content_metadata: DidDocumentMetadata,
+}
+
+impl DidUrlDereferencingResult {
+    /// Build failed dereferencing result
+    ///
+    /// # Params
+    /// - `error` dereferencing error code
+    /// - `message` human readable description of the error
+    ///
+    /// # Returns
+    /// DID URL dereferencing result without content
+    pub fn from_error(error: DidResolutionError, message: &str) -> DidUrlDereferencingResult {
+        let result = DidUrlDereferencingResult {
+            dereferencing_metadata: DidResolutionMetadata {
+                content_type: None,
+                error: Some(error),
+                error_message: Some(message.to_string()),
+            },
+            ..DidUrlDereferencingResult::default()
+        };
+
+        trace!("Created DidUrlDereferencingResult: {:?}", result);
+
+        result
+    }
+}
+
+impl DidDocument {
+    /// Expand DID URL relative to the document (`#KEY-1`, `?service=agent`, `/path`) into absolute DID URL
+    ///
+    /// # Params
+    /// - `url` absolute or relative DID URL
+    ///
+    /// # Returns
+    /// absolute DID URL
+    pub fn absolute_url(&self, url: &str) -> String {
+        if url.starts_with(['#', '?', '/']) {
+            format!("{}{}", self.id, url)
+        } else {
+            url.to_string()
+        }
+    }
+
+    /// Get entries of the verification relationship
+    pub fn relationship(
+        &self,
+        relationship: VerificationRelationship,
+    ) -> &[VerificationMethodOrReference] {
+        match relationship {
+            VerificationRelationship::Authentication => &self.authentication,
+            VerificationRelationship::AssertionMethod => &self.assertion_method,
+            VerificationRelationship::CapabilityInvocation => &self.capability_invocation,
+            VerificationRelationship::CapabilityDelegation => &self.capability_delegation,
+            VerificationRelationship::KeyAgreement => &self.key_agreement,
+        }
+    }
+
+    /// Find verification method by id among `verificationMethod` and methods embedded into verification relationships
+    ///
+    /// # Params
+    /// - `id` absolute or relative (`#KEY-1`) verification method id
+    ///
+    /// # Returns
+    /// verification method or `None` if the document does not contain it
+    pub fn find_verification_method(&self, id: &str) -> Option<&VerificationMethod> {
+        let id = self.absolute_url(id);
+        let embedded = [
+            &self.authentication,
+            &self.assertion_method,
+            &self.capability_invocation,
+            &self.capability_delegation,
+            &self.key_agreement,
+        ]
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| match entry {
+            VerificationMethodOrReference::VerificationMethod(method) => Some(method),
+            VerificationMethodOrReference::String(_) => None,
+        });
+        self.verification_method
+            .iter()
+            .chain(embedded)
+            .find(|method| self.absolute_url(&method.id) == id)
+    }
+
+    /// Get verification methods authorized for the relationship: embedded methods are returned as is, references are
+    /// followed to the methods they point to
+    ///
+    /// # Params
+    /// - `relationship` verification relationship
+    ///
+    /// # Returns
+    /// verification methods or `VdrError::DidUrlNotFound` if a reference points to a missing method
+    pub fn verification_methods(
+        &self,
+        relationship: VerificationRelationship,
+    ) -> VdrResult<Vec<&VerificationMethod>> {
+        self.relationship(relationship)
+            .iter()
+            .map(|entry| match entry {
+                VerificationMethodOrReference::VerificationMethod(method) => Ok(method),
+                VerificationMethodOrReference::String(reference) => {
+                    self.find_verification_method(reference).ok_or_else(|| {
+                        not_found(format!(
+                            "{} referenced in {:?} relationship",
+                            self.absolute_url(reference),
+                            relationship
+                        ))
+                    })
+                }
+            })
+            .collect()
+    }
+
+    /// Find service by id
+    ///
+    /// # Params
+    /// - `id` absolute or relative (`#SERVICE-1`) service id
+    ///
+    /// # Returns
+    /// service or `None` if the document does not contain it
+    pub fn find_service(&self, id: &str) -> Option<&Service> {
+        let id = self.absolute_url(id);
+        self.service
+            .iter()
+            .find(|service| self.absolute_url(&service.id) == id)
+    }
+
+    /// Dereference DID URL within the document following DID Core rules:
+    /// - no path, query and fragment: the document itself
+    /// - `service` query parameter: service endpoint URL with `relativeRef` and fragment applied
+    /// - fragment: verification method or service with the matching id
+    ///
+    /// Other query parameters (`versionId`, `versionTime`) are handled by resolution and ignored.
+    ///
+    /// # Params
+    /// - `did_url` DID URL with DID of the document
+    ///
+    /// # Returns
+    /// dereferenced resource
+    pub fn dereference(&self, did_url: &DidUrl) -> VdrResult<DereferencedResource> {
+        if did_url.did != self.id {
+            let vdr_error = VdrError::DidInvalidIdentifier(format!(
+                "{} does not belong to DID Document {}",
+                did_url, self.id
+            ));
+
+            warn!("Error: {} during DID URL dereferencing", vdr_error);
+
+            return Err(vdr_error);
+        }
+
+        let resource = if let Some(service) = did_url.query_param(SERVICE_PARAM) {
+            DereferencedResource::Url(self.service_url(did_url, service)?)
+        } else if did_url.path.is_some() {
+            return Err(not_found(did_url.to_string()));
+        } else if let Some(fragment) = &did_url.fragment {
+            let id = format!("#{}", fragment);
+            if let Some(method) = self.find_verification_method(&id) {
+                DereferencedResource::VerificationMethod(method.clone())
+            } else if let Some(service) = self.find_service(&id) {
+                DereferencedResource::Service(service.clone())
+            } else {
+                return Err(not_found(did_url.to_string()));
+            }
+        } else {
+            DereferencedResource::DidDocument(self.clone())
+        };
+
+        trace!(
+            "Dereferenced DID URL: {} into resource: {:?}",
+            did_url,
+            resource
+        );
+
+        Ok(resource)
+    }
+
+    fn service_url(&self, did_url: &DidUrl, service: &str) -> VdrResult<String> {
+        let service = self
+            .find_service(&format!("#{}", percent_decode(service)))
+            .ok_or_else(|| not_found(did_url.to_string()))?;
+        let endpoint = service
+            .service_endpoint
+            .uri()
+            .ok_or_else(|| not_found(format!("{}: service does not have URI endpoint", did_url)))?;
+
+        let mut url = match did_url.query_param(RELATIVE_REF_PARAM) {
+            Some(relative_ref) => resolve_reference(endpoint, &percent_decode(relative_ref)),
+            None => endpoint.to_string(),
+        };
+        if let Some(fragment) = &did_url.fragment {
+            if !url.contains('#') {
+                url = format!("{}#{}", url, fragment);
+            }
+        }
+        Ok(url)
+    }
+}
+
+fn not_found(resource: String) -> VdrError {
+    let vdr_error = VdrError::DidUrlNotFound(resource);
+
+    warn!("Error: {} during DID URL dereferencing", vdr_error);
+
+    vdr_error
+}
+
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = bytes
+            .get(i + 1..i + 3)
+            .filter(|_| bytes[i] == b'%')
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Resolve URI reference against base URI (RFC 3986 section 5.2)
+fn resolve_reference(base: &str, reference: &str) -> String {
+    let has_scheme = reference
+        .find(':')
+        .is_some_and(|position| !reference[..position].contains(['/', '?', '#']));
+    if has_scheme {
+        return reference.to_string();
+    }
+
+    let base = base.split('#').next().unwrap_or_default();
+    let (scheme, rest) = base.split_once(':').unwrap_or(("", base));
+    let (authority, path_and_query) = match rest.strip_prefix("//") {
+        Some(rest) => {
+            let end = rest.find(['/', '?']).unwrap_or(rest.len());
+            (format!("//{}", &rest[..end]), &rest[end..])
+        }
+        None => (String::new(), rest),
+    };
+    let path = path_and_query.split('?').next().unwrap_or_default();
+    let origin = format!("{}:{}", scheme, authority);
+
+    match reference.chars().next() {
+        None => base.to_string(),
+        Some('#') => format!("{}{}", base, reference),
+        Some('?') => format!("{}{}{}", origin, path, reference),
+        Some('/') if reference.starts_with("//") => format!("{}:{}", scheme, reference),
+        Some('/') => format!("{}{}", origin, remove_dot_segments(reference)),
+        Some(_) => {
+            let directory = match path.rfind('/') {
+                Some(position) => &path[..=position],
+                None if !authority.is_empty() => "/",
+                None => "",
+            };
+            format!(
+                "{}{}",
+                origin,
+                remove_dot_segments(&format!("{}{}", directory, reference))
+            )
+        }
+    }
+}
+
+fn remove_dot_segments(reference: &str) -> String {
+    let (path, suffix) = match reference.find(['?', '#']) {
+        Some(position) => reference.split_at(position),
+        None => (reference, ""),
+    };
+    let mut segments: Vec<&str> = Vec::new();
+    let mut parts = path.split('/').peekable();
+    while let Some(segment) = parts.next() {
+        let last = parts.peek().is_none();
+        match segment {
+            "." | ".." => {
+                if segment == ".." && segments.len() > 1 {
+                    segments.pop();
+                }
+                if last {
+                    segments.push("");
+                }
+            }
+            _ => segments.push(segment),
+        }
+    }
+    format!("{}{}", segments.join("/"), suffix)
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::contracts::did::types::did_doc::{
+        test::{did_doc, service, ISSUER_ID, KEY_1},
+        ServiceEndpoint, ServiceEndpointObject,
+    };
+    use std::str::FromStr;
+
+    const SERVICE_ID: &str = "did:indy2:testnet:3LpjszkgTmE3qThge25FZw#agent";
+    const ENDPOINT: &str = "https://example.com/messages/8377464";
+
+    fn did_doc_with_service() -> DidDocument {
+        let mut did_doc = did_doc(Some(ISSUER_ID));
+        let mut service = service(SERVICE_ID);
+        service.service_endpoint = ServiceEndpoint::String(ENDPOINT.to_string());
+        did_doc.service.push(service);
+        did_doc
+    }
+
+    fn dereference(did_doc: &DidDocument, did_url: &str) -> VdrResult<DereferencedResource> {
+        did_doc.dereference(&DidUrl::from_str(did_url).unwrap())
+    }
+
+    #[test]
+    fn dereference_did_document_test() {
+        let did_doc = did_doc_with_service();
+        assert_eq!(
+            DereferencedResource::DidDocument(did_doc.clone()),
+            dereference(&did_doc, ISSUER_ID).unwrap()
+        );
+    }
+
+    #[test]
+    fn dereference_fragment_test() {
+        let did_doc = did_doc_with_service();
+        assert_eq!(
+            DereferencedResource::VerificationMethod(did_doc.verification_method[0].clone()),
+            dereference(&did_doc, &format!("{}#{}", ISSUER_ID, KEY_1)).unwrap()
+        );
+        assert_eq!(
+            DereferencedResource::Service(did_doc.service[0].clone()),
+            dereference(&did_doc, SERVICE_ID).unwrap()
+        );
+
+        let err = dereference(&did_doc, &format!("{}#KEY-2", ISSUER_ID)).unwrap_err();
+        assert!(matches!(err, VdrError::DidUrlNotFound(_)));
+
+        let err =
+            dereference(&did_doc, "did:indy2:testnet:Q6Wvnm4v6ENzRC2mkUPkYR#KEY-1").unwrap_err();
+        assert!(matches!(err, VdrError::DidInvalidIdentifier(_)));
+    }
+
+    #[test]
+    fn dereference_relative_and_embedded_methods_test() {
+        let mut did_doc = did_doc_with_service();
+        let mut embedded = did_doc.verification_method[0].clone();
+        embedded.id = "#KEY-2".to_string();
+        did_doc
+            .key_agreement
+            .push(VerificationMethodOrReference::VerificationMethod(
+                embedded.clone(),
+            ));
+        did_doc
+            .assertion_method
+            .push(VerificationMethodOrReference::String("#KEY-2".to_string()));
+
+        assert_eq!(
+            DereferencedResource::VerificationMethod(embedded.clone()),
+            dereference(&did_doc, &format!("{}#KEY-2", ISSUER_ID)).unwrap()
+        );
+        assert_eq!(
+            vec![&embedded],
+            did_doc
+                .verification_methods(VerificationRelationship::AssertionMethod)
+                .unwrap()
+        );
+        assert_eq!(
+            vec![&did_doc.verification_method[0]],
+            did_doc
+                .verification_methods(VerificationRelationship::Authentication)
+                .unwrap()
+        );
+
+        did_doc
+            .capability_invocation
+            .push(VerificationMethodOrReference::String("#KEY-3".to_string()));
+        let err = did_doc
+            .verification_methods(VerificationRelationship::CapabilityInvocation)
+            .unwrap_err();
+        assert!(matches!(err, VdrError::DidUrlNotFound(_)));
+    }
+
+    #[test]
+    fn dereference_service_test() {
+        let mut did_doc = did_doc_with_service();
+        let cases = [
+            ("?service=agent", ENDPOINT.to_string()),
+            (
+                "?service=agent&relativeRef=%2Fsome%2Fpath%3Fquery#frag",
+                "https://example.com/some/path?query#frag".to_string(),
+            ),
+            (
+                "?service=agent&relativeRef=inbox",
+                "https://example.com/messages/inbox".to_string(),
+            ),
+            (
+                "?service=agent&relativeRef=..%2Finbox",
+                "https://example.com/inbox".to_string(),
+            ),
+            ("?service=agent#frag", format!("{}#frag", ENDPOINT)),
+        ];
+        for (query, expected) in cases {
+            assert_eq!(
+                DereferencedResource::Url(expected),
+                dereference(&did_doc, &format!("{}{}", ISSUER_ID, query)).unwrap()
+            );
+        }
+
+        let err = dereference(&did_doc, &format!("{}?service=mediator", ISSUER_ID)).unwrap_err();
+        assert!(matches!(err, VdrError::DidUrlNotFound(_)));
+
+        did_doc.service[0].id = "#agent".to_string();
+        did_doc.service[0].service_endpoint =
+            ServiceEndpoint::Set(vec![ServiceEndpoint::Object(ServiceEndpointObject {
+                uri: ENDPOINT.to_string(),
+                accept: vec![],
+                routing_keys: vec![],
+            })]);
+        assert_eq!(
+            DereferencedResource::Url(ENDPOINT.to_string()),
+            dereference(&did_doc, &format!("{}?service=agent", ISSUER_ID)).unwrap()
+        );
+    }
+}
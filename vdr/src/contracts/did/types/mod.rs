@@ -1,3 +1,6 @@
 pub mod did;
 pub mod did_doc;
 pub mod did_doc_builder;
+pub mod did_doc_validator;
+pub mod did_resolution;
+pub mod did_url_dereferencing;
@@ -0,0 +1,297 @@
+use log::{debug, info, trace, warn};
+
+use crate::{
+    client::LedgerClient,
+    contracts::did::{
+        did_registry,
+        did_resolver::INDY2_METHOD,
+        ethr::{did_ethr_resolver, types::ETHR_METHOD},
+        types::{
+            did::DID,
+            did_doc::{DidDocument, DidMetadata},
+        },
+    },
+    error::{VdrError, VdrResult},
+    types::{
+        ContractOutput, ContractParam, Transaction, TransactionBuilder, TransactionParser,
+        TransactionType,
+    },
+};
+
+const CONTRACT_NAME: &str = "UniversalDidResolver";
+const METHOD_RESOLVE_DOCUMENT: &str = "resolveDocument";
+const METHOD_RESOLVE_METADATA: &str = "resolveMetadata";
+
+/// Build transaction to execute UniversalDidResolver.resolveDocument contract method to receive a DID Document
+/// of any DID method supported by the network
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `did` DID to resolve
+///
+/// # Returns
+/// Read transaction to submit
+pub async fn build_resolve_document_transaction(
+    client: &LedgerClient,
+    did: &DID,
+) -> VdrResult<Transaction> {
+    build_resolve_transaction(client, METHOD_RESOLVE_DOCUMENT, did).await
+}
+
+/// Parse the result of execution UniversalDidResolver.resolveDocument contract method
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `bytes` result bytes returned from the ledger
+///
+/// # Returns
+/// parsed DID Document
+pub fn parse_resolve_document_result(
+    client: &LedgerClient,
+    bytes: &[u8],
+) -> VdrResult<DidDocument> {
+    parse_resolve_result::<DocumentOutput>(client, METHOD_RESOLVE_DOCUMENT, bytes)
+        .map(|document| document.0)
+}
+
+/// Build transaction to execute UniversalDidResolver.resolveMetadata contract method to receive metadata of a DID
+/// of any DID method supported by the network
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `did` DID to resolve
+///
+/// # Returns
+/// Read transaction to submit
+pub async fn build_resolve_metadata_transaction(
+    client: &LedgerClient,
+    did: &DID,
+) -> VdrResult<Transaction> {
+    build_resolve_transaction(client, METHOD_RESOLVE_METADATA, did).await
+}
+
+/// Parse the result of execution UniversalDidResolver.resolveMetadata contract method
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `bytes` result bytes returned from the ledger
+///
+/// # Returns
+/// parsed DID metadata (only `owner` is set for `did:ethr` DIDs)
+pub fn parse_resolve_metadata_result(
+    client: &LedgerClient,
+    bytes: &[u8],
+) -> VdrResult<DidMetadata> {
+    parse_resolve_result::<MetadataOutput>(client, METHOD_RESOLVE_METADATA, bytes)
+        .map(|metadata| metadata.0)
+}
+
+/// Resolve DID Document of any DID method supported by the network
+///
+/// The DID is resolved with UniversalDidResolver.resolveDocument if the contract is configured in the client.
+/// Otherwise, or if the contract does not support the DID method, the DID is resolved with the method specific
+/// module: IndyDidRegistry for `did:indy2` and EthereumExtDidRegistry events for `did:ethr`.
+///
+/// # Params
+/// - `client` client connected to the network where DID is stored
+/// - `did` DID to resolve
+///
+/// # Returns
+/// resolved DID Document
+pub async fn resolve_any_did(client: &LedgerClient, did: &DID) -> VdrResult<DidDocument> {
+    debug!("Any DID resolution has started. Did: {:?}", did);
+
+    if client.contract(CONTRACT_NAME).is_ok() {
+        let transaction = build_resolve_document_transaction(client, did).await?;
+        let result = client
+            .submit_transaction(&transaction)
+            .await
+            .and_then(|bytes| parse_resolve_document_result(client, &bytes));
+        match result {
+            Err(VdrError::ContractUnsupportedOperation(_, reason)) => {
+                debug!(
+                    "{} does not support the DID method: {}. Falling back to the method registry",
+                    CONTRACT_NAME, reason
+                );
+            }
+            result => {
+                info!("Any DID resolution has finished. Result: {:?}", result);
+
+                return result;
+            }
+        }
+    }
+
+    let did_doc = match did.method() {
+        INDY2_METHOD => {
+            let transaction = did_registry::build_resolve_did_transaction(client, did).await?;
+            let response = client.submit_transaction(&transaction).await?;
+            did_registry::parse_resolve_did_result(client, &response)?
+        }
+        ETHR_METHOD => {
+            did_ethr_resolver::resolve_did(client, did, None)
+                .await?
+                .document
+        }
+        method => {
+            let vdr_error = VdrError::ContractUnsupportedOperation(
+                format!("{}.{}", CONTRACT_NAME, METHOD_RESOLVE_DOCUMENT),
+                format!("Unsupported DID Method: '{}'", method),
+            );
+
+            warn!("Error: {} during any DID resolution", vdr_error);
+
+            return Err(vdr_error);
+        }
+    };
+
+    info!("Any DID resolution has finished. Result: {:?}", did_doc);
+
+    Ok(did_doc)
+}
+
+async fn build_resolve_transaction(
+    client: &LedgerClient,
+    method: &str,
+    did: &DID,
+) -> VdrResult<Transaction> {
+    debug!("{} txn build has started. Did: {:?}", method, did);
+
+    let transaction = TransactionBuilder::new()
+        .set_contract(CONTRACT_NAME)
+        .set_method(method)
+        .add_param(ContractParam::String(did.to_string()))
+        .set_type(TransactionType::Read)
+        .build(client)
+        .await?;
+
+    info!(
+        "{} txn build has finished. Result: {:?}",
+        method, transaction
+    );
+
+    Ok(transaction)
+}
+
+fn parse_resolve_result<T: TryFrom<ContractOutput, Error = VdrError> + std::fmt::Debug>(
+    client: &LedgerClient,
+    method: &str,
+    bytes: &[u8],
+) -> VdrResult<T> {
+    debug!(
+        "{} result parse has started. Bytes to parse: {:?}",
+        method, bytes
+    );
+
+    let result = TransactionParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_method(method)
+        .parse::<T>(client, bytes)?;
+
+    info!("{} result parse has finished. Result: {:?}", method, result);
+
+    Ok(result)
+}
+
+#[derive(Debug)]
+struct DocumentOutput(DidDocument);
+
+impl TryFrom<ContractOutput> for DocumentOutput {
+    type Error = VdrError;
+
+    fn try_from(value: ContractOutput) -> Result<Self, Self::Error> {
+        trace!("DidDocument convert from ContractOutput: {:?}", value);
+
+        DidDocument::try_from(&value).map(DocumentOutput)
+    }
+}
+
+#[derive(Debug)]
+struct MetadataOutput(DidMetadata);
+
+impl TryFrom<ContractOutput> for MetadataOutput {
+    type Error = VdrError;
+
+    fn try_from(value: ContractOutput) -> Result<Self, Self::Error> {
+        trace!("DidMetadata convert from ContractOutput: {:?}", value);
+
+        DidMetadata::try_from(value.get_tuple(0)?).map(MetadataOutput)
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::{
+        client::client::test::{mock_client, CHAIN_ID, UNIVERSAL_DID_RESOLVER_ADDRESS},
+        contracts::did::types::{did_doc::test::did_doc, did_resolution::test::did_record},
+        utils::init_env_logger,
+    };
+    use sha3::{Digest, Keccak256};
+
+    fn encode_call(signature: &str, did: &DID) -> Vec<u8> {
+        let mut data = Keccak256::digest(signature.as_bytes())[..4].to_vec();
+        data.extend(ethabi::encode(&[ContractParam::String(did.to_string())]));
+        data
+    }
+
+    #[async_std::test]
+    async fn build_resolve_document_transaction_test() {
+        init_env_logger();
+        let client = mock_client();
+        let did_doc = did_doc(None);
+        let transaction = build_resolve_document_transaction(&client, &did_doc.id)
+            .await
+            .unwrap();
+        let expected_transaction = Transaction {
+            type_: TransactionType::Read,
+            to: UNIVERSAL_DID_RESOLVER_ADDRESS.clone(),
+            chain_id: CHAIN_ID,
+            data: encode_call("resolveDocument(string)", &did_doc.id),
+            ..Transaction::default()
+        };
+        assert_eq!(expected_transaction, transaction);
+    }
+
+    #[test]
+    fn parse_resolve_document_result_test() {
+        init_env_logger();
+        let client = mock_client();
+        let did_doc = did_doc(None);
+        let data = ethabi::encode(&[ContractParam::String(
+            serde_json::to_string(&did_doc).unwrap(),
+        )]);
+        let parsed_did_doc = parse_resolve_document_result(&client, &data).unwrap();
+        assert_eq!(did_doc, parsed_did_doc);
+    }
+
+    #[async_std::test]
+    async fn build_resolve_metadata_transaction_test() {
+        init_env_logger();
+        let client = mock_client();
+        let did_doc = did_doc(None);
+        let transaction = build_resolve_metadata_transaction(&client, &did_doc.id)
+            .await
+            .unwrap();
+        assert_eq!(
+            encode_call("resolveMetadata(string)", &did_doc.id),
+            transaction.data
+        );
+    }
+
+    #[test]
+    fn parse_resolve_metadata_result_test() {
+        init_env_logger();
+        let client = mock_client();
+        let metadata = did_record(1705396351, false).metadata;
+        let data = ethabi::encode(&[ContractParam::Tuple(vec![
+            (&metadata.owner).try_into().unwrap(),
+            (&metadata.sender).try_into().unwrap(),
+            ContractParam::Uint(metadata.created.into()),
+            ContractParam::Uint(metadata.updated.into()),
+            ContractParam::Bool(metadata.deactivated),
+        ])]);
+        let parsed_metadata = parse_resolve_metadata_result(&client, &data).unwrap();
+        assert_eq!(metadata, parsed_metadata);
+    }
+}
@@ -0,0 +1,179 @@
+use log::{debug, info, trace, warn};
+use sha3::{Digest, Keccak256};
+
+use crate::{
+    client::LedgerClient,
+    error::{VdrError, VdrResult},
+    types::{
+        Address, ContractOutput, ContractParam, SignatureData, TransactionBuilder,
+        TransactionParser, TransactionType,
+    },
+};
+
+const METHOD_NONCE: &str = "nonce";
+const SIGNATURE_LENGTH: usize = 64;
+
+/// Build hash signed by the identity owner to authorize an Endorser to execute `method` of `contract` with `params`:
+///     `keccak256(0x19 0x00 registry chainId nonce method abi.encode(params))` where `nonce` is the current nonce
+///     of the identity in `contract`
+pub(crate) async fn build_endorsing_bytes(
+    client: &LedgerClient,
+    contract: &str,
+    method: &str,
+    identity: &Address,
+    params: &[ContractParam],
+) -> VdrResult<Vec<u8>> {
+    debug!(
+        "{} endorsing bytes build has started. Identity: {:?}, method: {}",
+        contract, identity, method
+    );
+
+    let transaction = TransactionBuilder::new()
+        .set_contract(contract)
+        .set_method(METHOD_NONCE)
+        .add_param(identity.try_into()?)
+        .set_type(TransactionType::Read)
+        .build(client)
+        .await?;
+    let response = client.submit_transaction(&transaction).await?;
+    let nonce = TransactionParser::new()
+        .set_contract(contract)
+        .set_method(METHOD_NONCE)
+        .parse::<NonceOutput>(client, &response)?
+        .0;
+
+    let registry = client.contract(contract)?.address().clone();
+    let bytes = endorsing_bytes(&registry, client.chain_id(), nonce, method, params)?;
+
+    info!(
+        "{} endorsing bytes build has finished. Nonce: {}, result: {:?}",
+        contract, nonce, bytes
+    );
+
+    Ok(bytes)
+}
+
+pub(crate) fn endorsing_bytes(
+    registry: &Address,
+    chain_id: u64,
+    nonce: u64,
+    method: &str,
+    params: &[ContractParam],
+) -> VdrResult<Vec<u8>> {
+    let mut hasher = Keccak256::new();
+    hasher.update([0x19, 0x00]);
+    hasher.update(encode_packed(&registry.try_into()?));
+    hasher.update(encode_packed(&ContractParam::Uint(chain_id.into())));
+    hasher.update(encode_packed(&ContractParam::Uint(nonce.into())));
+    hasher.update(method.as_bytes());
+    hasher.update(ethabi::encode(params));
+    Ok(hasher.finalize().to_vec())
+}
+
+/// Parameters of the identity owner signature passed to the contract: sigV, sigR, sigS
+pub(crate) fn signature_params(signature: &SignatureData) -> VdrResult<Vec<ContractParam>> {
+    if signature.signature.len() != SIGNATURE_LENGTH {
+        let vdr_error = VdrError::SignerInvalidSignature(format!(
+            "Signature must be {} bytes long, got {}",
+            SIGNATURE_LENGTH,
+            signature.signature.len()
+        ));
+
+        warn!("Error: {} during signed txn build", vdr_error);
+
+        return Err(vdr_error);
+    }
+    Ok(vec![
+        ContractParam::Uint((27 + signature.recovery_id).into()),
+        ContractParam::FixedBytes(signature.signature[..32].to_vec()),
+        ContractParam::FixedBytes(signature.signature[32..].to_vec()),
+    ])
+}
+
+/// Solidity `abi.encodePacked` of the parameter types signed by identity owners
+pub(crate) fn encode_packed(param: &ContractParam) -> Vec<u8> {
+    match param {
+        ContractParam::Address(address) => address.as_bytes().to_vec(),
+        ContractParam::Bytes(bytes) | ContractParam::FixedBytes(bytes) => bytes.clone(),
+        ContractParam::String(string) => string.as_bytes().to_vec(),
+        param => ethabi::encode(std::slice::from_ref(param)),
+    }
+}
+
+#[derive(Debug)]
+struct NonceOutput(u64);
+
+impl TryFrom<ContractOutput> for NonceOutput {
+    type Error = VdrError;
+
+    fn try_from(value: ContractOutput) -> Result<Self, Self::Error> {
+        trace!("Nonce convert from ContractOutput: {:?}", value);
+
+        value.get_u64(0).map(NonceOutput)
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::client::client::test::{CHAIN_ID, DID_REGISTRY_ADDRESS};
+
+    #[test]
+    fn endorsing_bytes_test() {
+        let did = "did:indy2:testnet:3LpjszkgTmE3qThge25FZw";
+        let did_doc = "{\"id\":\"did:indy2:testnet:3LpjszkgTmE3qThge25FZw\"}";
+        let params = [
+            ContractParam::String(did.into()),
+            ContractParam::String(did_doc.into()),
+        ];
+
+        // the same bytes hashed by the contract:
+        //  abi.encodePacked(bytes1(0x19), bytes1(0), this, chainid, nonce, method, abi.encode(did, document))
+        let mut packed = vec![0x19, 0x00];
+        packed.extend(hex::decode(&DID_REGISTRY_ADDRESS.as_ref()[2..]).unwrap());
+        packed.extend(ethabi::encode(&[ContractParam::Uint(CHAIN_ID.into())]));
+        let mut nonce = vec![0; 31];
+        nonce.push(5);
+        packed.extend(nonce);
+        packed.extend(b"endorseDid");
+        packed.extend(ethabi::encode(&params));
+
+        assert_eq!(
+            Keccak256::digest(&packed).to_vec(),
+            endorsing_bytes(&DID_REGISTRY_ADDRESS, CHAIN_ID, 5, "endorseDid", &params).unwrap()
+        );
+        assert_ne!(
+            endorsing_bytes(&DID_REGISTRY_ADDRESS, CHAIN_ID, 5, "endorseDid", &params).unwrap(),
+            endorsing_bytes(
+                &DID_REGISTRY_ADDRESS,
+                CHAIN_ID + 1,
+                5,
+                "endorseDid",
+                &params
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn signature_params_test() {
+        let signature = SignatureData {
+            recovery_id: 1,
+            signature: [vec![1; 32], vec![2; 32]].concat(),
+        };
+        assert_eq!(
+            vec![
+                ContractParam::Uint(28.into()),
+                ContractParam::FixedBytes(vec![1; 32]),
+                ContractParam::FixedBytes(vec![2; 32]),
+            ],
+            signature_params(&signature).unwrap()
+        );
+
+        let signature = SignatureData {
+            recovery_id: 0,
+            signature: vec![1; 65],
+        };
+        assert!(signature_params(&signature).is_err());
+    }
+}
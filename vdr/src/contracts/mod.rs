@@ -1,12 +1,7 @@
 pub mod auth;
 pub mod cl;
 pub mod did;
+pub mod endorsement;
 pub mod network;
 
-pub use auth::{role_control, Role};
-pub use cl::{credential_definition_registry, schema_registry, CredentialDefinition, Schema};
-pub use did::{
-    did_registry, DidDocument, DidRecord, Service, ServiceEndpoint, StringOrVector,
-    VerificationMethod, VerificationMethodOrReference,
-};
-pub use network::validator_control;
+pub use did::StringOrVector;
@@ -1,5 +1,2 @@
 pub mod validator_control;
 pub mod validator_info;
-
-pub use validator_control::*;
-pub use validator_info::*;
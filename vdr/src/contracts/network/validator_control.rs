@@ -2,16 +2,21 @@ use log::{debug, info};
 
 use crate::{
     error::VdrResult,
-    types::{Transaction, TransactionBuilder, TransactionParser, TransactionType},
+    types::{
+        EventLog, EventParser, EventStream, LedgerEvent, Transaction, TransactionBuilder,
+        TransactionParser, TransactionType,
+    },
     Address, LedgerClient,
 };
 
-use super::validator_info::ValidatorAddresses;
+use super::validator_info::{ValidatorAddedEvent, ValidatorAddresses, ValidatorRemovedEvent};
 
 const CONTRACT_NAME: &str = "ValidatorControl";
 const METHOD_ADD_VALIDATOR: &str = "addValidator";
 const METHOD_REMOVE_VALIDATOR: &str = "removeValidator";
 const METHOD_GET_VALIDATORS: &str = "getValidators";
+const EVENT_VALIDATOR_ADDED: &str = "ValidatorAdded";
+const EVENT_VALIDATOR_REMOVED: &str = "ValidatorRemoved";
 
 /// Build transaction to execute ValidatorControl.addValidator contract method to add a new Validator
 ///
@@ -140,6 +145,134 @@ pub fn parse_get_validators_result(
     Ok(result)
 }
 
+/// Subscribe to ValidatorControl.ValidatorAdded events emitted when a new validator is added
+///
+/// # Params
+/// - `client` client connected to the network (must support event subscriptions)
+///
+/// # Returns
+/// stream of event logs to parse with [parse_validator_added_event]
+pub async fn subscribe_validator_added(client: &LedgerClient) -> VdrResult<EventStream> {
+    debug!("{} event subscription has started", EVENT_VALIDATOR_ADDED);
+
+    let stream = EventParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_event(EVENT_VALIDATOR_ADDED)
+        .subscribe(client)
+        .await?;
+
+    info!("{} event subscription has finished", EVENT_VALIDATOR_ADDED);
+
+    Ok(stream)
+}
+
+/// Parse ValidatorControl.ValidatorAdded event log
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `log` event log received from the subscription
+///
+/// # Returns
+/// added validator with sender account and the number of validators
+pub fn parse_validator_added_event(
+    client: &LedgerClient,
+    log: &EventLog,
+) -> VdrResult<ValidatorAddedEvent> {
+    debug!(
+        "{} event parse has started. Log to parse: {:?}",
+        EVENT_VALIDATOR_ADDED, log
+    );
+
+    let event = EventParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_event(EVENT_VALIDATOR_ADDED)
+        .parse::<ValidatorAddedEvent>(client, log)?;
+
+    info!(
+        "{} event parse has finished. Result: {:?}",
+        EVENT_VALIDATOR_ADDED, event
+    );
+
+    Ok(event)
+}
+
+/// Subscribe to ValidatorControl.ValidatorRemoved events emitted when a validator is removed
+///
+/// # Params
+/// - `client` client connected to the network (must support event subscriptions)
+///
+/// # Returns
+/// stream of event logs to parse with [parse_validator_removed_event]
+pub async fn subscribe_validator_removed(client: &LedgerClient) -> VdrResult<EventStream> {
+    debug!("{} event subscription has started", EVENT_VALIDATOR_REMOVED);
+
+    let stream = EventParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_event(EVENT_VALIDATOR_REMOVED)
+        .subscribe(client)
+        .await?;
+
+    info!(
+        "{} event subscription has finished",
+        EVENT_VALIDATOR_REMOVED
+    );
+
+    Ok(stream)
+}
+
+/// Parse ValidatorControl.ValidatorRemoved event log
+///
+/// # Params
+/// - `client` client connected to the network where contract will be executed
+/// - `log` event log received from the subscription
+///
+/// # Returns
+/// removed validator with sender account and the number of validators
+pub fn parse_validator_removed_event(
+    client: &LedgerClient,
+    log: &EventLog,
+) -> VdrResult<ValidatorRemovedEvent> {
+    debug!(
+        "{} event parse has started. Log to parse: {:?}",
+        EVENT_VALIDATOR_REMOVED, log
+    );
+
+    let event = EventParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_event(EVENT_VALIDATOR_REMOVED)
+        .parse::<ValidatorRemovedEvent>(client, log)?;
+
+    info!(
+        "{} event parse has finished. Result: {:?}",
+        EVENT_VALIDATOR_REMOVED, event
+    );
+
+    Ok(event)
+}
+
+pub(crate) fn decode_event(
+    client: &LedgerClient,
+    log: &EventLog,
+) -> VdrResult<Option<LedgerEvent>> {
+    if EventParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_event(EVENT_VALIDATOR_ADDED)
+        .matches(client, log)
+    {
+        return parse_validator_added_event(client, log)
+            .map(|event| Some(LedgerEvent::ValidatorAdded(event)));
+    }
+    if EventParser::new()
+        .set_contract(CONTRACT_NAME)
+        .set_event(EVENT_VALIDATOR_REMOVED)
+        .matches(client, log)
+    {
+        return parse_validator_removed_event(client, log)
+            .map(|event| Some(LedgerEvent::ValidatorRemoved(event)));
+    }
+    Ok(None)
+}
+
 #[cfg(test)]
 pub mod test {
     use super::*;
@@ -180,6 +313,7 @@ pub mod test {
                 data: expected_data.into(),
                 signature: RwLock::new(None),
                 hash: None,
+                ..Transaction::default()
             };
 
             assert_eq!(expected_transaction, transaction);
@@ -211,6 +345,7 @@ pub mod test {
                 data: expected_data.into(),
                 signature: RwLock::new(None),
                 hash: None,
+                ..Transaction::default()
             };
 
             assert_eq!(expected_transaction, transaction);
@@ -236,6 +371,7 @@ pub mod test {
                 data: encoded_method.into(),
                 signature: RwLock::new(None),
                 hash: None,
+                ..Transaction::default()
             };
 
             assert_eq!(expected_transaction, transaction);
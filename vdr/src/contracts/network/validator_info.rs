@@ -1,4 +1,5 @@
 use log::trace;
+use serde_derive::{Deserialize, Serialize};
 
 use crate::{error::VdrError, types::ContractOutput, Address};
 
@@ -23,3 +24,69 @@ impl TryFrom<ContractOutput> for ValidatorAddresses {
         validator_addresses
     }
 }
+
+/// Data of ValidatorControl.ValidatorAdded event
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct ValidatorAddedEvent {
+    pub validator: Address,
+    pub by_account: Address,
+    pub num_validators: u8,
+}
+
+impl TryFrom<ContractOutput> for ValidatorAddedEvent {
+    type Error = VdrError;
+
+    fn try_from(value: ContractOutput) -> Result<Self, Self::Error> {
+        trace!(
+            "ValidatorAddedEvent convert from ContractOutput: {:?} has started",
+            value
+        );
+
+        let event = ValidatorAddedEvent {
+            validator: value.get_address(0)?,
+            by_account: value.get_address(1)?,
+            num_validators: value.get_u8(2)?,
+        };
+
+        trace!(
+            "ValidatorAddedEvent convert from ContractOutput: {:?} has finished. Result: {:?}",
+            value,
+            event
+        );
+
+        Ok(event)
+    }
+}
+
+/// Data of ValidatorControl.ValidatorRemoved event
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct ValidatorRemovedEvent {
+    pub validator: Address,
+    pub by_account: Address,
+    pub num_validators: u8,
+}
+
+impl TryFrom<ContractOutput> for ValidatorRemovedEvent {
+    type Error = VdrError;
+
+    fn try_from(value: ContractOutput) -> Result<Self, Self::Error> {
+        trace!(
+            "ValidatorRemovedEvent convert from ContractOutput: {:?} has started",
+            value
+        );
+
+        let event = ValidatorRemovedEvent {
+            validator: value.get_address(0)?,
+            by_account: value.get_address(1)?,
+            num_validators: value.get_u8(2)?,
+        };
+
+        trace!(
+            "ValidatorRemovedEvent convert from ContractOutput: {:?} has finished. Result: {:?}",
+            value,
+            event
+        );
+
+        Ok(event)
+    }
+}
@@ -1,3 +1,7 @@
+mod revert;
+
+use crate::Address;
+
 use log::trace;
 use serde_json::json;
 
@@ -23,12 +27,21 @@ pub enum VdrError {
     #[error("Ledger Client: Transaction reverted: {}", _0)]
     ClientTransactionReverted(String),
 
+    #[error("Ledger Client: Transaction failed: {}", _0)]
+    ClientTransactionFailed(String),
+
+    #[error("Ledger Client: Transaction rejected: {}", _0)]
+    ClientTransactionRejected(String),
+
     #[error("Ledger Client: Unexpected error occurred: {}", _0)]
     ClientUnexpectedError(String),
 
     #[error("Ledger Client: Invalid state {}", _0)]
     ClientInvalidState(String),
 
+    #[error("Ledger Client: Unsupported operation: {}", _0)]
+    ClientUnsupportedOperation(String),
+
     #[error("Contract: Invalid name: {}", _0)]
     ContractInvalidName(String),
 
@@ -41,9 +54,117 @@ pub enum VdrError {
     #[error("Contract: Invalid response data: {}", _0)]
     ContractInvalidResponseData(String),
 
+    #[error("Contract: Sender is not authorized to perform the operation: {}", _0)]
+    ContractUnauthorized(Address),
+
+    #[error("Contract: Required field is missing: {}", _0)]
+    ContractFieldRequired(String),
+
+    #[error("Contract: Conflicting fields are provided: {}", _0)]
+    ContractConflictingFields(String),
+
+    #[error("Contract: Unsupported operation: {}. {}", _0, _1)]
+    ContractUnsupportedOperation(String, String),
+
+    #[error("DID: Not found: {}", _0)]
+    DidNotFound(String),
+
+    #[error("DID: Already exists: {}", _0)]
+    DidAlreadyExists(String),
+
+    #[error("DID: Has been deactivated: {}", _0)]
+    DidHasBeenDeactivated(String),
+
+    #[error("DID: Incorrect DID: {}", _0)]
+    DidIncorrect(String),
+
+    #[error("DID: Invalid identifier: {}", _0)]
+    DidInvalidIdentifier(String),
+
+    #[error("DID: Invalid DID Document: {}", _0)]
+    DidInvalidDocument(String),
+
+    #[error("DID: Sender is not authorized to modify the DID: {}", _0)]
+    DidUnauthorizedSender(Address),
+
+    #[error("DID URL: Resource not found: {}", _0)]
+    DidUrlNotFound(String),
+
+    #[error("Issuer: Not found: {}", _0)]
+    IssuerNotFound(String),
+
+    #[error("Issuer: Invalid issuer id: {}", _0)]
+    IssuerInvalidId(String),
+
+    #[error("Issuer: Has been deactivated: {}", _0)]
+    IssuerHasBeenDeactivated(String),
+
+    #[error(
+        "Issuer: Sender is not authorized to act on behalf of the issuer: {}",
+        _0
+    )]
+    IssuerUnauthorized(Address),
+
+    #[error("Schema: Invalid schema id: {}", _0)]
+    SchemaInvalidId(String),
+
+    #[error("Schema: Already exists: {}", _0)]
+    SchemaAlreadyExists(String),
+
+    #[error("Schema: Not found: {}", _0)]
+    SchemaNotFound(String),
+
+    #[error("Credential Definition: Invalid credential definition id: {}", _0)]
+    CredentialDefinitionInvalidId(String),
+
+    #[error("Credential Definition: Already exists: {}", _0)]
+    CredentialDefinitionAlreadyExists(String),
+
+    #[error("Credential Definition: Not found: {}", _0)]
+    CredentialDefinitionNotFound(String),
+
+    #[error("Validator: Initial validators are required")]
+    ValidatorInitialValidatorsRequired,
+
+    #[error("Validator: Invalid validator address")]
+    ValidatorInvalidAddress,
+
+    #[error("Validator: Invalid validator account address")]
+    ValidatorInvalidAccountAddress,
+
+    #[error("Validator: Number of validators exceeds the limit: {}", _0)]
+    ValidatorLimitExceeded(u16),
+
+    #[error("Validator: Already exists: {}", _0)]
+    ValidatorAlreadyExists(Address),
+
+    #[error("Validator: Sender already has an active validator: {}", _0)]
+    ValidatorSenderHasActiveValidator(Address),
+
+    #[error("Validator: Cannot deactivate the last validator")]
+    ValidatorCannotDeactivateLast,
+
+    #[error("Validator: Not found: {}", _0)]
+    ValidatorNotFound(Address),
+
+    #[error("Upgrade: Insufficient approvals")]
+    UpgradeInsufficientApprovals,
+
+    #[error("Upgrade: Already proposed. Proxy: {}, implementation: {}", _0, _1)]
+    UpgradeAlreadyProposed(Address, Address),
+
+    #[error("Upgrade: Proposal not found. Proxy: {}, implementation: {}", _0, _1)]
+    UpgradeProposalNotFound(Address, Address),
+
+    #[error("Upgrade: Already approved. Proxy: {}, implementation: {}", _0, _1)]
+    UpgradeAlreadyApproved(Address, Address),
+
     #[error("Signer: Invalid private key")]
     SignerInvalidPrivateKey,
 
+    #[error("Signer: Invalid public key: {}", _0)]
+    SignerInvalidPublicKey(String),
+
     #[error("Signer: Invalid message")]
     SignerInvalidMessage,
 
@@ -53,6 +174,31 @@ pub enum VdrError {
     #[error("Signer: Unexpected error occurred: {}", _0)]
     SignerUnexpectedError(String),
 
+    #[error("Signer: Invalid signature: {}", _0)]
+    SignerInvalidSignature(String),
+
+    #[error("Signer: Unsupported operation: {}", _0)]
+    SignerUnsupportedOperation(String),
+
+    #[error("Signer: Invalid keystore: {}", _0)]
+    SignerInvalidKeystore(String),
+
+    #[error("Signer: Invalid keystore password")]
+    SignerInvalidPassword,
+
+    #[error("Signer: Invalid mnemonic: {}", _0)]
+    SignerInvalidMnemonic(String),
+
+    #[error("Signer: Invalid derivation path: {}", _0)]
+    SignerInvalidDerivationPath(String),
+
+    #[error(
+        "Signer: Transaction is signed by: {} instead of its sender: {}",
+        _0,
+        _1
+    )]
+    SignerMismatch(Address, Address),
+
     #[error("Invalid data: {}", _0)]
     CommonInvalidData(String),
 
@@ -0,0 +1,223 @@
+use crate::{
+    error::{VdrError, VdrResult},
+    types::ContractOutput,
+};
+
+use ethabi::{ParamType, Token};
+use log::trace;
+use serde_json::{json, Value};
+
+/// Selector of the standard `Error(string)` revert reason produced by `require`/`revert` statements
+const ERROR_STRING_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+impl VdrError {
+    /// Get the revert data of a reverted transaction.
+    ///     Clients report reverts as JSON-RPC error object with hex encoded revert data in `data`
+    pub(crate) fn revert_data(&self) -> Option<Vec<u8>> {
+        let message = match self {
+            VdrError::ClientTransactionReverted(message)
+            | VdrError::ClientTransactionFailed(message) => message,
+            _ => return None,
+        };
+        let error: Value = serde_json::from_str(message).ok()?;
+        let data = error.get("data")?.as_str()?;
+        hex::decode(data.trim_start_matches("0x")).ok()
+    }
+
+    /// Build error for a transaction which has been mined with the failure status
+    ///     The error is reported in the same format as reverts, so revert data can be decoded
+    ///
+    /// # Params
+    /// - `hash` hash of the failed transaction
+    /// - `data` revert data of the transaction if it could be obtained
+    pub(crate) fn transaction_failed(hash: &[u8], data: Option<&[u8]>) -> VdrError {
+        let mut error = json!({
+            "message": format!("Transaction 0x{} has been mined with failure status", hex::encode(hash)),
+            "transactionHash": format!("0x{}", hex::encode(hash)),
+        });
+        if let Some(data) = data {
+            error["data"] = json!(format!("0x{}", hex::encode(data)));
+        }
+        VdrError::ClientTransactionFailed(error.to_string())
+    }
+
+    /// Replace the message of revert error keeping its kind (rejected or mined with failure status)
+    pub(crate) fn with_revert_message(&self, message: String) -> VdrError {
+        match self {
+            VdrError::ClientTransactionFailed(_) => VdrError::ClientTransactionFailed(message),
+            _ => VdrError::ClientTransactionReverted(message),
+        }
+    }
+
+    /// Get the reason of revert caused by `require`/`revert` statement with a message
+    pub(crate) fn revert_reason(data: &[u8]) -> Option<String> {
+        if data.len() < 4 || data[..4] != ERROR_STRING_SELECTOR {
+            return None;
+        }
+        ethabi::decode(&[ParamType::String], &data[4..])
+            .ok()?
+            .into_iter()
+            .next()
+            .and_then(Token::into_string)
+    }
+
+    /// Convert Solidity custom error raised by the ledger contracts into the matching error variant
+    ///
+    /// # Params
+    /// - `name` name of the custom error
+    /// - `params` decoded error parameters
+    ///
+    /// # Returns
+    /// error variant or `None` if the custom error is unknown
+    pub(crate) fn from_contract_error(
+        name: &str,
+        params: &ContractOutput,
+    ) -> VdrResult<Option<VdrError>> {
+        trace!(
+            "VdrError convert from contract error: {} with params: {:?} has started",
+            name,
+            params
+        );
+
+        let vdr_error = match name {
+            "Unauthorized" => VdrError::ContractUnauthorized(params.get_address(0)?),
+            "FieldRequired" => VdrError::ContractFieldRequired(params.get_string(0)?),
+            "ConflictingFields" => VdrError::ContractConflictingFields(params.get_string(0)?),
+            "UnsupportedOperation" => {
+                VdrError::ContractUnsupportedOperation(params.get_string(0)?, params.get_string(1)?)
+            }
+            "InvalidSignature" => VdrError::SignerInvalidSignature(params.get_string(0)?),
+            "DidNotFound" => VdrError::DidNotFound(params.get_string(0)?),
+            "DidAlreadyExist" => VdrError::DidAlreadyExists(params.get_string(0)?),
+            "DidHasBeenDeactivated" => VdrError::DidHasBeenDeactivated(params.get_string(0)?),
+            "IncorrectDid" => VdrError::DidIncorrect(params.get_string(0)?),
+            "UnauthorizedSender" => VdrError::DidUnauthorizedSender(params.get_address(0)?),
+            "IssuerNotFound" => VdrError::IssuerNotFound(params.get_string(0)?),
+            "InvalidIssuerId" => VdrError::IssuerInvalidId(params.get_string(0)?),
+            "IssuerHasBeenDeactivated" => VdrError::IssuerHasBeenDeactivated(params.get_string(0)?),
+            "UnauthorizedIssuer" => VdrError::IssuerUnauthorized(params.get_address(0)?),
+            "InvalidSchemaId" => VdrError::SchemaInvalidId(params.get_string(0)?),
+            "SchemaAlreadyExist" => VdrError::SchemaAlreadyExists(params.get_string(0)?),
+            "SchemaNotFound" => VdrError::SchemaNotFound(params.get_string(0)?),
+            "InvalidCredentialDefinitionId" => {
+                VdrError::CredentialDefinitionInvalidId(params.get_string(0)?)
+            }
+            "CredentialDefinitionAlreadyExist" => {
+                VdrError::CredentialDefinitionAlreadyExists(params.get_string(0)?)
+            }
+            "CredentialDefinitionNotFound" => {
+                VdrError::CredentialDefinitionNotFound(params.get_string(0)?)
+            }
+            "InitialValidatorsRequired" => VdrError::ValidatorInitialValidatorsRequired,
+            "InvalidValidatorAddress" => VdrError::ValidatorInvalidAddress,
+            "InvalidValidatorAccountAddress" => VdrError::ValidatorInvalidAccountAddress,
+            "ExceedsValidatorLimit" => VdrError::ValidatorLimitExceeded(
+                u16::try_from(params.get_u128(0)?).map_err(|_| {
+                    VdrError::ContractInvalidResponseData("Invalid validator limit".to_string())
+                })?,
+            ),
+            "ValidatorAlreadyExists" => VdrError::ValidatorAlreadyExists(params.get_address(0)?),
+            "SenderHasActiveValidator" => {
+                VdrError::ValidatorSenderHasActiveValidator(params.get_address(0)?)
+            }
+            "CannotDeactivateLastValidator" => VdrError::ValidatorCannotDeactivateLast,
+            "ValidatorNotFound" => VdrError::ValidatorNotFound(params.get_address(0)?),
+            "InsufficientApprovals" => VdrError::UpgradeInsufficientApprovals,
+            "UpgradeAlreadyProposed" => {
+                VdrError::UpgradeAlreadyProposed(params.get_address(0)?, params.get_address(1)?)
+            }
+            "UpgradeProposalNotFound" => {
+                VdrError::UpgradeProposalNotFound(params.get_address(0)?, params.get_address(1)?)
+            }
+            "UpgradeAlreadyApproved" => {
+                VdrError::UpgradeAlreadyApproved(params.get_address(0)?, params.get_address(1)?)
+            }
+            _ => return Ok(None),
+        };
+
+        trace!(
+            "VdrError convert from contract error: {} has finished. Result: {:?}",
+            name,
+            vdr_error
+        );
+
+        Ok(Some(vdr_error))
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+
+    const DID: &str = "did:indy2:testnet:3LpjszkgTmE3qThge25FZw";
+
+    fn reverted(data: &[u8]) -> VdrError {
+        VdrError::ClientTransactionReverted(
+            json!({
+                "code": -32000,
+                "message": "Execution reverted",
+                "data": format!("0x{}", hex::encode(data)),
+            })
+            .to_string(),
+        )
+    }
+
+    #[test]
+    fn revert_data_test() {
+        assert_eq!(Some(vec![1, 2, 3]), reverted(&[1, 2, 3]).revert_data());
+        assert_eq!(
+            None,
+            VdrError::ClientTransactionReverted("reverted".to_string()).revert_data()
+        );
+        assert_eq!(None, VdrError::ClientNodeUnreachable.revert_data());
+    }
+
+    #[test]
+    fn transaction_failed_test() {
+        let err = VdrError::transaction_failed(&[1; 32], Some(&[1, 2, 3]));
+        assert!(matches!(err, VdrError::ClientTransactionFailed(_)));
+        assert_eq!(Some(vec![1, 2, 3]), err.revert_data());
+        assert!(matches!(
+            err.with_revert_message("reason".to_string()),
+            VdrError::ClientTransactionFailed(message) if message == "reason"
+        ));
+
+        assert_eq!(
+            None,
+            VdrError::transaction_failed(&[1; 32], None).revert_data()
+        );
+    }
+
+    #[test]
+    fn revert_reason_test() {
+        let mut data = ERROR_STRING_SELECTOR.to_vec();
+        data.extend(ethabi::encode(&[Token::String("Not allowed".to_string())]));
+        assert_eq!(
+            Some("Not allowed".to_string()),
+            VdrError::revert_reason(&data)
+        );
+        assert_eq!(None, VdrError::revert_reason(&[1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn from_contract_error_test() {
+        let params = ContractOutput::from(vec![Token::String(DID.to_string())]);
+        assert_eq!(
+            Some(VdrError::DidAlreadyExists(DID.to_string())),
+            VdrError::from_contract_error("DidAlreadyExist", &params).unwrap()
+        );
+        let reason = "Invalid signature provided";
+        assert_eq!(
+            Some(VdrError::SignerInvalidSignature(reason.to_string())),
+            VdrError::from_contract_error(
+                "InvalidSignature",
+                &ContractOutput::from(vec![Token::String(reason.to_string())])
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            None,
+            VdrError::from_contract_error("UnknownError", &params).unwrap()
+        );
+    }
+}
@@ -5,19 +5,21 @@ mod error;
 mod types;
 mod utils;
 
-#[cfg(feature = "basic_signer")]
 mod signer;
 
 #[cfg(feature = "migration")]
 pub mod migration;
 
-#[cfg(feature = "ledger_test")]
+#[cfg(any(feature = "ledger_test", feature = "embedded_test"))]
 #[cfg(test)]
 mod test;
 
-pub use client::{Client, Contract, LedgerClient};
+pub use client::{
+    implementation::web3::client::Web3Client, Client, Contract, LedgerClient, LedgerClientBuilder,
+    QuorumClientFactory,
+};
 pub use contracts::{
-    auth::{role_control, Role},
+    auth::{account_control, role_control, Role, RoleAssignedEvent, RoleRevokedEvent},
     cl::{
         credential_definition_registry, schema_registry,
         types::{
@@ -26,19 +28,58 @@ pub use contracts::{
         },
     },
     did::{
-        did_registry,
+        did_registry, did_resolver,
+        ethr::{
+            did_ethr_registry, did_ethr_resolver,
+            types::{
+                delegate_type::DelegateType,
+                did_doc_attribute::{
+                    DidDocAttribute, PublicKeyAttribute, PublicKeyEncoding, PublicKeyPurpose,
+                    PublicKeyType, ServiceAttribute,
+                },
+                did_events::{
+                    DidAttributeChangedEvent, DidDelegateChangedEvent, DidEthrEvent,
+                    DidOwnerChangedEvent,
+                },
+                DidEthrIdentity,
+            },
+        },
         types::{
-            did::DID,
-            did_doc::{DidDocument, VerificationKeyType},
+            did::{DidUrl, DID},
+            did_doc::{
+                DidDocument, DidMetadata, DidRecord, ServiceEndpoint, ServiceEndpointObject,
+                VerificationKeyType,
+            },
             did_doc_builder::DidDocumentBuilder,
+            did_resolution::{
+                DidChange, DidChangeKind, DidDocumentMetadata, DidResolutionError,
+                DidResolutionMetadata, DidResolutionOptions, DidResolutionResult, DidVersion,
+                DID_JSON, DID_LD_JSON,
+            },
+            did_url_dereferencing::{
+                DereferencedResource, DidUrlDereferencingResult, VerificationRelationship,
+                RELATIVE_REF_PARAM, SERVICE_PARAM, URI_LIST, VERSION_ID_PARAM, VERSION_TIME_PARAM,
+            },
         },
+        universal_did_resolver,
+    },
+    network::{
+        validator_control,
+        validator_info::{ValidatorAddedEvent, ValidatorRemovedEvent},
     },
-    network::validator_control,
     StringOrVector,
 };
 pub use error::{VdrError, VdrResult};
 pub use types::*;
 
 pub use crate::client::QuorumConfig;
+#[cfg(feature = "embedded")]
+pub use client::implementation::embedded::{client::EmbeddedClient, genesis::Genesis};
+#[cfg(not(feature = "wasm"))]
+pub use client::implementation::web3::client::Web3WsClient;
+pub use signer::{
+    message_hash, Ed25519KeyPair, Ed25519PublicKey, RemoteSigner, Signer, VerificationKey,
+    X25519KeyPair, X25519PublicKey,
+};
 #[cfg(feature = "basic_signer")]
-pub use signer::{BasicSigner, KeyPair};
+pub use signer::{BasicSigner, DerivationPath, KeyPair, KeystoreKdf, Mnemonic};
@@ -55,7 +55,7 @@ impl SchemaId {
 
             vdr_error
         })?;
-        let issuer_did = DID::build(DID_METHOD, NETWORK, id);
+        let issuer_did = DID::build(DID_METHOD, NETWORK, id)?;
 
         let besu_schema_id = SchemaId::build(&issuer_did, name, version);
 
@@ -116,7 +116,7 @@ impl TryFrom<IndySchemaFormat> for Schema {
 
             vdr_error
         })?;
-        let issuer_id = DID::build(DID_METHOD, NETWORK, id);
+        let issuer_id = DID::build(DID_METHOD, NETWORK, id)?;
 
         let besu_schema = Schema {
             id: SchemaId::build(&issuer_id, &schema.name, &schema.version),
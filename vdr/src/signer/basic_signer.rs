@@ -1,8 +1,20 @@
-use crate::error::{VdrError, VdrResult};
+use crate::{
+    error::{VdrError, VdrResult},
+    signer::{
+        keystore::{decrypt_key, encrypt_key},
+        DerivationPath, KeystoreKdf, Signer,
+    },
+};
 
-use log::warn;
+use async_trait::async_trait;
+use log::{trace, warn};
 use secp256k1::{All, Message, PublicKey, Secp256k1, SecretKey};
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+use zeroize::Zeroizing;
 
 use crate::types::{Address, SignatureData};
 use std::str::FromStr;
@@ -10,12 +22,21 @@ use web3::signing::keccak256;
 
 pub struct KeyPair {
     pub public_key: PublicKey,
-    private_key: SecretKey,
+    // `SecretKey` is `Copy` and cannot be reliably wiped, so only the key bytes are kept
+    // (zeroized on drop) and the key is restored for the time of signing
+    private_key: Zeroizing<[u8; 32]>,
+}
+
+impl KeyPair {
+    fn secret_key(&self) -> VdrResult<SecretKey> {
+        Ok(SecretKey::from_slice(&*self.private_key)?)
+    }
 }
 
 pub struct BasicSigner {
     secp: Secp256k1<All>,
     keys: HashMap<String, KeyPair>,
+    derivation_paths: HashMap<String, DerivationPath>,
 }
 
 impl BasicSigner {
@@ -23,6 +44,7 @@ impl BasicSigner {
         Ok(BasicSigner {
             secp: Secp256k1::new(),
             keys: HashMap::new(),
+            derivation_paths: HashMap::new(),
         })
     }
 
@@ -33,6 +55,180 @@ impl BasicSigner {
         Ok((account, public_key_bytes))
     }
 
+    /// Add key derived from BIP-32 seed (see [crate::Mnemonic::to_seed])
+    ///     Derivation path of the account is remembered and can be looked up with [BasicSigner::derivation_path]
+    ///
+    /// # Params
+    /// - `seed` BIP-32 seed
+    /// - `path` derivation path of the key ([DerivationPath::ethereum] for standard accounts)
+    ///
+    /// # Returns
+    /// account address and uncompressed public key
+    pub fn create_hd_key(
+        &mut self,
+        seed: &[u8],
+        path: &DerivationPath,
+    ) -> VdrResult<(Address, Vec<u8>)> {
+        let (account, key_pair) = self.derive_account(seed, path)?;
+        let public_key_bytes = key_pair.public_key.serialize_uncompressed().to_vec();
+        self.keys.insert(account.to_string(), key_pair);
+        self.derivation_paths
+            .insert(account.to_string(), path.clone());
+
+        trace!("Added key of account: {:?} derived at: {}", account, path);
+
+        Ok((account, public_key_bytes))
+    }
+
+    /// Regenerate keys of first accounts derived from BIP-32 seed by BIP-44 Ethereum path `m/44'/60'/0'/0/i`
+    ///
+    /// # Params
+    /// - `seed` BIP-32 seed
+    /// - `count` number of accounts to regenerate
+    ///
+    /// # Returns
+    /// addresses of regenerated accounts ordered by index
+    pub fn recover_hd_keys(&mut self, seed: &[u8], count: u32) -> VdrResult<Vec<Address>> {
+        (0..count)
+            .map(|index| {
+                self.create_hd_key(seed, &DerivationPath::ethereum(index))
+                    .map(|(account, _)| account)
+            })
+            .collect()
+    }
+
+    /// Get derivation path of an account added with [BasicSigner::create_hd_key]
+    ///
+    /// # Params
+    /// - `account` address of the account
+    ///
+    /// # Returns
+    /// derivation path or `None` if the key was not derived from a seed
+    pub fn derivation_path(&self, account: &str) -> Option<&DerivationPath> {
+        self.derivation_paths.get(account)
+    }
+
+    /// Import key from keystore v3 (Web3 Secret Storage) JSON
+    ///
+    /// # Params
+    /// - `keystore` keystore JSON string
+    /// - `password` password the key is encrypted with
+    ///
+    /// # Returns
+    /// account address and uncompressed public key
+    pub fn import_keystore(
+        &mut self,
+        keystore: &str,
+        password: &str,
+    ) -> VdrResult<(Address, Vec<u8>)> {
+        let (account, key_pair) = self.keystore_account(keystore, password)?;
+        let public_key_bytes = key_pair.public_key.serialize_uncompressed().to_vec();
+        self.keys.insert(account.to_string(), key_pair);
+
+        trace!("Imported key of account: {:?} from keystore", account);
+
+        Ok((account, public_key_bytes))
+    }
+
+    /// Export key of an account into keystore v3 (Web3 Secret Storage) JSON
+    ///
+    /// # Params
+    /// - `account` address of the account to export
+    /// - `password` password to encrypt the key with
+    /// - `kdf` key derivation function used for encryption ([KeystoreKdf::default] for standard one)
+    ///
+    /// # Returns
+    /// keystore JSON string
+    pub fn export_keystore(
+        &self,
+        account: &str,
+        password: &str,
+        kdf: &KeystoreKdf,
+    ) -> VdrResult<String> {
+        let key = self.key_for_account(account)?;
+        encrypt_key(&*key.private_key, &Address::from(account), password, kdf)
+    }
+
+    /// Import all keystores located in a directory
+    ///     Keys are imported only if all keystores are decrypted successfully
+    ///
+    /// # Params
+    /// - `dir` directory containing keystore files (hidden files are skipped)
+    /// - `password` password all keys are encrypted with
+    ///
+    /// # Returns
+    /// addresses of imported accounts
+    pub fn load_keystore_dir(&mut self, dir: &Path, password: &str) -> VdrResult<Vec<Address>> {
+        let mut key_pairs = Vec::new();
+        for entry in fs::read_dir(dir).map_err(|err| io_error(dir, err))? {
+            let path = entry.map_err(|err| io_error(dir, err))?.path();
+            let hidden = path
+                .file_name()
+                .map(|name| name.to_string_lossy().starts_with('.'))
+                .unwrap_or(true);
+            if !path.is_file() || hidden {
+                continue;
+            }
+            let keystore = fs::read_to_string(&path).map_err(|err| io_error(&path, err))?;
+            let key_pair = self
+                .keystore_account(&keystore, password)
+                .map_err(|vdr_error| {
+                    warn!("Error: {} during loading keystore {:?}", vdr_error, path);
+
+                    vdr_error
+                })?;
+            key_pairs.push(key_pair);
+        }
+        let accounts: Vec<Address> = key_pairs
+            .into_iter()
+            .map(|(account, key_pair)| {
+                self.keys.insert(account.to_string(), key_pair);
+                account
+            })
+            .collect();
+
+        trace!("Loaded accounts: {:?} from {:?}", accounts, dir);
+
+        Ok(accounts)
+    }
+
+    /// Save key of an account as keystore file into a directory
+    ///     File is named after the account address and is readable by the owner only
+    ///
+    /// # Params
+    /// - `dir` directory to save keystore file
+    /// - `account` address of the account to save
+    /// - `password` password to encrypt the key with
+    /// - `kdf` key derivation function used for encryption
+    ///
+    /// # Returns
+    /// path of the created file
+    pub fn save_keystore(
+        &self,
+        dir: &Path,
+        account: &str,
+        password: &str,
+        kdf: &KeystoreKdf,
+    ) -> VdrResult<PathBuf> {
+        let keystore = self.export_keystore(account, password, kdf)?;
+        let path = dir.join(format!(
+            "{}.json",
+            account.trim_start_matches("0x").to_lowercase()
+        ));
+
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        let mut file = options.open(&path).map_err(|err| io_error(&path, err))?;
+        std::io::Write::write_all(&mut file, keystore.as_bytes())
+            .map_err(|err| io_error(&path, err))?;
+
+        trace!("Saved key of account: {} into {:?}", account, path);
+
+        Ok(path)
+    }
+
     fn key_for_account(&self, account: &str) -> VdrResult<&KeyPair> {
         self.keys.get(account).ok_or_else(|| {
             let vdr_error = VdrError::SignerMissingKey(account.to_string());
@@ -56,13 +252,32 @@ impl BasicSigner {
             Some(private_key) => SecretKey::from_str(private_key)?,
             None => SecretKey::new(&mut rand::thread_rng()),
         };
+        Ok(self.key_pair(private_key))
+    }
+
+    pub fn derive_account(
+        &self,
+        seed: &[u8],
+        path: &DerivationPath,
+    ) -> VdrResult<(Address, KeyPair)> {
+        let private_key = path.derive_key(&self.secp, seed)?;
+        Ok(self.key_pair(private_key))
+    }
+
+    fn keystore_account(&self, keystore: &str, password: &str) -> VdrResult<(Address, KeyPair)> {
+        let private_key = decrypt_key(keystore, password)?;
+        let private_key = SecretKey::from_slice(&private_key)?;
+        Ok(self.key_pair(private_key))
+    }
+
+    fn key_pair(&self, private_key: SecretKey) -> (Address, KeyPair) {
         let public_key = PublicKey::from_secret_key(&self.secp, &private_key);
         let address = Address::from(self.account_from_key(&public_key).as_str());
         let key_pair = KeyPair {
             public_key,
-            private_key,
+            private_key: Zeroizing::new(private_key.secret_bytes()),
         };
-        Ok((address, key_pair))
+        (address, key_pair)
     }
 
     pub fn sign(&self, message: &[u8], account: &str) -> VdrResult<SignatureData> {
@@ -70,7 +285,7 @@ impl BasicSigner {
         let message = Message::from_digest_slice(message)?;
         let (recovery_id, signature) = self
             .secp
-            .sign_ecdsa_recoverable(&message, &key.private_key)
+            .sign_ecdsa_recoverable(&message, &key.secret_key()?)
             .serialize_compact();
         Ok(SignatureData {
             recovery_id: recovery_id.to_i32() as u64,
@@ -79,9 +294,38 @@ impl BasicSigner {
     }
 }
 
+fn io_error(path: &Path, err: std::io::Error) -> VdrError {
+    let vdr_error =
+        VdrError::SignerUnexpectedError(format!("Unable to access keystore {:?}: {}", path, err));
+
+    warn!("Error: {} during keystore file processing", vdr_error);
+
+    vdr_error
+}
+
+#[cfg_attr(not(feature = "wasm"), async_trait)]
+#[cfg_attr(feature = "wasm", async_trait(?Send))]
+impl Signer for BasicSigner {
+    async fn accounts(&self) -> VdrResult<Vec<Address>> {
+        Ok(self
+            .keys
+            .keys()
+            .map(|account| Address::from(account.as_str()))
+            .collect())
+    }
+
+    async fn sign_hash(&self, hash: &[u8], account: &Address) -> VdrResult<SignatureData> {
+        self.sign(hash, account.as_ref())
+    }
+}
+
 #[cfg(test)]
 pub mod test {
     use super::*;
+    use crate::signer::{
+        hd_wallet::{test::MNEMONIC, Mnemonic},
+        keystore::test::LIGHT_KDF,
+    };
     use once_cell::sync::Lazy;
 
     pub static TRUSTEE_ACC: Lazy<Address> =
@@ -125,4 +369,141 @@ pub mod test {
         ];
         assert_eq!(expected, signature.signature);
     }
+
+    #[async_std::test]
+    async fn basic_signer_accounts_test() {
+        let accounts = basic_signer().accounts().await.unwrap();
+        assert_eq!(vec![TRUSTEE_ACC.clone()], accounts);
+    }
+
+    #[async_std::test]
+    async fn basic_signer_sign_message_test() {
+        let basic_signer = basic_signer();
+        let message = b"message";
+
+        let signature = basic_signer
+            .sign_message(message, &TRUSTEE_ACC)
+            .await
+            .unwrap();
+
+        let expected = basic_signer
+            .sign(&crate::signer::message_hash(message), TRUSTEE_ACC.as_ref())
+            .unwrap();
+        assert_eq!(expected, signature);
+    }
+
+    #[test]
+    fn create_hd_key_test() {
+        let seed = Mnemonic::from_phrase(MNEMONIC)
+            .unwrap()
+            .to_seed("")
+            .unwrap();
+        let mut signer = BasicSigner::new().unwrap();
+
+        let (account, _) = signer
+            .create_hd_key(&seed, &DerivationPath::ethereum(0))
+            .unwrap();
+        assert_eq!(
+            Address::from("0x9858effd232b4033e47d90003d41ec34ecaeda94"),
+            account
+        );
+        assert_eq!(
+            Some(&DerivationPath::ethereum(0)),
+            signer.derivation_path(account.as_ref())
+        );
+        assert_eq!(None, signer.derivation_path(TRUSTEE_ACC.as_ref()));
+    }
+
+    #[test]
+    fn recover_hd_keys_test() {
+        let seed =
+            Mnemonic::from_phrase("test test test test test test test test test test test junk")
+                .unwrap()
+                .to_seed("")
+                .unwrap();
+        let mut signer = BasicSigner::new().unwrap();
+
+        let accounts = signer.recover_hd_keys(&seed, 2).unwrap();
+        assert_eq!(
+            vec![
+                Address::from("0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266"),
+                Address::from("0x70997970c51812dc3a010c7d01b50e0d17dc79c8"),
+            ],
+            accounts
+        );
+        assert_eq!(
+            Some(&DerivationPath::ethereum(1)),
+            signer.derivation_path(accounts[1].as_ref())
+        );
+    }
+
+    #[test]
+    fn export_import_keystore_test() {
+        let keystore = basic_signer()
+            .export_keystore(TRUSTEE_ACC.as_ref(), "password", &LIGHT_KDF)
+            .unwrap();
+
+        let mut signer = BasicSigner::new().unwrap();
+        let (account, _) = signer.import_keystore(&keystore, "password").unwrap();
+        assert_eq!(*TRUSTEE_ACC, account);
+
+        let data = [1; 32];
+        assert_eq!(
+            basic_signer().sign(&data, TRUSTEE_ACC.as_ref()).unwrap(),
+            signer.sign(&data, TRUSTEE_ACC.as_ref()).unwrap()
+        );
+    }
+
+    /// Temporary directory removed when dropped (also when a test panics)
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new() -> TempDir {
+            let dir = std::env::temp_dir().join(format!("vdr-keystore-{}", rand::random::<u64>()));
+            fs::create_dir(&dir).unwrap();
+            TempDir(dir)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn save_load_keystore_dir_test() {
+        let temp_dir = TempDir::new();
+        let dir = &temp_dir.0;
+
+        let path = basic_signer()
+            .save_keystore(dir, TRUSTEE_ACC.as_ref(), "password", &LIGHT_KDF)
+            .unwrap();
+        assert!(path.starts_with(dir));
+
+        let mut signer = BasicSigner::new().unwrap();
+        let accounts = signer.load_keystore_dir(dir, "password").unwrap();
+        assert_eq!(vec![TRUSTEE_ACC.clone()], accounts);
+
+        let err = BasicSigner::new()
+            .unwrap()
+            .load_keystore_dir(dir, "wrong")
+            .unwrap_err();
+        assert_eq!(VdrError::SignerInvalidPassword, err);
+    }
+
+    #[test]
+    fn load_keystore_dir_imports_all_or_nothing_test() {
+        let temp_dir = TempDir::new();
+        let dir = &temp_dir.0;
+        basic_signer()
+            .save_keystore(dir, TRUSTEE_ACC.as_ref(), "password", &LIGHT_KDF)
+            .unwrap();
+        fs::write(dir.join("broken.json"), "{}").unwrap();
+
+        let mut signer = BasicSigner::new().unwrap();
+        let err = signer.load_keystore_dir(dir, "password").unwrap_err();
+        assert!(matches!(err, VdrError::SignerInvalidKeystore(_)));
+        assert!(signer.key_for_account(TRUSTEE_ACC.as_ref()).is_err());
+    }
 }
@@ -0,0 +1,444 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use ed25519_dalek::{Signature, Signer as EdSigner, SigningKey, VerifyingKey};
+use log::{trace, warn};
+use rand_core::OsRng;
+use serde_json::{json, Value};
+use std::fmt;
+use x25519_dalek::{PublicKey as XPublicKey, StaticSecret};
+use zeroize::Zeroizing;
+
+use crate::{
+    error::{VdrError, VdrResult},
+    VerificationKeyType,
+};
+
+const KEY_LENGTH: usize = 32;
+pub(crate) const MULTIBASE_BASE58_BTC: char = 'z';
+const ED25519_MULTICODEC: [u8; 2] = [0xed, 0x01];
+const X25519_MULTICODEC: [u8; 2] = [0xec, 0x01];
+const JWK_KEY_TYPE: &str = "OKP";
+
+/// Public key which can be published as DID Document verification method
+pub trait VerificationKey {
+    /// Raw key encoded as base58 string (`publicKeyBase58`)
+    fn to_base58(&self) -> String;
+
+    /// Multicodec prefixed key encoded as base58btc multibase (`publicKeyMultibase`)
+    fn to_multibase(&self) -> String;
+
+    /// Key encoded as JSON Web Key (`publicKeyJwk`)
+    fn to_jwk(&self) -> Value;
+
+    /// Check whether the key can be published as verification method of the given type
+    fn supports(&self, type_: &VerificationKeyType) -> bool;
+}
+
+/// Ed25519 public key (Indy verkey)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ed25519PublicKey([u8; KEY_LENGTH]);
+
+/// X25519 public key used for key agreement
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct X25519PublicKey([u8; KEY_LENGTH]);
+
+/// Ed25519 key pair able to sign messages
+pub struct Ed25519KeyPair {
+    public_key: Ed25519PublicKey,
+    private_key: SigningKey,
+}
+
+/// X25519 key pair
+pub struct X25519KeyPair {
+    public_key: X25519PublicKey,
+    private_key: StaticSecret,
+}
+
+impl Ed25519KeyPair {
+    /// Generate new random key pair
+    pub fn generate() -> VdrResult<Ed25519KeyPair> {
+        Ok(Ed25519KeyPair::from_private_key(SigningKey::generate(
+            &mut OsRng,
+        )))
+    }
+
+    /// Restore key pair from 32 bytes private key seed
+    pub fn from_seed(seed: &[u8]) -> VdrResult<Ed25519KeyPair> {
+        let seed = Zeroizing::new(
+            <[u8; KEY_LENGTH]>::try_from(seed).map_err(|_| VdrError::SignerInvalidPrivateKey)?,
+        );
+        Ok(Ed25519KeyPair::from_private_key(SigningKey::from_bytes(
+            &seed,
+        )))
+    }
+
+    fn from_private_key(private_key: SigningKey) -> Ed25519KeyPair {
+        Ed25519KeyPair {
+            public_key: Ed25519PublicKey(private_key.verifying_key().to_bytes()),
+            private_key,
+        }
+    }
+
+    pub fn public_key(&self) -> &Ed25519PublicKey {
+        &self.public_key
+    }
+
+    /// Sign message
+    ///
+    /// # Params
+    /// - `message` message bytes (signed as is, without hashing)
+    ///
+    /// # Returns
+    /// 64 bytes Ed25519 signature
+    pub fn sign(&self, message: &[u8]) -> VdrResult<Vec<u8>> {
+        Ok(self.private_key.sign(message).to_vec())
+    }
+
+    /// Convert into X25519 key pair for key agreement
+    pub fn to_x25519(&self) -> VdrResult<X25519KeyPair> {
+        let secret = Zeroizing::new(self.private_key.to_scalar_bytes());
+        X25519KeyPair::from_secret(&*secret)
+    }
+}
+
+impl X25519KeyPair {
+    /// Generate new random key pair
+    pub fn generate() -> VdrResult<X25519KeyPair> {
+        Ok(X25519KeyPair::from_private_key(
+            StaticSecret::random_from_rng(OsRng),
+        ))
+    }
+
+    /// Restore key pair from 32 bytes secret
+    pub fn from_secret(secret: &[u8]) -> VdrResult<X25519KeyPair> {
+        let secret = Zeroizing::new(
+            <[u8; KEY_LENGTH]>::try_from(secret).map_err(|_| VdrError::SignerInvalidPrivateKey)?,
+        );
+        Ok(X25519KeyPair::from_private_key(StaticSecret::from(*secret)))
+    }
+
+    fn from_private_key(private_key: StaticSecret) -> X25519KeyPair {
+        X25519KeyPair {
+            public_key: X25519PublicKey(XPublicKey::from(&private_key).to_bytes()),
+            private_key,
+        }
+    }
+
+    pub fn public_key(&self) -> &X25519PublicKey {
+        &self.public_key
+    }
+
+    /// Compute shared secret with other party (X25519 key agreement)
+    ///
+    /// # Params
+    /// - `public_key` public key of the other party
+    ///
+    /// # Returns
+    /// 32 bytes shared secret
+    pub fn diffie_hellman(&self, public_key: &X25519PublicKey) -> VdrResult<Zeroizing<Vec<u8>>> {
+        let shared_secret = self
+            .private_key
+            .diffie_hellman(&XPublicKey::from(public_key.0));
+        if !shared_secret.was_contributory() {
+            return Err(public_key_error(
+                "Key agreement with low order key".to_string(),
+            ));
+        }
+        Ok(Zeroizing::new(shared_secret.as_bytes().to_vec()))
+    }
+}
+
+impl Ed25519PublicKey {
+    pub fn from_bytes(bytes: &[u8]) -> VdrResult<Ed25519PublicKey> {
+        Ok(Ed25519PublicKey(key_bytes(bytes)?))
+    }
+
+    /// Parse key from base58 string (Indy verkey)
+    pub fn from_base58(key: &str) -> VdrResult<Ed25519PublicKey> {
+        Ed25519PublicKey::from_bytes(&decode_base58(key)?)
+    }
+
+    /// Parse key from multicodec prefixed base58btc multibase string
+    pub fn from_multibase(key: &str) -> VdrResult<Ed25519PublicKey> {
+        Ed25519PublicKey::from_bytes(&decode_multibase(key, &ED25519_MULTICODEC)?)
+    }
+
+    /// Parse key from JSON Web Key
+    pub fn from_jwk(jwk: &Value) -> VdrResult<Ed25519PublicKey> {
+        Ed25519PublicKey::from_bytes(&decode_jwk(jwk, "Ed25519")?)
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// Encode key as base58 string (Indy verkey)
+    pub fn to_base58(&self) -> String {
+        bs58::encode(self.0).into_string()
+    }
+
+    /// Verify signature of message
+    ///
+    /// # Params
+    /// - `message` signed message bytes
+    /// - `signature` Ed25519 signature
+    ///
+    /// # Returns
+    /// whether the signature is valid
+    pub fn verify(&self, message: &[u8], signature: &[u8]) -> VdrResult<bool> {
+        let public_key =
+            VerifyingKey::from_bytes(&self.0).map_err(|err| public_key_error(err.to_string()))?;
+        let valid = Signature::from_slice(signature)
+            .and_then(|signature| public_key.verify_strict(message, &signature))
+            .is_ok();
+
+        trace!("Verified Ed25519 signature. Result: {}", valid);
+
+        Ok(valid)
+    }
+
+    /// Convert into X25519 public key (birational map `u = (1 + y) / (1 - y)`)
+    pub fn to_x25519(&self) -> VdrResult<X25519PublicKey> {
+        let public_key = VerifyingKey::from_bytes(&self.0)
+            .map_err(|_| public_key_error("Key can not be converted".to_string()))?;
+        Ok(X25519PublicKey(public_key.to_montgomery().to_bytes()))
+    }
+}
+
+impl X25519PublicKey {
+    pub fn from_bytes(bytes: &[u8]) -> VdrResult<X25519PublicKey> {
+        Ok(X25519PublicKey(key_bytes(bytes)?))
+    }
+
+    pub fn from_base58(key: &str) -> VdrResult<X25519PublicKey> {
+        X25519PublicKey::from_bytes(&decode_base58(key)?)
+    }
+
+    pub fn from_multibase(key: &str) -> VdrResult<X25519PublicKey> {
+        X25519PublicKey::from_bytes(&decode_multibase(key, &X25519_MULTICODEC)?)
+    }
+
+    pub fn from_jwk(jwk: &Value) -> VdrResult<X25519PublicKey> {
+        X25519PublicKey::from_bytes(&decode_jwk(jwk, "X25519")?)
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn to_base58(&self) -> String {
+        bs58::encode(self.0).into_string()
+    }
+}
+
+impl VerificationKey for Ed25519PublicKey {
+    fn to_base58(&self) -> String {
+        Ed25519PublicKey::to_base58(self)
+    }
+
+    fn to_multibase(&self) -> String {
+        encode_multibase(&ED25519_MULTICODEC, &self.0)
+    }
+
+    fn to_jwk(&self) -> Value {
+        encode_jwk("Ed25519", &self.0)
+    }
+
+    fn supports(&self, type_: &VerificationKeyType) -> bool {
+        matches!(
+            type_,
+            VerificationKeyType::Ed25519VerificationKey2018
+                | VerificationKeyType::Ed25519VerificationKey2020
+                | VerificationKeyType::JsonWebKey2020
+        )
+    }
+}
+
+impl VerificationKey for X25519PublicKey {
+    fn to_base58(&self) -> String {
+        X25519PublicKey::to_base58(self)
+    }
+
+    fn to_multibase(&self) -> String {
+        encode_multibase(&X25519_MULTICODEC, &self.0)
+    }
+
+    fn to_jwk(&self) -> Value {
+        encode_jwk("X25519", &self.0)
+    }
+
+    fn supports(&self, type_: &VerificationKeyType) -> bool {
+        matches!(
+            type_,
+            VerificationKeyType::X25519KeyAgreementKey2019
+                | VerificationKeyType::X25519KeyAgreementKey2020
+                | VerificationKeyType::JsonWebKey2020
+        )
+    }
+}
+
+impl fmt::Debug for Ed25519KeyPair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Ed25519KeyPair")
+            .field("public_key", &self.public_key)
+            .finish_non_exhaustive()
+    }
+}
+
+impl fmt::Debug for X25519KeyPair {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("X25519KeyPair")
+            .field("public_key", &self.public_key)
+            .finish_non_exhaustive()
+    }
+}
+
+fn key_bytes(bytes: &[u8]) -> VdrResult<[u8; KEY_LENGTH]> {
+    bytes.try_into().map_err(|_| {
+        public_key_error(format!(
+            "Key must be {} bytes long, got {}",
+            KEY_LENGTH,
+            bytes.len()
+        ))
+    })
+}
+
+fn encode_multibase(codec: &[u8], key: &[u8]) -> String {
+    format!(
+        "{}{}",
+        MULTIBASE_BASE58_BTC,
+        bs58::encode([codec, key].concat()).into_string()
+    )
+}
+
+fn decode_base58(key: &str) -> VdrResult<Vec<u8>> {
+    bs58::decode(key)
+        .into_vec()
+        .map_err(|err| public_key_error(format!("Invalid base58 string: {}", err)))
+}
+
+fn decode_multibase(key: &str, codec: &[u8]) -> VdrResult<Vec<u8>> {
+    let key = key
+        .strip_prefix(MULTIBASE_BASE58_BTC)
+        .ok_or_else(|| public_key_error(format!("Unsupported multibase encoding: {}", key)))?;
+    decode_base58(key)?
+        .strip_prefix(codec)
+        .map(|key| key.to_vec())
+        .ok_or_else(|| public_key_error("Unexpected multicodec key type".to_string()))
+}
+
+fn encode_jwk(curve: &str, key: &[u8]) -> Value {
+    let x = URL_SAFE_NO_PAD.encode(key);
+    json!({
+        "kty": JWK_KEY_TYPE,
+        "crv": curve,
+        "x": x,
+    })
+}
+
+fn decode_jwk(jwk: &Value, curve: &str) -> VdrResult<Vec<u8>> {
+    if jwk["kty"] != JWK_KEY_TYPE || jwk["crv"] != curve {
+        return Err(public_key_error(format!(
+            "Expected {} {} JWK",
+            JWK_KEY_TYPE, curve
+        )));
+    }
+    let x = jwk["x"]
+        .as_str()
+        .ok_or_else(|| public_key_error("JWK does not contain key".to_string()))?;
+    URL_SAFE_NO_PAD
+        .decode(x)
+        .map_err(|err| public_key_error(format!("Invalid base64 string: {}", err)))
+}
+
+fn public_key_error(message: String) -> VdrError {
+    let vdr_error = VdrError::SignerInvalidPublicKey(message);
+
+    warn!("Error: {} during public key processing", vdr_error);
+
+    vdr_error
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+
+    // RFC 8032 test vector 1
+    pub const SEED: &str = "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+    const PUBLIC_KEY: &str = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+    const SIGNATURE: &str = "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b";
+
+    pub fn key_pair() -> Ed25519KeyPair {
+        Ed25519KeyPair::from_seed(&hex::decode(SEED).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn sign_verify_test() {
+        let key_pair = key_pair();
+        assert_eq!(PUBLIC_KEY, hex::encode(key_pair.public_key().as_bytes()));
+
+        let signature = key_pair.sign(b"").unwrap();
+        assert_eq!(SIGNATURE, hex::encode(&signature));
+        assert!(key_pair.public_key().verify(b"", &signature).unwrap());
+        assert!(!key_pair.public_key().verify(b"other", &signature).unwrap());
+        assert!(!key_pair.public_key().verify(b"", &[0; 3]).unwrap());
+    }
+
+    #[test]
+    fn encode_decode_public_key_test() {
+        let public_key = key_pair().public_key().clone();
+
+        let multibase = public_key.to_multibase();
+        assert!(multibase.starts_with("z6Mk"));
+        assert_eq!(
+            public_key,
+            Ed25519PublicKey::from_multibase(&multibase).unwrap()
+        );
+        assert_eq!(
+            public_key,
+            Ed25519PublicKey::from_base58(&public_key.to_base58()).unwrap()
+        );
+
+        let jwk = public_key.to_jwk();
+        assert_eq!(
+            json!({"kty": "OKP", "crv": "Ed25519", "x": "11qYAYKxCrfVS_7TyWQHOg7hcvPapiMlrwIaaPcHURo"}),
+            jwk
+        );
+        assert_eq!(public_key, Ed25519PublicKey::from_jwk(&jwk).unwrap());
+
+        let x25519_multibase = public_key.to_x25519().unwrap().to_multibase();
+        assert!(x25519_multibase.starts_with("z6LS"));
+        Ed25519PublicKey::from_multibase(&x25519_multibase).unwrap_err();
+    }
+
+    #[test]
+    fn convert_to_x25519_test() {
+        let key_pair = key_pair();
+        let x25519_key_pair = key_pair.to_x25519().unwrap();
+        assert_eq!(
+            x25519_key_pair.public_key(),
+            &key_pair.public_key().to_x25519().unwrap()
+        );
+
+        let other = X25519KeyPair::generate().unwrap();
+        assert_eq!(
+            x25519_key_pair.diffie_hellman(other.public_key()).unwrap(),
+            other.diffie_hellman(x25519_key_pair.public_key()).unwrap()
+        );
+
+        let generated = Ed25519KeyPair::generate().unwrap();
+        assert_eq!(
+            generated.to_x25519().unwrap().public_key(),
+            &generated.public_key().to_x25519().unwrap()
+        );
+    }
+
+    #[test]
+    fn invalid_public_key_test() {
+        let err = Ed25519PublicKey::from_bytes(&[1; 31]).unwrap_err();
+        assert!(matches!(err, VdrError::SignerInvalidPublicKey(_)));
+
+        let err = Ed25519PublicKey::from_multibase("8rnQ4gvtEYi59DMAzN7FyCVatVATkFo7wPXVMy38WmvG")
+            .unwrap_err();
+        assert!(matches!(err, VdrError::SignerInvalidPublicKey(_)));
+    }
+}
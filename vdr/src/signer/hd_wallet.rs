@@ -0,0 +1,412 @@
+use log::{trace, warn};
+use openssl::{
+    hash::MessageDigest, pkcs5::pbkdf2_hmac, pkey::PKey, sha::sha256, sign::Signer as HmacSigner,
+};
+use rand::RngCore;
+use secp256k1::{PublicKey, Scalar, Secp256k1, SecretKey, Signing};
+use serde_derive::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
+use unicode_normalization::UnicodeNormalization;
+use zeroize::Zeroizing;
+
+use crate::error::{VdrError, VdrResult};
+
+const WORDLIST: &str = include_str!("bip39_english.txt");
+const WORD_BITS: usize = 11;
+const WORD_COUNTS: [usize; 5] = [12, 15, 18, 21, 24];
+const SEED_ITERATIONS: usize = 2048;
+const SEED_LENGTH: usize = 64;
+const MASTER_KEY_SALT: &[u8] = b"Bitcoin seed";
+const HARDENED: u32 = 0x8000_0000;
+
+/// BIP-39 mnemonic phrase (English wordlist)
+pub struct Mnemonic {
+    phrase: Zeroizing<String>,
+}
+
+impl Mnemonic {
+    /// Generate new random mnemonic
+    ///
+    /// # Params
+    /// - `word_count` number of words: 12, 15, 18, 21 or 24
+    ///
+    /// # Returns
+    /// generated mnemonic
+    pub fn generate(word_count: usize) -> VdrResult<Mnemonic> {
+        if !WORD_COUNTS.contains(&word_count) {
+            return Err(mnemonic_error(format!(
+                "Unsupported word count: {}",
+                word_count
+            )));
+        }
+        let mut entropy = Zeroizing::new(vec![0; word_count * 4 / 3]);
+        rand::thread_rng().fill_bytes(&mut entropy);
+        Mnemonic::from_entropy(&entropy)
+    }
+
+    /// Build mnemonic encoding the given entropy
+    ///
+    /// # Params
+    /// - `entropy` 16, 20, 24, 28 or 32 bytes of entropy
+    ///
+    /// # Returns
+    /// mnemonic
+    pub fn from_entropy(entropy: &[u8]) -> VdrResult<Mnemonic> {
+        if !WORD_COUNTS
+            .map(|count| count * 4 / 3)
+            .contains(&entropy.len())
+        {
+            return Err(mnemonic_error(format!(
+                "Unsupported entropy length: {}",
+                entropy.len()
+            )));
+        }
+        let checksum = sha256(entropy)[0];
+        let mut bits = Zeroizing::new(entropy.to_vec());
+        bits.push(checksum);
+
+        let wordlist: Vec<&str> = WORDLIST.lines().collect();
+        let word_count = (entropy.len() * 8 + entropy.len() / 4) / WORD_BITS;
+        let words: Vec<&str> = (0..word_count)
+            .map(|word| wordlist[read_bits(&bits, word * WORD_BITS, WORD_BITS)])
+            .collect();
+
+        Ok(Mnemonic {
+            phrase: Zeroizing::new(words.join(" ")),
+        })
+    }
+
+    /// Parse and validate mnemonic phrase (words and checksum)
+    ///
+    /// # Params
+    /// - `phrase` whitespace separated mnemonic words
+    ///
+    /// # Returns
+    /// mnemonic
+    pub fn from_phrase(phrase: &str) -> VdrResult<Mnemonic> {
+        let phrase: Zeroizing<String> = Zeroizing::new(phrase.nfkd().collect());
+        let words: Vec<&str> = phrase.split_whitespace().collect();
+        if !WORD_COUNTS.contains(&words.len()) {
+            return Err(mnemonic_error(format!(
+                "Unsupported word count: {}",
+                words.len()
+            )));
+        }
+
+        let wordlist: Vec<&str> = WORDLIST.lines().collect();
+        let mut bits = Zeroizing::new(vec![0; (words.len() * WORD_BITS).div_ceil(8)]);
+        for (position, word) in words.iter().enumerate() {
+            let index = wordlist
+                .binary_search(word)
+                .map_err(|_| mnemonic_error(format!("Unknown word: {}", word)))?;
+            write_bits(&mut bits, position * WORD_BITS, WORD_BITS, index);
+        }
+
+        let entropy_length = words.len() * 4 / 3;
+        let checksum_bits = entropy_length / 4;
+        let checksum = sha256(&bits[..entropy_length])[0] >> (8 - checksum_bits);
+        if read_bits(&bits, entropy_length * 8, checksum_bits) != checksum as usize {
+            return Err(mnemonic_error("Invalid checksum".to_string()));
+        }
+
+        Ok(Mnemonic {
+            phrase: Zeroizing::new(words.join(" ")),
+        })
+    }
+
+    /// Get mnemonic phrase
+    pub fn phrase(&self) -> &str {
+        &self.phrase
+    }
+
+    /// Derive BIP-39 seed from the mnemonic
+    ///
+    /// # Params
+    /// - `passphrase` optional passphrase ("" if not used)
+    ///
+    /// # Returns
+    /// 64 bytes seed to derive keys from
+    pub fn to_seed(&self, passphrase: &str) -> VdrResult<Zeroizing<Vec<u8>>> {
+        let salt: Zeroizing<String> =
+            Zeroizing::new(format!("mnemonic{}", passphrase).nfkd().collect());
+        let mut seed = Zeroizing::new(vec![0; SEED_LENGTH]);
+        pbkdf2_hmac(
+            self.phrase.as_bytes(),
+            salt.as_bytes(),
+            SEED_ITERATIONS,
+            MessageDigest::sha512(),
+            &mut seed,
+        )
+        .map_err(|err| VdrError::SignerUnexpectedError(err.to_string()))?;
+        Ok(seed)
+    }
+}
+
+impl fmt::Debug for Mnemonic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Mnemonic(***)")
+    }
+}
+
+/// BIP-32 derivation path like `m/44'/60'/0'/0/0`
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct DerivationPath(Vec<u32>);
+
+impl DerivationPath {
+    /// BIP-44 path of Ethereum account: `m/44'/60'/0'/0/{index}`
+    pub fn ethereum(index: u32) -> DerivationPath {
+        DerivationPath(vec![44 | HARDENED, 60 | HARDENED, HARDENED, 0, index])
+    }
+
+    /// Derive private key for the path from BIP-32 seed
+    ///
+    /// # Params
+    /// - `secp` secp256k1 context
+    /// - `seed` BIP-32 seed (see [Mnemonic::to_seed])
+    ///
+    /// # Returns
+    /// derived private key
+    pub(crate) fn derive_key<C: Signing>(
+        &self,
+        secp: &Secp256k1<C>,
+        seed: &[u8],
+    ) -> VdrResult<SecretKey> {
+        let extended = Zeroizing::new(hmac_sha512(MASTER_KEY_SALT, seed)?);
+        let mut private_key = SecretKey::from_slice(&extended[..32])?;
+        let mut chain_code = Zeroizing::new(extended[32..].to_vec());
+
+        for index in self.0.iter() {
+            let mut data = Zeroizing::new(Vec::with_capacity(37));
+            if index & HARDENED != 0 {
+                data.push(0);
+                data.extend_from_slice(&private_key.secret_bytes());
+            } else {
+                data.extend_from_slice(&PublicKey::from_secret_key(secp, &private_key).serialize());
+            }
+            data.extend_from_slice(&index.to_be_bytes());
+
+            let extended = Zeroizing::new(hmac_sha512(&chain_code, &data)?);
+            let mut tweak = [0; 32];
+            tweak.copy_from_slice(&extended[..32]);
+            let tweak = Scalar::from_be_bytes(tweak).map_err(|_| {
+                VdrError::SignerInvalidDerivationPath(format!("Invalid key at index: {}", index))
+            })?;
+            private_key = private_key.add_tweak(&tweak)?;
+            chain_code = Zeroizing::new(extended[32..].to_vec());
+        }
+
+        trace!("Derived key for path: {}", self);
+
+        Ok(private_key)
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = VdrError;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        let mut segments = path.split('/');
+        if segments.next() != Some("m") {
+            return Err(derivation_path_error(path));
+        }
+        segments
+            .map(|segment| {
+                let (index, hardened) = match segment.strip_suffix(['\'', 'h']) {
+                    Some(index) => (index, true),
+                    None => (segment, false),
+                };
+                let index = index
+                    .parse::<u32>()
+                    .ok()
+                    .filter(|index| index & HARDENED == 0)
+                    .ok_or_else(|| derivation_path_error(path))?;
+                Ok(if hardened { index | HARDENED } else { index })
+            })
+            .collect::<VdrResult<Vec<u32>>>()
+            .map(DerivationPath)
+    }
+}
+
+impl TryFrom<String> for DerivationPath {
+    type Error = VdrError;
+
+    fn try_from(path: String) -> Result<Self, Self::Error> {
+        DerivationPath::from_str(&path)
+    }
+}
+
+impl From<DerivationPath> for String {
+    fn from(path: DerivationPath) -> Self {
+        path.to_string()
+    }
+}
+
+impl fmt::Display for DerivationPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("m")?;
+        for index in self.0.iter() {
+            if index & HARDENED != 0 {
+                write!(f, "/{}'", index & !HARDENED)?;
+            } else {
+                write!(f, "/{}", index)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn read_bits(bytes: &[u8], offset: usize, count: usize) -> usize {
+    (offset..offset + count).fold(0, |value, bit| {
+        (value << 1) | ((bytes[bit / 8] >> (7 - bit % 8)) & 1) as usize
+    })
+}
+
+fn write_bits(bytes: &mut [u8], offset: usize, count: usize, value: usize) {
+    for bit in 0..count {
+        if (value >> (count - 1 - bit)) & 1 == 1 {
+            let position = offset + bit;
+            bytes[position / 8] |= 1 << (7 - position % 8);
+        }
+    }
+}
+
+fn hmac_sha512(key: &[u8], data: &[u8]) -> VdrResult<Vec<u8>> {
+    let key = PKey::hmac(key).map_err(|err| VdrError::SignerUnexpectedError(err.to_string()))?;
+    HmacSigner::new(MessageDigest::sha512(), &key)
+        .and_then(|mut signer| signer.sign_oneshot_to_vec(data))
+        .map_err(|err| VdrError::SignerUnexpectedError(err.to_string()))
+}
+
+fn mnemonic_error(message: String) -> VdrError {
+    let vdr_error = VdrError::SignerInvalidMnemonic(message);
+
+    warn!("Error: {} during mnemonic processing", vdr_error);
+
+    vdr_error
+}
+
+fn derivation_path_error(path: &str) -> VdrError {
+    let vdr_error = VdrError::SignerInvalidDerivationPath(path.to_string());
+
+    warn!("Error: {} during derivation path parsing", vdr_error);
+
+    vdr_error
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+
+    pub const MNEMONIC: &str =
+        "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+
+    #[test]
+    fn mnemonic_from_entropy_test() {
+        let vectors = [
+            ("00000000000000000000000000000000", MNEMONIC),
+            (
+                "7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f",
+                "legal winner thank year wave sausage worth useful legal winner thank yellow",
+            ),
+            (
+                "80808080808080808080808080808080",
+                "letter advice cage absurd amount doctor acoustic avoid letter advice cage above",
+            ),
+            (
+                "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+                "zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo zoo vote",
+            ),
+            (
+                "9e885d952ad362caeb4efe34a8e91bd2",
+                "ozone drill grab fiber curtain grace pudding thank cruise elder eight picnic",
+            ),
+        ];
+        for (entropy, phrase) in vectors {
+            let mnemonic = Mnemonic::from_entropy(&hex::decode(entropy).unwrap()).unwrap();
+            assert_eq!(phrase, mnemonic.phrase());
+            Mnemonic::from_phrase(phrase).unwrap();
+        }
+    }
+
+    #[test]
+    fn generate_mnemonic_test() {
+        for word_count in [12, 15, 18, 21, 24] {
+            let mnemonic = Mnemonic::generate(word_count).unwrap();
+            assert_eq!(word_count, mnemonic.phrase().split(' ').count());
+            Mnemonic::from_phrase(mnemonic.phrase()).unwrap();
+        }
+        Mnemonic::generate(13).unwrap_err();
+    }
+
+    #[test]
+    fn invalid_mnemonic_test() {
+        let invalid_checksum = MNEMONIC.replace("about", "abandon");
+        let unknown_word = MNEMONIC.replace("about", "bitcoin");
+        let short = "abandon abandon about";
+        for phrase in [invalid_checksum.as_str(), unknown_word.as_str(), short] {
+            let err = Mnemonic::from_phrase(phrase).unwrap_err();
+            assert!(matches!(err, VdrError::SignerInvalidMnemonic(_)));
+        }
+    }
+
+    #[test]
+    fn mnemonic_to_seed_test() {
+        let seed = Mnemonic::from_phrase(MNEMONIC)
+            .unwrap()
+            .to_seed("TREZOR")
+            .unwrap();
+        assert_eq!(
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+            hex::encode(&*seed)
+        );
+    }
+
+    #[test]
+    fn derive_key_test() {
+        // BIP-32 test vector 1
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+        let secp = Secp256k1::signing_only();
+        let vectors = [
+            (
+                "m",
+                "e8f32e723decf4051aefac8e2c93c9c5b214313817cdb01a1494b917c8436b35",
+            ),
+            (
+                "m/0'",
+                "edb2e14f9ee77d26dd93b4ecede8d16ed408ce149b6cd80b0715a2d911a0afea",
+            ),
+            (
+                "m/0'/1",
+                "3c6cb8d0f6a264c91ea8b5030fadaa8e538b020f0a387421a12de9319dc93368",
+            ),
+            (
+                "m/0h/1/2h",
+                "cbce0d719ecf7431d88e6a89fa1483e02e35092af60c042b1df2ff59fa424dca",
+            ),
+        ];
+        for (path, private_key) in vectors {
+            let key = DerivationPath::from_str(path)
+                .unwrap()
+                .derive_key(&secp, &seed)
+                .unwrap();
+            assert_eq!(private_key, hex::encode(key.secret_bytes()));
+        }
+    }
+
+    #[test]
+    fn derivation_path_test() {
+        let path = DerivationPath::from_str("m/44'/60'/0'/0/7").unwrap();
+        assert_eq!(DerivationPath::ethereum(7), path);
+        assert_eq!("m/44'/60'/0'/0/7", path.to_string());
+        assert_eq!(
+            "\"m/44'/60'/0'/0/7\"",
+            serde_json::to_string(&path).unwrap()
+        );
+
+        for path in ["", "44'/60'", "m/x", "m/2147483648", "m//0"] {
+            let err = DerivationPath::from_str(path).unwrap_err();
+            assert!(matches!(err, VdrError::SignerInvalidDerivationPath(_)));
+        }
+    }
+}
@@ -0,0 +1,465 @@
+use log::{trace, warn};
+use openssl::{
+    hash::MessageDigest,
+    memcmp,
+    pkcs5::{pbkdf2_hmac, scrypt},
+    symm::{decrypt, encrypt, Cipher},
+};
+use rand::RngCore;
+use serde_derive::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+use zeroize::Zeroizing;
+
+use crate::{
+    error::{VdrError, VdrResult},
+    types::Address,
+};
+
+const VERSION: u8 = 3;
+const CIPHER: &str = "aes-128-ctr";
+const DERIVED_KEY_LENGTH: usize = 32;
+const PBKDF2_PRF: &str = "hmac-sha256";
+const SCRYPT_MEMORY_MARGIN: u64 = 1024 * 1024;
+// Limits of scrypt parameters read from keystore files, so a crafted file cannot exhaust
+// CPU and memory. Allows n = 2^20 with r = 8 (1 GiB) used by the most demanding wallets
+const MAX_SCRYPT_N: u64 = 1 << 20;
+const MAX_SCRYPT_COST: u64 = MAX_SCRYPT_N * 8;
+const MAX_SCRYPT_MEMORY: u64 = (1 << 30) + 16 * SCRYPT_MEMORY_MARGIN;
+// Wallets use up to 2^18 pbkdf2 iterations, the limit leaves room for stronger settings
+const MAX_PBKDF2_ITERATIONS: u32 = 1 << 24;
+
+/// Key derivation function used to encrypt keystore (Web3 Secret Storage)
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum KeystoreKdf {
+    /// scrypt with CPU/memory cost `n` (power of 2), block size `r` and parallelization `p`
+    Scrypt { n: u64, r: u32, p: u32 },
+    /// PBKDF2 with HMAC-SHA256 and `c` iterations
+    Pbkdf2 { c: u32 },
+}
+
+impl Default for KeystoreKdf {
+    /// Parameters used by geth and other Ethereum clients for newly created keys
+    fn default() -> Self {
+        KeystoreKdf::Scrypt {
+            n: 262144,
+            r: 8,
+            p: 1,
+        }
+    }
+}
+
+/// Web3 Secret Storage (keystore v3) JSON file content
+#[derive(Debug, Serialize, Deserialize)]
+struct Keystore {
+    version: u8,
+    id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    address: Option<String>,
+    #[serde(alias = "Crypto")]
+    crypto: KeystoreCrypto,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct KeystoreCrypto {
+    cipher: String,
+    cipherparams: CipherParams,
+    ciphertext: String,
+    kdf: String,
+    kdfparams: KdfParams,
+    mac: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CipherParams {
+    iv: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+enum KdfParams {
+    Scrypt {
+        dklen: usize,
+        n: u64,
+        r: u32,
+        p: u32,
+        salt: String,
+    },
+    Pbkdf2 {
+        dklen: usize,
+        c: u32,
+        prf: String,
+        salt: String,
+    },
+}
+
+/// Encrypt private key into keystore v3 JSON
+///
+/// # Params
+/// - `private_key` secp256k1 private key bytes
+/// - `address` account address matching the key
+/// - `password` password to encrypt the key with
+/// - `kdf` key derivation function and its parameters
+///
+/// # Returns
+/// keystore JSON string
+pub(crate) fn encrypt_key(
+    private_key: &[u8],
+    address: &Address,
+    password: &str,
+    kdf: &KeystoreKdf,
+) -> VdrResult<String> {
+    let salt = random_bytes::<32>();
+    let iv = random_bytes::<16>();
+
+    let kdfparams = match kdf {
+        KeystoreKdf::Scrypt { n, r, p } => KdfParams::Scrypt {
+            dklen: DERIVED_KEY_LENGTH,
+            n: *n,
+            r: *r,
+            p: *p,
+            salt: hex::encode(salt),
+        },
+        KeystoreKdf::Pbkdf2 { c } => KdfParams::Pbkdf2 {
+            dklen: DERIVED_KEY_LENGTH,
+            c: *c,
+            prf: PBKDF2_PRF.to_string(),
+            salt: hex::encode(salt),
+        },
+    };
+    let derived_key = derive_key(password, &kdfparams)?;
+    let ciphertext = encrypt(
+        Cipher::aes_128_ctr(),
+        &derived_key[..16],
+        Some(&iv),
+        private_key,
+    )
+    .map_err(crypto_error)?;
+
+    let keystore = Keystore {
+        version: VERSION,
+        id: uuid(),
+        address: Some(address.as_ref().trim_start_matches("0x").to_lowercase()),
+        crypto: KeystoreCrypto {
+            cipher: CIPHER.to_string(),
+            cipherparams: CipherParams {
+                iv: hex::encode(iv),
+            },
+            mac: hex::encode(mac(&derived_key, &ciphertext)),
+            ciphertext: hex::encode(ciphertext),
+            kdf: match kdf {
+                KeystoreKdf::Scrypt { .. } => "scrypt".to_string(),
+                KeystoreKdf::Pbkdf2 { .. } => "pbkdf2".to_string(),
+            },
+            kdfparams,
+        },
+    };
+
+    trace!("Encrypted key of account: {:?} into keystore", address);
+
+    serde_json::to_string(&keystore).map_err(|err| {
+        VdrError::SignerInvalidKeystore(format!("Unable to serialize keystore: {:?}", err))
+    })
+}
+
+/// Decrypt private key from keystore v3 JSON
+///
+/// # Params
+/// - `keystore` keystore JSON string
+/// - `password` password the key is encrypted with
+///
+/// # Returns
+/// private key bytes (zeroized on drop)
+pub(crate) fn decrypt_key(keystore: &str, password: &str) -> VdrResult<Zeroizing<Vec<u8>>> {
+    let keystore: Keystore = serde_json::from_str(keystore).map_err(|err| {
+        let vdr_error = VdrError::SignerInvalidKeystore(format!("Unable to parse: {:?}", err));
+
+        warn!("Error: {} during decrypting keystore", vdr_error);
+
+        vdr_error
+    })?;
+    if keystore.version != VERSION {
+        return Err(VdrError::SignerInvalidKeystore(format!(
+            "Unsupported version: {}",
+            keystore.version
+        )));
+    }
+    if keystore.crypto.cipher != CIPHER {
+        return Err(VdrError::SignerInvalidKeystore(format!(
+            "Unsupported cipher: {}",
+            keystore.crypto.cipher
+        )));
+    }
+
+    let ciphertext = decode_hex(&keystore.crypto.ciphertext, "ciphertext")?;
+    let iv = decode_hex(&keystore.crypto.cipherparams.iv, "iv")?;
+    let derived_key = derive_key(password, &keystore.crypto.kdfparams)?;
+
+    let expected_mac = decode_hex(&keystore.crypto.mac, "mac")?;
+    let mac = mac(&derived_key, &ciphertext);
+    // constant time comparison, so the derived key is not leaked through timing
+    if mac.len() != expected_mac.len() || !memcmp::eq(&mac, &expected_mac) {
+        let vdr_error = VdrError::SignerInvalidPassword;
+
+        warn!("Error: {} during decrypting keystore", vdr_error);
+
+        return Err(vdr_error);
+    }
+
+    let private_key = decrypt(
+        Cipher::aes_128_ctr(),
+        &derived_key[..16],
+        Some(&iv),
+        &ciphertext,
+    )
+    .map_err(crypto_error)?;
+
+    trace!("Decrypted keystore: {}", keystore.id);
+
+    Ok(Zeroizing::new(private_key))
+}
+
+fn derive_key(password: &str, kdfparams: &KdfParams) -> VdrResult<Zeroizing<Vec<u8>>> {
+    let (dklen, salt) = match kdfparams {
+        KdfParams::Scrypt { dklen, salt, .. } | KdfParams::Pbkdf2 { dklen, salt, .. } => {
+            (*dklen, decode_hex(salt, "salt")?)
+        }
+    };
+    // only the first 32 bytes of the derived key are used, so other lengths are not expected
+    if dklen != DERIVED_KEY_LENGTH {
+        return Err(VdrError::SignerInvalidKeystore(format!(
+            "Unsupported derived key length: {}",
+            dklen
+        )));
+    }
+
+    let mut derived_key = Zeroizing::new(vec![0; dklen]);
+    match kdfparams {
+        KdfParams::Scrypt { n, r, p, .. } => {
+            let (n, r, p) = (*n, *r as u64, *p as u64);
+            // RFC 7914 requires n < 2^(128 * r / 8), OpenSSL refuses to derive otherwise
+            if r < 4 && n >> (16 * r) != 0 {
+                return Err(VdrError::SignerInvalidKeystore(format!(
+                    "Unsupported scrypt parameters: n={}, r={}",
+                    n, r
+                )));
+            }
+            // memory required by scrypt: 128 * r * (n + p + 2) bytes and OpenSSL bookkeeping
+            let maxmem = n
+                .saturating_add(p)
+                .saturating_add(2)
+                .saturating_mul(128)
+                .saturating_mul(r)
+                .saturating_add(SCRYPT_MEMORY_MARGIN);
+            let cost = n.saturating_mul(r).saturating_mul(p);
+            if n > MAX_SCRYPT_N || cost > MAX_SCRYPT_COST || maxmem > MAX_SCRYPT_MEMORY {
+                return Err(VdrError::SignerInvalidKeystore(format!(
+                    "Too expensive scrypt parameters: n={}, r={}, p={}",
+                    n, r, p
+                )));
+            }
+            scrypt(
+                password.as_bytes(),
+                &salt,
+                n,
+                r,
+                p,
+                maxmem,
+                &mut derived_key,
+            )
+            .map_err(crypto_error)?
+        }
+        KdfParams::Pbkdf2 { c, prf, .. } => {
+            if prf != PBKDF2_PRF {
+                return Err(VdrError::SignerInvalidKeystore(format!(
+                    "Unsupported pbkdf2 function: {}",
+                    prf
+                )));
+            }
+            if *c > MAX_PBKDF2_ITERATIONS {
+                return Err(VdrError::SignerInvalidKeystore(format!(
+                    "Too expensive pbkdf2 parameters: c={}",
+                    c
+                )));
+            }
+            pbkdf2_hmac(
+                password.as_bytes(),
+                &salt,
+                *c as usize,
+                MessageDigest::sha256(),
+                &mut derived_key,
+            )
+            .map_err(crypto_error)?
+        }
+    }
+    Ok(derived_key)
+}
+
+fn mac(derived_key: &[u8], ciphertext: &[u8]) -> Vec<u8> {
+    let mut hasher = Keccak256::new();
+    hasher.update(&derived_key[16..32]);
+    hasher.update(ciphertext);
+    hasher.finalize().to_vec()
+}
+
+fn random_bytes<const N: usize>() -> [u8; N] {
+    let mut bytes = [0; N];
+    rand::thread_rng().fill_bytes(&mut bytes);
+    bytes
+}
+
+// random (version 4) UUID
+fn uuid() -> String {
+    let mut bytes = random_bytes::<16>();
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex = hex::encode(bytes);
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..]
+    )
+}
+
+fn decode_hex(value: &str, name: &str) -> VdrResult<Vec<u8>> {
+    hex::decode(value.trim_start_matches("0x"))
+        .map_err(|err| VdrError::SignerInvalidKeystore(format!("Invalid {}: {:?}", name, err)))
+}
+
+fn crypto_error(err: openssl::error::ErrorStack) -> VdrError {
+    let vdr_error = VdrError::SignerInvalidKeystore(format!("Crypto operation failed: {}", err));
+
+    warn!("Error: {} during keystore processing", vdr_error);
+
+    vdr_error
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::signer::basic_signer::test::TRUSTEE_ACC;
+
+    // test vectors of Web3 Secret Storage Definition
+    const PASSWORD: &str = "testpassword";
+    const PRIVATE_KEY: &str = "7a28b5ba57c53603b0b07b56bba752f7784bf506fa95edc395f5cf6c7514fe9d";
+    const PBKDF2_KEYSTORE: &str = r#"{
+        "crypto": {
+            "cipher": "aes-128-ctr",
+            "cipherparams": { "iv": "6087dab2f9fdbbfaddc31a909735c1e6" },
+            "ciphertext": "5318b4d5bcd28de64ee5559e671353e16f075ecae9f99c7a79a38af5f869aa46",
+            "kdf": "pbkdf2",
+            "kdfparams": {
+                "c": 262144,
+                "dklen": 32,
+                "prf": "hmac-sha256",
+                "salt": "ae3cd4e7013836a3df6bd7241b12db061dbe2c6785853cce422d148a624ce0bd"
+            },
+            "mac": "517ead924a9d0dc3124507e3393d175ce3ff7c1e96529c6c555ce9e51205e9b2"
+        },
+        "id": "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+        "version": 3
+    }"#;
+    const SCRYPT_KEYSTORE: &str = r#"{
+        "crypto": {
+            "cipher": "aes-128-ctr",
+            "cipherparams": { "iv": "83dbcc02d8ccb40e466191a123791e0e" },
+            "ciphertext": "3b4309355ad643f2b15cfb6a83a7f6f328e7a6459a56ab8c6e25a89c8f43eb80",
+            "kdf": "scrypt",
+            "kdfparams": {
+                "dklen": 32,
+                "n": 4096,
+                "p": 1,
+                "r": 8,
+                "salt": "ab0c7876052600dd703518d6fc3fe8984592145b591fc8fb5c6d43190334ba19"
+            },
+            "mac": "994d83f6bfb7e6e3aa95980f72b6ad87db9d352789d0f2e433cf777425db3a42"
+        },
+        "id": "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+        "version": 3
+    }"#;
+
+    pub const LIGHT_KDF: KeystoreKdf = KeystoreKdf::Scrypt {
+        n: 4096,
+        r: 8,
+        p: 1,
+    };
+
+    #[test]
+    fn decrypt_pbkdf2_keystore_test() {
+        let private_key = decrypt_key(PBKDF2_KEYSTORE, PASSWORD).unwrap();
+        assert_eq!(PRIVATE_KEY, hex::encode(&*private_key));
+    }
+
+    #[test]
+    fn decrypt_scrypt_keystore_test() {
+        let private_key = decrypt_key(SCRYPT_KEYSTORE, PASSWORD).unwrap();
+        assert_eq!(PRIVATE_KEY, hex::encode(&*private_key));
+    }
+
+    #[test]
+    fn decrypt_keystore_with_unsupported_scrypt_params_test() {
+        let keystore = SCRYPT_KEYSTORE
+            .replace(r#""p": 1"#, r#""p": 8"#)
+            .replace(r#""r": 8"#, r#""r": 1"#)
+            .replace(r#""n": 4096"#, r#""n": 262144"#);
+        let err = decrypt_key(&keystore, PASSWORD).unwrap_err();
+        assert!(matches!(err, VdrError::SignerInvalidKeystore(_)));
+    }
+
+    #[test]
+    fn decrypt_keystore_with_too_expensive_scrypt_params_test() {
+        for (n, r, p) in [(1 << 21, 8, 1), (1 << 20, 16, 1), (4096, 8, 1 << 20)] {
+            let keystore = SCRYPT_KEYSTORE
+                .replace(r#""n": 4096"#, &format!(r#""n": {}"#, n))
+                .replace(r#""r": 8"#, &format!(r#""r": {}"#, r))
+                .replace(r#""p": 1"#, &format!(r#""p": {}"#, p));
+            let err = decrypt_key(&keystore, PASSWORD).unwrap_err();
+            assert!(matches!(err, VdrError::SignerInvalidKeystore(_)));
+        }
+    }
+
+    #[test]
+    fn decrypt_keystore_with_too_expensive_pbkdf2_params_test() {
+        let keystore = PBKDF2_KEYSTORE.replace(r#""c": 262144"#, r#""c": 4294967295"#);
+        let err = decrypt_key(&keystore, PASSWORD).unwrap_err();
+        assert!(matches!(err, VdrError::SignerInvalidKeystore(_)));
+    }
+
+    #[test]
+    fn decrypt_keystore_with_unsupported_dklen_test() {
+        for dklen in ["16", "64", "18446744073709551615"] {
+            let keystore =
+                PBKDF2_KEYSTORE.replace(r#""dklen": 32"#, &format!(r#""dklen": {}"#, dklen));
+            let err = decrypt_key(&keystore, PASSWORD).unwrap_err();
+            assert!(matches!(err, VdrError::SignerInvalidKeystore(_)));
+        }
+    }
+
+    #[test]
+    fn decrypt_keystore_with_short_mac_test() {
+        let keystore = PBKDF2_KEYSTORE.replace(
+            "517ead924a9d0dc3124507e3393d175ce3ff7c1e96529c6c555ce9e51205e9b2",
+            "517ead924a9d0dc3",
+        );
+        let err = decrypt_key(&keystore, PASSWORD).unwrap_err();
+        assert_eq!(VdrError::SignerInvalidPassword, err);
+    }
+
+    #[test]
+    fn decrypt_keystore_with_wrong_password_test() {
+        let err = decrypt_key(PBKDF2_KEYSTORE, "wrong").unwrap_err();
+        assert_eq!(VdrError::SignerInvalidPassword, err);
+    }
+
+    #[test]
+    fn encrypt_decrypt_key_test() {
+        let private_key = hex::decode(PRIVATE_KEY).unwrap();
+        for kdf in [LIGHT_KDF, KeystoreKdf::Pbkdf2 { c: 1024 }] {
+            let keystore = encrypt_key(&private_key, &TRUSTEE_ACC, PASSWORD, &kdf).unwrap();
+
+            assert_eq!(private_key, *decrypt_key(&keystore, PASSWORD).unwrap());
+        }
+    }
+}
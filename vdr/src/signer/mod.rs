@@ -1,3 +1,104 @@
+#[cfg(feature = "basic_signer")]
 pub mod basic_signer;
+pub mod ed25519;
+#[cfg(feature = "basic_signer")]
+pub mod hd_wallet;
+#[cfg(feature = "basic_signer")]
+pub mod keystore;
+pub mod remote_signer;
 
+#[cfg(feature = "basic_signer")]
 pub use basic_signer::{BasicSigner, KeyPair};
+pub use ed25519::{
+    Ed25519KeyPair, Ed25519PublicKey, VerificationKey, X25519KeyPair, X25519PublicKey,
+};
+#[cfg(feature = "basic_signer")]
+pub use hd_wallet::{DerivationPath, Mnemonic};
+#[cfg(feature = "basic_signer")]
+pub use keystore::KeystoreKdf;
+pub use remote_signer::RemoteSigner;
+
+use async_trait::async_trait;
+use log::warn;
+use sha3::{Digest, Keccak256};
+
+use crate::{
+    error::{VdrError, VdrResult},
+    types::{Address, SignatureData, Transaction},
+};
+
+/// Holder of account keys used to sign transactions and messages.
+///
+/// [crate::LedgerClient::sign_and_submit] accepts any implementation, so keys can be kept outside
+/// of the library (KMS, hardware or browser wallet, test double).
+/// [BasicSigner] is the in-memory implementation available with `basic_signer` feature.
+#[cfg_attr(not(feature = "wasm"), async_trait)]
+#[cfg_attr(feature = "wasm", async_trait(?Send))]
+pub trait Signer: Sync + Send {
+    /// Get the accounts the signer holds keys for
+    ///
+    /// # Returns
+    /// list of account addresses
+    async fn accounts(&self) -> VdrResult<Vec<Address>>;
+
+    /// Sign a 32-byte hash with the key of an account
+    ///
+    /// # Params
+    /// - `hash` hash to sign (for transactions: [crate::Transaction::get_signing_bytes])
+    /// - `account` address of the account to sign with
+    ///
+    /// # Returns
+    /// recoverable secp256k1 signature
+    async fn sign_hash(&self, hash: &[u8], account: &Address) -> VdrResult<SignatureData>;
+
+    /// Sign a transaction with the key of its sender
+    ///     The default implementation signs [crate::Transaction::get_signing_bytes] using
+    ///     [Signer::sign_hash]. Signers which do not accept raw hashes (like signing services)
+    ///     override it
+    ///
+    /// # Params
+    /// - `transaction` transaction to sign
+    ///
+    /// # Returns
+    /// recoverable secp256k1 signature to set into the transaction
+    async fn sign_transaction(&self, transaction: &Transaction) -> VdrResult<SignatureData> {
+        let from = transaction.from.as_ref().ok_or_else(|| {
+            let vdr_error =
+                VdrError::ClientInvalidTransaction("Transaction `sender` is not set".to_string());
+
+            warn!("Error: {} during signing transaction", vdr_error);
+
+            vdr_error
+        })?;
+        self.sign_hash(&transaction.get_signing_bytes()?, from)
+            .await
+    }
+
+    /// Sign an arbitrary message with the key of an account (EIP-191 `personal_sign`)
+    ///     The default implementation signs the hash of the message prefixed with
+    ///     `"\x19Ethereum Signed Message:\n" + message length`
+    ///
+    /// # Params
+    /// - `message` message to sign
+    /// - `account` address of the account to sign with
+    ///
+    /// # Returns
+    /// recoverable secp256k1 signature
+    async fn sign_message(&self, message: &[u8], account: &Address) -> VdrResult<SignatureData> {
+        self.sign_hash(&message_hash(message), account).await
+    }
+}
+
+/// Calculate the hash signed for an arbitrary message (EIP-191 version `0x45`)
+///
+/// # Params
+/// - `message` message to hash
+///
+/// # Returns
+/// keccak256 hash of the prefixed message
+pub fn message_hash(message: &[u8]) -> Vec<u8> {
+    let mut hasher = Keccak256::new();
+    hasher.update(format!("\x19Ethereum Signed Message:\n{}", message.len()).as_bytes());
+    hasher.update(message);
+    hasher.finalize().to_vec()
+}
@@ -0,0 +1,436 @@
+use async_trait::async_trait;
+use log::{trace, warn};
+use serde_json::{json, Value};
+
+use crate::{
+    error::{VdrError, VdrResult},
+    signer::Signer,
+    types::{Address, SignatureData, Transaction},
+};
+
+#[cfg(not(feature = "wasm"))]
+use web3::{transports::Http, Error as Web3Error, Transport};
+
+#[cfg(feature = "wasm")]
+use web3_wasm::{transports::Http, Error as Web3Error, Transport};
+
+/// Signer delegating to an external signing service over the Ethereum JSON-RPC API
+/// (`eth_accounts`, `eth_signTransaction`, `eth_sign`) as implemented by EthSigner and Web3Signer.
+///
+/// Keys never leave the signing service. Transactions are sent to the service as JSON-RPC
+/// transaction objects and the returned signed transaction is checked to match the requested one.
+pub struct RemoteSigner {
+    transport: Http,
+}
+
+impl RemoteSigner {
+    /// Create signer connected to the signing service
+    ///
+    /// # Params
+    /// - `signer_address` HTTP JSON-RPC endpoint of the signing service
+    ///
+    /// # Returns
+    /// remote signer
+    pub fn new(signer_address: &str) -> VdrResult<RemoteSigner> {
+        trace!(
+            "Started creating new RemoteSigner. Signer address: {}",
+            signer_address
+        );
+
+        let transport = Http::new(signer_address).map_err(|err| {
+            let vdr_error = VdrError::SignerUnexpectedError(format!(
+                "Invalid signing service address {}: {:?}",
+                signer_address, err
+            ));
+
+            warn!("Error: {} during creating RemoteSigner", vdr_error);
+
+            vdr_error
+        })?;
+
+        trace!(
+            "Created new RemoteSigner. Signer address: {}",
+            signer_address
+        );
+
+        Ok(RemoteSigner { transport })
+    }
+
+    async fn execute(
+        &self,
+        method: &str,
+        params: Vec<Value>,
+        account: Option<&Address>,
+    ) -> VdrResult<Value> {
+        trace!("Sending request: {} to the signing service", method);
+
+        self.transport.execute(method, params).await.map_err(|err| {
+            let vdr_error = signer_error(err, account);
+
+            warn!("Error: {} during request: {}", vdr_error, method);
+
+            vdr_error
+        })
+    }
+}
+
+#[cfg_attr(not(feature = "wasm"), async_trait)]
+#[cfg_attr(feature = "wasm", async_trait(?Send))]
+impl Signer for RemoteSigner {
+    async fn accounts(&self) -> VdrResult<Vec<Address>> {
+        let response = self.execute("eth_accounts", vec![], None).await?;
+        let accounts: Vec<String> = parse_response(response, "accounts")?;
+
+        trace!("Got signing service accounts: {:?}", accounts);
+
+        Ok(accounts
+            .iter()
+            .map(|account| Address::from(account.as_str()))
+            .collect())
+    }
+
+    async fn sign_hash(&self, _hash: &[u8], _account: &Address) -> VdrResult<SignatureData> {
+        Err(VdrError::SignerUnsupportedOperation(
+            "Signing service does not sign raw hashes".to_string(),
+        ))
+    }
+
+    async fn sign_transaction(&self, transaction: &Transaction) -> VdrResult<SignatureData> {
+        let request = transaction.to_rpc_request()?;
+        let response = self
+            .execute(
+                "eth_signTransaction",
+                vec![request],
+                transaction.from.as_ref(),
+            )
+            .await?;
+
+        // services return either raw signed transaction or an object containing it
+        let signed: String = match response.get("raw") {
+            Some(raw) => parse_response(raw.clone(), "signed transaction")?,
+            None => parse_response(response, "signed transaction")?,
+        };
+        let signature = transaction.signature_from_signed(&decode_hex(&signed)?)?;
+
+        trace!("Got transaction signature: {:?}", signature);
+
+        Ok(signature)
+    }
+
+    async fn sign_message(&self, message: &[u8], account: &Address) -> VdrResult<SignatureData> {
+        let params = vec![
+            json!(account.as_ref()),
+            json!(format!("0x{}", hex::encode(message))),
+        ];
+        let response = self.execute("eth_sign", params, Some(account)).await?;
+        let signature: String = parse_response(response, "message signature")?;
+        let signature = decode_hex(&signature)?;
+
+        // signature is encoded as `r || s || v`, where `v` is 27 or 28
+        if signature.len() != 65 {
+            return Err(VdrError::SignerInvalidSignature(format!(
+                "Invalid message signature length: {}",
+                signature.len()
+            )));
+        }
+        let signature = SignatureData {
+            recovery_id: (signature[64] as u64) % 27,
+            signature: signature[..64].to_vec(),
+        };
+
+        trace!("Got message signature: {:?}", signature);
+
+        Ok(signature)
+    }
+}
+
+/// JSON-RPC error code returned for methods not implemented by the signing service
+const METHOD_NOT_FOUND_CODE: i64 = -32601;
+
+/// Messages of the signing services reporting that they do not hold the key of the account
+const MISSING_KEY_MESSAGES: [&str; 4] = [
+    // EthSigner
+    "From address is not an unlocked account",
+    // Web3Signer
+    "Signer not found for identifier",
+    // geth / clef
+    "unknown account",
+    "authentication needed",
+];
+
+fn signer_error(err: Web3Error, account: Option<&Address>) -> VdrError {
+    match (err, account) {
+        (Web3Error::Rpc(err), _) if err.code.code() == METHOD_NOT_FOUND_CODE => {
+            VdrError::SignerUnsupportedOperation(err.message)
+        }
+        (Web3Error::Rpc(err), Some(account))
+            if MISSING_KEY_MESSAGES
+                .iter()
+                .any(|message| err.message.contains(message)) =>
+        {
+            VdrError::SignerMissingKey(account.to_string())
+        }
+        (Web3Error::Rpc(err), _) => VdrError::SignerUnexpectedError(json!(err).to_string()),
+        (Web3Error::Unreachable, _) | (Web3Error::Transport(_), _) => {
+            VdrError::SignerUnexpectedError("Signing service is unreachable".to_string())
+        }
+        (err, _) => VdrError::SignerUnexpectedError(err.to_string()),
+    }
+}
+
+fn parse_response<T: serde::de::DeserializeOwned>(response: Value, name: &str) -> VdrResult<T> {
+    serde_json::from_value(response).map_err(|err| {
+        let vdr_error =
+            VdrError::SignerUnexpectedError(format!("Unable to parse {}: {:?}", name, err));
+
+        warn!(
+            "Error: {} during parsing signing service response",
+            vdr_error
+        );
+
+        vdr_error
+    })
+}
+
+fn decode_hex(value: &str) -> VdrResult<Vec<u8>> {
+    hex::decode(value.trim_start_matches("0x")).map_err(|err| {
+        VdrError::SignerInvalidSignature(format!("Unable to decode hex value: {:?}", err))
+    })
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::{
+        client::client::test::{
+            sign, sign_hash, CHAIN_ID, DEFAULT_NONCE, IDENTITY_ACC, ROLE_CONTROL_ADDRESS,
+            TRUSTEE_ACC, TRUSTEE_PRIVATE_KEY,
+        },
+        signer::message_hash,
+        types::{TransactionEnvelopeType, TransactionType},
+    };
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::{TcpListener, TcpStream},
+        thread,
+    };
+
+    /// Local stand-in of the signing service holding the trustee key
+    fn start_signing_service() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = format!("http://{}", listener.local_addr().unwrap());
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                thread::spawn(move || serve(stream.unwrap()));
+            }
+        });
+        address
+    }
+
+    fn serve(stream: TcpStream) {
+        let mut reader = BufReader::new(stream.try_clone().unwrap());
+        let mut stream = stream;
+        loop {
+            let mut content_length = None;
+            loop {
+                let mut line = String::new();
+                if reader.read_line(&mut line).unwrap_or(0) == 0 {
+                    return;
+                }
+                let line = line.trim_end();
+                if line.is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse::<usize>().ok();
+                    }
+                }
+            }
+            let mut body = vec![0; content_length.unwrap_or_default()];
+            reader.read_exact(&mut body).unwrap();
+
+            let request: Value = serde_json::from_slice(&body).unwrap();
+            let response = match handle(&request["method"], &request["params"]) {
+                Ok(result) => json!({ "jsonrpc": "2.0", "id": request["id"], "result": result }),
+                Err((code, message)) => json!({
+                    "jsonrpc": "2.0",
+                    "id": request["id"],
+                    "error": { "code": code, "message": message }
+                }),
+            }
+            .to_string();
+            write!(
+                stream,
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+                response.len(),
+                response
+            )
+            .unwrap();
+        }
+    }
+
+    fn handle(method: &Value, params: &Value) -> Result<Value, (i64, String)> {
+        let check_account = |account: &Value| {
+            if account.as_str() != Some(TRUSTEE_ACC.as_ref()) {
+                return Err((
+                    -32000,
+                    "From address is not an unlocked account".to_string(),
+                ));
+            }
+            Ok(())
+        };
+
+        match method.as_str().unwrap() {
+            "eth_accounts" => Ok(json!([TRUSTEE_ACC.as_ref()])),
+            "eth_sign" => {
+                check_account(&params[0])?;
+                let message = decode_hex(params[1].as_str().unwrap()).unwrap();
+                let signature = sign_hash(&message_hash(&message), TRUSTEE_PRIVATE_KEY);
+                let mut signature_bytes = signature.signature;
+                signature_bytes.push(signature.recovery_id as u8 + 27);
+                Ok(json!(format!("0x{}", hex::encode(signature_bytes))))
+            }
+            "eth_signTransaction" => {
+                let request = &params[0];
+                check_account(&request["from"])?;
+                let transaction = transaction_from_request(request);
+                sign(&transaction, TRUSTEE_PRIVATE_KEY);
+                Ok(json!(format!(
+                    "0x{}",
+                    hex::encode(transaction.encode().unwrap())
+                )))
+            }
+            "eth_getBalance" => Err((-32000, format!("Account {} not found", params[0]))),
+            _ => Err((METHOD_NOT_FOUND_CODE, "Method not found".to_string())),
+        }
+    }
+
+    fn transaction_from_request(request: &Value) -> Transaction {
+        let quantity = |name: &str| {
+            request[name]
+                .as_str()
+                .map(|value| u64::from_str_radix(value.trim_start_matches("0x"), 16).unwrap())
+        };
+        let envelope = match quantity("type") {
+            Some(1) => TransactionEnvelopeType::AccessList,
+            Some(2) => TransactionEnvelopeType::DynamicFee,
+            _ => TransactionEnvelopeType::Legacy,
+        };
+        Transaction {
+            type_: TransactionType::Write,
+            from: Some(Address::from(request["from"].as_str().unwrap())),
+            to: Address::from(request["to"].as_str().unwrap()),
+            nonce: Some(vec![quantity("nonce").unwrap(), 0, 0, 0]),
+            chain_id: quantity("chainId").unwrap(),
+            data: decode_hex(request["data"].as_str().unwrap()).unwrap(),
+            envelope,
+            gas_limit: quantity("gas"),
+            gas_price: quantity("gasPrice"),
+            max_fee_per_gas: quantity("maxFeePerGas"),
+            max_priority_fee_per_gas: quantity("maxPriorityFeePerGas"),
+            ..Transaction::default()
+        }
+    }
+
+    fn transaction(from: &Address, envelope: TransactionEnvelopeType) -> Transaction {
+        Transaction {
+            type_: TransactionType::Write,
+            from: Some(from.clone()),
+            to: ROLE_CONTROL_ADDRESS.clone(),
+            nonce: Some(DEFAULT_NONCE.clone()),
+            chain_id: CHAIN_ID,
+            data: vec![1, 2, 3],
+            envelope,
+            gas_limit: Some(100000),
+            gas_price: Some(10),
+            max_fee_per_gas: Some(20),
+            max_priority_fee_per_gas: Some(1),
+            ..Transaction::default()
+        }
+    }
+
+    fn remote_signer() -> RemoteSigner {
+        RemoteSigner::new(&start_signing_service()).unwrap()
+    }
+
+    #[async_std::test]
+    async fn remote_signer_accounts_test() {
+        let accounts = remote_signer().accounts().await.unwrap();
+        assert_eq!(vec![TRUSTEE_ACC.clone()], accounts);
+    }
+
+    #[async_std::test]
+    async fn remote_signer_sign_transaction_test() {
+        let signer = remote_signer();
+        for envelope in [
+            TransactionEnvelopeType::Legacy,
+            TransactionEnvelopeType::DynamicFee,
+        ] {
+            let transaction = transaction(&TRUSTEE_ACC, envelope);
+
+            let signature = signer.sign_transaction(&transaction).await.unwrap();
+            transaction.set_signature(signature);
+
+            transaction.verify_signer().unwrap();
+        }
+    }
+
+    #[async_std::test]
+    async fn remote_signer_sign_message_test() {
+        let message = b"message";
+
+        let signature = remote_signer()
+            .sign_message(message, &TRUSTEE_ACC)
+            .await
+            .unwrap();
+
+        let expected = sign_hash(&message_hash(message), TRUSTEE_PRIVATE_KEY);
+        assert_eq!(expected, signature);
+    }
+
+    #[async_std::test]
+    async fn remote_signer_missing_key_test() {
+        let transaction = transaction(&IDENTITY_ACC, TransactionEnvelopeType::Legacy);
+
+        let err = remote_signer()
+            .sign_transaction(&transaction)
+            .await
+            .unwrap_err();
+        assert_eq!(VdrError::SignerMissingKey(IDENTITY_ACC.to_string()), err);
+    }
+
+    #[async_std::test]
+    async fn remote_signer_method_not_found_test() {
+        let err = remote_signer()
+            .execute("eth_signTypedData", vec![], Some(&TRUSTEE_ACC))
+            .await
+            .unwrap_err();
+        assert_eq!(
+            VdrError::SignerUnsupportedOperation("Method not found".to_string()),
+            err
+        );
+    }
+
+    #[async_std::test]
+    async fn remote_signer_unexpected_error_test() {
+        let err = remote_signer()
+            .execute(
+                "eth_getBalance",
+                vec![json!(TRUSTEE_ACC.as_ref())],
+                Some(&TRUSTEE_ACC),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, VdrError::SignerUnexpectedError(_)));
+    }
+
+    #[async_std::test]
+    async fn remote_signer_sign_hash_not_supported_test() {
+        let err = remote_signer()
+            .sign_hash(&[1; 32], &TRUSTEE_ACC)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, VdrError::SignerUnsupportedOperation(_)));
+    }
+}
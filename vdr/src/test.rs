@@ -4,8 +4,11 @@ use crate::{
         auth::Role,
         cl::{
             schema_registry,
-            types::{credential_definition::test::credential_definition, schema::test::schema},
-            CredentialDefinition, Schema, SchemaId,
+            types::{
+                credential_definition::{test::credential_definition, CredentialDefinition},
+                schema::{test::schema, Schema},
+                schema_id::SchemaId,
+            },
         },
         did::{
             did_registry,
@@ -13,19 +16,40 @@ use crate::{
             DidDocument,
         },
     },
-    error::VdrResult,
+    error::{VdrError, VdrResult},
     signer::basic_signer::{
         test::{basic_signer, TRUSTEE_ACC},
         BasicSigner,
     },
-    types::{Address, Transaction},
+    types::{Address, LedgerEvent, Transaction, TransactionReceipt},
     LedgerClient,
 };
+use futures::StreamExt;
+
+#[cfg(feature = "embedded_test")]
+async fn subscription_client() -> LedgerClient {
+    client()
+}
+
+#[cfg(not(feature = "embedded_test"))]
+async fn subscription_client() -> LedgerClient {
+    use crate::{
+        client::client::test::{contracts, CHAIN_ID, WS_NODE_ADDRESS},
+        LedgerClientBuilder, Web3WsClient,
+    };
+
+    let client = Web3WsClient::new_ws(WS_NODE_ADDRESS).await.unwrap();
+    LedgerClientBuilder::new(CHAIN_ID)
+        .set_client(Box::new(client))
+        .set_contract_configs(&contracts())
+        .build()
+        .unwrap()
+}
 
 async fn create_did(client: &LedgerClient, signer: &crate::BasicSigner) -> DidDocument {
     let did_doc = did_doc(None);
     let transaction = did_registry::build_create_did_transaction(
-        &client,
+        client,
         &TRUSTEE_ACC,
         &IDENTITY_ACC,
         &did_doc.id,
@@ -49,7 +73,7 @@ pub async fn create_schema(
 ) -> (SchemaId, Schema) {
     let (id, schema) = schema(issuer_id, None);
     let transaction =
-        schema_registry::build_create_schema_transaction(&client, &TRUSTEE_ACC, &id, &schema)
+        schema_registry::build_create_schema_transaction(client, &TRUSTEE_ACC, &id, &schema)
             .await
             .unwrap();
 
@@ -65,25 +89,25 @@ async fn sign_and_submit_transaction(
     client: &LedgerClient,
     transaction: Transaction,
     signer: &BasicSigner,
-) -> String {
-    let sign_bytes = transaction.get_signing_bytes().unwrap();
-    let signature = signer.sign(&sign_bytes, TRUSTEE_ACC.as_ref()).unwrap();
-    transaction.set_signature(signature);
-    let block_hash = client.submit_transaction(&transaction).await.unwrap();
+) -> TransactionReceipt {
+    let block_hash = client.sign_and_submit(&transaction, signer).await.unwrap();
     client.get_receipt(&block_hash).await.unwrap()
 }
 
 mod did {
     use super::*;
-    use crate::{client::client::test::IDENTITY_ACC, did_registry};
+    use crate::{
+        client::client::test::IDENTITY_ACC, did_registry, did_resolver, DereferencedResource,
+        DidDocumentBuilder, DidResolutionError, DidResolutionOptions, DID_LD_JSON,
+    };
 
     pub(crate) async fn build_and_submit_create_did_doc_transaction(
         client: &LedgerClient,
         did_doc: &DidDocument,
         signer: &BasicSigner,
-    ) -> String {
+    ) -> TransactionReceipt {
         let transaction = did_registry::build_create_did_transaction(
-            &client,
+            client,
             &TRUSTEE_ACC,
             &IDENTITY_ACC,
             &did_doc.id,
@@ -102,7 +126,11 @@ mod did {
         // write
         let did_doc = did_doc(None);
         let receipt = build_and_submit_create_did_doc_transaction(&client, &did_doc, &signer).await;
-        println!("Receipt: {}", receipt);
+        println!("Receipt: {:?}", receipt);
+        assert_eq!(
+            vec![LedgerEvent::DidCreated(did_doc.id.clone())],
+            receipt.events
+        );
 
         // read
         let transaction = did_registry::build_resolve_did_transaction(&client, &did_doc.id)
@@ -114,6 +142,321 @@ mod did {
 
         Ok(())
     }
+
+    #[async_std::test]
+    async fn demo_simulate_create_did_transaction_test() -> VdrResult<()> {
+        let signer = basic_signer();
+        let client = client();
+        let did_doc = did_doc(None);
+        let transaction = did_registry::build_create_did_transaction(
+            &client,
+            &TRUSTEE_ACC,
+            &IDENTITY_ACC,
+            &did_doc.id,
+            &did_doc,
+        )
+        .await
+        .unwrap();
+
+        // simulation does not change the ledger state
+        client.simulate_transaction(&transaction).await.unwrap();
+        client.simulate_transaction(&transaction).await.unwrap();
+
+        build_and_submit_create_did_doc_transaction(&client, &did_doc, &signer).await;
+
+        let err = client
+            .simulate_transaction(&transaction)
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(VdrError::DidAlreadyExists(did_doc.id.to_string()), err);
+
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn demo_resolve_not_existing_did_test() -> VdrResult<()> {
+        let client = client();
+        let did = DID::try_from("did:indy2:testnet:NotExistingDid1234567").unwrap();
+
+        let transaction = did_registry::build_resolve_did_transaction(&client, &did)
+            .await
+            .unwrap();
+        let err = client.submit_transaction(&transaction).await.err().unwrap();
+        assert_eq!(VdrError::DidNotFound(did.to_string()), err);
+
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn demo_resolve_did_with_resolution_metadata_test() -> VdrResult<()> {
+        let signer = basic_signer();
+        let client = client();
+        let did_doc = did_doc(None);
+        build_and_submit_create_did_doc_transaction(&client, &did_doc, &signer).await;
+
+        let result = did_resolver::resolve(&client, did_doc.id.as_ref(), None).await;
+        assert_eq!(None, result.did_resolution_metadata.error);
+        assert_eq!(
+            Some(DID_LD_JSON.to_string()),
+            result.did_resolution_metadata.content_type
+        );
+        assert_eq!(Some(did_doc.clone()), result.did_document);
+        assert!(result.did_document_metadata.created.is_some());
+        assert!(result.did_document_metadata.version_id.is_some());
+
+        let key_id = did_doc.verification_method[0].id.clone();
+        let result = did_resolver::dereference(&client, &key_id, None).await;
+        assert_eq!(
+            Some(DereferencedResource::VerificationMethod(
+                did_doc.verification_method[0].clone()
+            )),
+            result.content_stream
+        );
+
+        let result =
+            did_resolver::resolve(&client, "did:indy2:testnet:NotExistingDid1234567", None).await;
+        assert_eq!(
+            Some(DidResolutionError::NotFound),
+            result.did_resolution_metadata.error
+        );
+        assert_eq!(None, result.did_document);
+
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn demo_update_did_with_builder_test() -> VdrResult<()> {
+        let signer = basic_signer();
+        let client = client();
+        let did_doc = did_doc(None);
+        build_and_submit_create_did_doc_transaction(&client, &did_doc, &signer).await;
+
+        // edit resolved DID Document
+        let transaction = did_registry::build_resolve_did_transaction(&client, &did_doc.id).await?;
+        let result = client.submit_transaction(&transaction).await?;
+        let resolved_did_doc = did_registry::parse_resolve_did_result(&client, &result)?;
+        let builder = DidDocumentBuilder::from_document(&resolved_did_doc)
+            .add_service("DIDCommService", "https://example.com/endpoint")
+            .add_also_known_as("did:example:alias");
+        let transaction = did_registry::build_update_did_transaction_from_builder(
+            &client,
+            &TRUSTEE_ACC,
+            &builder,
+        )
+        .await?;
+        sign_and_submit_transaction(&client, transaction, &signer).await;
+
+        let transaction = did_registry::build_resolve_did_transaction(&client, &did_doc.id).await?;
+        let result = client.submit_transaction(&transaction).await?;
+        let updated_did_doc = did_registry::parse_resolve_did_result(&client, &result)?;
+        assert_eq!(builder.build(), updated_did_doc);
+
+        Ok(())
+    }
+
+    #[async_std::test]
+    async fn demo_resolve_did_versions_test() -> VdrResult<()> {
+        let signer = basic_signer();
+        let client = client();
+        let did_doc = did_doc(None);
+        build_and_submit_create_did_doc_transaction(&client, &did_doc, &signer).await;
+        let created = did_resolver::resolve(&client, did_doc.id.as_ref(), None)
+            .await
+            .did_document_metadata;
+
+        // update DID Document
+        let mut updated_doc = did_doc.clone();
+        updated_doc.also_known_as = Some(vec!["did:example:alias".to_string()]);
+        let transaction = did_registry::build_update_did_transaction(
+            &client,
+            &TRUSTEE_ACC,
+            &did_doc.id,
+            &updated_doc,
+        )
+        .await
+        .unwrap();
+        sign_and_submit_transaction(&client, transaction, &signer).await;
+        let updated = did_resolver::resolve(&client, did_doc.id.as_ref(), None)
+            .await
+            .did_document_metadata;
+        assert_ne!(created.version_id, updated.version_id);
+
+        // resolve the first version
+        let options = DidResolutionOptions {
+            version_id: created.version_id.clone(),
+            ..DidResolutionOptions::default()
+        };
+        let result = did_resolver::resolve(&client, did_doc.id.as_ref(), Some(&options)).await;
+        assert_eq!(None, result.did_resolution_metadata.error);
+        assert_eq!(Some(did_doc.clone()), result.did_document);
+        assert_eq!(created.version_id, result.did_document_metadata.version_id);
+        assert_eq!(
+            updated.version_id,
+            result.did_document_metadata.next_version_id
+        );
+        assert_eq!(updated.updated, result.did_document_metadata.next_update);
+
+        // resolve the version valid at the time of update
+        let options = DidResolutionOptions {
+            version_time: updated.updated.clone(),
+            ..DidResolutionOptions::default()
+        };
+        let result = did_resolver::resolve(&client, did_doc.id.as_ref(), Some(&options)).await;
+        assert_eq!(Some(updated_doc), result.did_document);
+        assert_eq!(None, result.did_document_metadata.next_version_id);
+
+        // dereference key of the first version
+        let key_id = format!(
+            "{}?versionId={}#KEY-1",
+            did_doc.id.as_ref(),
+            created.version_id.unwrap()
+        );
+        let result = did_resolver::dereference(&client, &key_id, None).await;
+        assert_eq!(
+            Some(DereferencedResource::VerificationMethod(
+                did_doc.verification_method[0].clone()
+            )),
+            result.content_stream
+        );
+
+        // unknown version
+        let options = DidResolutionOptions {
+            version_id: Some("1".to_string()),
+            ..DidResolutionOptions::default()
+        };
+        let result = did_resolver::resolve(&client, did_doc.id.as_ref(), Some(&options)).await;
+        assert_eq!(
+            Some(DidResolutionError::NotFound),
+            result.did_resolution_metadata.error
+        );
+
+        Ok(())
+    }
+}
+
+mod did_ethr {
+    use super::*;
+    use crate::{
+        client::client::test::IDENTITY_ACC,
+        contracts::did::ethr::types::did_doc_attribute::test::public_key_attribute,
+        did_ethr_registry, did_resolver, DelegateType, DidResolutionOptions, Signer,
+    };
+    use std::str::FromStr;
+
+    const VALIDITY: u64 = 86400;
+
+    #[async_std::test]
+    async fn demo_build_and_submit_did_ethr_transactions_test() -> VdrResult<()> {
+        let signer = basic_signer();
+        let client = client();
+        let did = DID::from_str(&format!("did:ethr:{}", TRUSTEE_ACC.as_ref()))?;
+
+        // add delegate
+        let transaction = did_ethr_registry::build_did_add_delegate_transaction(
+            &client,
+            &TRUSTEE_ACC,
+            &did,
+            &DelegateType::SigAuth,
+            &IDENTITY_ACC,
+            VALIDITY,
+        )
+        .await?;
+        let receipt = sign_and_submit_transaction(&client, transaction, &signer).await;
+        assert!(matches!(
+            receipt.events[0],
+            LedgerEvent::DidDelegateChanged(_)
+        ));
+
+        // set attribute with meta transaction signed by the identity owner
+        let attribute = public_key_attribute();
+        let signing_bytes = did_ethr_registry::build_did_set_attribute_signing_bytes(
+            &client, &did, &attribute, VALIDITY,
+        )
+        .await?;
+        let signature = signer.sign_hash(&signing_bytes, &TRUSTEE_ACC).await?;
+        let transaction = did_ethr_registry::build_did_set_attribute_signed_transaction(
+            &client,
+            &TRUSTEE_ACC,
+            &did,
+            &attribute,
+            VALIDITY,
+            &signature,
+        )
+        .await?;
+        sign_and_submit_transaction(&client, transaction, &signer).await;
+
+        let result = did_resolver::resolve(&client, did.as_ref(), None).await;
+        let did_doc = result.did_document.unwrap();
+        assert_eq!(3, did_doc.verification_method.len());
+        assert_eq!(2, did_doc.authentication.len());
+        let version_id = result.did_document_metadata.version_id;
+
+        // revoke delegate
+        let transaction = did_ethr_registry::build_did_revoke_delegate_transaction(
+            &client,
+            &TRUSTEE_ACC,
+            &did,
+            &DelegateType::SigAuth,
+            &IDENTITY_ACC,
+        )
+        .await?;
+        sign_and_submit_transaction(&client, transaction, &signer).await;
+        let did_doc = did_resolver::resolve(&client, did.as_ref(), None)
+            .await
+            .did_document
+            .unwrap();
+        assert_eq!(2, did_doc.verification_method.len());
+        assert_eq!(1, did_doc.authentication.len());
+
+        // resolve the version before revocation
+        let options = DidResolutionOptions {
+            version_id,
+            ..DidResolutionOptions::default()
+        };
+        let result = did_resolver::resolve(&client, did.as_ref(), Some(&options)).await;
+        assert_eq!(None, result.did_resolution_metadata.error);
+        assert_eq!(3, result.did_document.unwrap().verification_method.len());
+        assert!(result.did_document_metadata.next_version_id.is_some());
+
+        Ok(())
+    }
+}
+
+mod universal_did_resolver {
+    use super::*;
+    use crate::{did_resolver, universal_did_resolver};
+    use std::str::FromStr;
+
+    #[async_std::test]
+    async fn demo_resolve_any_did_test() -> VdrResult<()> {
+        let signer = basic_signer();
+        let client = client();
+
+        // did:indy2 is resolved by UniversalDidResolver contract
+        let did_doc = create_did(&client, &signer).await;
+        let resolved_did_doc =
+            universal_did_resolver::resolve_any_did(&client, &did_doc.id).await?;
+        assert_eq!(did_doc, resolved_did_doc);
+
+        let transaction =
+            universal_did_resolver::build_resolve_metadata_transaction(&client, &did_doc.id)
+                .await?;
+        let result = client.submit_transaction(&transaction).await?;
+        let metadata = universal_did_resolver::parse_resolve_metadata_result(&client, &result)?;
+        assert_eq!(IDENTITY_ACC.clone(), metadata.owner);
+
+        // did:ethr DID Document is built from EthereumExtDidRegistry events
+        let did = DID::from_str(&format!("did:ethr:{}", IDENTITY_ACC.as_ref()))?;
+        let resolved_did_doc = universal_did_resolver::resolve_any_did(&client, &did).await?;
+        let expected_did_doc = did_resolver::resolve(&client, did.as_ref(), None)
+            .await
+            .did_document
+            .unwrap();
+        assert_eq!(expected_did_doc, resolved_did_doc);
+
+        Ok(())
+    }
 }
 
 mod schema {
@@ -125,9 +468,9 @@ mod schema {
         id: &SchemaId,
         schema: &Schema,
         signer: &BasicSigner,
-    ) -> String {
+    ) -> TransactionReceipt {
         let transaction =
-            schema_registry::build_create_schema_transaction(&client, &TRUSTEE_ACC, id, schema)
+            schema_registry::build_create_schema_transaction(client, &TRUSTEE_ACC, id, schema)
                 .await
                 .unwrap();
         sign_and_submit_transaction(client, transaction, signer).await
@@ -145,7 +488,7 @@ mod schema {
         let (schema_id, schema) = schema(&did_doc.id, None);
         let receipt =
             build_and_submit_create_schema_transaction(&client, &schema_id, &schema, &signer).await;
-        println!("Receipt: {}", receipt);
+        println!("Receipt: {:?}", receipt);
 
         // read
         let transaction = schema_registry::build_resolve_schema_transaction(&client, &schema_id)
@@ -169,10 +512,10 @@ mod credential_definition {
         id: &CredentialDefinitionId,
         cred_def: &CredentialDefinition,
         signer: &BasicSigner,
-    ) -> String {
+    ) -> TransactionReceipt {
         let transaction =
             credential_definition_registry::build_create_credential_definition_transaction(
-                &client,
+                client,
                 &TRUSTEE_ACC,
                 id,
                 cred_def,
@@ -201,7 +544,7 @@ mod credential_definition {
             &signer,
         )
         .await;
-        println!("Receipt: {}", receipt);
+        println!("Receipt: {:?}", receipt);
 
         // read
         let transaction =
@@ -223,6 +566,125 @@ mod credential_definition {
     }
 }
 
+mod endorsement {
+    use super::*;
+    use crate::{credential_definition_registry, did_registry, schema_registry};
+
+    #[async_std::test]
+    async fn demo_endorse_did_schema_and_credential_definition_test() -> VdrResult<()> {
+        let endorser_signer = basic_signer();
+        let client = client();
+        let mut author_signer = BasicSigner::new()?;
+        let (author, _) = author_signer.create_key(None)?;
+
+        // author signs the DID Document, Endorser writes it
+        let (did_doc, another_did_doc) = (did_doc(None), did_doc(None));
+        let sign_bytes =
+            did_registry::build_endorse_did_signing_bytes(&client, &author, &did_doc.id, &did_doc)
+                .await?;
+        let author_signature = author_signer.sign(&sign_bytes, author.as_ref())?;
+        let transaction = did_registry::build_endorse_did_transaction(
+            &client,
+            &TRUSTEE_ACC,
+            &author,
+            &did_doc.id,
+            &did_doc,
+            &author_signature,
+        )
+        .await?;
+        let receipt = sign_and_submit_transaction(&client, transaction, &endorser_signer).await;
+        assert_eq!(
+            vec![LedgerEvent::DidCreated(did_doc.id.clone())],
+            receipt.events
+        );
+
+        let transaction = did_registry::build_resolve_did_transaction(&client, &did_doc.id).await?;
+        let result = client.submit_transaction(&transaction).await?;
+        let did_record = did_registry::parse_resolve_did_record(&client, &result)?;
+        assert_eq!(did_doc, did_record.document);
+        assert_eq!(author, did_record.metadata.owner);
+        assert_eq!(*TRUSTEE_ACC, did_record.metadata.sender);
+
+        // the author signature can not be used for another DID: the DID and the nonce are signed
+        let err = did_registry::build_endorse_did_transaction(
+            &client,
+            &TRUSTEE_ACC,
+            &author,
+            &another_did_doc.id,
+            &another_did_doc,
+            &author_signature,
+        )
+        .await
+        .err()
+        .unwrap();
+        assert!(matches!(err, VdrError::SignerInvalidSignature(_)));
+
+        // author signs the Schema, Endorser writes it
+        let (schema_id, schema) = schema(&did_doc.id, None);
+        let sign_bytes = schema_registry::build_endorse_schema_signing_bytes(
+            &client, &author, &schema_id, &schema,
+        )
+        .await?;
+        let author_signature = author_signer.sign(&sign_bytes, author.as_ref())?;
+        let transaction = schema_registry::build_endorse_schema_transaction(
+            &client,
+            &TRUSTEE_ACC,
+            &schema_id,
+            &schema,
+            &author_signature,
+        )
+        .await?;
+        sign_and_submit_transaction(&client, transaction, &endorser_signer).await;
+
+        let transaction =
+            schema_registry::build_resolve_schema_transaction(&client, &schema_id).await?;
+        let result = client.submit_transaction(&transaction).await?;
+        assert_eq!(
+            schema,
+            schema_registry::parse_resolve_schema_result(&client, &result)?
+        );
+
+        // author signs the Credential Definition, Endorser writes it
+        let (credential_definition_id, credential_definition) =
+            credential_definition(&did_doc.id, &schema_id, None);
+        let sign_bytes =
+            credential_definition_registry::build_endorse_credential_definition_signing_bytes(
+                &client,
+                &author,
+                &credential_definition_id,
+                &credential_definition,
+            )
+            .await?;
+        let author_signature = author_signer.sign(&sign_bytes, author.as_ref())?;
+        let transaction =
+            credential_definition_registry::build_endorse_credential_definition_transaction(
+                &client,
+                &TRUSTEE_ACC,
+                &credential_definition_id,
+                &credential_definition,
+                &author_signature,
+            )
+            .await?;
+        sign_and_submit_transaction(&client, transaction, &endorser_signer).await;
+
+        let transaction =
+            credential_definition_registry::build_resolve_credential_definition_transaction(
+                &client,
+                &credential_definition_id,
+            )
+            .await?;
+        let result = client.submit_transaction(&transaction).await?;
+        assert_eq!(
+            credential_definition,
+            credential_definition_registry::parse_resolve_credential_definition_result(
+                &client, &result,
+            )?
+        );
+
+        Ok(())
+    }
+}
+
 mod role {
     use super::*;
     use crate::role_control;
@@ -232,7 +694,7 @@ mod role {
         assignee_account: &Address,
         role_to_assign: &Role,
         signer: &BasicSigner,
-    ) -> String {
+    ) -> TransactionReceipt {
         let transaction = role_control::build_assign_role_transaction(
             client,
             &TRUSTEE_ACC,
@@ -249,7 +711,7 @@ mod role {
         revokee_account: &Address,
         role_to_revoke: &Role,
         signer: &BasicSigner,
-    ) -> String {
+    ) -> TransactionReceipt {
         let transaction = role_control::build_revoke_role_transaction(
             client,
             &TRUSTEE_ACC,
@@ -276,7 +738,7 @@ mod role {
             .await
             .unwrap();
         let result = client.submit_transaction(&transaction).await.unwrap();
-        role_control::parse_get_role_result(&client, &result).unwrap()
+        role_control::parse_get_role_result(client, &result).unwrap()
     }
 
     async fn build_and_submit_has_role_transaction(
@@ -288,7 +750,7 @@ mod role {
             .await
             .unwrap();
         let result = client.submit_transaction(&transaction).await.unwrap();
-        role_control::parse_has_role_result(&client, &result).unwrap()
+        role_control::parse_has_role_result(client, &result).unwrap()
     }
 
     #[async_std::test]
@@ -305,7 +767,11 @@ mod role {
             &signer,
         )
         .await;
-        println!("Receipt: {}", receipt);
+        println!("Receipt: {:?}", receipt);
+        assert!(matches!(
+            receipt.events.as_slice(),
+            [LedgerEvent::RoleAssigned(event)] if event.role == role_to_assign
+        ));
 
         let assigned_role = build_and_submit_get_role_transaction(&client, &assignee_account).await;
         assert_eq!(role_to_assign, assigned_role);
@@ -317,7 +783,11 @@ mod role {
             &signer,
         )
         .await;
-        println!("Receipt: {}", receipt);
+        println!("Receipt: {:?}", receipt);
+        assert!(matches!(
+            receipt.events.as_slice(),
+            [LedgerEvent::RoleRevoked(event)] if event.role == role_to_assign
+        ));
 
         let has_role =
             build_and_submit_has_role_transaction(&client, &role_to_assign, &assignee_account)
@@ -326,12 +796,38 @@ mod role {
 
         Ok(())
     }
+
+    #[async_std::test]
+    async fn demo_subscribe_role_assigned_events_test() -> VdrResult<()> {
+        let signer = basic_signer();
+        let (assignee_account, _) = signer.create_account(None).unwrap();
+        let client = subscription_client().await;
+        let role_to_assign = Role::Endorser;
+
+        let mut events = role_control::subscribe_role_assigned(&client).await?;
+
+        build_and_submit_assign_role_transaction(
+            &client,
+            &assignee_account,
+            &role_to_assign,
+            &signer,
+        )
+        .await;
+
+        let log = events.next().await.unwrap()?;
+        let event = role_control::parse_role_assigned_event(&client, &log)?;
+        assert_eq!(role_to_assign, event.role);
+        assert_eq!(assignee_account, event.account);
+        assert_eq!(TRUSTEE_ACC.clone(), event.sender);
+
+        Ok(())
+    }
 }
 
 mod validator {
     use crate::{
-        contracts::network::ValidatorAddresses, signer::basic_signer::test::basic_signer,
-        validator_control,
+        contracts::network::validator_info::ValidatorAddresses,
+        signer::basic_signer::test::basic_signer, validator_control,
     };
 
     use super::*;
@@ -339,21 +835,21 @@ mod validator {
     async fn build_and_submit_get_validators_transaction(
         client: &LedgerClient,
     ) -> ValidatorAddresses {
-        let transaction = validator_control::build_get_validators_transaction(&client)
+        let transaction = validator_control::build_get_validators_transaction(client)
             .await
             .unwrap();
         let result = client.submit_transaction(&transaction).await.unwrap();
 
-        validator_control::parse_get_validators_result(&client, &result).unwrap()
+        validator_control::parse_get_validators_result(client, &result).unwrap()
     }
 
     async fn build_and_submit_add_validator_transaction(
         client: &LedgerClient,
         new_validator_address: &Address,
         signer: &BasicSigner,
-    ) -> String {
+    ) -> TransactionReceipt {
         let transaction = validator_control::build_add_validator_transaction(
-            &client,
+            client,
             &TRUSTEE_ACC,
             new_validator_address,
         )
@@ -366,10 +862,10 @@ mod validator {
         client: &LedgerClient,
         validator_address: &Address,
         signer: &BasicSigner,
-    ) -> String {
+    ) -> TransactionReceipt {
         // write
         let transaction = validator_control::build_remove_validator_transaction(
-            &client,
+            client,
             &TRUSTEE_ACC,
             validator_address,
         )
@@ -394,7 +890,7 @@ mod validator {
         let receipt =
             build_and_submit_add_validator_transaction(&client, &new_validator_address, &signer)
                 .await;
-        println!("Receipt: {}", receipt);
+        println!("Receipt: {:?}", receipt);
 
         let validator_list = build_and_submit_get_validators_transaction(&client).await;
         assert_eq!(validator_list.len(), 5);
@@ -403,7 +899,7 @@ mod validator {
         let receipt =
             build_and_submit_remove_validator_transaction(&client, &new_validator_address, &signer)
                 .await;
-        println!("Receipt: {}", receipt);
+        println!("Receipt: {:?}", receipt);
 
         let validator_list = build_and_submit_get_validators_transaction(&client).await;
         assert_eq!(validator_list.len(), 4);
@@ -6,9 +6,9 @@ use crate::{
 use ethereum_types::Address as Address_;
 use log::trace;
 use serde::{Deserialize, Serialize};
-use std::str::FromStr;
+use std::{fmt, str::FromStr};
 
-#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Address(String);
 
 const PREFIX: &str = "0x";
@@ -29,9 +29,9 @@ impl AsRef<str> for Address {
     }
 }
 
-impl ToString for Address {
-    fn to_string(&self) -> String {
-        self.0.to_string()
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
     }
 }
 
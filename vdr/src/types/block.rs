@@ -0,0 +1,10 @@
+use serde_derive::{Deserialize, Serialize};
+
+/// Header fields of a ledger block
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Block {
+    /// Number of the block
+    pub number: u64,
+    /// Unix timestamp (in seconds) of the block
+    pub timestamp: u64,
+}
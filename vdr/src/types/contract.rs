@@ -8,7 +8,7 @@ use log::{trace, warn};
 use serde::{Deserialize, Serialize};
 
 /// Contract configuration
-#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ContractConfig {
     /// Address of deployed contract
     pub address: String,
@@ -149,6 +149,36 @@ impl ContractOutput {
             .as_u128())
     }
 
+    /// Get uint value saturated to `u64::MAX` (timestamps set far in the future)
+    pub fn get_u64(&self, index: usize) -> VdrResult<u64> {
+        let value = self
+            .0
+            .get(index)
+            .ok_or_else(|| VdrError::ContractInvalidResponseData("Missing uint value".to_string()))?
+            .clone()
+            .into_uint()
+            .ok_or_else(|| {
+                VdrError::ContractInvalidResponseData("Missing uint value".to_string())
+            })?;
+        Ok(if value.bits() > 64 {
+            u64::MAX
+        } else {
+            value.as_u64()
+        })
+    }
+
+    /// Get value of `bytes` or `bytesN` type
+    pub fn get_bytes(&self, index: usize) -> VdrResult<Vec<u8>> {
+        match self.0.get(index) {
+            Some(ContractParam::Bytes(bytes)) | Some(ContractParam::FixedBytes(bytes)) => {
+                Ok(bytes.clone())
+            }
+            _ => Err(VdrError::ContractInvalidResponseData(
+                "Missing bytes value".to_string(),
+            )),
+        }
+    }
+
     pub fn get_u8(&self, index: usize) -> VdrResult<u8> {
         Ok(self
             .0
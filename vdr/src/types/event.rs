@@ -0,0 +1,166 @@
+use crate::{
+    client::LedgerClient,
+    error::{VdrError, VdrResult},
+    types::ContractOutput,
+    Address,
+};
+
+use futures::stream::Stream;
+use log::trace;
+use serde_derive::{Deserialize, Serialize};
+use std::pin::Pin;
+
+/// Log entry emitted by a contract during transaction execution
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventLog {
+    /// Address of the contract emitted the event
+    pub address: Address,
+    /// Indexed event parameters. The first topic is the hash of the event signature
+    pub topics: Vec<Vec<u8>>,
+    /// ABI encoded not indexed event parameters
+    pub data: Vec<u8>,
+    /// Number of the block containing the transaction
+    pub block_number: Option<u64>,
+    /// Hash of the transaction emitted the event
+    pub transaction_hash: Option<Vec<u8>>,
+}
+
+/// Filter defining contract events to subscribe
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct EventQuery {
+    /// Address of the contract emitting events
+    pub address: Address,
+    /// Hash of the event signature (first log topic). All contract events are matched if not set
+    pub event_signature: Option<Vec<u8>>,
+    /// Value of the first indexed event parameter (second log topic). Any value is matched if not set
+    pub indexed_topic: Option<Vec<u8>>,
+}
+
+impl EventQuery {
+    /// Check whether the log matches to the query
+    pub fn matches(&self, log: &EventLog) -> bool {
+        let address_matches = self
+            .address
+            .as_ref()
+            .eq_ignore_ascii_case(log.address.as_ref());
+        let signature_matches = match self.event_signature.as_ref() {
+            Some(signature) => log.topics.first() == Some(signature),
+            None => true,
+        };
+        let indexed_topic_matches = match self.indexed_topic.as_ref() {
+            Some(topic) => log.topics.get(1) == Some(topic),
+            None => true,
+        };
+        address_matches && signature_matches && indexed_topic_matches
+    }
+}
+
+/// Stream of contract events delivered by a subscription
+pub type EventStream = Pin<Box<dyn Stream<Item = VdrResult<EventLog>> + Send>>;
+
+#[derive(Debug, Default)]
+pub struct EventParser {
+    contract: String,
+    event: String,
+}
+
+impl EventParser {
+    pub fn new() -> EventParser {
+        EventParser::default()
+    }
+
+    pub fn set_contract(mut self, contract: &str) -> EventParser {
+        self.contract = contract.to_string();
+
+        trace!("Set contract: {} to EventParser: {:?}", contract, self);
+
+        self
+    }
+
+    pub fn set_event(mut self, event: &str) -> EventParser {
+        self.event = event.to_string();
+
+        trace!("Set event: {} to EventParser: {:?}", event, self);
+
+        self
+    }
+
+    pub async fn subscribe(self, client: &LedgerClient) -> VdrResult<EventStream> {
+        client.subscribe_events(&self.contract, &self.event).await
+    }
+
+    pub fn matches(&self, client: &LedgerClient, log: &EventLog) -> bool {
+        let contract = match client.contract(&self.contract) {
+            Ok(contract) => contract,
+            Err(_) => return false,
+        };
+        let query = EventQuery {
+            address: contract.address().clone(),
+            event_signature: contract.event_signature(&self.event).ok(),
+            indexed_topic: None,
+        };
+        query.event_signature.is_some() && query.matches(log)
+    }
+
+    pub fn parse<T: TryFrom<ContractOutput, Error = VdrError>>(
+        self,
+        client: &LedgerClient,
+        log: &EventLog,
+    ) -> VdrResult<T> {
+        let contract = client.contract(&self.contract)?;
+        let output = contract.decode_event(&self.event, log)?;
+
+        trace!("Decoded event: {:?}", output);
+
+        T::try_from(output)
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+
+    fn event_log() -> EventLog {
+        EventLog {
+            address: Address::from("0x0000000000000000000000000000000000003333"),
+            topics: vec![vec![1; 32]],
+            ..EventLog::default()
+        }
+    }
+
+    #[test]
+    fn event_query_matches_test() {
+        let query = EventQuery {
+            address: Address::from("0x0000000000000000000000000000000000003333"),
+            event_signature: Some(vec![1; 32]),
+            indexed_topic: None,
+        };
+        assert!(query.matches(&event_log()));
+    }
+
+    #[test]
+    fn event_query_matches_indexed_topic_test() {
+        let log = EventLog {
+            topics: vec![vec![1; 32], vec![3; 32]],
+            ..event_log()
+        };
+        let query = |topic: Vec<u8>| EventQuery {
+            address: Address::from("0x0000000000000000000000000000000000003333"),
+            event_signature: None,
+            indexed_topic: Some(topic),
+        };
+        assert!(query(vec![3; 32]).matches(&log));
+        assert!(!query(vec![4; 32]).matches(&log));
+        assert!(!query(vec![3; 32]).matches(&event_log()));
+    }
+
+    #[test]
+    fn event_query_does_not_match_other_event_test() {
+        let query = EventQuery {
+            address: Address::from("0x0000000000000000000000000000000000003333"),
+            event_signature: Some(vec![2; 32]),
+            indexed_topic: None,
+        };
+        assert!(!query.matches(&event_log()));
+    }
+}
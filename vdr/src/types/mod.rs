@@ -1,11 +1,22 @@
 mod address;
+mod block;
 mod contract;
+mod event;
+mod receipt;
+mod serialized_transaction;
 mod status;
 mod transaction;
 
 pub use address::Address;
+pub use block::Block;
 pub use contract::{ContractConfig, ContractSpec};
 pub(crate) use contract::{ContractOutput, ContractParam};
+pub(crate) use event::EventParser;
+pub use event::{EventLog, EventQuery, EventStream};
+pub use receipt::{LedgerEvent, ReceiptStatus, TransactionReceipt};
 pub use status::{PingStatus, Status};
-pub use transaction::{SignatureData, Transaction, TransactionSignature, TransactionType};
+pub use transaction::{
+    AccessListItem, SignatureData, Transaction, TransactionCall, TransactionCallParam,
+    TransactionConfig, TransactionEnvelopeType, TransactionSignature, TransactionType,
+};
 pub(crate) use transaction::{TransactionBuilder, TransactionParser};
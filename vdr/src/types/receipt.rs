@@ -0,0 +1,213 @@
+use crate::{
+    client::LedgerClient,
+    contracts::{
+        auth::{role_control, RoleAssignedEvent, RoleRevokedEvent},
+        cl::{
+            credential_definition_registry, schema_registry,
+            types::{credential_definition_id::CredentialDefinitionId, schema_id::SchemaId},
+        },
+        did::{
+            did_registry,
+            ethr::{
+                did_ethr_registry,
+                types::did_events::{
+                    DidAttributeChangedEvent, DidDelegateChangedEvent, DidOwnerChangedEvent,
+                },
+            },
+            types::did::DID,
+        },
+        network::{
+            validator_control,
+            validator_info::{ValidatorAddedEvent, ValidatorRemovedEvent},
+        },
+    },
+    error::VdrResult,
+    types::EventLog,
+    Address,
+};
+
+use log::{trace, warn};
+use serde_derive::{Deserialize, Serialize};
+
+/// Receipt of a transaction included into a block
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransactionReceipt {
+    /// Hash of the transaction
+    pub transaction_hash: Vec<u8>,
+    /// Hash of the block containing the transaction
+    pub block_hash: Option<Vec<u8>>,
+    /// Number of the block containing the transaction
+    pub block_number: Option<u64>,
+    /// Transaction sender account address
+    pub from: Address,
+    /// Address of the executed contract
+    pub to: Option<Address>,
+    /// Amount of gas used by the transaction
+    pub gas_used: Option<u64>,
+    /// Transaction execution status
+    pub status: ReceiptStatus,
+    /// Raw logs emitted during transaction execution
+    pub logs: Vec<EventLog>,
+    /// Logs emitted by known contracts decoded into typed events
+    pub events: Vec<LedgerEvent>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ReceiptStatus {
+    Success,
+    #[default]
+    Failure,
+}
+
+/// Event emitted by one of the ledger contracts
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum LedgerEvent {
+    DidCreated(DID),
+    DidUpdated(DID),
+    DidDeactivated(DID),
+    DidOwnerChanged(DidOwnerChangedEvent),
+    DidDelegateChanged(DidDelegateChangedEvent),
+    DidAttributeChanged(DidAttributeChangedEvent),
+    SchemaCreated(SchemaId),
+    CredentialDefinitionCreated(CredentialDefinitionId),
+    RoleAssigned(RoleAssignedEvent),
+    RoleRevoked(RoleRevokedEvent),
+    ValidatorAdded(ValidatorAddedEvent),
+    ValidatorRemoved(ValidatorRemovedEvent),
+}
+
+impl LedgerEvent {
+    /// Decode event log emitted by one of the contracts loaded into the client
+    ///
+    /// # Params
+    /// - `client` client connected to the network where the log was emitted
+    /// - `log` event log to decode
+    ///
+    /// # Returns
+    /// decoded event or `None` if the log does not belong to a known contract event
+    pub fn decode(client: &LedgerClient, log: &EventLog) -> VdrResult<Option<LedgerEvent>> {
+        trace!("LedgerEvent decode from EventLog: {:?} has started", log);
+
+        let decoders = [
+            did_registry::decode_event,
+            did_ethr_registry::decode_event,
+            schema_registry::decode_event,
+            credential_definition_registry::decode_event,
+            role_control::decode_event,
+            validator_control::decode_event,
+        ];
+
+        let mut event = None;
+        for decoder in decoders {
+            event = decoder(client, log)?;
+            if event.is_some() {
+                break;
+            }
+        }
+
+        trace!(
+            "LedgerEvent decode from EventLog: {:?} has finished. Result: {:?}",
+            log,
+            event
+        );
+
+        Ok(event)
+    }
+}
+
+impl TransactionReceipt {
+    /// Decode logs of known contracts into typed events
+    ///     Logs which can not be decoded are skipped: they are still available in `logs`
+    pub(crate) fn decode_events(mut self, client: &LedgerClient) -> TransactionReceipt {
+        let mut events = Vec::new();
+        for log in self.logs.iter() {
+            match LedgerEvent::decode(client, log) {
+                Ok(Some(event)) => events.push(event),
+                Ok(None) => {}
+                Err(err) => warn!(
+                    "Error: {} during decoding event log: {:?}. The event is skipped",
+                    err, log
+                ),
+            }
+        }
+        self.events = events;
+        self
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::{
+        client::{
+            client::test::CHAIN_ID,
+            implementation::web3::contract::test::{role_control_spec, ROLE_CONTROL_ADDRESS},
+            LedgerClientBuilder, MockClient as AutoMockClient,
+        },
+        contracts::auth::Role,
+        types::ContractConfig,
+    };
+    use ethabi::Token;
+    use ethereum_types::H160;
+    use std::str::FromStr;
+
+    const ACCOUNT: &str = "0xb9059400dcd05158ffd8ca092937989dd27b3bdc";
+    const SENDER: &str = "0xf0e2db6c8dc6c681bb5d6ad121a107f300e9b2b5";
+
+    fn role_control_client() -> LedgerClient {
+        LedgerClientBuilder::new(CHAIN_ID)
+            .set_client(Box::new(AutoMockClient::new()))
+            .set_contract_configs(&[ContractConfig {
+                address: ROLE_CONTROL_ADDRESS.to_string(),
+                spec_path: None,
+                spec: Some(role_control_spec()),
+            }])
+            .build()
+            .unwrap()
+    }
+
+    fn address_topic(address: &str) -> Vec<u8> {
+        ethabi::encode(&[Token::Address(H160::from_str(address).unwrap())])
+    }
+
+    fn role_assigned_log(client: &LedgerClient, data: Vec<u8>) -> EventLog {
+        EventLog {
+            address: Address::from(ROLE_CONTROL_ADDRESS),
+            topics: vec![
+                client
+                    .contract("RoleControl")
+                    .unwrap()
+                    .event_signature("RoleAssigned")
+                    .unwrap(),
+                address_topic(ACCOUNT),
+                address_topic(SENDER),
+            ],
+            data,
+            ..EventLog::default()
+        }
+    }
+
+    #[test]
+    fn decode_events_skips_malformed_log_test() {
+        let client = role_control_client();
+        let receipt = TransactionReceipt {
+            logs: vec![
+                role_assigned_log(&client, vec![1, 2, 3]),
+                role_assigned_log(&client, ethabi::encode(&[Token::Uint(1.into())])),
+            ],
+            ..TransactionReceipt::default()
+        };
+
+        let receipt = receipt.decode_events(&client);
+
+        assert_eq!(2, receipt.logs.len());
+        assert_eq!(
+            vec![LedgerEvent::RoleAssigned(RoleAssignedEvent {
+                role: Role::Trustee,
+                account: Address::from(ACCOUNT),
+                sender: Address::from(SENDER),
+            })],
+            receipt.events
+        );
+    }
+}
@@ -0,0 +1,704 @@
+use log::{trace, warn};
+use rlp::{Rlp, RlpStream};
+use serde_derive::{Deserialize, Serialize};
+use std::sync::RwLock;
+
+use crate::{
+    client::implementation::web3::contract::format_token,
+    error::{VdrError, VdrResult},
+    types::{
+        AccessListItem, Address, Transaction, TransactionCall, TransactionCallParam,
+        TransactionEnvelopeType, TransactionSignature, TransactionType,
+    },
+};
+
+/// Version of the transaction serialization format.
+/// Must be increased on any incompatible change of the JSON or binary layout
+const VERSION: u8 = 1;
+
+/// Prefix of binary serialized transactions (followed by the format version and RLP list)
+const MAGIC: &[u8] = b"IVDR";
+
+/// Number of items in the RLP list of binary serialized transaction
+const RLP_ITEMS: usize = 15;
+
+/// JSON representation of a transaction transferred between the building and signing sides.
+/// Binary values are `0x` prefixed hex strings, so the content can be reviewed by a human
+#[derive(Debug, Serialize, Deserialize)]
+struct SerializedTransaction {
+    version: u8,
+    #[serde(rename = "type")]
+    type_: TransactionType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    from: Option<Address>,
+    to: Address,
+    chain_id: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    nonce: Option<Vec<u64>>,
+    data: String,
+    #[serde(default)]
+    envelope: TransactionEnvelopeType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    gas_limit: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    gas_price: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_fee_per_gas: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max_priority_fee_per_gas: Option<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    access_list: Vec<SerializedAccessListItem>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    call: Option<TransactionCall>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    signature: Option<SerializedSignature>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    hash: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SerializedAccessListItem {
+    address: Address,
+    storage_keys: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SerializedSignature {
+    v: u64,
+    r: String,
+    s: String,
+}
+
+impl Transaction {
+    /// Serialize transaction into versioned JSON string
+    ///     Can be used to pass unsigned transaction to an offline (air-gapped) signer
+    ///     and signed transaction back for the submission
+    ///
+    /// # Returns
+    /// JSON string containing transaction, its decoded contract call and signature (if set)
+    pub fn to_json(&self) -> VdrResult<String> {
+        let serialized = SerializedTransaction {
+            version: VERSION,
+            type_: self.type_.clone(),
+            from: self.from.clone(),
+            to: self.to.clone(),
+            chain_id: self.chain_id,
+            nonce: self.nonce.clone(),
+            data: encode_hex(&self.data),
+            envelope: self.envelope.clone(),
+            gas_limit: self.gas_limit,
+            gas_price: self.gas_price,
+            max_fee_per_gas: self.max_fee_per_gas,
+            max_priority_fee_per_gas: self.max_priority_fee_per_gas,
+            access_list: self
+                .access_list
+                .iter()
+                .map(|item| SerializedAccessListItem {
+                    address: item.address.clone(),
+                    storage_keys: item
+                        .storage_keys
+                        .iter()
+                        .map(|key| encode_hex(key))
+                        .collect(),
+                })
+                .collect(),
+            call: self.call.clone(),
+            signature: self.read_signature()?.map(|signature| SerializedSignature {
+                v: signature.v,
+                r: encode_hex(&signature.r),
+                s: encode_hex(&signature.s),
+            }),
+            hash: self.hash.as_deref().map(encode_hex),
+        };
+
+        let json = serde_json::to_string(&serialized).map_err(|err| {
+            let vdr_error = VdrError::CommonInvalidData(format!(
+                "Unable to serialize transaction into JSON. Err: {:?}",
+                err
+            ));
+
+            warn!("Error: {} during transaction serialization", vdr_error);
+
+            vdr_error
+        })?;
+
+        trace!("Serialized transaction into JSON: {}", json);
+
+        Ok(json)
+    }
+
+    /// Restore transaction from JSON string created by [Transaction::to_json]
+    ///     The decoded contract call is rejected if it does not describe the transaction data
+    ///
+    /// # Params
+    /// - `json` serialized transaction
+    ///
+    /// # Returns
+    /// transaction object
+    pub fn from_json(json: &str) -> VdrResult<Transaction> {
+        let serialized: SerializedTransaction = serde_json::from_str(json).map_err(|err| {
+            let vdr_error = VdrError::CommonInvalidData(format!(
+                "Unable to parse transaction from JSON. Err: {:?}",
+                err
+            ));
+
+            warn!("Error: {} during transaction deserialization", vdr_error);
+
+            vdr_error
+        })?;
+        check_version(serialized.version)?;
+
+        let signature = match serialized.signature {
+            Some(signature) => Some(TransactionSignature {
+                v: signature.v,
+                r: decode_hex(&signature.r)?,
+                s: decode_hex(&signature.s)?,
+            }),
+            None => None,
+        };
+
+        let transaction = Transaction {
+            type_: serialized.type_,
+            from: serialized.from,
+            to: serialized.to,
+            nonce: serialized.nonce,
+            chain_id: serialized.chain_id,
+            data: decode_hex(&serialized.data)?,
+            signature: RwLock::new(signature),
+            hash: serialized.hash.as_deref().map(decode_hex).transpose()?,
+            envelope: serialized.envelope,
+            gas_limit: serialized.gas_limit,
+            gas_price: serialized.gas_price,
+            max_fee_per_gas: serialized.max_fee_per_gas,
+            max_priority_fee_per_gas: serialized.max_priority_fee_per_gas,
+            access_list: serialized
+                .access_list
+                .into_iter()
+                .map(|item| {
+                    Ok(AccessListItem {
+                        address: item.address,
+                        storage_keys: item
+                            .storage_keys
+                            .iter()
+                            .map(|key| decode_hex(key))
+                            .collect::<VdrResult<_>>()?,
+                    })
+                })
+                .collect::<VdrResult<_>>()?,
+            call: serialized.call,
+        };
+        check_call(&transaction)?;
+
+        trace!("Restored transaction from JSON: {:?}", transaction);
+
+        Ok(transaction)
+    }
+
+    /// Serialize transaction into versioned compact binary form
+    ///     (magic prefix, format version and RLP list of transaction fields)
+    ///     Can be used for channels with limited capacity, like QR codes
+    ///
+    /// # Returns
+    /// bytes containing transaction, its decoded contract call and signature (if set)
+    pub fn to_bytes(&self) -> VdrResult<Vec<u8>> {
+        let signature = self.read_signature()?;
+
+        let mut stream = RlpStream::new_list(RLP_ITEMS);
+        stream.append(&encode_type(&self.type_));
+        append_optional(&mut stream, self.from.as_ref(), |stream, from| {
+            stream.append(&from.as_ref());
+        });
+        stream.append(&self.to.as_ref());
+        stream.append(&self.chain_id);
+        append_optional(&mut stream, self.nonce.as_ref(), |stream, nonce| {
+            stream.append_list(nonce);
+        });
+        stream.append(&self.data);
+        stream.append(&encode_envelope(&self.envelope));
+        for value in [
+            self.gas_limit,
+            self.gas_price,
+            self.max_fee_per_gas,
+            self.max_priority_fee_per_gas,
+        ] {
+            append_optional(&mut stream, value.as_ref(), |stream, value| {
+                stream.append(value);
+            });
+        }
+        stream.begin_list(self.access_list.len());
+        for item in self.access_list.iter() {
+            stream.begin_list(2);
+            stream.append(&item.address.as_ref());
+            stream.begin_list(item.storage_keys.len());
+            for key in item.storage_keys.iter() {
+                stream.append(key);
+            }
+        }
+        append_optional(&mut stream, self.call.as_ref(), |stream, call| {
+            stream.begin_list(3);
+            stream.append(&call.contract);
+            stream.append(&call.method);
+            stream.begin_list(call.params.len());
+            for param in call.params.iter() {
+                stream.begin_list(3);
+                stream.append(&param.name);
+                stream.append(&param.type_);
+                stream.append(&param.value);
+            }
+        });
+        append_optional(&mut stream, signature.as_ref(), |stream, signature| {
+            stream.begin_list(3);
+            stream.append(&signature.v);
+            stream.append(&signature.r);
+            stream.append(&signature.s);
+        });
+        append_optional(&mut stream, self.hash.as_ref(), |stream, hash| {
+            stream.append(hash);
+        });
+
+        let mut bytes = MAGIC.to_vec();
+        bytes.push(VERSION);
+        bytes.extend(stream.out());
+
+        trace!("Serialized transaction into {} bytes", bytes.len());
+
+        Ok(bytes)
+    }
+
+    /// Restore transaction from bytes created by [Transaction::to_bytes]
+    ///     The decoded contract call is rejected if it does not describe the transaction data
+    ///
+    /// # Params
+    /// - `bytes` serialized transaction
+    ///
+    /// # Returns
+    /// transaction object
+    pub fn from_bytes(bytes: &[u8]) -> VdrResult<Transaction> {
+        let payload = bytes.strip_prefix(MAGIC).ok_or_else(|| {
+            VdrError::CommonInvalidData("Bytes do not contain serialized transaction".to_string())
+        })?;
+        let (version, payload) = payload.split_first().ok_or_else(|| {
+            VdrError::CommonInvalidData("Serialized transaction version is missing".to_string())
+        })?;
+        check_version(*version)?;
+
+        let transaction = decode_rlp(&Rlp::new(payload)).map_err(|err| {
+            let vdr_error = VdrError::CommonInvalidData(format!(
+                "Unable to parse transaction from bytes. Err: {:?}",
+                err
+            ));
+
+            warn!("Error: {} during transaction deserialization", vdr_error);
+
+            vdr_error
+        })?;
+        check_call(&transaction)?;
+
+        trace!("Restored transaction from bytes: {:?}", transaction);
+
+        Ok(transaction)
+    }
+
+    fn read_signature(&self) -> VdrResult<Option<TransactionSignature>> {
+        self.signature
+            .read()
+            .map(|signature| signature.clone())
+            .map_err(|_| VdrError::CommonInvalidData("Transaction signature is poisoned".into()))
+    }
+}
+
+fn decode_rlp(rlp: &Rlp) -> Result<Transaction, rlp::DecoderError> {
+    if rlp.item_count()? != RLP_ITEMS {
+        return Err(rlp::DecoderError::RlpIncorrectListLen);
+    }
+
+    let access_list = rlp
+        .at(11)?
+        .iter()
+        .map(|item| {
+            Ok(AccessListItem {
+                address: Address::from(item.val_at::<String>(0)?.as_str()),
+                storage_keys: item.list_at(1)?,
+            })
+        })
+        .collect::<Result<_, rlp::DecoderError>>()?;
+
+    let call = decode_optional(&rlp.at(12)?, |call| {
+        Ok(TransactionCall {
+            contract: call.val_at(0)?,
+            method: call.val_at(1)?,
+            params: call
+                .at(2)?
+                .iter()
+                .map(|param| {
+                    Ok(TransactionCallParam {
+                        name: param.val_at(0)?,
+                        type_: param.val_at(1)?,
+                        value: param.val_at(2)?,
+                    })
+                })
+                .collect::<Result<_, rlp::DecoderError>>()?,
+        })
+    })?;
+
+    let signature = decode_optional(&rlp.at(13)?, |signature| {
+        Ok(TransactionSignature {
+            v: signature.val_at(0)?,
+            r: signature.val_at(1)?,
+            s: signature.val_at(2)?,
+        })
+    })?;
+
+    Ok(Transaction {
+        type_: decode_type(rlp.val_at(0)?)?,
+        from: decode_optional(&rlp.at(1)?, |from| {
+            Ok(Address::from(from.as_val::<String>()?.as_str()))
+        })?,
+        to: Address::from(rlp.val_at::<String>(2)?.as_str()),
+        chain_id: rlp.val_at(3)?,
+        nonce: decode_optional(&rlp.at(4)?, |nonce| nonce.as_list())?,
+        data: rlp.val_at(5)?,
+        envelope: decode_envelope(rlp.val_at(6)?)?,
+        gas_limit: decode_optional(&rlp.at(7)?, |value| value.as_val())?,
+        gas_price: decode_optional(&rlp.at(8)?, |value| value.as_val())?,
+        max_fee_per_gas: decode_optional(&rlp.at(9)?, |value| value.as_val())?,
+        max_priority_fee_per_gas: decode_optional(&rlp.at(10)?, |value| value.as_val())?,
+        access_list,
+        call,
+        signature: RwLock::new(signature),
+        hash: decode_optional(&rlp.at(14)?, |hash| hash.as_val())?,
+    })
+}
+
+// Optional values are encoded as lists containing zero or one item
+fn append_optional<T>(
+    stream: &mut RlpStream,
+    value: Option<&T>,
+    append: impl FnOnce(&mut RlpStream, &T),
+) {
+    match value {
+        Some(value) => {
+            stream.begin_list(1);
+            append(stream, value);
+        }
+        None => {
+            stream.begin_list(0);
+        }
+    }
+}
+
+fn decode_optional<T>(
+    rlp: &Rlp,
+    decode: impl FnOnce(&Rlp) -> Result<T, rlp::DecoderError>,
+) -> Result<Option<T>, rlp::DecoderError> {
+    match rlp.item_count()? {
+        0 => Ok(None),
+        1 => decode(&rlp.at(0)?).map(Some),
+        _ => Err(rlp::DecoderError::RlpIncorrectListLen),
+    }
+}
+
+fn encode_type(type_: &TransactionType) -> u8 {
+    match type_ {
+        TransactionType::Read => 0,
+        TransactionType::Write => 1,
+    }
+}
+
+fn decode_type(value: u8) -> Result<TransactionType, rlp::DecoderError> {
+    match value {
+        0 => Ok(TransactionType::Read),
+        1 => Ok(TransactionType::Write),
+        _ => Err(rlp::DecoderError::Custom("Unknown transaction type")),
+    }
+}
+
+fn encode_envelope(envelope: &TransactionEnvelopeType) -> u8 {
+    match envelope {
+        TransactionEnvelopeType::Legacy => 0,
+        TransactionEnvelopeType::AccessList => 1,
+        TransactionEnvelopeType::DynamicFee => 2,
+    }
+}
+
+fn decode_envelope(value: u8) -> Result<TransactionEnvelopeType, rlp::DecoderError> {
+    match value {
+        0 => Ok(TransactionEnvelopeType::Legacy),
+        1 => Ok(TransactionEnvelopeType::AccessList),
+        2 => Ok(TransactionEnvelopeType::DynamicFee),
+        _ => Err(rlp::DecoderError::Custom("Unknown transaction envelope")),
+    }
+}
+
+/// Check that the human readable contract call describes the transaction data, so the reviewed call
+/// is the one which is signed. Only the contract specification embedded into the call is used
+/// (method name and parameter types), so no client is needed: the method selector and the ABI
+/// encoding of the parameter values must be equal to the data. The contract and parameter names are
+/// not part of the data and can be checked with `LedgerClient::decode_transaction_call`
+fn check_call(transaction: &Transaction) -> VdrResult<()> {
+    let call = match transaction.call.as_ref() {
+        Some(call) => call,
+        None => return Ok(()),
+    };
+    if !call_matches_data(call, &transaction.data) {
+        let vdr_error = VdrError::CommonInvalidData(format!(
+            "Transaction call {}.{} does not match transaction data",
+            call.contract, call.method
+        ));
+
+        warn!("Error: {} during transaction deserialization", vdr_error);
+
+        return Err(vdr_error);
+    }
+    Ok(())
+}
+
+fn call_matches_data(call: &TransactionCall, data: &[u8]) -> bool {
+    let types = match call
+        .params
+        .iter()
+        .map(|param| ethabi::param_type::Reader::read(&param.type_))
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(types) => types,
+        Err(_) => return false,
+    };
+    if data.len() < 4 || data[..4] != ethabi::short_signature(&call.method, &types) {
+        return false;
+    }
+    let tokens = match ethabi::decode(&types, &data[4..]) {
+        Ok(tokens) => tokens,
+        Err(_) => return false,
+    };
+    ethabi::encode(&tokens) == data[4..]
+        && tokens
+            .iter()
+            .zip(call.params.iter())
+            .all(|(token, param)| format_token(token) == param.value)
+}
+
+fn check_version(version: u8) -> VdrResult<()> {
+    if version != VERSION {
+        let vdr_error = VdrError::CommonInvalidData(format!(
+            "Unsupported serialized transaction version: {}. Expected: {}",
+            version, VERSION
+        ));
+
+        warn!("Error: {} during transaction deserialization", vdr_error);
+
+        return Err(vdr_error);
+    }
+    Ok(())
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+fn decode_hex(value: &str) -> VdrResult<Vec<u8>> {
+    hex::decode(value.trim_start_matches("0x")).map_err(|err| {
+        VdrError::CommonInvalidData(format!(
+            "Unable to decode hex value: {}. Err: {:?}",
+            value, err
+        ))
+    })
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::{
+        client::client::test::{CHAIN_ID, TRUSTEE_ACC, VALIDATOR_CONTROL_ADDRESS},
+        types::SignatureData,
+    };
+
+    const NEW_VALIDATOR: &str = "0x93917cadbace5dfce132b991732c6cda9bcc5b8a";
+
+    fn transaction() -> Transaction {
+        Transaction {
+            type_: TransactionType::Write,
+            from: Some(TRUSTEE_ACC.clone()),
+            to: VALIDATOR_CONTROL_ADDRESS.clone(),
+            nonce: Some(vec![7, 0, 0, 0]),
+            chain_id: CHAIN_ID,
+            data: add_validator_data(),
+            envelope: TransactionEnvelopeType::DynamicFee,
+            gas_limit: Some(100000),
+            max_fee_per_gas: Some(2000),
+            max_priority_fee_per_gas: Some(0),
+            access_list: vec![AccessListItem {
+                address: VALIDATOR_CONTROL_ADDRESS.clone(),
+                storage_keys: vec![vec![1; 32]],
+            }],
+            call: Some(TransactionCall {
+                contract: "ValidatorControl".to_string(),
+                method: "addValidator".to_string(),
+                params: vec![TransactionCallParam {
+                    name: "newValidator".to_string(),
+                    type_: "address".to_string(),
+                    value: NEW_VALIDATOR.to_string(),
+                }],
+            }),
+            ..Transaction::default()
+        }
+    }
+
+    fn add_validator_data() -> Vec<u8> {
+        let mut data = vec![77, 35, 140, 142];
+        data.extend(
+            hex::decode(format!("{:0>64}", NEW_VALIDATOR.trim_start_matches("0x"))).unwrap(),
+        );
+        data
+    }
+
+    fn signed_transaction() -> Transaction {
+        let transaction = transaction();
+        transaction.set_signature(SignatureData {
+            recovery_id: 1,
+            signature: vec![2; 64],
+        });
+        transaction
+    }
+
+    fn assert_same(expected: &Transaction, actual: &Transaction) {
+        assert_eq!(expected, actual);
+        assert_eq!(expected.call, actual.call);
+        assert_eq!(
+            *expected.signature.read().unwrap(),
+            *actual.signature.read().unwrap()
+        );
+    }
+
+    mod json {
+        use super::*;
+
+        #[test]
+        fn unsigned_transaction_round_trip_test() {
+            let transaction = transaction();
+
+            let json = transaction.to_json().unwrap();
+            let restored = Transaction::from_json(&json).unwrap();
+
+            assert_same(&transaction, &restored);
+        }
+
+        #[test]
+        fn signed_transaction_round_trip_test() {
+            let transaction = signed_transaction();
+
+            let json = transaction.to_json().unwrap();
+            let restored = Transaction::from_json(&json).unwrap();
+
+            assert_same(&transaction, &restored);
+            assert_eq!(transaction.encode().unwrap(), restored.encode().unwrap());
+        }
+
+        #[test]
+        fn json_is_human_readable_test() {
+            let json: serde_json::Value =
+                serde_json::from_str(&transaction().to_json().unwrap()).unwrap();
+
+            assert_eq!(VERSION, json["version"]);
+            assert_eq!(encode_hex(&add_validator_data()), json["data"]);
+            assert_eq!("addValidator", json["call"]["method"]);
+            assert_eq!("address", json["call"]["params"][0]["type"]);
+        }
+
+        #[test]
+        fn tampered_call_test() {
+            let mut json: serde_json::Value =
+                serde_json::from_str(&transaction().to_json().unwrap()).unwrap();
+            json["call"]["params"][0]["value"] =
+                serde_json::json!("0xf0e2db6c8dc6c681bb5d6ad121a107f300e9b2b5");
+
+            let err = Transaction::from_json(&json.to_string()).unwrap_err();
+            assert!(matches!(err, VdrError::CommonInvalidData(_)));
+
+            json["call"]["params"][0]["value"] = serde_json::json!(NEW_VALIDATOR);
+            json["call"]["method"] = serde_json::json!("removeValidator");
+
+            let err = Transaction::from_json(&json.to_string()).unwrap_err();
+            assert!(matches!(err, VdrError::CommonInvalidData(_)));
+        }
+
+        #[test]
+        fn tampered_data_test() {
+            let transaction = Transaction {
+                data: vec![77, 35, 140, 142, 0, 1, 2],
+                ..transaction()
+            };
+
+            let err = Transaction::from_json(&transaction.to_json().unwrap()).unwrap_err();
+            assert!(matches!(err, VdrError::CommonInvalidData(_)));
+        }
+
+        #[test]
+        fn unsupported_version_test() {
+            let mut json: serde_json::Value =
+                serde_json::from_str(&transaction().to_json().unwrap()).unwrap();
+            json["version"] = serde_json::json!(VERSION + 1);
+
+            let err = Transaction::from_json(&json.to_string()).unwrap_err();
+            assert!(matches!(err, VdrError::CommonInvalidData(_)));
+        }
+    }
+
+    mod bytes {
+        use super::*;
+
+        #[test]
+        fn unsigned_transaction_round_trip_test() {
+            let transaction = Transaction {
+                envelope: TransactionEnvelopeType::Legacy,
+                call: None,
+                access_list: vec![],
+                ..transaction()
+            };
+
+            let bytes = transaction.to_bytes().unwrap();
+            let restored = Transaction::from_bytes(&bytes).unwrap();
+
+            assert_same(&transaction, &restored);
+        }
+
+        #[test]
+        fn signed_transaction_round_trip_test() {
+            let transaction = signed_transaction();
+
+            let bytes = transaction.to_bytes().unwrap();
+            let restored = Transaction::from_bytes(&bytes).unwrap();
+
+            assert_same(&transaction, &restored);
+            assert_eq!(transaction.encode().unwrap(), restored.encode().unwrap());
+        }
+
+        #[test]
+        fn unsupported_version_test() {
+            let mut bytes = transaction().to_bytes().unwrap();
+            bytes[MAGIC.len()] = VERSION + 1;
+
+            let err = Transaction::from_bytes(&bytes).unwrap_err();
+            assert!(matches!(err, VdrError::CommonInvalidData(_)));
+        }
+
+        #[test]
+        fn tampered_call_test() {
+            let mut transaction = transaction();
+            transaction.call.as_mut().unwrap().params[0].type_ = "bytes20".to_string();
+
+            let err = Transaction::from_bytes(&transaction.to_bytes().unwrap()).unwrap_err();
+            assert!(matches!(err, VdrError::CommonInvalidData(_)));
+        }
+
+        #[test]
+        fn invalid_bytes_test() {
+            let bytes = transaction().to_bytes().unwrap();
+
+            let err = Transaction::from_bytes(&bytes[..bytes.len() - 1]).unwrap_err();
+            assert!(matches!(err, VdrError::CommonInvalidData(_)));
+
+            let err = Transaction::from_bytes(&bytes[MAGIC.len()..]).unwrap_err();
+            assert!(matches!(err, VdrError::CommonInvalidData(_)));
+        }
+    }
+}
@@ -1,10 +1,15 @@
 use ethereum::{
-    EnvelopedEncodable, LegacyTransaction, LegacyTransactionMessage, TransactionAction,
-    TransactionSignature as EthTransactionSignature,
+    AccessListItem as EthAccessListItem, EIP1559Transaction, EIP1559TransactionMessage,
+    EIP2930Transaction, EIP2930TransactionMessage, EnvelopedDecodable, EnvelopedEncodable,
+    LegacyTransaction, LegacyTransactionMessage, TransactionAction,
+    TransactionSignature as EthTransactionSignature, TransactionV2,
 };
 use ethereum_types::{H160, H256, U256};
+use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
 use log::{trace, warn};
 use serde_derive::{Deserialize, Serialize};
+use serde_json::json;
+use sha3::{Digest, Keccak256};
 use std::{str::FromStr, sync::RwLock};
 
 use crate::{
@@ -23,6 +28,76 @@ pub enum TransactionType {
     Write,
 }
 
+/// Envelope of write transaction (EIP-2718) defining its fee model, signing hash and encoding
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum TransactionEnvelopeType {
+    /// legacy transaction protected from replay attacks with EIP-155 `v` value
+    #[default]
+    Legacy,
+    /// EIP-2930 transaction with an access list
+    AccessList,
+    /// EIP-1559 transaction paying base fee and priority fee
+    DynamicFee,
+}
+
+/// Account and its storage slots accessed by the transaction (EIP-2930)
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AccessListItem {
+    /// address of the accessed account
+    pub address: Address,
+    /// accessed storage slots (32 bytes each)
+    pub storage_keys: Vec<Vec<u8>>,
+}
+
+/// Configuration of write transactions built by [LedgerClient]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TransactionConfig {
+    /// envelope of built transactions. Legacy if not set
+    pub envelope: Option<TransactionEnvelopeType>,
+    /// percent added on top of the gas estimated by the node (`eth_estimateGas`)
+    /// to tolerate state changes between estimation and execution
+    pub gas_limit_margin: Option<u64>,
+    /// fixed gas limit of built transactions. Gas is not estimated if set, so transactions depending
+    /// on not yet executed ones (like a schema of a DID created in the same batch) can be built
+    pub gas_limit: Option<u64>,
+}
+
+const DEFAULT_GAS_LIMIT_MARGIN: u64 = 20;
+
+impl TransactionConfig {
+    pub(crate) fn envelope(&self) -> TransactionEnvelopeType {
+        self.envelope.clone().unwrap_or_default()
+    }
+
+    pub(crate) fn gas_limit_with_margin(&self, estimated_gas: u64) -> u64 {
+        let margin = self.gas_limit_margin.unwrap_or(DEFAULT_GAS_LIMIT_MARGIN);
+        estimated_gas.saturating_add(estimated_gas.saturating_mul(margin) / 100)
+    }
+}
+
+/// Human readable description of the contract method executed by a transaction
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TransactionCall {
+    /// name of the called contract
+    pub contract: String,
+    /// name of the called contract method
+    pub method: String,
+    /// parameters passed to the method
+    pub params: Vec<TransactionCallParam>,
+}
+
+/// Parameter of the contract method executed by a transaction
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TransactionCallParam {
+    /// name of the parameter
+    pub name: String,
+    /// solidity type of the parameter
+    #[serde(rename = "type")]
+    pub type_: String,
+    /// parameter value
+    pub value: String,
+}
+
 /// Transaction object
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub struct Transaction {
@@ -46,6 +121,29 @@ pub struct Transaction {
     pub signature: RwLock<Option<TransactionSignature>>,
     /// transaction hash
     pub hash: Option<Vec<u8>>,
+    /// envelope of write transaction
+    #[serde(default)]
+    pub envelope: TransactionEnvelopeType,
+    /// gas limit of write transaction. The network maximum is used if not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas_limit: Option<u64>,
+    /// gas price of legacy and access list transactions. Zero if not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gas_price: Option<u64>,
+    /// maximum total fee per gas of dynamic fee transaction. Zero if not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_fee_per_gas: Option<u64>,
+    /// maximum priority fee per gas of dynamic fee transaction. Zero if not set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_priority_fee_per_gas: Option<u64>,
+    /// access list of access list and dynamic fee transactions
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub access_list: Vec<AccessListItem>,
+    /// decoded contract method call for review before signing.
+    /// Describes `data` (checked when the transaction is deserialized from JSON or bytes),
+    /// so it is not compared when checking transactions for equality
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call: Option<TransactionCall>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -58,6 +156,7 @@ pub struct SignatureData {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TransactionSignature {
+    /// EIP-155 `v` value for legacy transactions or `yParity` (0 or 1) for typed transactions
     pub v: u64,
     pub r: Vec<u8>,
     pub s: Vec<u8>,
@@ -81,26 +180,60 @@ impl Transaction {
             data,
             nonce,
             signature: RwLock::new(signature),
-            hash: None,
+            ..Transaction::default()
         }
     }
 
     pub fn get_signing_bytes(&self) -> VdrResult<Vec<u8>> {
-        let eth_transaction: LegacyTransactionMessage = LegacyTransactionMessage {
-            nonce: self.get_nonce()?,
-            gas_price: *GAS_PRICE,
-            gas_limit: *GAS_LIMIT,
-            action: TransactionAction::Call(self.get_to()?),
-            value: Default::default(),
-            input: self.data.clone(),
-            chain_id: Some(self.chain_id),
+        let action = TransactionAction::Call(self.get_to()?);
+        let input = self.data.clone();
+        let hash = match self.envelope {
+            TransactionEnvelopeType::Legacy => LegacyTransactionMessage {
+                nonce: self.get_nonce()?,
+                gas_price: self.get_gas_price(),
+                gas_limit: self.get_gas_limit(),
+                action,
+                value: Default::default(),
+                input,
+                chain_id: Some(self.chain_id),
+            }
+            .hash(),
+            TransactionEnvelopeType::AccessList => EIP2930TransactionMessage {
+                chain_id: self.chain_id,
+                nonce: self.get_nonce()?,
+                gas_price: self.get_gas_price(),
+                gas_limit: self.get_gas_limit(),
+                action,
+                value: Default::default(),
+                input,
+                access_list: self.get_access_list()?,
+            }
+            .hash(),
+            TransactionEnvelopeType::DynamicFee => EIP1559TransactionMessage {
+                chain_id: self.chain_id,
+                nonce: self.get_nonce()?,
+                max_priority_fee_per_gas: U256::from(
+                    self.max_priority_fee_per_gas.unwrap_or_default(),
+                ),
+                max_fee_per_gas: U256::from(self.max_fee_per_gas.unwrap_or_default()),
+                gas_limit: self.get_gas_limit(),
+                action,
+                value: Default::default(),
+                input,
+                access_list: self.get_access_list()?,
+            }
+            .hash(),
         };
-        let hash = eth_transaction.hash();
         Ok(hash.as_bytes().to_vec())
     }
 
     pub fn set_signature(&self, signature_data: SignatureData) {
-        let v = signature_data.recovery_id + 35 + self.chain_id * 2;
+        let v = match self.envelope {
+            TransactionEnvelopeType::Legacy => signature_data.recovery_id + 35 + self.chain_id * 2,
+            TransactionEnvelopeType::AccessList | TransactionEnvelopeType::DynamicFee => {
+                signature_data.recovery_id
+            }
+        };
         let transaction_signature = TransactionSignature {
             v,
             r: signature_data.signature[..32].to_vec(),
@@ -110,15 +243,205 @@ impl Transaction {
         *signature = Some(transaction_signature)
     }
 
+    /// Recover the account which signed the transaction
+    ///     secp256k1 public key recovery is performed over the transaction signing bytes
+    ///
+    /// # Returns
+    /// address of the account matching the transaction signature
+    pub fn recover_signer(&self) -> VdrResult<Address> {
+        let recovery_id = match self.envelope {
+            TransactionEnvelopeType::Legacy => self.get_transaction_signature()?.standard_v(),
+            TransactionEnvelopeType::AccessList | TransactionEnvelopeType::DynamicFee => {
+                self.get_typed_transaction_signature()?.0 as u8
+            }
+        };
+        let signature = self.get_signature()?;
+        let message = self.get_signing_bytes()?;
+
+        let invalid_signature = |err: k256::ecdsa::Error| {
+            let vdr_error = VdrError::SignerInvalidSignature(err.to_string());
+
+            warn!("Error: {} during recovering transaction signer", vdr_error);
+
+            vdr_error
+        };
+        let recovery_id = RecoveryId::from_byte(recovery_id).ok_or_else(|| {
+            VdrError::SignerInvalidSignature(format!("Invalid recovery id {}", recovery_id))
+        })?;
+        let signature = Signature::from_scalars(
+            Self::to_h256(&signature.r, "signature `r`")?.to_fixed_bytes(),
+            Self::to_h256(&signature.s, "signature `s`")?.to_fixed_bytes(),
+        )
+        .map_err(invalid_signature)?;
+        let public_key = VerifyingKey::recover_from_prehash(&message, &signature, recovery_id)
+            .map_err(invalid_signature)?;
+
+        // account address is the last 20 bytes of the uncompressed public key hash
+        let public_key = public_key.to_encoded_point(false);
+        let hash = Keccak256::digest(&public_key.as_bytes()[1..]);
+        let signer = Address::from(hex::encode(&hash[12..]).as_str());
+
+        trace!("Recovered transaction signer: {:?}", signer);
+
+        Ok(signer)
+    }
+
+    /// Check that the transaction is signed by its sender
+    ///
+    /// # Returns
+    /// error if the signature is missing, invalid or does not belong to the transaction sender
+    pub fn verify_signer(&self) -> VdrResult<()> {
+        let from = self.from.as_ref().ok_or_else(|| {
+            VdrError::ClientInvalidTransaction("Transaction `sender` is not set".to_string())
+        })?;
+        let signer = self.recover_signer()?;
+
+        if !signer.as_ref().eq_ignore_ascii_case(from.as_ref()) {
+            let vdr_error = VdrError::SignerMismatch(signer, from.clone());
+
+            warn!("Error: {} during verifying transaction signer", vdr_error);
+
+            return Err(vdr_error);
+        }
+        Ok(())
+    }
+
+    // Transaction object of `eth_signTransaction` / `eth_sendTransaction` JSON-RPC requests
+    pub(crate) fn to_rpc_request(&self) -> VdrResult<serde_json::Value> {
+        let mut request = json!({
+            "from": self.from.as_ref().map(|from| from.to_string()),
+            "to": self.get_to()?,
+            "nonce": self.get_nonce()?,
+            "gas": self.get_gas_limit(),
+            "value": U256::zero(),
+            "data": format!("0x{}", hex::encode(&self.data)),
+            "chainId": U256::from(self.chain_id),
+        });
+        match self.envelope {
+            TransactionEnvelopeType::Legacy => {
+                request["gasPrice"] = json!(self.get_gas_price());
+            }
+            TransactionEnvelopeType::AccessList => {
+                request["type"] = json!(U256::from(1));
+                request["gasPrice"] = json!(self.get_gas_price());
+                request["accessList"] = self.get_rpc_access_list()?;
+            }
+            TransactionEnvelopeType::DynamicFee => {
+                request["type"] = json!(U256::from(2));
+                request["maxFeePerGas"] =
+                    json!(U256::from(self.max_fee_per_gas.unwrap_or_default()));
+                request["maxPriorityFeePerGas"] = json!(U256::from(
+                    self.max_priority_fee_per_gas.unwrap_or_default()
+                ));
+                request["accessList"] = self.get_rpc_access_list()?;
+            }
+        }
+        Ok(request)
+    }
+
+    fn get_rpc_access_list(&self) -> VdrResult<serde_json::Value> {
+        let access_list: Vec<serde_json::Value> = self
+            .get_access_list()?
+            .into_iter()
+            .map(|item| json!({ "address": item.address, "storageKeys": item.storage_keys }))
+            .collect();
+        Ok(json!(access_list))
+    }
+
+    // Extract signature from the transaction signed by an external service (`eth_signTransaction`)
+    // checking that exactly this transaction was signed
+    pub(crate) fn signature_from_signed(&self, signed: &[u8]) -> VdrResult<SignatureData> {
+        let invalid_signature = |reason: String| {
+            let vdr_error = VdrError::SignerInvalidSignature(reason);
+
+            warn!("Error: {} during parsing signed transaction", vdr_error);
+
+            vdr_error
+        };
+
+        let signed = TransactionV2::decode(signed)
+            .map_err(|err| invalid_signature(format!("Unable to decode transaction: {:?}", err)))?;
+        let (hash, recovery_id, r, s) = match signed {
+            TransactionV2::Legacy(transaction) => (
+                LegacyTransactionMessage::from(transaction.clone()).hash(),
+                transaction.signature.standard_v() as u64,
+                *transaction.signature.r(),
+                *transaction.signature.s(),
+            ),
+            TransactionV2::EIP2930(transaction) => (
+                EIP2930TransactionMessage::from(transaction.clone()).hash(),
+                transaction.odd_y_parity as u64,
+                transaction.r,
+                transaction.s,
+            ),
+            TransactionV2::EIP1559(transaction) => (
+                EIP1559TransactionMessage::from(transaction.clone()).hash(),
+                transaction.odd_y_parity as u64,
+                transaction.r,
+                transaction.s,
+            ),
+        };
+        if hash.as_bytes() != self.get_signing_bytes()? {
+            return Err(invalid_signature(
+                "Signed transaction does not match the requested one".to_string(),
+            ));
+        }
+
+        Ok(SignatureData {
+            recovery_id,
+            signature: [r.as_bytes(), s.as_bytes()].concat(),
+        })
+    }
+
     pub fn encode(&self) -> VdrResult<Vec<u8>> {
-        let transaction = LegacyTransaction {
-            nonce: self.get_nonce()?,
-            gas_price: *GAS_PRICE,
-            gas_limit: *GAS_LIMIT,
-            action: TransactionAction::Call(self.get_to()?),
-            value: Default::default(),
-            input: self.data.clone(),
-            signature: self.get_transaction_signature()?,
+        let nonce = self.get_nonce()?;
+        let action = TransactionAction::Call(self.get_to()?);
+        let input = self.data.clone();
+        let transaction = match self.envelope {
+            TransactionEnvelopeType::Legacy => TransactionV2::Legacy(LegacyTransaction {
+                nonce,
+                gas_price: self.get_gas_price(),
+                gas_limit: self.get_gas_limit(),
+                action,
+                value: Default::default(),
+                input,
+                signature: self.get_transaction_signature()?,
+            }),
+            TransactionEnvelopeType::AccessList => {
+                let (odd_y_parity, r, s) = self.get_typed_transaction_signature()?;
+                TransactionV2::EIP2930(EIP2930Transaction {
+                    chain_id: self.chain_id,
+                    nonce,
+                    gas_price: self.get_gas_price(),
+                    gas_limit: self.get_gas_limit(),
+                    action,
+                    value: Default::default(),
+                    input,
+                    access_list: self.get_access_list()?,
+                    odd_y_parity,
+                    r,
+                    s,
+                })
+            }
+            TransactionEnvelopeType::DynamicFee => {
+                let (odd_y_parity, r, s) = self.get_typed_transaction_signature()?;
+                TransactionV2::EIP1559(EIP1559Transaction {
+                    chain_id: self.chain_id,
+                    nonce,
+                    max_priority_fee_per_gas: U256::from(
+                        self.max_priority_fee_per_gas.unwrap_or_default(),
+                    ),
+                    max_fee_per_gas: U256::from(self.max_fee_per_gas.unwrap_or_default()),
+                    gas_limit: self.get_gas_limit(),
+                    action,
+                    value: Default::default(),
+                    input,
+                    access_list: self.get_access_list()?,
+                    odd_y_parity,
+                    r,
+                    s,
+                })
+            }
         };
         Ok(transaction.encode().to_vec())
     }
@@ -145,23 +468,89 @@ impl Transaction {
         Ok(U256(nonce))
     }
 
+    fn get_gas_limit(&self) -> U256 {
+        self.gas_limit.map(U256::from).unwrap_or(*GAS_LIMIT)
+    }
+
+    fn get_gas_price(&self) -> U256 {
+        self.gas_price.map(U256::from).unwrap_or(*GAS_PRICE)
+    }
+
+    fn get_access_list(&self) -> VdrResult<Vec<EthAccessListItem>> {
+        self.access_list
+            .iter()
+            .map(|item| {
+                let address = H160::from_str(item.address.as_ref()).map_err(|_| {
+                    VdrError::ClientInvalidTransaction(format!(
+                        "Invalid access list address {:?}",
+                        item.address
+                    ))
+                })?;
+                let storage_keys = item
+                    .storage_keys
+                    .iter()
+                    .map(|key| Self::to_h256(key, "access list storage key"))
+                    .collect::<VdrResult<Vec<H256>>>()?;
+                Ok(EthAccessListItem {
+                    address,
+                    storage_keys,
+                })
+            })
+            .collect()
+    }
+
+    fn get_signature(&self) -> VdrResult<TransactionSignature> {
+        self.signature
+            .read()
+            .unwrap()
+            .clone()
+            .ok_or_else(|| VdrError::ClientInvalidTransaction("Missing signature".to_string()))
+    }
+
     fn get_transaction_signature(&self) -> VdrResult<EthTransactionSignature> {
-        let signature = self.signature.read().unwrap();
-        let signature = signature
-            .as_ref()
-            .ok_or_else(|| VdrError::ClientInvalidTransaction("Missing signature".to_string()))?
-            .clone();
+        let signature = self.get_signature()?;
 
         let signature = EthTransactionSignature::new(
             signature.v,
-            H256::from_slice(&signature.r),
-            H256::from_slice(&signature.s),
+            Self::to_h256(&signature.r, "signature `r`")?,
+            Self::to_h256(&signature.s, "signature `s`")?,
         )
         .ok_or_else(|| {
-            VdrError::ClientInvalidTransaction("Transaction `nonce` is not set".to_string())
+            VdrError::ClientInvalidTransaction("Invalid transaction signature".to_string())
         })?;
         Ok(signature)
     }
+
+    fn get_typed_transaction_signature(&self) -> VdrResult<(bool, H256, H256)> {
+        let signature = self.get_signature()?;
+
+        let odd_y_parity = match signature.v {
+            0 => false,
+            1 => true,
+            v => {
+                return Err(VdrError::ClientInvalidTransaction(format!(
+                    "Invalid signature `yParity` {}",
+                    v
+                )))
+            }
+        };
+        Ok((
+            odd_y_parity,
+            Self::to_h256(&signature.r, "signature `r`")?,
+            Self::to_h256(&signature.s, "signature `s`")?,
+        ))
+    }
+
+    fn to_h256(bytes: &[u8], name: &str) -> VdrResult<H256> {
+        if bytes.len() != 32 {
+            return Err(VdrError::ClientInvalidTransaction(format!(
+                "Invalid transaction {} length: {}",
+                name,
+                bytes.len()
+            )));
+        }
+        Ok(H256::from_slice(bytes))
+    }
 }
 
 impl PartialEq for Transaction {
@@ -174,6 +563,12 @@ impl PartialEq for Transaction {
             && self.nonce == other.nonce
             && self.chain_id == other.chain_id
             && self.data == other.data
+            && self.envelope == other.envelope
+            && self.gas_limit == other.gas_limit
+            && self.gas_price == other.gas_price
+            && self.max_fee_per_gas == other.max_fee_per_gas
+            && self.max_priority_fee_per_gas == other.max_priority_fee_per_gas
+            && self.access_list == other.access_list
             && *self_signature == *other_signature
     }
 }
@@ -248,30 +643,24 @@ impl TransactionBuilder {
     pub async fn build(self, client: &LedgerClient) -> VdrResult<Transaction> {
         let contract = client.contract(&self.contract)?;
         let data = contract.encode_input(&self.method, &self.params)?;
-        let nonce = match self.type_ {
-            TransactionType::Write => {
-                let from = self.from.as_ref().ok_or_else(|| {
-                    VdrError::ClientInvalidTransaction(
-                        "Transaction `sender` is not set".to_string(),
-                    )
-                })?;
-
-                let nonce = client.get_transaction_count(from).await?;
-                Some(nonce.to_vec())
-            }
-            TransactionType::Read => None,
-        };
-
-        let transaction = Transaction {
+        let mut transaction = Transaction {
             type_: self.type_,
             from: self.from,
             to: contract.address().clone(),
             chain_id: client.chain_id(),
             data,
-            nonce,
-            signature: RwLock::new(None),
-            hash: None,
+            ..Transaction::default()
         };
+        if transaction.type_ == TransactionType::Write {
+            let from = transaction.from.clone().ok_or_else(|| {
+                VdrError::ClientInvalidTransaction("Transaction `sender` is not set".to_string())
+            })?;
+
+            // gas is estimated first, so failed estimation does not leave a gap in sender nonces
+            client.set_transaction_gas(&mut transaction).await?;
+            transaction.nonce = Some(client.next_nonce(&from).await?);
+        }
+        transaction.call = client.decode_transaction_call(&transaction)?;
 
         trace!("Built transaction: {:?}", transaction);
 
@@ -341,18 +730,91 @@ impl TransactionParser {
     }
 }
 
-#[cfg(test)]
-impl std::clone::Clone for Transaction {
+impl Clone for Transaction {
     fn clone(&self) -> Self {
         Transaction {
             type_: self.type_.clone(),
             from: self.from.clone(),
             to: self.to.clone(),
             nonce: self.nonce.clone(),
-            chain_id: self.chain_id.clone(),
+            chain_id: self.chain_id,
             data: self.data.clone(),
             signature: RwLock::new(self.signature.read().unwrap().clone()),
             hash: self.hash.clone(),
+            envelope: self.envelope.clone(),
+            gas_limit: self.gas_limit,
+            gas_price: self.gas_price,
+            max_fee_per_gas: self.max_fee_per_gas,
+            max_priority_fee_per_gas: self.max_priority_fee_per_gas,
+            access_list: self.access_list.clone(),
+            call: self.call.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    use super::*;
+    use crate::client::client::test::{
+        sign, CHAIN_ID, DEFAULT_NONCE, OTHER_PRIVATE_KEY, ROLE_CONTROL_ADDRESS, TRUSTEE_ACC,
+        TRUSTEE_PRIVATE_KEY,
+    };
+
+    fn transaction(envelope: TransactionEnvelopeType) -> Transaction {
+        Transaction {
+            type_: TransactionType::Write,
+            from: Some(TRUSTEE_ACC.clone()),
+            to: ROLE_CONTROL_ADDRESS.clone(),
+            nonce: Some(DEFAULT_NONCE.clone()),
+            chain_id: CHAIN_ID,
+            data: vec![1, 2, 3],
+            envelope,
+            ..Transaction::default()
+        }
+    }
+
+    mod recover_signer {
+        use super::*;
+
+        #[test]
+        fn recover_signer_test() {
+            for envelope in [
+                TransactionEnvelopeType::Legacy,
+                TransactionEnvelopeType::AccessList,
+                TransactionEnvelopeType::DynamicFee,
+            ] {
+                let transaction = transaction(envelope);
+                sign(&transaction, TRUSTEE_PRIVATE_KEY);
+
+                assert_eq!(*TRUSTEE_ACC, transaction.recover_signer().unwrap());
+                transaction.verify_signer().unwrap();
+            }
+        }
+
+        #[test]
+        fn verify_signer_mismatch_test() {
+            let transaction = transaction(TransactionEnvelopeType::Legacy);
+            sign(&transaction, OTHER_PRIVATE_KEY);
+
+            let err = transaction.verify_signer().unwrap_err();
+            assert!(matches!(err, VdrError::SignerMismatch(_, from) if from == *TRUSTEE_ACC));
+        }
+
+        #[test]
+        fn recover_signer_of_modified_transaction_test() {
+            let mut transaction = transaction(TransactionEnvelopeType::DynamicFee);
+            sign(&transaction, TRUSTEE_PRIVATE_KEY);
+            transaction.data = vec![3, 2, 1];
+
+            assert_ne!(*TRUSTEE_ACC, transaction.recover_signer().unwrap());
+        }
+
+        #[test]
+        fn recover_signer_without_signature_test() {
+            let err = transaction(TransactionEnvelopeType::Legacy)
+                .recover_signer()
+                .unwrap_err();
+            assert!(matches!(err, VdrError::ClientInvalidTransaction(_)));
         }
     }
 }
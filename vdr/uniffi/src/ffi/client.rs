@@ -2,11 +2,14 @@ use crate::{
     ffi::{
         error::VdrResult,
         transaction::Transaction,
-        types::{ContractConfig, PingStatus, QuorumConfig},
+        types::{ContractConfig, PingStatus, QuorumConfig, TransactionConfig, TransactionReceipt},
     },
     VdrError,
 };
-use indy2_vdr::{ContractConfig as ContractConfig_, LedgerClient as LedgerClient_};
+use indy2_vdr::{
+    Address, ContractConfig as ContractConfig_, LedgerClient as LedgerClient_, LedgerClientBuilder,
+    QuorumConfig as QuorumConfig_, TransactionConfig as TransactionConfig_, Web3Client,
+};
 
 #[derive(uniffi::Object)]
 pub struct LedgerClient {
@@ -21,18 +24,24 @@ impl LedgerClient {
         node_address: String,
         contract_configs: Vec<ContractConfig>,
         quorum_config: Option<QuorumConfig>,
+        transaction_config: Option<TransactionConfig>,
     ) -> VdrResult<LedgerClient> {
         let contract_configs: Vec<ContractConfig_> = contract_configs
             .into_iter()
             .map(ContractConfig::into)
             .collect();
-        let quorum_config = quorum_config.map(QuorumConfig::into);
-        let client = LedgerClient_::new(
-            chain_id,
-            &node_address,
-            &contract_configs,
-            quorum_config.as_ref(),
-        )?;
+        let mut builder = LedgerClientBuilder::new(chain_id)
+            .set_client(Box::new(Web3Client::new(&node_address)?))
+            .set_contract_configs(&contract_configs);
+        if let Some(quorum_config) = quorum_config {
+            let quorum_config: QuorumConfig_ = quorum_config.into();
+            builder = builder.set_quorum_config(&quorum_config);
+        }
+        if let Some(transaction_config) = transaction_config {
+            let transaction_config: TransactionConfig_ = transaction_config.into();
+            builder = builder.set_transaction_config(&transaction_config);
+        }
+        let client = builder.build()?;
         Ok(LedgerClient { client })
     }
 
@@ -48,7 +57,37 @@ impl LedgerClient {
             .map_err(VdrError::from)
     }
 
-    pub async fn get_receipt(&self, hash: Vec<u8>) -> VdrResult<String> {
-        self.client.get_receipt(&hash).await.map_err(VdrError::from)
+    pub async fn simulate_transaction(&self, transaction: &Transaction) -> VdrResult<Vec<u8>> {
+        self.client
+            .simulate_transaction(&transaction.transaction)
+            .await
+            .map_err(VdrError::from)
+    }
+
+    pub async fn get_receipt(&self, hash: Vec<u8>) -> VdrResult<TransactionReceipt> {
+        self.client
+            .get_receipt(&hash)
+            .await
+            .map(TransactionReceipt::from)
+            .map_err(VdrError::from)
+    }
+
+    pub async fn reserve_nonces(&self, address: &str, count: u64) -> VdrResult<Vec<u64>> {
+        let nonces = self
+            .client
+            .reserve_nonces(&Address::from(address), count)
+            .await?;
+        Ok(nonces.collect())
+    }
+
+    pub fn release_nonces(&self, address: &str, nonces: Vec<u64>) {
+        let address = Address::from(address);
+        for nonce in nonces {
+            self.client.release_nonces(&address, nonce..nonce + 1)
+        }
+    }
+
+    pub fn reset_nonce(&self, address: &str) {
+        self.client.reset_nonce(&Address::from(address))
     }
 }
@@ -2,6 +2,7 @@ use crate::ffi::{
     client::LedgerClient,
     error::{VdrError, VdrResult},
     transaction::Transaction,
+    types::SignatureData,
 };
 use indy2_vdr::{credential_definition_registry, Address, CredentialDefinitionId};
 use serde_json::json;
@@ -28,6 +29,52 @@ pub async fn build_create_credential_definition_transaction(
     Ok(Transaction { transaction })
 }
 
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn build_endorse_credential_definition_signing_bytes(
+    client: &LedgerClient,
+    identity: &str,
+    id: &str,
+    credential_definition: &str,
+) -> VdrResult<Vec<u8>> {
+    let credential_definition =
+        serde_json::from_str(credential_definition).map_err(|err| VdrError::CommonInvalidData {
+            msg: format!("Unable to parse credential definition. Err: {:?}", err),
+        })?;
+    let bytes =
+        credential_definition_registry::build_endorse_credential_definition_signing_bytes(
+            &client.client,
+            &Address::from(identity),
+            &CredentialDefinitionId::from(id),
+            &credential_definition,
+        )
+        .await?;
+    Ok(bytes)
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn build_endorse_credential_definition_transaction(
+    client: &LedgerClient,
+    from: &str,
+    id: &str,
+    credential_definition: &str,
+    signature: SignatureData,
+) -> VdrResult<Transaction> {
+    let credential_definition =
+        serde_json::from_str(credential_definition).map_err(|err| VdrError::CommonInvalidData {
+            msg: format!("Unable to parse credential definition. Err: {:?}", err),
+        })?;
+    let transaction =
+        credential_definition_registry::build_endorse_credential_definition_transaction(
+            &client.client,
+            &Address::from(from),
+            &CredentialDefinitionId::from(id),
+            &credential_definition,
+            &signature.into(),
+        )
+        .await?;
+    Ok(Transaction { transaction })
+}
+
 #[uniffi::export(async_runtime = "tokio")]
 pub async fn build_resolve_credential_definition_transaction(
     client: &LedgerClient,
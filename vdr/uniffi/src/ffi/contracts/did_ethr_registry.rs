@@ -0,0 +1,346 @@
+use crate::ffi::{
+    client::LedgerClient,
+    error::{VdrError, VdrResult},
+    transaction::Transaction,
+    types::SignatureData,
+};
+use indy2_vdr::{did_ethr_registry, Address, DelegateType, DidDocAttribute, DID};
+use std::str::FromStr;
+
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn build_did_change_owner_transaction(
+    client: &LedgerClient,
+    from: &str,
+    did: &str,
+    new_owner: &str,
+) -> VdrResult<Transaction> {
+    let transaction = did_ethr_registry::build_did_change_owner_transaction(
+        &client.client,
+        &Address::from(from),
+        &DID::try_from(did)?,
+        &Address::from(new_owner),
+    )
+    .await?;
+    Ok(Transaction { transaction })
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn build_did_change_owner_signed_transaction(
+    client: &LedgerClient,
+    from: &str,
+    did: &str,
+    new_owner: &str,
+    signature: SignatureData,
+) -> VdrResult<Transaction> {
+    let transaction = did_ethr_registry::build_did_change_owner_signed_transaction(
+        &client.client,
+        &Address::from(from),
+        &DID::try_from(did)?,
+        &Address::from(new_owner),
+        &signature.into(),
+    )
+    .await?;
+    Ok(Transaction { transaction })
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn build_did_change_owner_signing_bytes(
+    client: &LedgerClient,
+    did: &str,
+    new_owner: &str,
+) -> VdrResult<Vec<u8>> {
+    let bytes = did_ethr_registry::build_did_change_owner_signing_bytes(
+        &client.client,
+        &DID::try_from(did)?,
+        &Address::from(new_owner),
+    )
+    .await?;
+    Ok(bytes)
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn build_did_add_delegate_transaction(
+    client: &LedgerClient,
+    from: &str,
+    did: &str,
+    delegate_type: &str,
+    delegate: &str,
+    validity: u64,
+) -> VdrResult<Transaction> {
+    let transaction = did_ethr_registry::build_did_add_delegate_transaction(
+        &client.client,
+        &Address::from(from),
+        &DID::try_from(did)?,
+        &DelegateType::from_str(delegate_type)?,
+        &Address::from(delegate),
+        validity,
+    )
+    .await?;
+    Ok(Transaction { transaction })
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn build_did_add_delegate_signed_transaction(
+    client: &LedgerClient,
+    from: &str,
+    did: &str,
+    delegate_type: &str,
+    delegate: &str,
+    validity: u64,
+    signature: SignatureData,
+) -> VdrResult<Transaction> {
+    let transaction = did_ethr_registry::build_did_add_delegate_signed_transaction(
+        &client.client,
+        &Address::from(from),
+        &DID::try_from(did)?,
+        &DelegateType::from_str(delegate_type)?,
+        &Address::from(delegate),
+        validity,
+        &signature.into(),
+    )
+    .await?;
+    Ok(Transaction { transaction })
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn build_did_add_delegate_signing_bytes(
+    client: &LedgerClient,
+    did: &str,
+    delegate_type: &str,
+    delegate: &str,
+    validity: u64,
+) -> VdrResult<Vec<u8>> {
+    let bytes = did_ethr_registry::build_did_add_delegate_signing_bytes(
+        &client.client,
+        &DID::try_from(did)?,
+        &DelegateType::from_str(delegate_type)?,
+        &Address::from(delegate),
+        validity,
+    )
+    .await?;
+    Ok(bytes)
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn build_did_revoke_delegate_transaction(
+    client: &LedgerClient,
+    from: &str,
+    did: &str,
+    delegate_type: &str,
+    delegate: &str,
+) -> VdrResult<Transaction> {
+    let transaction = did_ethr_registry::build_did_revoke_delegate_transaction(
+        &client.client,
+        &Address::from(from),
+        &DID::try_from(did)?,
+        &DelegateType::from_str(delegate_type)?,
+        &Address::from(delegate),
+    )
+    .await?;
+    Ok(Transaction { transaction })
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn build_did_revoke_delegate_signed_transaction(
+    client: &LedgerClient,
+    from: &str,
+    did: &str,
+    delegate_type: &str,
+    delegate: &str,
+    signature: SignatureData,
+) -> VdrResult<Transaction> {
+    let transaction = did_ethr_registry::build_did_revoke_delegate_signed_transaction(
+        &client.client,
+        &Address::from(from),
+        &DID::try_from(did)?,
+        &DelegateType::from_str(delegate_type)?,
+        &Address::from(delegate),
+        &signature.into(),
+    )
+    .await?;
+    Ok(Transaction { transaction })
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn build_did_revoke_delegate_signing_bytes(
+    client: &LedgerClient,
+    did: &str,
+    delegate_type: &str,
+    delegate: &str,
+) -> VdrResult<Vec<u8>> {
+    let bytes = did_ethr_registry::build_did_revoke_delegate_signing_bytes(
+        &client.client,
+        &DID::try_from(did)?,
+        &DelegateType::from_str(delegate_type)?,
+        &Address::from(delegate),
+    )
+    .await?;
+    Ok(bytes)
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn build_did_set_attribute_transaction(
+    client: &LedgerClient,
+    from: &str,
+    did: &str,
+    attribute: &str,
+    validity: u64,
+) -> VdrResult<Transaction> {
+    let transaction = did_ethr_registry::build_did_set_attribute_transaction(
+        &client.client,
+        &Address::from(from),
+        &DID::try_from(did)?,
+        &parse_attribute(attribute)?,
+        validity,
+    )
+    .await?;
+    Ok(Transaction { transaction })
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn build_did_set_attribute_signed_transaction(
+    client: &LedgerClient,
+    from: &str,
+    did: &str,
+    attribute: &str,
+    validity: u64,
+    signature: SignatureData,
+) -> VdrResult<Transaction> {
+    let transaction = did_ethr_registry::build_did_set_attribute_signed_transaction(
+        &client.client,
+        &Address::from(from),
+        &DID::try_from(did)?,
+        &parse_attribute(attribute)?,
+        validity,
+        &signature.into(),
+    )
+    .await?;
+    Ok(Transaction { transaction })
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn build_did_set_attribute_signing_bytes(
+    client: &LedgerClient,
+    did: &str,
+    attribute: &str,
+    validity: u64,
+) -> VdrResult<Vec<u8>> {
+    let bytes = did_ethr_registry::build_did_set_attribute_signing_bytes(
+        &client.client,
+        &DID::try_from(did)?,
+        &parse_attribute(attribute)?,
+        validity,
+    )
+    .await?;
+    Ok(bytes)
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn build_did_revoke_attribute_transaction(
+    client: &LedgerClient,
+    from: &str,
+    did: &str,
+    attribute: &str,
+) -> VdrResult<Transaction> {
+    let transaction = did_ethr_registry::build_did_revoke_attribute_transaction(
+        &client.client,
+        &Address::from(from),
+        &DID::try_from(did)?,
+        &parse_attribute(attribute)?,
+    )
+    .await?;
+    Ok(Transaction { transaction })
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn build_did_revoke_attribute_signed_transaction(
+    client: &LedgerClient,
+    from: &str,
+    did: &str,
+    attribute: &str,
+    signature: SignatureData,
+) -> VdrResult<Transaction> {
+    let transaction = did_ethr_registry::build_did_revoke_attribute_signed_transaction(
+        &client.client,
+        &Address::from(from),
+        &DID::try_from(did)?,
+        &parse_attribute(attribute)?,
+        &signature.into(),
+    )
+    .await?;
+    Ok(Transaction { transaction })
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn build_did_revoke_attribute_signing_bytes(
+    client: &LedgerClient,
+    did: &str,
+    attribute: &str,
+) -> VdrResult<Vec<u8>> {
+    let bytes = did_ethr_registry::build_did_revoke_attribute_signing_bytes(
+        &client.client,
+        &DID::try_from(did)?,
+        &parse_attribute(attribute)?,
+    )
+    .await?;
+    Ok(bytes)
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn build_get_did_owner_transaction(
+    client: &LedgerClient,
+    did: &str,
+) -> VdrResult<Transaction> {
+    let transaction =
+        did_ethr_registry::build_get_did_owner_transaction(&client.client, &DID::try_from(did)?)
+            .await?;
+    Ok(Transaction { transaction })
+}
+
+#[uniffi::export]
+pub fn parse_did_owner_result(client: &LedgerClient, bytes: Vec<u8>) -> VdrResult<String> {
+    let owner = did_ethr_registry::parse_did_owner_result(&client.client, &bytes)?;
+    Ok(owner.to_string())
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn build_get_did_changed_transaction(
+    client: &LedgerClient,
+    did: &str,
+) -> VdrResult<Transaction> {
+    let transaction =
+        did_ethr_registry::build_get_did_changed_transaction(&client.client, &DID::try_from(did)?)
+            .await?;
+    Ok(Transaction { transaction })
+}
+
+#[uniffi::export]
+pub fn parse_did_changed_result(client: &LedgerClient, bytes: Vec<u8>) -> VdrResult<u64> {
+    let changed = did_ethr_registry::parse_did_changed_result(&client.client, &bytes)?;
+    Ok(changed)
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn build_get_identity_nonce_transaction(
+    client: &LedgerClient,
+    account: &str,
+) -> VdrResult<Transaction> {
+    let transaction = did_ethr_registry::build_get_identity_nonce_transaction(
+        &client.client,
+        &Address::from(account),
+    )
+    .await?;
+    Ok(Transaction { transaction })
+}
+
+#[uniffi::export]
+pub fn parse_identity_nonce_result(client: &LedgerClient, bytes: Vec<u8>) -> VdrResult<u64> {
+    let nonce = did_ethr_registry::parse_identity_nonce_result(&client.client, &bytes)?;
+    Ok(nonce)
+}
+
+fn parse_attribute(attribute: &str) -> VdrResult<DidDocAttribute> {
+    serde_json::from_str(attribute).map_err(|err| VdrError::CommonInvalidData {
+        msg: format!("Unable to parse DID Document attribute. Err: {:?}", err),
+    })
+}
@@ -2,6 +2,7 @@ use crate::ffi::{
     client::LedgerClient,
     error::{VdrError, VdrResult},
     transaction::Transaction,
+    types::SignatureData,
 };
 use indy2_vdr::{did_registry, Address, DID};
 use serde_json::json;
@@ -22,13 +23,79 @@ pub async fn build_create_did_transaction(
             &client.client,
             &Address::from(from),
             &Address::from(identity),
-            &DID::from(did),
+            &DID::try_from(did)?,
             &did_doc,
         )
             .await?;
     Ok(Transaction { transaction })
 }
 
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn build_create_migrated_did_transaction(
+    client: &LedgerClient,
+    from: &str,
+    identity: &str,
+    did: &str,
+    did_doc: &str,
+) -> VdrResult<Transaction> {
+    let did_doc = serde_json::from_str(did_doc).map_err(|err| VdrError::CommonInvalidData {
+        msg: format!("Unable to parse DID DDocument. Err: {:?}", err),
+    })?;
+    let transaction = did_registry::build_create_migrated_did_transaction(
+        &client.client,
+        &Address::from(from),
+        &Address::from(identity),
+        &DID::try_from(did)?,
+        &did_doc,
+    )
+    .await?;
+    Ok(Transaction { transaction })
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn build_endorse_did_signing_bytes(
+    client: &LedgerClient,
+    identity: &str,
+    did: &str,
+    did_doc: &str,
+) -> VdrResult<Vec<u8>> {
+    let did_doc = serde_json::from_str(did_doc).map_err(|err| VdrError::CommonInvalidData {
+        msg: format!("Unable to parse DID DDocument. Err: {:?}", err),
+    })?;
+    let bytes = did_registry::build_endorse_did_signing_bytes(
+        &client.client,
+        &Address::from(identity),
+        &DID::try_from(did)?,
+        &did_doc,
+    )
+    .await?;
+    Ok(bytes)
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn build_endorse_did_transaction(
+    client: &LedgerClient,
+    from: &str,
+    identity: &str,
+    did: &str,
+    did_doc: &str,
+    signature: SignatureData,
+) -> VdrResult<Transaction> {
+    let did_doc = serde_json::from_str(did_doc).map_err(|err| VdrError::CommonInvalidData {
+        msg: format!("Unable to parse DID DDocument. Err: {:?}", err),
+    })?;
+    let transaction = did_registry::build_endorse_did_transaction(
+        &client.client,
+        &Address::from(from),
+        &Address::from(identity),
+        &DID::try_from(did)?,
+        &did_doc,
+        &signature.into(),
+    )
+    .await?;
+    Ok(Transaction { transaction })
+}
+
 #[uniffi::export(async_runtime = "tokio")]
 pub async fn build_update_did_transaction(
     client: &LedgerClient,
@@ -43,7 +110,7 @@ pub async fn build_update_did_transaction(
         did_registry::build_update_did_transaction(
             &client.client,
             &Address::from(from),
-            &DID::from(did),
+            &DID::try_from(did)?,
             &did_doc
         )
             .await?;
@@ -59,7 +126,7 @@ pub async fn build_deactivate_did_transaction(
     let transaction = did_registry::build_deactivate_did_transaction(
         &client.client,
         &Address::from(from),
-        &DID::from(did),
+        &DID::try_from(did)?,
     )
         .await?;
     Ok(Transaction { transaction })
@@ -71,7 +138,7 @@ pub async fn build_resolve_did_transaction(
     did: &str,
 ) -> VdrResult<Transaction> {
     let transaction =
-        did_registry::build_resolve_did_transaction(&client.client, &DID::from(did)).await?;
+        did_registry::build_resolve_did_transaction(&client.client, &DID::try_from(did)?).await?;
     Ok(Transaction { transaction })
 }
 
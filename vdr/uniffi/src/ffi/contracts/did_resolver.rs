@@ -0,0 +1,38 @@
+use crate::ffi::{
+    client::LedgerClient,
+    error::{VdrError, VdrResult},
+};
+use indy2_vdr::{did_resolver, DidResolutionOptions};
+use serde_json::json;
+
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn resolve_did(
+    client: &LedgerClient,
+    did: &str,
+    options: Option<String>,
+) -> VdrResult<String> {
+    let options = parse_options(options)?;
+    let resolution_result = did_resolver::resolve(&client.client, did, options.as_ref()).await;
+    Ok(json!(resolution_result).to_string())
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn dereference_did_url(
+    client: &LedgerClient,
+    did_url: &str,
+    options: Option<String>,
+) -> VdrResult<String> {
+    let options = parse_options(options)?;
+    let dereferencing_result =
+        did_resolver::dereference(&client.client, did_url, options.as_ref()).await;
+    Ok(json!(dereferencing_result).to_string())
+}
+
+fn parse_options(options: Option<String>) -> VdrResult<Option<DidResolutionOptions>> {
+    options
+        .map(|options| serde_json::from_str::<DidResolutionOptions>(&options))
+        .transpose()
+        .map_err(|err| VdrError::CommonInvalidData {
+            msg: format!("Unable to parse DID resolution options. Err: {:?}", err),
+        })
+}
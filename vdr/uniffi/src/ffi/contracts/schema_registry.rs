@@ -2,6 +2,7 @@ use crate::ffi::{
     client::LedgerClient,
     error::{VdrError, VdrResult},
     transaction::Transaction,
+    types::SignatureData,
 };
 use indy2_vdr::{schema_registry, Address, SchemaId};
 use serde_json::json;
@@ -26,6 +27,48 @@ pub async fn build_create_schema_transaction(
     Ok(Transaction { transaction })
 }
 
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn build_endorse_schema_signing_bytes(
+    client: &LedgerClient,
+    identity: &str,
+    id: &str,
+    schema: &str,
+) -> VdrResult<Vec<u8>> {
+    let schema = serde_json::from_str(schema).map_err(|err| VdrError::CommonInvalidData {
+        msg: format!("Unable to parse schema. Err: {:?}", err),
+    })?;
+    let bytes = schema_registry::build_endorse_schema_signing_bytes(
+        &client.client,
+        &Address::from(identity),
+        &SchemaId::from(id),
+        &schema,
+    )
+    .await?;
+    Ok(bytes)
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn build_endorse_schema_transaction(
+    client: &LedgerClient,
+    from: &str,
+    id: &str,
+    schema: &str,
+    signature: SignatureData,
+) -> VdrResult<Transaction> {
+    let schema = serde_json::from_str(schema).map_err(|err| VdrError::CommonInvalidData {
+        msg: format!("Unable to parse schema. Err: {:?}", err),
+    })?;
+    let transaction = schema_registry::build_endorse_schema_transaction(
+        &client.client,
+        &Address::from(from),
+        &SchemaId::from(id),
+        &schema,
+        &signature.into(),
+    )
+    .await?;
+    Ok(Transaction { transaction })
+}
+
 #[uniffi::export(async_runtime = "tokio")]
 pub async fn build_resolve_schema_transaction(
     client: &LedgerClient,
@@ -0,0 +1,48 @@
+use crate::ffi::{client::LedgerClient, error::VdrResult, transaction::Transaction};
+use indy2_vdr::{universal_did_resolver, DID};
+use serde_json::json;
+
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn build_resolve_document_transaction(
+    client: &LedgerClient,
+    did: &str,
+) -> VdrResult<Transaction> {
+    let transaction = universal_did_resolver::build_resolve_document_transaction(
+        &client.client,
+        &DID::try_from(did)?,
+    )
+    .await?;
+    Ok(Transaction { transaction })
+}
+
+#[uniffi::export]
+pub fn parse_resolve_document_result(client: &LedgerClient, bytes: Vec<u8>) -> VdrResult<String> {
+    let did_doc = universal_did_resolver::parse_resolve_document_result(&client.client, &bytes)?;
+    Ok(json!(did_doc).to_string())
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn build_resolve_metadata_transaction(
+    client: &LedgerClient,
+    did: &str,
+) -> VdrResult<Transaction> {
+    let transaction = universal_did_resolver::build_resolve_metadata_transaction(
+        &client.client,
+        &DID::try_from(did)?,
+    )
+    .await?;
+    Ok(Transaction { transaction })
+}
+
+#[uniffi::export]
+pub fn parse_resolve_metadata_result(client: &LedgerClient, bytes: Vec<u8>) -> VdrResult<String> {
+    let metadata = universal_did_resolver::parse_resolve_metadata_result(&client.client, &bytes)?;
+    Ok(json!(metadata).to_string())
+}
+
+#[uniffi::export(async_runtime = "tokio")]
+pub async fn resolve_any_did(client: &LedgerClient, did: &str) -> VdrResult<String> {
+    let did_doc =
+        universal_did_resolver::resolve_any_did(&client.client, &DID::try_from(did)?).await?;
+    Ok(json!(did_doc).to_string())
+}
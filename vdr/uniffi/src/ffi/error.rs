@@ -14,12 +14,21 @@ pub enum VdrError {
     #[error("Ledger Client: Transaction reverted: {}", msg)]
     ClientTransactionReverted { msg: String },
 
+    #[error("Ledger Client: Transaction failed: {}", msg)]
+    ClientTransactionFailed { msg: String },
+
+    #[error("Ledger Client: Transaction rejected: {}", msg)]
+    ClientTransactionRejected { msg: String },
+
     #[error("Ledger Client: Unexpected error occurred: {}", msg)]
     ClientUnexpectedError { msg: String },
 
     #[error("Ledger Client: Invalid state {}", msg)]
     ClientInvalidState { msg: String },
 
+    #[error("Ledger Client: Unsupported operation: {}", msg)]
+    ClientUnsupportedOperation { msg: String },
+
     #[error("Contract: Invalid name: {}", msg)]
     ContractInvalidName { msg: String },
 
@@ -32,9 +41,144 @@ pub enum VdrError {
     #[error("Contract: Invalid response data: {}", msg)]
     ContractInvalidResponseData { msg: String },
 
+    #[error(
+        "Contract: Sender is not authorized to perform the operation: {}",
+        sender
+    )]
+    ContractUnauthorized { sender: String },
+
+    #[error("Contract: Required field is missing: {}", name)]
+    ContractFieldRequired { name: String },
+
+    #[error("Contract: Conflicting fields are provided: {}", names)]
+    ContractConflictingFields { names: String },
+
+    #[error("Contract: Unsupported operation: {}. {}", operation, description)]
+    ContractUnsupportedOperation {
+        operation: String,
+        description: String,
+    },
+
+    #[error("DID: Not found: {}", did)]
+    DidNotFound { did: String },
+
+    #[error("DID: Already exists: {}", did)]
+    DidAlreadyExists { did: String },
+
+    #[error("DID: Has been deactivated: {}", did)]
+    DidHasBeenDeactivated { did: String },
+
+    #[error("DID: Incorrect DID: {}", did)]
+    DidIncorrect { did: String },
+
+    #[error("DID: Invalid identifier: {}", msg)]
+    DidInvalidIdentifier { msg: String },
+
+    #[error("DID: Invalid DID Document: {}", msg)]
+    DidInvalidDocument { msg: String },
+
+    #[error("DID: Sender is not authorized to modify the DID: {}", sender)]
+    DidUnauthorizedSender { sender: String },
+
+    #[error("DID URL: Resource not found: {}", msg)]
+    DidUrlNotFound { msg: String },
+
+    #[error("Issuer: Not found: {}", id)]
+    IssuerNotFound { id: String },
+
+    #[error("Issuer: Invalid issuer id: {}", id)]
+    IssuerInvalidId { id: String },
+
+    #[error("Issuer: Has been deactivated: {}", id)]
+    IssuerHasBeenDeactivated { id: String },
+
+    #[error(
+        "Issuer: Sender is not authorized to act on behalf of the issuer: {}",
+        sender
+    )]
+    IssuerUnauthorized { sender: String },
+
+    #[error("Schema: Invalid schema id: {}", id)]
+    SchemaInvalidId { id: String },
+
+    #[error("Schema: Already exists: {}", id)]
+    SchemaAlreadyExists { id: String },
+
+    #[error("Schema: Not found: {}", id)]
+    SchemaNotFound { id: String },
+
+    #[error("Credential Definition: Invalid credential definition id: {}", id)]
+    CredentialDefinitionInvalidId { id: String },
+
+    #[error("Credential Definition: Already exists: {}", id)]
+    CredentialDefinitionAlreadyExists { id: String },
+
+    #[error("Credential Definition: Not found: {}", id)]
+    CredentialDefinitionNotFound { id: String },
+
+    #[error("Validator: Initial validators are required")]
+    ValidatorInitialValidatorsRequired,
+
+    #[error("Validator: Invalid validator address")]
+    ValidatorInvalidAddress,
+
+    #[error("Validator: Invalid validator account address")]
+    ValidatorInvalidAccountAddress,
+
+    #[error("Validator: Number of validators exceeds the limit: {}", limit)]
+    ValidatorLimitExceeded { limit: u16 },
+
+    #[error("Validator: Already exists: {}", validator)]
+    ValidatorAlreadyExists { validator: String },
+
+    #[error("Validator: Sender already has an active validator: {}", sender)]
+    ValidatorSenderHasActiveValidator { sender: String },
+
+    #[error("Validator: Cannot deactivate the last validator")]
+    ValidatorCannotDeactivateLast,
+
+    #[error("Validator: Not found: {}", validator)]
+    ValidatorNotFound { validator: String },
+
+    #[error("Upgrade: Insufficient approvals")]
+    UpgradeInsufficientApprovals,
+
+    #[error(
+        "Upgrade: Already proposed. Proxy: {}, implementation: {}",
+        proxy,
+        implementation
+    )]
+    UpgradeAlreadyProposed {
+        proxy: String,
+        implementation: String,
+    },
+
+    #[error(
+        "Upgrade: Proposal not found. Proxy: {}, implementation: {}",
+        proxy,
+        implementation
+    )]
+    UpgradeProposalNotFound {
+        proxy: String,
+        implementation: String,
+    },
+
+    #[error(
+        "Upgrade: Already approved. Proxy: {}, implementation: {}",
+        proxy,
+        implementation
+    )]
+    UpgradeAlreadyApproved {
+        proxy: String,
+        implementation: String,
+    },
+
     #[error("Signer: Invalid private key")]
     SignerInvalidPrivateKey,
 
+    #[error("Signer: Invalid public key: {}", msg)]
+    SignerInvalidPublicKey { msg: String },
+
     #[error("Signer: Invalid message")]
     SignerInvalidMessage,
 
@@ -44,6 +188,31 @@ pub enum VdrError {
     #[error("Signer: Unexpected error occurred: {}", msg)]
     SignerUnexpectedError { msg: String },
 
+    #[error("Signer: Invalid signature: {}", msg)]
+    SignerInvalidSignature { msg: String },
+
+    #[error("Signer: Unsupported operation: {}", msg)]
+    SignerUnsupportedOperation { msg: String },
+
+    #[error("Signer: Invalid keystore: {}", msg)]
+    SignerInvalidKeystore { msg: String },
+
+    #[error("Signer: Invalid keystore password")]
+    SignerInvalidPassword,
+
+    #[error("Signer: Invalid mnemonic: {}", msg)]
+    SignerInvalidMnemonic { msg: String },
+
+    #[error("Signer: Invalid derivation path: {}", msg)]
+    SignerInvalidDerivationPath { msg: String },
+
+    #[error(
+        "Signer: Transaction is signed by: {} instead of its sender: {}",
+        signer,
+        sender
+    )]
+    SignerMismatch { signer: String, sender: String },
+
     #[error("Invalid data: {}", msg)]
     CommonInvalidData { msg: String },
 
@@ -67,18 +236,118 @@ impl From<VdrError_> for VdrError {
             VdrError_::ClientTransactionReverted(msg) => {
                 VdrError::ClientTransactionReverted { msg }
             }
+            VdrError_::ClientTransactionFailed(msg) => VdrError::ClientTransactionFailed { msg },
+            VdrError_::ClientTransactionRejected(msg) => {
+                VdrError::ClientTransactionRejected { msg }
+            }
             VdrError_::ClientUnexpectedError(msg) => VdrError::ClientUnexpectedError { msg },
             VdrError_::ClientInvalidState(msg) => VdrError::ClientInvalidState { msg },
+            VdrError_::ClientUnsupportedOperation(msg) => {
+                VdrError::ClientUnsupportedOperation { msg }
+            }
             VdrError_::ContractInvalidName(msg) => VdrError::ContractInvalidName { msg },
             VdrError_::ContractInvalidSpec(msg) => VdrError::ContractInvalidSpec { msg },
             VdrError_::ContractInvalidInputData => VdrError::ContractInvalidInputData,
             VdrError_::ContractInvalidResponseData(msg) => {
                 VdrError::ContractInvalidResponseData { msg }
             }
+            VdrError_::ContractUnauthorized(sender) => VdrError::ContractUnauthorized {
+                sender: sender.to_string(),
+            },
+            VdrError_::ContractFieldRequired(name) => VdrError::ContractFieldRequired { name },
+            VdrError_::ContractConflictingFields(names) => {
+                VdrError::ContractConflictingFields { names }
+            }
+            VdrError_::ContractUnsupportedOperation(operation, description) => {
+                VdrError::ContractUnsupportedOperation {
+                    operation,
+                    description,
+                }
+            }
+            VdrError_::DidNotFound(did) => VdrError::DidNotFound { did },
+            VdrError_::DidAlreadyExists(did) => VdrError::DidAlreadyExists { did },
+            VdrError_::DidHasBeenDeactivated(did) => VdrError::DidHasBeenDeactivated { did },
+            VdrError_::DidIncorrect(did) => VdrError::DidIncorrect { did },
+            VdrError_::DidInvalidIdentifier(msg) => VdrError::DidInvalidIdentifier { msg },
+            VdrError_::DidInvalidDocument(msg) => VdrError::DidInvalidDocument { msg },
+            VdrError_::DidUrlNotFound(msg) => VdrError::DidUrlNotFound { msg },
+            VdrError_::DidUnauthorizedSender(sender) => VdrError::DidUnauthorizedSender {
+                sender: sender.to_string(),
+            },
+            VdrError_::IssuerNotFound(id) => VdrError::IssuerNotFound { id },
+            VdrError_::IssuerInvalidId(id) => VdrError::IssuerInvalidId { id },
+            VdrError_::IssuerHasBeenDeactivated(id) => VdrError::IssuerHasBeenDeactivated { id },
+            VdrError_::IssuerUnauthorized(sender) => VdrError::IssuerUnauthorized {
+                sender: sender.to_string(),
+            },
+            VdrError_::SchemaInvalidId(id) => VdrError::SchemaInvalidId { id },
+            VdrError_::SchemaAlreadyExists(id) => VdrError::SchemaAlreadyExists { id },
+            VdrError_::SchemaNotFound(id) => VdrError::SchemaNotFound { id },
+            VdrError_::CredentialDefinitionInvalidId(id) => {
+                VdrError::CredentialDefinitionInvalidId { id }
+            }
+            VdrError_::CredentialDefinitionAlreadyExists(id) => {
+                VdrError::CredentialDefinitionAlreadyExists { id }
+            }
+            VdrError_::CredentialDefinitionNotFound(id) => {
+                VdrError::CredentialDefinitionNotFound { id }
+            }
+            VdrError_::ValidatorInitialValidatorsRequired => {
+                VdrError::ValidatorInitialValidatorsRequired
+            }
+            VdrError_::ValidatorInvalidAddress => VdrError::ValidatorInvalidAddress,
+            VdrError_::ValidatorInvalidAccountAddress => VdrError::ValidatorInvalidAccountAddress,
+            VdrError_::ValidatorLimitExceeded(limit) => VdrError::ValidatorLimitExceeded { limit },
+            VdrError_::ValidatorAlreadyExists(validator) => VdrError::ValidatorAlreadyExists {
+                validator: validator.to_string(),
+            },
+            VdrError_::ValidatorSenderHasActiveValidator(sender) => {
+                VdrError::ValidatorSenderHasActiveValidator {
+                    sender: sender.to_string(),
+                }
+            }
+            VdrError_::ValidatorCannotDeactivateLast => VdrError::ValidatorCannotDeactivateLast,
+            VdrError_::ValidatorNotFound(validator) => VdrError::ValidatorNotFound {
+                validator: validator.to_string(),
+            },
+            VdrError_::UpgradeInsufficientApprovals => VdrError::UpgradeInsufficientApprovals,
+            VdrError_::UpgradeAlreadyProposed(proxy, implementation) => {
+                VdrError::UpgradeAlreadyProposed {
+                    proxy: proxy.to_string(),
+                    implementation: implementation.to_string(),
+                }
+            }
+            VdrError_::UpgradeProposalNotFound(proxy, implementation) => {
+                VdrError::UpgradeProposalNotFound {
+                    proxy: proxy.to_string(),
+                    implementation: implementation.to_string(),
+                }
+            }
+            VdrError_::UpgradeAlreadyApproved(proxy, implementation) => {
+                VdrError::UpgradeAlreadyApproved {
+                    proxy: proxy.to_string(),
+                    implementation: implementation.to_string(),
+                }
+            }
             VdrError_::SignerInvalidPrivateKey => VdrError::SignerInvalidPrivateKey,
+            VdrError_::SignerInvalidPublicKey(msg) => VdrError::SignerInvalidPublicKey { msg },
             VdrError_::SignerInvalidMessage => VdrError::SignerInvalidMessage,
             VdrError_::SignerMissingKey(msg) => VdrError::SignerMissingKey { msg },
             VdrError_::SignerUnexpectedError(msg) => VdrError::SignerUnexpectedError { msg },
+            VdrError_::SignerInvalidSignature(msg) => VdrError::SignerInvalidSignature { msg },
+            VdrError_::SignerUnsupportedOperation(msg) => {
+                VdrError::SignerUnsupportedOperation { msg }
+            }
+            VdrError_::SignerInvalidKeystore(msg) => VdrError::SignerInvalidKeystore { msg },
+            VdrError_::SignerInvalidPassword => VdrError::SignerInvalidPassword,
+            VdrError_::SignerInvalidMnemonic(msg) => VdrError::SignerInvalidMnemonic { msg },
+            VdrError_::SignerInvalidDerivationPath(msg) => {
+                VdrError::SignerInvalidDerivationPath { msg }
+            }
+            VdrError_::SignerMismatch(signer, sender) => VdrError::SignerMismatch {
+                signer: signer.to_string(),
+                sender: sender.to_string(),
+            },
             VdrError_::CommonInvalidData(msg) => VdrError::CommonInvalidData { msg },
             VdrError_::QuorumNotReached(msg) => VdrError::QuorumNotReached { msg },
             VdrError_::GetTransactionError(msg) => VdrError::GetTransactionError { msg },
@@ -1,6 +1,6 @@
 use crate::ffi::{
     error::{VdrError, VdrResult},
-    types::{SignatureData, TransactionSignature, TransactionType},
+    types::{SignatureData, TransactionCall, TransactionSignature, TransactionType},
 };
 use indy2_vdr::{Address, Transaction as Transaction_};
 
@@ -34,6 +34,30 @@ impl Transaction {
         }
     }
 
+    #[uniffi::constructor]
+    pub fn from_json(json: String) -> VdrResult<Transaction> {
+        let transaction = Transaction_::from_json(&json)?;
+        Ok(Transaction { transaction })
+    }
+
+    #[uniffi::constructor]
+    pub fn from_bytes(bytes: Vec<u8>) -> VdrResult<Transaction> {
+        let transaction = Transaction_::from_bytes(&bytes)?;
+        Ok(Transaction { transaction })
+    }
+
+    pub fn to_json(&self) -> VdrResult<String> {
+        self.transaction.to_json().map_err(VdrError::from)
+    }
+
+    pub fn to_bytes(&self) -> VdrResult<Vec<u8>> {
+        self.transaction.to_bytes().map_err(VdrError::from)
+    }
+
+    pub fn get_call(&self) -> Option<TransactionCall> {
+        self.transaction.call.clone().map(TransactionCall::from)
+    }
+
     pub fn get_signing_bytes(&self) -> VdrResult<Vec<u8>> {
         self.transaction.get_signing_bytes().map_err(VdrError::from)
     }
@@ -41,4 +65,9 @@ impl Transaction {
     pub fn set_signature(&self, signature_data: SignatureData) {
         self.transaction.set_signature(signature_data.into())
     }
+
+    pub fn recover_signer(&self) -> VdrResult<String> {
+        let signer = self.transaction.recover_signer()?;
+        Ok(signer.to_string())
+    }
 }
@@ -1,8 +1,12 @@
 use crate::JsonValue;
 use indy2_vdr::{
-    ContractConfig as ContractConfig_, ContractSpec as ContractSpec_, PingStatus as PingStatus_,
-    QuorumConfig as QuorumConfig_, SignatureData as SignatureData_, Status as Status_,
-    TransactionSignature as TransactionSignature_, TransactionType as TransactionType_,
+    ContractConfig as ContractConfig_, ContractSpec as ContractSpec_, EventLog as EventLog_,
+    LedgerEvent as LedgerEvent_, PingStatus as PingStatus_, QuorumConfig as QuorumConfig_,
+    ReceiptStatus as ReceiptStatus_, SignatureData as SignatureData_, Status as Status_,
+    TransactionCall as TransactionCall_, TransactionCallParam as TransactionCallParam_,
+    TransactionConfig as TransactionConfig_, TransactionEnvelopeType as TransactionEnvelopeType_,
+    TransactionReceipt as TransactionReceipt_, TransactionSignature as TransactionSignature_,
+    TransactionType as TransactionType_,
 };
 
 #[derive(uniffi::Record)]
@@ -56,6 +60,120 @@ pub struct QuorumConfig {
     pub retry_interval: Option<u64>,
 }
 
+#[derive(uniffi::Enum)]
+pub enum TransactionEnvelopeType {
+    Legacy,
+    AccessList,
+    DynamicFee,
+}
+
+#[derive(uniffi::Record)]
+pub struct TransactionConfig {
+    pub envelope: Option<TransactionEnvelopeType>,
+    pub gas_limit_margin: Option<u64>,
+    pub gas_limit: Option<u64>,
+}
+
+#[derive(uniffi::Record)]
+pub struct TransactionCall {
+    pub contract: String,
+    pub method: String,
+    pub params: Vec<TransactionCallParam>,
+}
+
+#[derive(uniffi::Record)]
+pub struct TransactionCallParam {
+    pub name: String,
+    pub type_: String,
+    pub value: String,
+}
+
+#[derive(uniffi::Record)]
+pub struct TransactionReceipt {
+    pub transaction_hash: Vec<u8>,
+    pub block_hash: Option<Vec<u8>>,
+    pub block_number: Option<u64>,
+    pub from: String,
+    pub to: Option<String>,
+    pub gas_used: Option<u64>,
+    pub status: ReceiptStatus,
+    pub logs: Vec<EventLog>,
+    pub events: Vec<LedgerEvent>,
+}
+
+#[derive(uniffi::Enum)]
+pub enum ReceiptStatus {
+    Success,
+    Failure,
+}
+
+#[derive(uniffi::Record)]
+pub struct EventLog {
+    pub address: String,
+    pub topics: Vec<Vec<u8>>,
+    pub data: Vec<u8>,
+    pub block_number: Option<u64>,
+    pub transaction_hash: Option<Vec<u8>>,
+}
+
+#[derive(uniffi::Enum)]
+pub enum LedgerEvent {
+    DidCreated {
+        did: String,
+    },
+    DidUpdated {
+        did: String,
+    },
+    DidDeactivated {
+        did: String,
+    },
+    DidOwnerChanged {
+        identity: String,
+        owner: String,
+        previous_change: u64,
+    },
+    DidDelegateChanged {
+        identity: String,
+        delegate_type: String,
+        delegate: String,
+        valid_to: u64,
+        previous_change: u64,
+    },
+    DidAttributeChanged {
+        identity: String,
+        name: String,
+        value: Vec<u8>,
+        valid_to: u64,
+        previous_change: u64,
+    },
+    SchemaCreated {
+        schema_id: String,
+    },
+    CredentialDefinitionCreated {
+        credential_definition_id: String,
+    },
+    RoleAssigned {
+        role: u8,
+        account: String,
+        sender: String,
+    },
+    RoleRevoked {
+        role: u8,
+        account: String,
+        sender: String,
+    },
+    ValidatorAdded {
+        validator: String,
+        by_account: String,
+        num_validators: u8,
+    },
+    ValidatorRemoved {
+        validator: String,
+        by_account: String,
+        num_validators: u8,
+    },
+}
+
 impl From<PingStatus_> for PingStatus {
     fn from(status: PingStatus_) -> Self {
         PingStatus {
@@ -130,3 +248,147 @@ impl Into<QuorumConfig_> for QuorumConfig {
         }
     }
 }
+
+impl Into<TransactionEnvelopeType_> for TransactionEnvelopeType {
+    fn into(self) -> TransactionEnvelopeType_ {
+        match self {
+            TransactionEnvelopeType::Legacy => TransactionEnvelopeType_::Legacy,
+            TransactionEnvelopeType::AccessList => TransactionEnvelopeType_::AccessList,
+            TransactionEnvelopeType::DynamicFee => TransactionEnvelopeType_::DynamicFee,
+        }
+    }
+}
+
+impl Into<TransactionConfig_> for TransactionConfig {
+    fn into(self) -> TransactionConfig_ {
+        TransactionConfig_ {
+            envelope: self.envelope.map(TransactionEnvelopeType::into),
+            gas_limit_margin: self.gas_limit_margin,
+            gas_limit: self.gas_limit,
+        }
+    }
+}
+
+impl From<TransactionCall_> for TransactionCall {
+    fn from(call: TransactionCall_) -> Self {
+        TransactionCall {
+            contract: call.contract,
+            method: call.method,
+            params: call
+                .params
+                .into_iter()
+                .map(TransactionCallParam::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<TransactionCallParam_> for TransactionCallParam {
+    fn from(param: TransactionCallParam_) -> Self {
+        TransactionCallParam {
+            name: param.name,
+            type_: param.type_,
+            value: param.value,
+        }
+    }
+}
+
+impl From<TransactionReceipt_> for TransactionReceipt {
+    fn from(receipt: TransactionReceipt_) -> Self {
+        TransactionReceipt {
+            transaction_hash: receipt.transaction_hash,
+            block_hash: receipt.block_hash,
+            block_number: receipt.block_number,
+            from: receipt.from.to_string(),
+            to: receipt.to.map(|to| to.to_string()),
+            gas_used: receipt.gas_used,
+            status: ReceiptStatus::from(receipt.status),
+            logs: receipt.logs.into_iter().map(EventLog::from).collect(),
+            events: receipt.events.into_iter().map(LedgerEvent::from).collect(),
+        }
+    }
+}
+
+impl From<ReceiptStatus_> for ReceiptStatus {
+    fn from(status: ReceiptStatus_) -> Self {
+        match status {
+            ReceiptStatus_::Success => ReceiptStatus::Success,
+            ReceiptStatus_::Failure => ReceiptStatus::Failure,
+        }
+    }
+}
+
+impl From<EventLog_> for EventLog {
+    fn from(log: EventLog_) -> Self {
+        EventLog {
+            address: log.address.to_string(),
+            topics: log.topics,
+            data: log.data,
+            block_number: log.block_number,
+            transaction_hash: log.transaction_hash,
+        }
+    }
+}
+
+impl From<LedgerEvent_> for LedgerEvent {
+    fn from(event: LedgerEvent_) -> Self {
+        match event {
+            LedgerEvent_::DidCreated(did) => LedgerEvent::DidCreated {
+                did: did.to_string(),
+            },
+            LedgerEvent_::DidUpdated(did) => LedgerEvent::DidUpdated {
+                did: did.to_string(),
+            },
+            LedgerEvent_::DidDeactivated(did) => LedgerEvent::DidDeactivated {
+                did: did.to_string(),
+            },
+            LedgerEvent_::DidOwnerChanged(event) => LedgerEvent::DidOwnerChanged {
+                identity: event.identity.to_string(),
+                owner: event.owner.to_string(),
+                previous_change: event.previous_change,
+            },
+            LedgerEvent_::DidDelegateChanged(event) => LedgerEvent::DidDelegateChanged {
+                identity: event.identity.to_string(),
+                delegate_type: event.delegate_type,
+                delegate: event.delegate.to_string(),
+                valid_to: event.valid_to,
+                previous_change: event.previous_change,
+            },
+            LedgerEvent_::DidAttributeChanged(event) => LedgerEvent::DidAttributeChanged {
+                identity: event.identity.to_string(),
+                name: event.name,
+                value: event.value,
+                valid_to: event.valid_to,
+                previous_change: event.previous_change,
+            },
+            LedgerEvent_::SchemaCreated(schema_id) => LedgerEvent::SchemaCreated {
+                schema_id: schema_id.to_string(),
+            },
+            LedgerEvent_::CredentialDefinitionCreated(credential_definition_id) => {
+                LedgerEvent::CredentialDefinitionCreated {
+                    credential_definition_id: credential_definition_id.to_string(),
+                }
+            }
+            LedgerEvent_::RoleAssigned(event) => LedgerEvent::RoleAssigned {
+                role: event.role.into(),
+                account: event.account.to_string(),
+                sender: event.sender.to_string(),
+            },
+            LedgerEvent_::RoleRevoked(event) => LedgerEvent::RoleRevoked {
+                role: event.role.into(),
+                account: event.account.to_string(),
+                sender: event.sender.to_string(),
+            },
+            LedgerEvent_::ValidatorAdded(event) => LedgerEvent::ValidatorAdded {
+                validator: event.validator.to_string(),
+                by_account: event.by_account.to_string(),
+                num_validators: event.num_validators,
+            },
+            LedgerEvent_::ValidatorRemoved(event) => LedgerEvent::ValidatorRemoved {
+                validator: event.validator.to_string(),
+                by_account: event.by_account.to_string(),
+                num_validators: event.num_validators,
+            },
+        }
+    }
+}
@@ -25,7 +25,7 @@ async function main() {
             "spec": JSON.parse(fs.readFileSync(didRegistryConfig.specPath, 'utf8')),
         }
     ]
-    const client = new LedgerClient(chainId, nodeAddress, contractConfigs, null)
+    const client = new LedgerClient(chainId, nodeAddress, contractConfigs, null, null)
     const status = await client.ping()
     console.log('Status: ' + JSON.stringify(status, null, 2))
 
@@ -65,7 +65,7 @@ async function main() {
     console.log('Transaction hash: ' + txnHash)
 
     const receipt = await client.getReceipt(txnHash)
-    console.log('Transaction receipt: ' + receipt)
+    console.log('Transaction receipt: ' + JSON.stringify(receipt, null, 2))
 
     console.log('3. Resolve DID Document')
     transaction = await IndyDidRegistry.buildResolveDidTransaction(client, didDoc.id)
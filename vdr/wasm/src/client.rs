@@ -3,7 +3,10 @@ use std::rc::Rc;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::future_to_promise;
 
-use indy2_vdr::{ContractConfig, LedgerClient, QuorumConfig};
+use indy2_vdr::{
+    Address, ContractConfig, LedgerClient, LedgerClientBuilder, QuorumConfig, TransactionConfig,
+    Web3Client,
+};
 
 use crate::{
     error::{JsResult, Result},
@@ -21,19 +24,25 @@ impl LedgerClientWrapper {
         node_address: String,
         contract_configs: JsValue,
         quorum_config: JsValue,
+        transaction_config: JsValue,
     ) -> Result<LedgerClientWrapper> {
         console_error_panic_hook::set_once();
         let contract_configs: Vec<ContractConfig> =
             serde_wasm_bindgen::from_value(contract_configs)?;
         let quorum_config: Option<QuorumConfig> =
             serde_wasm_bindgen::from_value(quorum_config).ok();
-        let client = LedgerClient::new(
-            chain_id as u64,
-            &node_address,
-            &contract_configs,
-            quorum_config.as_ref(),
-        )
-        .as_js()?;
+        let transaction_config: Option<TransactionConfig> =
+            serde_wasm_bindgen::from_value(transaction_config).ok();
+        let mut builder = LedgerClientBuilder::new(chain_id as u64)
+            .set_client(Box::new(Web3Client::new(&node_address).as_js()?))
+            .set_contract_configs(&contract_configs);
+        if let Some(quorum_config) = quorum_config.as_ref() {
+            builder = builder.set_quorum_config(quorum_config);
+        }
+        if let Some(transaction_config) = transaction_config.as_ref() {
+            builder = builder.set_transaction_config(transaction_config);
+        }
+        let client = builder.build().as_js()?;
         Ok(LedgerClientWrapper(Rc::new(client)))
     }
 
@@ -57,6 +66,17 @@ impl LedgerClientWrapper {
         })
     }
 
+    #[wasm_bindgen(js_name = simulateTransaction)]
+    pub async fn simulate_transaction(&self, transaction: &TransactionWrapper) -> Promise {
+        let client = self.0.clone();
+        let transaction = transaction.0.clone();
+        future_to_promise(async move {
+            let response = client.simulate_transaction(&transaction).await.as_js()?;
+            let result: JsValue = serde_wasm_bindgen::to_value(&response)?;
+            Ok(result)
+        })
+    }
+
     #[wasm_bindgen(js_name = getReceipt)]
     pub async fn get_receipt(&self, hash: Vec<u8>) -> Promise {
         let client = self.0.clone();
@@ -66,4 +86,32 @@ impl LedgerClientWrapper {
             Ok(result)
         })
     }
+
+    #[wasm_bindgen(js_name = reserveNonces)]
+    pub async fn reserve_nonces(&self, address: &str, count: u32) -> Promise {
+        let client = self.0.clone();
+        let address = Address::from(address);
+        future_to_promise(async move {
+            let nonces: Vec<u64> = client
+                .reserve_nonces(&address, count as u64)
+                .await
+                .as_js()?
+                .collect();
+            let result: JsValue = serde_wasm_bindgen::to_value(&nonces)?;
+            Ok(result)
+        })
+    }
+
+    #[wasm_bindgen(js_name = releaseNonces)]
+    pub fn release_nonces(&self, address: &str, nonces: Vec<u64>) {
+        let address = Address::from(address);
+        for nonce in nonces {
+            self.0.release_nonces(&address, nonce..nonce + 1)
+        }
+    }
+
+    #[wasm_bindgen(js_name = resetNonce)]
+    pub fn reset_nonce(&self, address: &str) {
+        self.0.reset_nonce(&Address::from(address))
+    }
 }
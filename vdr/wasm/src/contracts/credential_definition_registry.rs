@@ -1,5 +1,6 @@
 use indy2_vdr::{
     credential_definition_registry, Address, CredentialDefinition, CredentialDefinitionId,
+    SignatureData,
 };
 use std::rc::Rc;
 use wasm_bindgen::prelude::*;
@@ -35,6 +36,46 @@ impl CredentialDefinitionRegistry {
         Ok(TransactionWrapper(Rc::new(transaction)))
     }
 
+    #[wasm_bindgen(js_name = buildEndorseCredentialDefinitionSigningBytes)]
+    pub async fn build_endorse_credential_definition_signing_bytes(
+        client: &LedgerClientWrapper,
+        identity: &str,
+        id: &str,
+        cred_def: JsValue,
+    ) -> Result<Vec<u8>> {
+        let cred_def: CredentialDefinition = serde_wasm_bindgen::from_value(cred_def)?;
+        let identity = Address::from(identity);
+        let id = CredentialDefinitionId::from(id);
+        let bytes =
+            credential_definition_registry::build_endorse_credential_definition_signing_bytes(
+                &client.0, &identity, &id, &cred_def,
+            )
+            .await
+            .as_js()?;
+        Ok(bytes)
+    }
+
+    #[wasm_bindgen(js_name = buildEndorseCredentialDefinitionTransaction)]
+    pub async fn build_endorse_credential_definition_transaction(
+        client: &LedgerClientWrapper,
+        from: &str,
+        id: &str,
+        cred_def: JsValue,
+        signature: JsValue,
+    ) -> Result<TransactionWrapper> {
+        let cred_def: CredentialDefinition = serde_wasm_bindgen::from_value(cred_def)?;
+        let signature: SignatureData = serde_wasm_bindgen::from_value(signature)?;
+        let address = Address::from(from);
+        let id = CredentialDefinitionId::from(id);
+        let transaction =
+            credential_definition_registry::build_endorse_credential_definition_transaction(
+                &client.0, &address, &id, &cred_def, &signature,
+            )
+            .await
+            .as_js()?;
+        Ok(TransactionWrapper(Rc::new(transaction)))
+    }
+
     #[wasm_bindgen(js_name = buildResolveCredentialDefinitionTransaction)]
     pub async fn build_resolve_credential_definition_transaction(
         client: &LedgerClientWrapper,
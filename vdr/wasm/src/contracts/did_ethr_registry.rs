@@ -0,0 +1,395 @@
+use indy2_vdr::{did_ethr_registry, Address, DelegateType, DidDocAttribute, SignatureData, DID};
+use std::{rc::Rc, str::FromStr};
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    client::LedgerClientWrapper,
+    error::{JsResult, Result},
+    transaction::TransactionWrapper,
+};
+
+#[wasm_bindgen(js_name = EthrDidRegistry)]
+pub struct EthrDidRegistry;
+
+#[wasm_bindgen(js_class = EthrDidRegistry)]
+impl EthrDidRegistry {
+    #[wasm_bindgen(js_name = buildDidChangeOwnerTransaction)]
+    pub async fn build_did_change_owner_transaction(
+        client: &LedgerClientWrapper,
+        from: &str,
+        did: &str,
+        new_owner: &str,
+    ) -> Result<TransactionWrapper> {
+        let did = DID::try_from(did).as_js()?;
+        let transaction = did_ethr_registry::build_did_change_owner_transaction(
+            &client.0,
+            &Address::from(from),
+            &did,
+            &Address::from(new_owner),
+        )
+        .await
+        .as_js()?;
+        Ok(TransactionWrapper(Rc::new(transaction)))
+    }
+
+    #[wasm_bindgen(js_name = buildDidChangeOwnerSignedTransaction)]
+    pub async fn build_did_change_owner_signed_transaction(
+        client: &LedgerClientWrapper,
+        from: &str,
+        did: &str,
+        new_owner: &str,
+        signature: JsValue,
+    ) -> Result<TransactionWrapper> {
+        let did = DID::try_from(did).as_js()?;
+        let signature: SignatureData = serde_wasm_bindgen::from_value(signature)?;
+        let transaction = did_ethr_registry::build_did_change_owner_signed_transaction(
+            &client.0,
+            &Address::from(from),
+            &did,
+            &Address::from(new_owner),
+            &signature,
+        )
+        .await
+        .as_js()?;
+        Ok(TransactionWrapper(Rc::new(transaction)))
+    }
+
+    #[wasm_bindgen(js_name = buildDidChangeOwnerSigningBytes)]
+    pub async fn build_did_change_owner_signing_bytes(
+        client: &LedgerClientWrapper,
+        did: &str,
+        new_owner: &str,
+    ) -> Result<Vec<u8>> {
+        let did = DID::try_from(did).as_js()?;
+        let bytes = did_ethr_registry::build_did_change_owner_signing_bytes(
+            &client.0,
+            &did,
+            &Address::from(new_owner),
+        )
+        .await
+        .as_js()?;
+        Ok(bytes)
+    }
+
+    #[wasm_bindgen(js_name = buildDidAddDelegateTransaction)]
+    pub async fn build_did_add_delegate_transaction(
+        client: &LedgerClientWrapper,
+        from: &str,
+        did: &str,
+        delegate_type: &str,
+        delegate: &str,
+        validity: u64,
+    ) -> Result<TransactionWrapper> {
+        let did = DID::try_from(did).as_js()?;
+        let delegate_type = DelegateType::from_str(delegate_type).as_js()?;
+        let transaction = did_ethr_registry::build_did_add_delegate_transaction(
+            &client.0,
+            &Address::from(from),
+            &did,
+            &delegate_type,
+            &Address::from(delegate),
+            validity,
+        )
+        .await
+        .as_js()?;
+        Ok(TransactionWrapper(Rc::new(transaction)))
+    }
+
+    #[wasm_bindgen(js_name = buildDidAddDelegateSignedTransaction)]
+    pub async fn build_did_add_delegate_signed_transaction(
+        client: &LedgerClientWrapper,
+        from: &str,
+        did: &str,
+        delegate_type: &str,
+        delegate: &str,
+        validity: u64,
+        signature: JsValue,
+    ) -> Result<TransactionWrapper> {
+        let did = DID::try_from(did).as_js()?;
+        let delegate_type = DelegateType::from_str(delegate_type).as_js()?;
+        let signature: SignatureData = serde_wasm_bindgen::from_value(signature)?;
+        let transaction = did_ethr_registry::build_did_add_delegate_signed_transaction(
+            &client.0,
+            &Address::from(from),
+            &did,
+            &delegate_type,
+            &Address::from(delegate),
+            validity,
+            &signature,
+        )
+        .await
+        .as_js()?;
+        Ok(TransactionWrapper(Rc::new(transaction)))
+    }
+
+    #[wasm_bindgen(js_name = buildDidAddDelegateSigningBytes)]
+    pub async fn build_did_add_delegate_signing_bytes(
+        client: &LedgerClientWrapper,
+        did: &str,
+        delegate_type: &str,
+        delegate: &str,
+        validity: u64,
+    ) -> Result<Vec<u8>> {
+        let did = DID::try_from(did).as_js()?;
+        let delegate_type = DelegateType::from_str(delegate_type).as_js()?;
+        let bytes = did_ethr_registry::build_did_add_delegate_signing_bytes(
+            &client.0,
+            &did,
+            &delegate_type,
+            &Address::from(delegate),
+            validity,
+        )
+        .await
+        .as_js()?;
+        Ok(bytes)
+    }
+
+    #[wasm_bindgen(js_name = buildDidRevokeDelegateTransaction)]
+    pub async fn build_did_revoke_delegate_transaction(
+        client: &LedgerClientWrapper,
+        from: &str,
+        did: &str,
+        delegate_type: &str,
+        delegate: &str,
+    ) -> Result<TransactionWrapper> {
+        let did = DID::try_from(did).as_js()?;
+        let delegate_type = DelegateType::from_str(delegate_type).as_js()?;
+        let transaction = did_ethr_registry::build_did_revoke_delegate_transaction(
+            &client.0,
+            &Address::from(from),
+            &did,
+            &delegate_type,
+            &Address::from(delegate),
+        )
+        .await
+        .as_js()?;
+        Ok(TransactionWrapper(Rc::new(transaction)))
+    }
+
+    #[wasm_bindgen(js_name = buildDidRevokeDelegateSignedTransaction)]
+    pub async fn build_did_revoke_delegate_signed_transaction(
+        client: &LedgerClientWrapper,
+        from: &str,
+        did: &str,
+        delegate_type: &str,
+        delegate: &str,
+        signature: JsValue,
+    ) -> Result<TransactionWrapper> {
+        let did = DID::try_from(did).as_js()?;
+        let delegate_type = DelegateType::from_str(delegate_type).as_js()?;
+        let signature: SignatureData = serde_wasm_bindgen::from_value(signature)?;
+        let transaction = did_ethr_registry::build_did_revoke_delegate_signed_transaction(
+            &client.0,
+            &Address::from(from),
+            &did,
+            &delegate_type,
+            &Address::from(delegate),
+            &signature,
+        )
+        .await
+        .as_js()?;
+        Ok(TransactionWrapper(Rc::new(transaction)))
+    }
+
+    #[wasm_bindgen(js_name = buildDidRevokeDelegateSigningBytes)]
+    pub async fn build_did_revoke_delegate_signing_bytes(
+        client: &LedgerClientWrapper,
+        did: &str,
+        delegate_type: &str,
+        delegate: &str,
+    ) -> Result<Vec<u8>> {
+        let did = DID::try_from(did).as_js()?;
+        let delegate_type = DelegateType::from_str(delegate_type).as_js()?;
+        let bytes = did_ethr_registry::build_did_revoke_delegate_signing_bytes(
+            &client.0,
+            &did,
+            &delegate_type,
+            &Address::from(delegate),
+        )
+        .await
+        .as_js()?;
+        Ok(bytes)
+    }
+
+    #[wasm_bindgen(js_name = buildDidSetAttributeTransaction)]
+    pub async fn build_did_set_attribute_transaction(
+        client: &LedgerClientWrapper,
+        from: &str,
+        did: &str,
+        attribute: JsValue,
+        validity: u64,
+    ) -> Result<TransactionWrapper> {
+        let did = DID::try_from(did).as_js()?;
+        let attribute: DidDocAttribute = serde_wasm_bindgen::from_value(attribute)?;
+        let transaction = did_ethr_registry::build_did_set_attribute_transaction(
+            &client.0,
+            &Address::from(from),
+            &did,
+            &attribute,
+            validity,
+        )
+        .await
+        .as_js()?;
+        Ok(TransactionWrapper(Rc::new(transaction)))
+    }
+
+    #[wasm_bindgen(js_name = buildDidSetAttributeSignedTransaction)]
+    pub async fn build_did_set_attribute_signed_transaction(
+        client: &LedgerClientWrapper,
+        from: &str,
+        did: &str,
+        attribute: JsValue,
+        validity: u64,
+        signature: JsValue,
+    ) -> Result<TransactionWrapper> {
+        let did = DID::try_from(did).as_js()?;
+        let attribute: DidDocAttribute = serde_wasm_bindgen::from_value(attribute)?;
+        let signature: SignatureData = serde_wasm_bindgen::from_value(signature)?;
+        let transaction = did_ethr_registry::build_did_set_attribute_signed_transaction(
+            &client.0,
+            &Address::from(from),
+            &did,
+            &attribute,
+            validity,
+            &signature,
+        )
+        .await
+        .as_js()?;
+        Ok(TransactionWrapper(Rc::new(transaction)))
+    }
+
+    #[wasm_bindgen(js_name = buildDidSetAttributeSigningBytes)]
+    pub async fn build_did_set_attribute_signing_bytes(
+        client: &LedgerClientWrapper,
+        did: &str,
+        attribute: JsValue,
+        validity: u64,
+    ) -> Result<Vec<u8>> {
+        let did = DID::try_from(did).as_js()?;
+        let attribute: DidDocAttribute = serde_wasm_bindgen::from_value(attribute)?;
+        let bytes = did_ethr_registry::build_did_set_attribute_signing_bytes(
+            &client.0, &did, &attribute, validity,
+        )
+        .await
+        .as_js()?;
+        Ok(bytes)
+    }
+
+    #[wasm_bindgen(js_name = buildDidRevokeAttributeTransaction)]
+    pub async fn build_did_revoke_attribute_transaction(
+        client: &LedgerClientWrapper,
+        from: &str,
+        did: &str,
+        attribute: JsValue,
+    ) -> Result<TransactionWrapper> {
+        let did = DID::try_from(did).as_js()?;
+        let attribute: DidDocAttribute = serde_wasm_bindgen::from_value(attribute)?;
+        let transaction = did_ethr_registry::build_did_revoke_attribute_transaction(
+            &client.0,
+            &Address::from(from),
+            &did,
+            &attribute,
+        )
+        .await
+        .as_js()?;
+        Ok(TransactionWrapper(Rc::new(transaction)))
+    }
+
+    #[wasm_bindgen(js_name = buildDidRevokeAttributeSignedTransaction)]
+    pub async fn build_did_revoke_attribute_signed_transaction(
+        client: &LedgerClientWrapper,
+        from: &str,
+        did: &str,
+        attribute: JsValue,
+        signature: JsValue,
+    ) -> Result<TransactionWrapper> {
+        let did = DID::try_from(did).as_js()?;
+        let attribute: DidDocAttribute = serde_wasm_bindgen::from_value(attribute)?;
+        let signature: SignatureData = serde_wasm_bindgen::from_value(signature)?;
+        let transaction = did_ethr_registry::build_did_revoke_attribute_signed_transaction(
+            &client.0,
+            &Address::from(from),
+            &did,
+            &attribute,
+            &signature,
+        )
+        .await
+        .as_js()?;
+        Ok(TransactionWrapper(Rc::new(transaction)))
+    }
+
+    #[wasm_bindgen(js_name = buildDidRevokeAttributeSigningBytes)]
+    pub async fn build_did_revoke_attribute_signing_bytes(
+        client: &LedgerClientWrapper,
+        did: &str,
+        attribute: JsValue,
+    ) -> Result<Vec<u8>> {
+        let did = DID::try_from(did).as_js()?;
+        let attribute: DidDocAttribute = serde_wasm_bindgen::from_value(attribute)?;
+        let bytes = did_ethr_registry::build_did_revoke_attribute_signing_bytes(
+            &client.0, &did, &attribute,
+        )
+        .await
+        .as_js()?;
+        Ok(bytes)
+    }
+
+    #[wasm_bindgen(js_name = buildGetDidOwnerTransaction)]
+    pub async fn build_get_did_owner_transaction(
+        client: &LedgerClientWrapper,
+        did: &str,
+    ) -> Result<TransactionWrapper> {
+        let did = DID::try_from(did).as_js()?;
+        let transaction = did_ethr_registry::build_get_did_owner_transaction(&client.0, &did)
+            .await
+            .as_js()?;
+        Ok(TransactionWrapper(Rc::new(transaction)))
+    }
+
+    #[wasm_bindgen(js_name = parseDidOwnerResult)]
+    pub fn parse_did_owner_result(client: &LedgerClientWrapper, bytes: Vec<u8>) -> Result<String> {
+        let owner = did_ethr_registry::parse_did_owner_result(&client.0, &bytes).as_js()?;
+        Ok(owner.to_string())
+    }
+
+    #[wasm_bindgen(js_name = buildGetDidChangedTransaction)]
+    pub async fn build_get_did_changed_transaction(
+        client: &LedgerClientWrapper,
+        did: &str,
+    ) -> Result<TransactionWrapper> {
+        let did = DID::try_from(did).as_js()?;
+        let transaction = did_ethr_registry::build_get_did_changed_transaction(&client.0, &did)
+            .await
+            .as_js()?;
+        Ok(TransactionWrapper(Rc::new(transaction)))
+    }
+
+    #[wasm_bindgen(js_name = parseDidChangedResult)]
+    pub fn parse_did_changed_result(client: &LedgerClientWrapper, bytes: Vec<u8>) -> Result<u64> {
+        let changed = did_ethr_registry::parse_did_changed_result(&client.0, &bytes).as_js()?;
+        Ok(changed)
+    }
+
+    #[wasm_bindgen(js_name = buildGetIdentityNonceTransaction)]
+    pub async fn build_get_identity_nonce_transaction(
+        client: &LedgerClientWrapper,
+        account: &str,
+    ) -> Result<TransactionWrapper> {
+        let transaction = did_ethr_registry::build_get_identity_nonce_transaction(
+            &client.0,
+            &Address::from(account),
+        )
+        .await
+        .as_js()?;
+        Ok(TransactionWrapper(Rc::new(transaction)))
+    }
+
+    #[wasm_bindgen(js_name = parseIdentityNonceResult)]
+    pub fn parse_identity_nonce_result(
+        client: &LedgerClientWrapper,
+        bytes: Vec<u8>,
+    ) -> Result<u64> {
+        let nonce = did_ethr_registry::parse_identity_nonce_result(&client.0, &bytes).as_js()?;
+        Ok(nonce)
+    }
+}
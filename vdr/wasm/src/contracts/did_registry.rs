@@ -1,4 +1,4 @@
-use indy2_vdr::{did_registry, Address, DidDocument, DID};
+use indy2_vdr::{did_registry, Address, DidDocument, SignatureData, DID};
 use std::rc::Rc;
 use wasm_bindgen::prelude::*;
 
@@ -24,13 +24,69 @@ impl IndyDidRegistry {
         let did_doc: DidDocument = serde_wasm_bindgen::from_value(did_doc)?;
         let address = Address::from(from);
         let identity = Address::from(identity);
-        let did = DID::from(did);
+        let did = DID::try_from(did).as_js()?;
         let transaction = did_registry::build_create_did_transaction(&client.0, &address, &identity, &did, &did_doc)
             .await
             .as_js()?;
         Ok(TransactionWrapper(Rc::new(transaction)))
     }
 
+    #[wasm_bindgen(js_name = buildCreateMigratedDidTransaction)]
+    pub async fn build_create_migrated_did_transaction(
+        client: &LedgerClientWrapper,
+        from: &str,
+        identity: &str,
+        did: &str,
+        did_doc: JsValue,
+    ) -> Result<TransactionWrapper> {
+        let did_doc: DidDocument = serde_wasm_bindgen::from_value(did_doc)?;
+        let address = Address::from(from);
+        let identity = Address::from(identity);
+        let did = DID::try_from(did).as_js()?;
+        let transaction =
+            did_registry::build_create_migrated_did_transaction(&client.0, &address, &identity, &did, &did_doc)
+                .await
+                .as_js()?;
+        Ok(TransactionWrapper(Rc::new(transaction)))
+    }
+
+    #[wasm_bindgen(js_name = buildEndorseDidSigningBytes)]
+    pub async fn build_endorse_did_signing_bytes(
+        client: &LedgerClientWrapper,
+        identity: &str,
+        did: &str,
+        did_doc: JsValue,
+    ) -> Result<Vec<u8>> {
+        let did_doc: DidDocument = serde_wasm_bindgen::from_value(did_doc)?;
+        let identity = Address::from(identity);
+        let did = DID::try_from(did).as_js()?;
+        let bytes =
+            did_registry::build_endorse_did_signing_bytes(&client.0, &identity, &did, &did_doc)
+                .await
+                .as_js()?;
+        Ok(bytes)
+    }
+
+    #[wasm_bindgen(js_name = buildEndorseDidTransaction)]
+    pub async fn build_endorse_did_transaction(
+        client: &LedgerClientWrapper,
+        from: &str,
+        identity: &str,
+        did: &str,
+        did_doc: JsValue,
+        signature: JsValue,
+    ) -> Result<TransactionWrapper> {
+        let did_doc: DidDocument = serde_wasm_bindgen::from_value(did_doc)?;
+        let signature: SignatureData = serde_wasm_bindgen::from_value(signature)?;
+        let address = Address::from(from);
+        let identity = Address::from(identity);
+        let did = DID::try_from(did).as_js()?;
+        let transaction = did_registry::build_endorse_did_transaction(&client.0, &address, &identity, &did, &did_doc, &signature)
+            .await
+            .as_js()?;
+        Ok(TransactionWrapper(Rc::new(transaction)))
+    }
+
     #[wasm_bindgen(js_name = buildUpdateDidTransaction)]
     pub async fn build_update_did_transaction(
         client: &LedgerClientWrapper,
@@ -40,7 +96,7 @@ impl IndyDidRegistry {
     ) -> Result<TransactionWrapper> {
         let did_doc: DidDocument = serde_wasm_bindgen::from_value(did_doc)?;
         let address = Address::from(from);
-        let did = DID::from(did);
+        let did = DID::try_from(did).as_js()?;
         let transaction = did_registry::build_update_did_transaction(&client.0, &address, &did, &did_doc)
             .await
             .as_js()?;
@@ -54,7 +110,7 @@ impl IndyDidRegistry {
         did: &str,
     ) -> Result<TransactionWrapper> {
         let address = Address::from(from);
-        let did = DID::from(did);
+        let did = DID::try_from(did).as_js()?;
         let transaction = did_registry::build_deactivate_did_transaction(&client.0, &address, &did)
             .await
             .as_js()?;
@@ -66,7 +122,7 @@ impl IndyDidRegistry {
         client: &LedgerClientWrapper,
         did: &str,
     ) -> Result<TransactionWrapper> {
-        let did = DID::from(did);
+        let did = DID::try_from(did).as_js()?;
         let transaction = did_registry::build_resolve_did_transaction(&client.0, &did)
             .await
             .as_js()?;
@@ -0,0 +1,35 @@
+use indy2_vdr::{did_resolver, DidResolutionOptions};
+use wasm_bindgen::prelude::*;
+
+use crate::{client::LedgerClientWrapper, error::Result};
+
+#[wasm_bindgen(js_name = DidResolver)]
+pub struct DidResolver;
+
+#[wasm_bindgen(js_class = DidResolver)]
+impl DidResolver {
+    #[wasm_bindgen(js_name = resolve)]
+    pub async fn resolve(
+        client: &LedgerClientWrapper,
+        did: &str,
+        options: JsValue,
+    ) -> Result<JsValue> {
+        let options: Option<DidResolutionOptions> = serde_wasm_bindgen::from_value(options)?;
+        let resolution_result = did_resolver::resolve(&client.0, did, options.as_ref()).await;
+        let result: JsValue = serde_wasm_bindgen::to_value(&resolution_result)?;
+        Ok(result)
+    }
+
+    #[wasm_bindgen(js_name = dereference)]
+    pub async fn dereference(
+        client: &LedgerClientWrapper,
+        did_url: &str,
+        options: JsValue,
+    ) -> Result<JsValue> {
+        let options: Option<DidResolutionOptions> = serde_wasm_bindgen::from_value(options)?;
+        let dereferencing_result =
+            did_resolver::dereference(&client.0, did_url, options.as_ref()).await;
+        let result: JsValue = serde_wasm_bindgen::to_value(&dereferencing_result)?;
+        Ok(result)
+    }
+}
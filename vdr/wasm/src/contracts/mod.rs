@@ -1,5 +1,8 @@
 pub mod credential_definition_registry;
+pub mod did_ethr_registry;
 pub mod did_registry;
+pub mod did_resolver;
 pub mod role_control;
 pub mod schema_registry;
+pub mod universal_did_resolver;
 pub mod validator_control;
@@ -1,4 +1,4 @@
-use indy2_vdr::{schema_registry, Address, Schema, SchemaId};
+use indy2_vdr::{schema_registry, Address, Schema, SchemaId, SignatureData};
 use std::rc::Rc;
 use wasm_bindgen::prelude::*;
 
@@ -30,6 +30,44 @@ impl SchemaRegistry {
         Ok(TransactionWrapper(Rc::new(transaction)))
     }
 
+    #[wasm_bindgen(js_name = buildEndorseSchemaSigningBytes)]
+    pub async fn build_endorse_schema_signing_bytes(
+        client: &LedgerClientWrapper,
+        identity: &str,
+        id: &str,
+        schema: JsValue,
+    ) -> Result<Vec<u8>> {
+        let schema: Schema = serde_wasm_bindgen::from_value(schema)?;
+        let identity = Address::from(identity);
+        let id = SchemaId::from(id);
+        let bytes = schema_registry::build_endorse_schema_signing_bytes(
+            &client.0, &identity, &id, &schema,
+        )
+        .await
+        .as_js()?;
+        Ok(bytes)
+    }
+
+    #[wasm_bindgen(js_name = buildEndorseSchemaTransaction)]
+    pub async fn build_endorse_schema_transaction(
+        client: &LedgerClientWrapper,
+        from: &str,
+        id: &str,
+        schema: JsValue,
+        signature: JsValue,
+    ) -> Result<TransactionWrapper> {
+        let schema: Schema = serde_wasm_bindgen::from_value(schema)?;
+        let signature: SignatureData = serde_wasm_bindgen::from_value(signature)?;
+        let address = Address::from(from);
+        let id = SchemaId::from(id);
+        let transaction = schema_registry::build_endorse_schema_transaction(
+            &client.0, &address, &id, &schema, &signature,
+        )
+        .await
+        .as_js()?;
+        Ok(TransactionWrapper(Rc::new(transaction)))
+    }
+
     #[wasm_bindgen(js_name = buildResolveSchemaTransaction)]
     pub async fn build_resolve_schema_transaction(
         client: &LedgerClientWrapper,
@@ -0,0 +1,69 @@
+use indy2_vdr::{universal_did_resolver, DID};
+use std::rc::Rc;
+use wasm_bindgen::prelude::*;
+
+use crate::{
+    client::LedgerClientWrapper,
+    error::{JsResult, Result},
+    transaction::TransactionWrapper,
+};
+
+#[wasm_bindgen(js_name = UniversalDidResolver)]
+pub struct UniversalDidResolver;
+
+#[wasm_bindgen(js_class = UniversalDidResolver)]
+impl UniversalDidResolver {
+    #[wasm_bindgen(js_name = buildResolveDocumentTransaction)]
+    pub async fn build_resolve_document_transaction(
+        client: &LedgerClientWrapper,
+        did: &str,
+    ) -> Result<TransactionWrapper> {
+        let did = DID::try_from(did).as_js()?;
+        let transaction = universal_did_resolver::build_resolve_document_transaction(&client.0, &did)
+            .await
+            .as_js()?;
+        Ok(TransactionWrapper(Rc::new(transaction)))
+    }
+
+    #[wasm_bindgen(js_name = parseResolveDocumentResult)]
+    pub fn parse_resolve_document_result(
+        client: &LedgerClientWrapper,
+        bytes: Vec<u8>,
+    ) -> Result<JsValue> {
+        let did_doc = universal_did_resolver::parse_resolve_document_result(&client.0, &bytes).as_js()?;
+        let result: JsValue = serde_wasm_bindgen::to_value(&did_doc)?;
+        Ok(result)
+    }
+
+    #[wasm_bindgen(js_name = buildResolveMetadataTransaction)]
+    pub async fn build_resolve_metadata_transaction(
+        client: &LedgerClientWrapper,
+        did: &str,
+    ) -> Result<TransactionWrapper> {
+        let did = DID::try_from(did).as_js()?;
+        let transaction = universal_did_resolver::build_resolve_metadata_transaction(&client.0, &did)
+            .await
+            .as_js()?;
+        Ok(TransactionWrapper(Rc::new(transaction)))
+    }
+
+    #[wasm_bindgen(js_name = parseResolveMetadataResult)]
+    pub fn parse_resolve_metadata_result(
+        client: &LedgerClientWrapper,
+        bytes: Vec<u8>,
+    ) -> Result<JsValue> {
+        let metadata = universal_did_resolver::parse_resolve_metadata_result(&client.0, &bytes).as_js()?;
+        let result: JsValue = serde_wasm_bindgen::to_value(&metadata)?;
+        Ok(result)
+    }
+
+    #[wasm_bindgen(js_name = resolveAnyDid)]
+    pub async fn resolve_any_did(client: &LedgerClientWrapper, did: &str) -> Result<JsValue> {
+        let did = DID::try_from(did).as_js()?;
+        let did_doc = universal_did_resolver::resolve_any_did(&client.0, &did)
+            .await
+            .as_js()?;
+        let result: JsValue = serde_wasm_bindgen::to_value(&did_doc)?;
+        Ok(result)
+    }
+}
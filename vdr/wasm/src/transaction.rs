@@ -2,13 +2,42 @@ use indy2_vdr::{SignatureData, Transaction};
 use std::rc::Rc;
 use wasm_bindgen::prelude::*;
 
-use crate::error::Result;
+use crate::error::{JsResult, Result};
 
 #[wasm_bindgen(js_name = Transaction)]
 pub struct TransactionWrapper(pub(crate) Rc<Transaction>);
 
 #[wasm_bindgen(js_class = Transaction)]
 impl TransactionWrapper {
+    #[wasm_bindgen(js_name = fromJson)]
+    pub fn from_json(json: &str) -> Result<TransactionWrapper> {
+        let transaction = Transaction::from_json(json).as_js()?;
+        Ok(TransactionWrapper(Rc::new(transaction)))
+    }
+
+    #[wasm_bindgen(js_name = fromBytes)]
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<TransactionWrapper> {
+        let transaction = Transaction::from_bytes(&bytes).as_js()?;
+        Ok(TransactionWrapper(Rc::new(transaction)))
+    }
+
+    #[wasm_bindgen(js_name = toJson)]
+    pub fn to_json(&self) -> Result<String> {
+        let json = self.0.to_json().as_js()?;
+        Ok(json)
+    }
+
+    #[wasm_bindgen(js_name = toBytes)]
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let bytes = self.0.to_bytes().as_js()?;
+        Ok(bytes)
+    }
+
+    pub fn call(&self) -> Result<JsValue> {
+        let call: JsValue = serde_wasm_bindgen::to_value(&self.0.call)?;
+        Ok(call)
+    }
+
     pub fn to(&self) -> Result<String> {
         Ok(self.0.to.to_string())
     }
@@ -25,4 +54,10 @@ impl TransactionWrapper {
         self.0.set_signature(signature_data);
         Ok(())
     }
+
+    #[wasm_bindgen(js_name = recoverSigner)]
+    pub fn recover_signer(&self) -> Result<String> {
+        let signer = self.0.recover_signer().as_js()?;
+        Ok(signer.to_string())
+    }
 }
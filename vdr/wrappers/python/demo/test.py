@@ -1,6 +1,8 @@
 import asyncio
+import hashlib
 import json
-import os
+from cryptography.hazmat.primitives import serialization
+from cryptography.hazmat.primitives.asymmetric.ed25519 import Ed25519PrivateKey
 from eth_keys import keys
 
 import base58
@@ -27,12 +29,15 @@ async def demo():
     contract_configs = [
         ContractConfig(did_contact_address, did_contact_spec_path, None)
     ]
-    client = LedgerClient(chain_id, node_address, contract_configs, None)
+    client = LedgerClient(chain_id, node_address, contract_configs, None, None)
     status = await client.ping()
     print('Status: ' + str(status))
 
     print("2. Publish DID Document")
-    did = 'did:indy2:testnet:' + str(base58.b58encode(os.urandom(16)).decode())
+    # did:indy2 identifier is derived from the Ed25519 verkey: Base58(Truncate_msb(16(SHA256(verkey))))
+    verkey = Ed25519PrivateKey.generate().public_key().public_bytes(
+        serialization.Encoding.Raw, serialization.PublicFormat.Raw)
+    did = 'did:indy2:testnet:' + base58.b58encode(hashlib.sha256(verkey).digest()[:16]).decode()
     kid = did + '#KEY-1'
     did_doc = {
         "@context": ["https://www.w3.org/ns/did/v1"],
@@ -41,9 +46,9 @@ async def demo():
         "verificationMethod": [
             {
                 "id": kid,
-                "type": "EcdsaSecp256k1VerificationKey2019",
+                "type": "Ed25519VerificationKey2018",
                 "controller": did,
-                "publicKeyMultibase": "zQ3shnKp9QFbmV6Xj4YkoCg23DryaxNMTCJikSezYwLibafef"
+                "publicKeyBase58": base58.b58encode(verkey).decode()
             }
         ],
         "authentication": [kid],